@@ -0,0 +1,258 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    Timestamp,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{
+    Bounty, BountyStatus, Config, Submission, BOUNTIES, BOUNTY_COUNT, CONFIG, SUBMISSIONS,
+};
+
+use archway_bindings::{ArchwayQuery, ArchwayResult};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:pantheon-bounty";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ArchwayResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let admin = deps.api.addr_validate(&msg.admin)?;
+    let config = Config { admin };
+    CONFIG.save(deps.storage, &config)?;
+
+    BOUNTY_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::new().add_attribute("admin", config.admin))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ArchwayResult<ContractError> {
+    match msg {
+        ExecuteMsg::PostBounty {
+            title,
+            description,
+            amount,
+            deadline,
+        } => execute_post_bounty(deps, env, info, title, description, amount, deadline),
+        ExecuteMsg::Submit { bounty_id, content } => {
+            execute_submit(deps, env, info, bounty_id, content)
+        }
+        ExecuteMsg::ApproveSubmission {
+            bounty_id,
+            claimant,
+        } => execute_approve_submission(deps, env, info, bounty_id, claimant),
+        ExecuteMsg::CancelBounty { bounty_id } => execute_cancel_bounty(deps, env, info, bounty_id),
+    }
+}
+
+fn execute_post_bounty(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    amount: Vec<Coin>,
+    deadline: Timestamp,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only the admin can post a bounty
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if amount.is_empty() || amount.iter().any(|coin| coin.amount.is_zero()) {
+        return Err(ContractError::EmptyBountyAmount {});
+    }
+
+    if deadline <= env.block.time {
+        return Err(ContractError::InvalidDeadline {});
+    }
+
+    let id = BOUNTY_COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+    let bounty = Bounty {
+        id,
+        title,
+        description,
+        amount,
+        deadline,
+        status: BountyStatus::Open,
+        winner: None,
+    };
+    BOUNTIES.save(deps.storage, id, &bounty)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "post_bounty")
+        .add_attribute("bounty_id", id.to_string()))
+}
+
+fn execute_submit(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    bounty_id: u64,
+    content: String,
+) -> ArchwayResult<ContractError> {
+    let bounty = BOUNTIES.load(deps.storage, bounty_id)?;
+
+    if bounty.status != BountyStatus::Open {
+        return Err(ContractError::BountyNotOpen {});
+    }
+
+    if env.block.time > bounty.deadline {
+        return Err(ContractError::DeadlinePassed {});
+    }
+
+    // Submitting again overwrites the previous submission of the claimant
+    let submission = Submission {
+        claimant: info.sender.clone(),
+        content,
+        time: env.block.time,
+    };
+    SUBMISSIONS.save(deps.storage, (bounty_id, info.sender.clone()), &submission)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "submit")
+        .add_attribute("bounty_id", bounty_id.to_string())
+        .add_attribute("claimant", info.sender))
+}
+
+fn execute_approve_submission(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    bounty_id: u64,
+    claimant: String,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut bounty = BOUNTIES.load(deps.storage, bounty_id)?;
+
+    if bounty.status != BountyStatus::Open {
+        return Err(ContractError::BountyNotOpen {});
+    }
+
+    let claimant = deps.api.addr_validate(&claimant)?;
+    SUBMISSIONS.load(deps.storage, (bounty_id, claimant.clone()))?;
+
+    bounty.status = BountyStatus::Paid;
+    bounty.winner = Some(claimant.clone());
+    BOUNTIES.save(deps.storage, bounty_id, &bounty)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: claimant.to_string(),
+            amount: bounty.amount,
+        })
+        .add_attribute("action", "approve_submission")
+        .add_attribute("bounty_id", bounty_id.to_string())
+        .add_attribute("claimant", claimant))
+}
+
+fn execute_cancel_bounty(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    bounty_id: u64,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut bounty = BOUNTIES.load(deps.storage, bounty_id)?;
+
+    if bounty.status != BountyStatus::Open {
+        return Err(ContractError::BountyNotOpen {});
+    }
+
+    bounty.status = BountyStatus::Cancelled;
+    BOUNTIES.save(deps.storage, bounty_id, &bounty)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_bounty")
+        .add_attribute("bounty_id", bounty_id.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Bounty { id } => to_binary(&BOUNTIES.load(deps.storage, id)?),
+        QueryMsg::Bounties { start_after, limit } => {
+            to_binary(&query_bounties(deps, start_after, limit)?)
+        }
+        QueryMsg::Submissions {
+            bounty_id,
+            start_after,
+            limit,
+        } => to_binary(&query_submissions(deps, bounty_id, start_after, limit)?),
+    }
+}
+
+fn query_bounties(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Bounty>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let bounties = BOUNTIES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, bounty) = item?;
+            Ok(bounty)
+        })
+        .collect::<StdResult<Vec<Bounty>>>()?;
+
+    Ok(bounties)
+}
+
+fn query_submissions(
+    deps: Deps<ArchwayQuery>,
+    bounty_id: u64,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Submission>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let submissions = SUBMISSIONS
+        .prefix(bounty_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, submission) = item?;
+            Ok(submission)
+        })
+        .collect::<StdResult<Vec<Submission>>>()?;
+
+    Ok(submissions)
+}
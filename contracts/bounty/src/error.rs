@@ -0,0 +1,23 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Bounty amount cannot be empty")]
+    EmptyBountyAmount {},
+
+    #[error("Deadline must be in the future")]
+    InvalidDeadline {},
+
+    #[error("Bounty is not open")]
+    BountyNotOpen {},
+
+    #[error("Bounty deadline has passed")]
+    DeadlinePassed {},
+}
@@ -0,0 +1,58 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, Timestamp};
+
+use crate::state::{Bounty, Config, Submission};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Post a new bounty, paid from the contract balance once approved
+    PostBounty {
+        title: String,
+        description: String,
+        amount: Vec<Coin>,
+        deadline: Timestamp,
+    },
+    // Submit work for an open bounty
+    Submit {
+        bounty_id: u64,
+        content: String,
+    },
+    // Approve a submission and pay the bounty to its claimant
+    ApproveSubmission {
+        bounty_id: u64,
+        claimant: String,
+    },
+    // Cancel an open bounty
+    CancelBounty {
+        bounty_id: u64,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Returns the contract config
+    #[returns(Config)]
+    Config {},
+    // Returns a single bounty
+    #[returns(Bounty)]
+    Bounty { id: u64 },
+    // Returns all the bounties
+    #[returns(Vec<Bounty>)]
+    Bounties {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+    // Returns the submissions of a bounty
+    #[returns(Vec<Submission>)]
+    Submissions {
+        bounty_id: u64,
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+}
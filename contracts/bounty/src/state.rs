@@ -0,0 +1,45 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+// Contract configuration
+#[cw_serde]
+pub struct Config {
+    pub admin: Addr,
+}
+
+#[cw_serde]
+pub enum BountyStatus {
+    Open,
+    Paid,
+    Cancelled,
+}
+
+// Bounty posted by the admin
+// Submissions are accepted until the deadline
+#[cw_serde]
+pub struct Bounty {
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub amount: Vec<Coin>,
+    pub deadline: Timestamp,
+    pub status: BountyStatus,
+    pub winner: Option<Addr>,
+}
+
+// Work submitted by a claimant for a bounty
+#[cw_serde]
+pub struct Submission {
+    pub claimant: Addr,
+    pub content: String,
+    pub time: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+pub const BOUNTY_COUNT: Item<u64> = Item::new("bounty_count");
+
+pub const BOUNTIES: Map<u64, Bounty> = Map::new("bounties");
+
+pub const SUBMISSIONS: Map<(u64, Addr), Submission> = Map::new("submissions");
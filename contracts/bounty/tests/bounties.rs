@@ -0,0 +1,132 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_bounty::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::{Bounty, BountyStatus},
+    ContractError,
+};
+
+#[test]
+fn test_happy_path() {
+    let mut app = mock_app();
+    let bounty_addr = proper_instantiate(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        bounty_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let deadline = app.block_info().time.plus_seconds(DEADLINE);
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        bounty_addr.clone(),
+        &ExecuteMsg::PostBounty {
+            title: "Docs".to_string(),
+            description: "Write the splitter docs".to_string(),
+            amount: coins(600, DENOM),
+            deadline,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        bounty_addr.clone(),
+        &ExecuteMsg::Submit {
+            bounty_id: 1,
+            content: "https://example.com/docs".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        bounty_addr.clone(),
+        &ExecuteMsg::ApproveSubmission {
+            bounty_id: 1,
+            claimant: USER.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(600));
+
+    let res: Bounty = app
+        .wrap()
+        .query_wasm_smart(bounty_addr, &QueryMsg::Bounty { id: 1 })
+        .unwrap();
+    assert_eq!(res.status, BountyStatus::Paid);
+    assert_eq!(res.winner, Some(Addr::unchecked(USER)));
+}
+
+#[test]
+fn test_deadline_passed() {
+    let mut app = mock_app();
+    let bounty_addr = proper_instantiate(&mut app);
+
+    let deadline = app.block_info().time.plus_seconds(DEADLINE);
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        bounty_addr.clone(),
+        &ExecuteMsg::PostBounty {
+            title: "Docs".to_string(),
+            description: "Write the splitter docs".to_string(),
+            amount: coins(600, DENOM),
+            deadline,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(DEADLINE + 1));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            bounty_addr.clone(),
+            &ExecuteMsg::Submit {
+                bounty_id: 1,
+                content: "https://example.com/docs".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::DeadlinePassed {}.to_string()
+    )
+}
+
+#[test]
+fn test_invalid_admin() {
+    let mut app = mock_app();
+    let bounty_addr = proper_instantiate(&mut app);
+
+    let deadline = app.block_info().time.plus_seconds(DEADLINE);
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            bounty_addr.clone(),
+            &ExecuteMsg::PostBounty {
+                title: "Docs".to_string(),
+                description: "Write the splitter docs".to_string(),
+                amount: coins(600, DENOM),
+                deadline,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    )
+}
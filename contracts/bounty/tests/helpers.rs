@@ -0,0 +1,68 @@
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{testing::MockApi, Addr, Coin, MemoryStorage, Uint128};
+use cw_multi_test::{
+    custom_app, App, BankKeeper, Contract, ContractWrapper, Executor, FailingModule, WasmKeeper,
+};
+use pantheon_bounty::msg::InstantiateMsg;
+
+pub fn bounty_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(
+        pantheon_bounty::contract::execute,
+        pantheon_bounty::contract::instantiate,
+        pantheon_bounty::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub const ADMIN: &str = "admin";
+pub const USER: &str = "user";
+
+pub const DENOM: &str = "aconst";
+
+pub const DEADLINE: u64 = 604_800;
+
+pub fn mock_app() -> App<
+    BankKeeper,
+    MockApi,
+    MemoryStorage,
+    FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+    WasmKeeper<ArchwayMsg, ArchwayQuery>,
+> {
+    custom_app::<ArchwayMsg, ArchwayQuery, _>(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(ADMIN),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: Uint128::new(1_000_000_000_000_000_000),
+                }],
+            )
+            .unwrap();
+    })
+}
+
+pub fn proper_instantiate(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MemoryStorage,
+        FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+        WasmKeeper<ArchwayMsg, ArchwayQuery>,
+    >,
+) -> Addr {
+    let code_id = app.store_code(bounty_contract());
+
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(ADMIN),
+        &InstantiateMsg {
+            admin: ADMIN.to_string(),
+        },
+        &vec![],
+        "Pantheon Bounty",
+        None,
+    )
+    .unwrap()
+}
@@ -0,0 +1,299 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    coins, to_binary, BankMsg, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Order, Reply,
+    Response, StdResult, SubMsg, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{Campaign, CampaignStatus, Config, CAMPAIGN, CONFIG, CONTRIBUTIONS};
+
+use archway_bindings::{ArchwayQuery, ArchwayResult};
+
+use pantheon_factory::msg::{ExecuteMsg as FactoryExecuteMsg, QueryMsg as FactoryQueryMsg};
+use pantheon_factory::state::Config as FactoryConfig;
+use pantheon_utils::Share;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:pantheon-crowdfund";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const CREATE_SPLITTER_REPLY_ID: u64 = 1;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ArchwayResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.deadline <= env.block.time {
+        return Err(ContractError::InvalidDeadline {});
+    }
+
+    let config = Config {
+        beneficiary: deps.api.addr_validate(&msg.beneficiary)?,
+        factory: deps.api.addr_validate(&msg.factory)?,
+        denom: msg.denom,
+        goal: msg.goal,
+        deadline: msg.deadline,
+        splitter_label: msg.splitter_label,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let campaign = Campaign {
+        status: CampaignStatus::Open,
+        total: Uint128::zero(),
+        splitter: None,
+    };
+    CAMPAIGN.save(deps.storage, &campaign)?;
+
+    Ok(Response::new().add_attribute("beneficiary", config.beneficiary))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ArchwayResult<ContractError> {
+    match msg {
+        ExecuteMsg::Contribute {} => execute_contribute(deps, env, info),
+        ExecuteMsg::Finalize {} => execute_finalize(deps, env, info),
+        ExecuteMsg::Refund {} => execute_refund(deps, env, info),
+    }
+}
+
+fn execute_contribute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if env.block.time >= config.deadline {
+        return Err(ContractError::FundingClosed {});
+    }
+
+    if info.funds.len() != 1
+        || info.funds[0].denom != config.denom
+        || info.funds[0].amount.is_zero()
+    {
+        return Err(ContractError::InvalidContribution {
+            denom: config.denom,
+        });
+    }
+    let amount = info.funds[0].amount;
+
+    CONTRIBUTIONS.update(
+        deps.storage,
+        info.sender.clone(),
+        |contribution| -> StdResult<_> {
+            Ok(contribution.unwrap_or_default().checked_add(amount)?)
+        },
+    )?;
+
+    let mut campaign = CAMPAIGN.load(deps.storage)?;
+    campaign.total = campaign.total.checked_add(amount)?;
+    CAMPAIGN.save(deps.storage, &campaign)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "contribute")
+        .add_attribute("contributor", info.sender)
+        .add_attribute("amount", amount))
+}
+
+fn execute_finalize(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut campaign = CAMPAIGN.load(deps.storage)?;
+
+    if campaign.status != CampaignStatus::Open {
+        return Err(ContractError::InvalidStatus {
+            status: CampaignStatus::Open.to_string(),
+        });
+    }
+
+    if env.block.time < config.deadline {
+        return Err(ContractError::FundingOpen {});
+    }
+
+    // Contributors can claim a refund if the goal is not reached
+    if campaign.total.is_zero() || campaign.total < config.goal {
+        campaign.status = CampaignStatus::Failed;
+        CAMPAIGN.save(deps.storage, &campaign)?;
+
+        return Ok(Response::new()
+            .add_attribute("action", "finalize")
+            .add_attribute("status", campaign.status.to_string()));
+    }
+
+    campaign.status = CampaignStatus::Succeeded;
+    CAMPAIGN.save(deps.storage, &campaign)?;
+
+    let shares = contribution_shares(deps.as_ref(), campaign.total)?;
+
+    // The creation fee of the factory is paid from the raised funds when they are in the same denom
+    // Otherwise the caller has to send it, so the campaign can always be finalized
+    let factory_config: FactoryConfig = deps
+        .querier
+        .query_wasm_smart(config.factory.to_string(), &FactoryQueryMsg::Config {})?;
+    let (creation_fee, raised) = match factory_config.creation_fee {
+        Some(fee) if fee.denom == config.denom && fee.amount <= campaign.total => {
+            let raised = campaign.total.checked_sub(fee.amount)?;
+            (vec![fee], raised)
+        }
+        Some(fee) => {
+            if info.funds != [fee.clone()] {
+                return Err(ContractError::InvalidCreationFee {
+                    fee: fee.to_string(),
+                });
+            }
+            (info.funds, campaign.total)
+        }
+        None => (vec![], campaign.total),
+    };
+
+    // Shares of the backers can not be changed after the campaign
+    let create_splitter = WasmMsg::Execute {
+        contract_addr: config.factory.to_string(),
+        msg: to_binary(&FactoryExecuteMsg::CreateSplitter {
+            shares,
+            mutable: false,
+            transfer_requires_approval: false,
+            label: config.splitter_label,
+            tags: vec![],
+            retain_admin: false,
+            referral_code: None,
+            salt: None,
+        })?,
+        funds: creation_fee,
+    };
+
+    let mut response = Response::new().add_submessage(SubMsg::reply_on_success(
+        create_splitter,
+        CREATE_SPLITTER_REPLY_ID,
+    ));
+    if !raised.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: config.beneficiary.to_string(),
+            amount: coins(raised.u128(), config.denom),
+        });
+    }
+
+    Ok(response
+        .add_attribute("action", "finalize")
+        .add_attribute("status", campaign.status.to_string()))
+}
+
+fn execute_refund(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let campaign = CAMPAIGN.load(deps.storage)?;
+
+    if campaign.status != CampaignStatus::Failed {
+        return Err(ContractError::InvalidStatus {
+            status: CampaignStatus::Failed.to_string(),
+        });
+    }
+
+    let amount = CONTRIBUTIONS
+        .may_load(deps.storage, info.sender.clone())?
+        .unwrap_or_default();
+    if amount.is_zero() {
+        return Err(ContractError::NothingToRefund {});
+    }
+    CONTRIBUTIONS.remove(deps.storage, info.sender.clone());
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(amount.u128(), config.denom),
+        })
+        .add_attribute("action", "refund")
+        .add_attribute("contributor", info.sender)
+        .add_attribute("amount", amount))
+}
+
+// Calculates the share of every contributor
+// Rounding dust is given to the last contributor so the total is exactly 100%
+fn contribution_shares(deps: Deps<ArchwayQuery>, total: Uint128) -> StdResult<Vec<Share>> {
+    let mut shares = CONTRIBUTIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (contributor, amount) = item?;
+            Ok(Share {
+                recipient: contributor.to_string(),
+                percentage: Decimal::from_ratio(amount, total),
+                ibc: None,
+                burn: false,
+            })
+        })
+        .collect::<StdResult<Vec<Share>>>()?;
+
+    let allocated = shares
+        .iter()
+        .fold(Decimal::zero(), |acc, share| acc + share.percentage);
+    if let Some(last) = shares.last_mut() {
+        last.percentage += Decimal::one() - allocated;
+    }
+
+    Ok(shares)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut<ArchwayQuery>, _env: Env, msg: Reply) -> ArchwayResult<ContractError> {
+    match msg.id {
+        CREATE_SPLITTER_REPLY_ID => {
+            let res = msg
+                .result
+                .into_result()
+                .map_err(|_| ContractError::SplitterCreationError {})?;
+
+            // Reading the splitter address from the instantiate event
+            let splitter = res
+                .events
+                .iter()
+                .filter(|event| event.ty == "instantiate")
+                .flat_map(|event| event.attributes.iter())
+                .find(|attribute| attribute.key == "_contract_address")
+                .map(|attribute| attribute.value.clone())
+                .ok_or(ContractError::SplitterCreationError {})?;
+
+            let mut campaign = CAMPAIGN.load(deps.storage)?;
+            campaign.splitter = Some(deps.api.addr_validate(&splitter)?);
+            CAMPAIGN.save(deps.storage, &campaign)?;
+
+            Ok(Response::new().add_attribute("splitter", splitter))
+        }
+        _ => Err(ContractError::SplitterCreationError {}),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Campaign {} => to_binary(&CAMPAIGN.load(deps.storage)?),
+        QueryMsg::Contribution { address } => to_binary(&query_contribution(deps, address)?),
+    }
+}
+
+fn query_contribution(deps: Deps<ArchwayQuery>, address: String) -> StdResult<Uint128> {
+    let address = deps.api.addr_validate(&address)?;
+    let contribution = CONTRIBUTIONS
+        .may_load(deps.storage, address)?
+        .unwrap_or_default();
+    Ok(contribution)
+}
@@ -0,0 +1,35 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Contribution must be sent in {denom}")]
+    InvalidContribution { denom: String },
+
+    #[error("Funding window is closed")]
+    FundingClosed {},
+
+    #[error("Funding window is still open")]
+    FundingOpen {},
+
+    #[error("Deadline must be in the future")]
+    InvalidDeadline {},
+
+    #[error("Campaign is not in the {status} status")]
+    InvalidStatus { status: String },
+
+    #[error("Nothing to refund")]
+    NothingToRefund {},
+
+    #[error("Invalid creation fee of the splitter, expected {fee}")]
+    InvalidCreationFee { fee: String },
+
+    #[error("Could not create splitter")]
+    SplitterCreationError {},
+}
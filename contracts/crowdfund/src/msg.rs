@@ -0,0 +1,40 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Timestamp, Uint128};
+
+use crate::state::{Campaign, Config};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub beneficiary: String,
+    pub factory: String,
+    pub denom: String,
+    pub goal: Uint128,
+    pub deadline: Timestamp,
+    pub splitter_label: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Contribute the sent funds to the campaign
+    Contribute {},
+    // Close the campaign after the deadline
+    // On success a splitter is created with shares proportional to the contributions
+    // The creation fee of the factory is taken from the raised funds, or sent by the caller in another denom
+    Finalize {},
+    // Refund the contribution of the sender if the campaign failed
+    Refund {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Returns the contract config
+    #[returns(Config)]
+    Config {},
+    // Returns the campaign status
+    #[returns(Campaign)]
+    Campaign {},
+    // Returns the contribution of an address
+    #[returns(Uint128)]
+    Contribution { address: String },
+}
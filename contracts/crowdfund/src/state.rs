@@ -0,0 +1,46 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+// Contract configuration
+// Beneficiary receives the raised funds when the campaign succeeds
+// Factory is used to create the splitter for the contributors
+#[cw_serde]
+pub struct Config {
+    pub beneficiary: Addr,
+    pub factory: Addr,
+    pub denom: String,
+    pub goal: Uint128,
+    pub deadline: Timestamp,
+    pub splitter_label: String,
+}
+
+#[cw_serde]
+pub enum CampaignStatus {
+    Open,
+    Succeeded,
+    Failed,
+}
+
+impl std::fmt::Display for CampaignStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CampaignStatus::Open => write!(f, "open"),
+            CampaignStatus::Succeeded => write!(f, "succeeded"),
+            CampaignStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Campaign {
+    pub status: CampaignStatus,
+    pub total: Uint128,
+    pub splitter: Option<Addr>,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+pub const CAMPAIGN: Item<Campaign> = Item::new("campaign");
+
+pub const CONTRIBUTIONS: Map<Addr, Uint128> = Map::new("contributions");
@@ -0,0 +1,139 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_crowdfund::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::{Campaign, CampaignStatus},
+    ContractError,
+};
+
+#[test]
+fn test_failed_campaign_refund() {
+    let mut app = mock_app();
+    let crowdfund_addr = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        crowdfund_addr.clone(),
+        &ExecuteMsg::Contribute {},
+        &coins(300, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER2),
+        crowdfund_addr.clone(),
+        &ExecuteMsg::Contribute {},
+        &coins(200, DENOM),
+    )
+    .unwrap();
+
+    let res: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            crowdfund_addr.clone(),
+            &QueryMsg::Contribution {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Uint128::new(300));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            crowdfund_addr.clone(),
+            &ExecuteMsg::Finalize {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::FundingOpen {}.to_string()
+    );
+
+    app.update_block(|block| block.time = block.time.plus_seconds(DURATION));
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        crowdfund_addr.clone(),
+        &ExecuteMsg::Finalize {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Campaign = app
+        .wrap()
+        .query_wasm_smart(crowdfund_addr.clone(), &QueryMsg::Campaign {})
+        .unwrap();
+    assert_eq!(res.status, CampaignStatus::Failed);
+    assert_eq!(res.total, Uint128::new(500));
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        crowdfund_addr.clone(),
+        &ExecuteMsg::Refund {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_000_000));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            crowdfund_addr.clone(),
+            &ExecuteMsg::Refund {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToRefund {}.to_string()
+    )
+}
+
+#[test]
+fn test_funding_closed() {
+    let mut app = mock_app();
+    let crowdfund_addr = proper_instantiate(&mut app);
+
+    app.update_block(|block| block.time = block.time.plus_seconds(DURATION));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            crowdfund_addr.clone(),
+            &ExecuteMsg::Contribute {},
+            &coins(300, DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::FundingClosed {}.to_string()
+    )
+}
+
+#[test]
+fn test_invalid_contribution() {
+    let mut app = mock_app();
+    let crowdfund_addr = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            crowdfund_addr.clone(),
+            &ExecuteMsg::Contribute {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidContribution {
+            denom: DENOM.to_string()
+        }
+        .to_string()
+    )
+}
@@ -0,0 +1,215 @@
+pub mod helpers;
+use helpers::*;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coin, coins, from_binary, testing::MockApi, to_binary, Addr, Binary, Coin, Decimal, Deps,
+    DepsMut, Empty, Env, MemoryStorage, MessageInfo, Response, StdError, StdResult, Uint128,
+    WasmMsg,
+};
+use cw_multi_test::{App, BankKeeper, ContractWrapper, Executor, FailingModule, WasmKeeper};
+use cw_storage_plus::Item;
+use pantheon_crowdfund::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::{Campaign, CampaignStatus},
+    ContractError,
+};
+use pantheon_factory::{
+    msg::{ExecuteMsg as FactoryExecuteMsg, QueryMsg as FactoryQueryMsg},
+    state::Config as FactoryConfig,
+};
+
+type CrowdfundApp = App<
+    BankKeeper,
+    MockApi,
+    MemoryStorage,
+    FailingModule<ArchwayMsg, ArchwayQuery, Empty>,
+    WasmKeeper<ArchwayMsg, ArchwayQuery>,
+>;
+
+const OTHER_DENOM: &str = "uother";
+
+// Stand-in for the factory that charges the creation fee like the real one
+// multi-test can not execute the instantiate2 and archway messages of the factory
+#[cw_serde]
+struct MockFactoryInstantiateMsg {
+    creation_fee: Option<Coin>,
+    splitter_code_id: u64,
+}
+
+const MOCK_FACTORY: Item<MockFactoryInstantiateMsg> = Item::new("mock_factory");
+
+fn mock_factory_instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: MockFactoryInstantiateMsg,
+) -> StdResult<Response<ArchwayMsg>> {
+    MOCK_FACTORY.save(deps.storage, &msg)?;
+    Ok(Response::new())
+}
+
+fn mock_factory_execute(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    msg: FactoryExecuteMsg,
+) -> StdResult<Response<ArchwayMsg>> {
+    let factory = MOCK_FACTORY.load(deps.storage)?;
+    match msg {
+        FactoryExecuteMsg::CreateSplitter { label, .. } => {
+            if let Some(fee) = factory.creation_fee {
+                if info.funds != [fee.clone()] {
+                    return Err(StdError::generic_err(format!(
+                        "Invalid creation fee, expected {}",
+                        fee
+                    )));
+                }
+            }
+            Ok(Response::new().add_message(WasmMsg::Instantiate {
+                admin: None,
+                code_id: factory.splitter_code_id,
+                msg: to_binary(&MockFactoryInstantiateMsg {
+                    creation_fee: None,
+                    splitter_code_id: factory.splitter_code_id,
+                })?,
+                funds: vec![],
+                label,
+            }))
+        }
+        _ => Err(StdError::generic_err("unsupported")),
+    }
+}
+
+fn mock_factory_query(deps: Deps<ArchwayQuery>, _env: Env, msg: Binary) -> StdResult<Binary> {
+    match from_binary(&msg)? {
+        FactoryQueryMsg::Config {} => to_binary(&FactoryConfig {
+            quarantine_address: None,
+            referral_percentage: Decimal::zero(),
+            creation_fee: MOCK_FACTORY.load(deps.storage)?.creation_fee,
+            fee_splitter: None,
+            fee_threshold: None,
+            rewards_splitter: None,
+        }),
+        _ => Err(StdError::generic_err("unsupported")),
+    }
+}
+
+fn mock_factory(app: &mut CrowdfundApp, creation_fee: Option<Coin>) -> Addr {
+    let code_id = app.store_code(Box::new(ContractWrapper::new(
+        mock_factory_execute,
+        mock_factory_instantiate,
+        mock_factory_query,
+    )));
+
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(ADMIN),
+        &MockFactoryInstantiateMsg {
+            creation_fee,
+            splitter_code_id: code_id,
+        },
+        &vec![],
+        "Pantheon Factory",
+        None,
+    )
+    .unwrap()
+}
+
+fn fund_campaign(app: &mut CrowdfundApp, crowdfund_addr: &Addr) {
+    app.execute_contract(
+        Addr::unchecked(USER),
+        crowdfund_addr.clone(),
+        &ExecuteMsg::Contribute {},
+        &coins(600, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER2),
+        crowdfund_addr.clone(),
+        &ExecuteMsg::Contribute {},
+        &coins(400, DENOM),
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(DURATION));
+}
+
+#[test]
+fn test_finalize_pays_creation_fee_from_raised_funds() {
+    let mut app = mock_app();
+    let factory_addr = mock_factory(&mut app, Some(coin(100, DENOM)));
+    let crowdfund_addr = instantiate_with_factory(&mut app, &factory_addr);
+    fund_campaign(&mut app, &crowdfund_addr);
+
+    let before = app.wrap().query_balance(ADMIN, DENOM).unwrap().amount;
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        crowdfund_addr.clone(),
+        &ExecuteMsg::Finalize {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Campaign = app
+        .wrap()
+        .query_wasm_smart(crowdfund_addr.clone(), &QueryMsg::Campaign {})
+        .unwrap();
+    assert_eq!(res.status, CampaignStatus::Succeeded);
+    assert!(res.splitter.is_some());
+
+    // The fee is deducted from the funds of the beneficiary
+    let res = app.wrap().query_balance(factory_addr, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(100));
+    let res = app.wrap().query_balance(ADMIN, DENOM).unwrap();
+    assert_eq!(res.amount - before, Uint128::new(900));
+}
+
+#[test]
+fn test_finalize_creation_fee_in_other_denom() {
+    let mut app = mock_app();
+    app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &Addr::unchecked(USER), coins(100, OTHER_DENOM))
+            .unwrap();
+    });
+    let factory_addr = mock_factory(&mut app, Some(coin(100, OTHER_DENOM)));
+    let crowdfund_addr = instantiate_with_factory(&mut app, &factory_addr);
+    fund_campaign(&mut app, &crowdfund_addr);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            crowdfund_addr.clone(),
+            &ExecuteMsg::Finalize {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidCreationFee {
+            fee: coin(100, OTHER_DENOM).to_string()
+        }
+        .to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        crowdfund_addr.clone(),
+        &ExecuteMsg::Finalize {},
+        &coins(100, OTHER_DENOM),
+    )
+    .unwrap();
+
+    let res: Campaign = app
+        .wrap()
+        .query_wasm_smart(crowdfund_addr, &QueryMsg::Campaign {})
+        .unwrap();
+    assert_eq!(res.status, CampaignStatus::Succeeded);
+
+    let res = app.wrap().query_balance(factory_addr, OTHER_DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(100));
+}
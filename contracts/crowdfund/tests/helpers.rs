@@ -0,0 +1,112 @@
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{testing::MockApi, Addr, Coin, MemoryStorage, Uint128};
+use cw_multi_test::{
+    custom_app, App, BankKeeper, Contract, ContractWrapper, Executor, FailingModule, WasmKeeper,
+};
+use pantheon_crowdfund::msg::InstantiateMsg;
+
+pub fn crowdfund_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(
+        pantheon_crowdfund::contract::execute,
+        pantheon_crowdfund::contract::instantiate,
+        pantheon_crowdfund::contract::query,
+    )
+    .with_reply(pantheon_crowdfund::contract::reply);
+    Box::new(contract)
+}
+
+pub const ADMIN: &str = "admin";
+pub const USER: &str = "user";
+pub const USER2: &str = "user2";
+
+pub const DENOM: &str = "aconst";
+pub const FACTORY: &str = "factory";
+
+pub const DURATION: u64 = 604_800;
+
+pub fn mock_app() -> App<
+    BankKeeper,
+    MockApi,
+    MemoryStorage,
+    FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+    WasmKeeper<ArchwayMsg, ArchwayQuery>,
+> {
+    custom_app::<ArchwayMsg, ArchwayQuery, _>(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(ADMIN),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: Uint128::new(1_000_000_000_000_000_000),
+                }],
+            )
+            .unwrap();
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(USER),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: Uint128::new(1_000_000),
+                }],
+            )
+            .unwrap();
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(USER2),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: Uint128::new(1_000_000),
+                }],
+            )
+            .unwrap();
+    })
+}
+
+pub fn proper_instantiate(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MemoryStorage,
+        FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+        WasmKeeper<ArchwayMsg, ArchwayQuery>,
+    >,
+) -> Addr {
+    instantiate_with_factory(app, &Addr::unchecked(FACTORY))
+}
+
+pub fn instantiate_with_factory(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MemoryStorage,
+        FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+        WasmKeeper<ArchwayMsg, ArchwayQuery>,
+    >,
+    factory: &Addr,
+) -> Addr {
+    let code_id = app.store_code(crowdfund_contract());
+    let deadline = app.block_info().time.plus_seconds(DURATION);
+
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(ADMIN),
+        &InstantiateMsg {
+            beneficiary: ADMIN.to_string(),
+            factory: factory.to_string(),
+            denom: DENOM.to_string(),
+            goal: Uint128::new(1_000),
+            deadline,
+            splitter_label: "Backers Splitter".to_string(),
+        },
+        &vec![],
+        "Pantheon Crowdfund",
+        None,
+    )
+    .unwrap()
+}
@@ -12,4 +12,8 @@ pub enum ExecuteMsg {
 
 #[cw_serde]
 #[derive(QueryResponses)]
-pub enum QueryMsg {}
+pub enum QueryMsg {
+    // Returns the number of increments
+    #[returns(u64)]
+    Counter {},
+}
@@ -1,39 +1,119 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    instantiate2_address, to_binary, Binary, CodeInfoResponse, Deps, DepsMut, Env, MessageInfo,
-    Response, StdResult, WasmMsg,
+    coin, from_binary, instantiate2_address, to_binary, Addr, Api, BankMsg, Binary,
+    CodeInfoResponse, Coin, Decimal, Deps, DepsMut, Empty, Env, Event, MessageInfo, Order,
+    QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
-use cw2::set_contract_version;
+use cw2::{ensure_from_older_version, set_contract_version};
+use cw_ownable::{get_ownership, initialize_owner, update_ownership, Action};
+use cw_storage_plus::Bound;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Config, CONFIG, SPLITTER_CODE_ID};
+use crate::ica::{register_interchain_account, set_remote_contract_metadata};
+use crate::msg::{
+    ChildHealthResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, OutstandingRewardsResponse,
+    QueryMsg,
+};
+use crate::state::{
+    Child, ChildrenMigration, Config, InterchainAccount, PendingContractWithSplitter, Proposal,
+    QueuedAction, Timelock, ARCHIVED_CHILDREN, CHILDREN, CHILDREN_MIGRATION, CHILD_TAGS,
+    COLLECTED_FEES, CONFIG, INTERCHAIN_ACCOUNTS, LAST_PROPOSAL_ID, LEGACY_CONFIG,
+    PENDING_CONTRACT_WITH_SPLITTER, PENDING_MIGRATIONS, PENDING_REWARDS_SPLITTER, PROPOSALS, RBAC,
+    REFERRAL_CODES, REFERRAL_REWARDS, SPLITTER_CODE_ID, TIMELOCK, WITHDRAWN_REWARDS,
+};
 
-use archway_bindings::{ArchwayMsg, ArchwayQuery, ArchwayResult};
+use archway_bindings::types::rewards::{
+    ContractMetadataResponse, RewardsRecordsResponse, WithdrawRewardsResponse,
+};
+use archway_bindings::{ArchwayMsg, ArchwayQuery, ArchwayResult, PageRequest};
 
-use pantheon_splitter::msg::InstantiateMsg as SplitterInstantiateMsg;
+use pantheon_splitter::msg::{
+    ExecuteMsg as SplitterExecuteMsg, InstantiateMsg as SplitterInstantiateMsg,
+};
+use pantheon_utils::premium::set_premium;
 use pantheon_utils::Share;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:pantheon-factory";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const WITHDRAW_REPLY_ID: u64 = 1;
+const MIGRATE_REPLY_ID: u64 = 2;
+const REWARDS_SPLITTER_REPLY_ID: u64 = 3;
+const CUSTOM_CONTRACT_REPLY_ID: u64 = 4;
+const CUSTOM_SPLITTER_REPLY_ID: u64 = 5;
+
+// Longest salt a creator can choose, it is hashed with the sender before Instantiate2
+const MAX_SALT_LENGTH: usize = 64;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut<ArchwayQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> ArchwayResult<ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    let config = Config { admin: info.sender };
+    initialize_owner(deps.storage, deps.api, Some(info.sender.as_str()))?;
+
+    let config = Config {
+        quarantine_address: None,
+        referral_percentage: Decimal::zero(),
+        creation_fee: None,
+        fee_splitter: None,
+        fee_threshold: None,
+        rewards_splitter: None,
+    };
     CONFIG.save(deps.storage, &config)?;
 
     SPLITTER_CODE_ID.save(deps.storage, &msg.splitter_code_id)?;
 
-    Ok(Response::new().add_attribute("admin", config.admin))
+    let response = match msg.rewards_splitter {
+        Some(splitter) => create_rewards_splitter(deps, &env, splitter.shares, splitter.label)?,
+        None => Response::new(),
+    };
+
+    Ok(response.add_attribute("admin", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> ArchwayResult<ContractError> {
+    // Rejects other contracts and downgrades before touching the state
+    ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // The legacy config shares its key with the current one, a config in the current layout or
+    // no config has nothing to convert, and a config that reads as neither fails the migration
+    if CONFIG.load(deps.storage).is_ok() {
+        return Ok(Response::new().add_attribute("action", "migrate"));
+    }
+    let legacy = match LEGACY_CONFIG.may_load(deps.storage)? {
+        Some(legacy) => legacy,
+        None => return Ok(Response::new().add_attribute("action", "migrate")),
+    };
+
+    initialize_owner(deps.storage, deps.api, Some(legacy.admin.as_str()))?;
+
+    let config = Config {
+        quarantine_address: None,
+        referral_percentage: Decimal::zero(),
+        creation_fee: None,
+        fee_splitter: None,
+        fee_threshold: None,
+        rewards_splitter: None,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("admin", legacy.admin))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -50,9 +130,410 @@ pub fn execute(
         ExecuteMsg::CreateSplitter {
             shares,
             mutable,
+            transfer_requires_approval,
+            label,
+            tags,
+            retain_admin,
+            referral_code,
+            salt,
+        } => execute_create_splitter(
+            deps,
+            env,
+            info,
+            shares,
+            mutable,
+            transfer_requires_approval,
+            label,
+            tags,
+            retain_admin,
+            referral_code,
+            salt,
+        ),
+        ExecuteMsg::CreateContractWithSplitter {
+            code_id,
+            msg,
+            label,
+            shares,
+            mutable,
+            transfer_requires_approval,
+            tags,
+            retain_admin,
+            referral_code,
+            salt,
+        } => execute_create_contract_with_splitter(
+            deps,
+            env,
+            info,
+            code_id,
+            msg,
             label,
-        } => execute_create_splitter(deps, env, info, shares, mutable, label),
+            shares,
+            mutable,
+            transfer_requires_approval,
+            tags,
+            retain_admin,
+            referral_code,
+            salt,
+        ),
+        ExecuteMsg::RegisterContract { address } => {
+            execute_register_contract(deps, env, info, address)
+        }
+        ExecuteMsg::DeregisterChild { address } => {
+            execute_deregister_child(deps, env, info, address)
+        }
+        ExecuteMsg::UpdateCreationFee { fee } => execute_update_creation_fee(deps, info, fee),
+        ExecuteMsg::UpdateFeeSplitter { address, threshold } => {
+            execute_update_fee_splitter(deps, info, address, threshold)
+        }
+        ExecuteMsg::ForwardFees {} => execute_forward_fees(deps),
+        ExecuteMsg::RegisterReferralCode { code } => {
+            execute_register_referral_code(deps, info, code)
+        }
+        ExecuteMsg::UpdateReferralPercentage { percentage } => {
+            execute_update_referral_percentage(deps, info, percentage)
+        }
+        ExecuteMsg::ClaimReferralRewards {} => execute_claim_referral_rewards(deps, info),
+        ExecuteMsg::TransferChildAdmin { address, new_admin } => {
+            execute_transfer_child_admin(deps, env, info, address, new_admin)
+        }
+        ExecuteMsg::UpdateChildPremium { address, fee } => {
+            execute_update_child_premium(deps, env, info, address, fee)
+        }
+        ExecuteMsg::UpdateChildTags { address, tags } => {
+            execute_update_child_tags(deps, info, address, tags)
+        }
+        ExecuteMsg::SuspendTag { tag } => execute_suspend_tag(deps, env, info, tag),
+        ExecuteMsg::WithdrawTagRewards { tag } => {
+            let addresses = tag_children(deps.as_ref(), &tag)?
+                .into_iter()
+                .map(|address| address.to_string())
+                .collect();
+            execute_withdraw_child_rewards(deps, env, info, addresses)
+        }
+        ExecuteMsg::MigrateTag { tag, code_id, msg } => {
+            execute_migrate_tag(deps, info, tag, code_id, msg)
+        }
+        ExecuteMsg::MigrateChildren {
+            code_id_from,
+            code_id_to,
+            msg,
+            limit,
+        } => execute_migrate_children(deps, info, code_id_from, code_id_to, msg, limit),
+        ExecuteMsg::CreateRewardsSplitter { shares, label } => {
+            execute_create_rewards_splitter(deps, env, info, shares, label)
+        }
+        ExecuteMsg::UpdateQuarantineAddress { address } => {
+            execute_update_quarantine_address(deps, info, address)
+        }
+        ExecuteMsg::SuspendChild { address } => execute_suspend_child(deps, env, info, address),
+        ExecuteMsg::ResumeChild { address } => execute_resume_child(deps, env, info, address),
+        ExecuteMsg::WithdrawChildRewards { addresses } => {
+            execute_withdraw_child_rewards(deps, env, info, addresses)
+        }
+        ExecuteMsg::RegisterInterchainAccount { connection_id } => {
+            execute_register_interchain_account(deps, env, info, connection_id)
+        }
+        ExecuteMsg::SetInterchainAccountAddress {
+            connection_id,
+            address,
+        } => execute_set_interchain_account_address(deps, info, connection_id, address),
+        ExecuteMsg::UpdateOwnership(action) => execute_update_ownership(deps, env, info, action),
+        ExecuteMsg::GrantRole { role, address } => execute_grant_role(deps, info, role, address),
+        ExecuteMsg::RevokeRole { role, address } => execute_revoke_role(deps, info, role, address),
+        ExecuteMsg::UpdateRoleActions { role, actions } => {
+            execute_update_role_actions(deps, info, role, actions)
+        }
+        ExecuteMsg::UpdateTimelock { delay, guardian } => {
+            execute_update_timelock(deps, info, delay, guardian)
+        }
+        ExecuteMsg::ProposeAction { action } => execute_propose_action(deps, env, info, action),
+        ExecuteMsg::ExecuteProposal { id } => execute_execute_proposal(deps, env, info, id),
+        ExecuteMsg::CancelProposal { id } => execute_cancel_proposal(deps, info, id),
+        ExecuteMsg::UpdateRemoteContractMetadata {
+            connection_id,
+            contract_address,
+            owner_address,
+            rewards_address,
+            timeout_seconds,
+        } => execute_update_remote_contract_metadata(
+            deps,
+            env,
+            info,
+            connection_id,
+            contract_address,
+            owner_address,
+            rewards_address,
+            timeout_seconds,
+        ),
+    }
+}
+
+fn execute_update_ownership(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    action: Action,
+) -> ArchwayResult<ContractError> {
+    let ownership = update_ownership(deps.into_empty(), &env.block, &info.sender, action)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes()))
+}
+
+// The owner can run every action, the members of a role only the actions permitted to the role
+fn assert_allowed(storage: &dyn Storage, sender: &Addr, action: &str) -> Result<(), ContractError> {
+    let owner = get_ownership(storage)?.owner;
+    RBAC.assert_allowed(storage, owner.as_ref(), sender, action)?;
+
+    Ok(())
+}
+
+// Rewards of the children are sent to the owner, or to the factory once the ownership is renounced
+fn rewards_address(deps: Deps<ArchwayQuery>, env: &Env) -> StdResult<Addr> {
+    if let Some(rewards_splitter) = CONFIG.load(deps.storage)?.rewards_splitter {
+        return Ok(rewards_splitter);
+    }
+
+    Ok(get_ownership(deps.storage)?
+        .owner
+        .unwrap_or_else(|| env.contract.address.clone()))
+}
+
+fn execute_grant_role(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    role: String,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_roles")?;
+
+    let address = deps.api.addr_validate(&address)?;
+    RBAC.grant_role(deps.storage, &role, &address)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("role", role)
+        .add_attribute("address", address))
+}
+
+fn execute_revoke_role(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    role: String,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_roles")?;
+
+    let address = deps.api.addr_validate(&address)?;
+    RBAC.revoke_role(deps.storage, &role, &address);
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("role", role)
+        .add_attribute("address", address))
+}
+
+fn execute_update_role_actions(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    role: String,
+    actions: Vec<String>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_roles")?;
+
+    RBAC.set_role_actions(deps.storage, &role, &actions)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_role_actions")
+        .add_attribute("role", role)
+        .add_attribute("actions", actions.join(",")))
+}
+
+// Sensitive admin actions have to go through the proposal queue while the timelock is set
+fn assert_not_timelocked(storage: &dyn Storage) -> Result<(), ContractError> {
+    if TIMELOCK.exists(storage) {
+        return Err(ContractError::TimelockRequired {});
+    }
+
+    Ok(())
+}
+
+fn queued_action_name(action: &QueuedAction) -> &'static str {
+    match action {
+        QueuedAction::UpdateSplitterCodeId { .. } => "update_splitter_code_id",
+        QueuedAction::UpdateReferralPercentage { .. } => "update_referral_percentage",
+        QueuedAction::MigrateTag { .. } => "migrate_tag",
+        QueuedAction::UpdateTimelock { .. } | QueuedAction::RemoveTimelock {} => "update_timelock",
+        QueuedAction::MigrateChildren { .. } => "migrate_children",
+        QueuedAction::CreateRewardsSplitter { .. } => "create_rewards_splitter",
+        QueuedAction::UpdateCreationFee { .. } => "update_creation_fee",
+        QueuedAction::UpdateFeeSplitter { .. } => "update_fee_splitter",
+        QueuedAction::TransferChildAdmin { .. } => "transfer_child_admin",
+    }
+}
+
+// Validates a queued action when it is proposed so invalid actions are not waiting for the delay
+fn check_queued_action(
+    deps: Deps<ArchwayQuery>,
+    action: &QueuedAction,
+) -> Result<(), ContractError> {
+    match action {
+        QueuedAction::UpdateReferralPercentage { percentage } if *percentage > Decimal::one() => {
+            Err(ContractError::InvalidReferralPercentage {})
+        }
+        QueuedAction::UpdateTimelock { guardian, .. } => {
+            deps.api.addr_validate(guardian)?;
+            Ok(())
+        }
+        QueuedAction::CreateRewardsSplitter { shares, label } => {
+            validate_splitter_params(deps, shares, label)
+        }
+        QueuedAction::UpdateCreationFee { fee: Some(fee) } if fee.amount.is_zero() => {
+            Err(ContractError::InvalidCreationFee {
+                fee: fee.to_string(),
+            })
+        }
+        QueuedAction::UpdateFeeSplitter {
+            address: Some(address),
+            ..
+        } => {
+            deps.api.addr_validate(address)?;
+            Ok(())
+        }
+        QueuedAction::TransferChildAdmin { address, new_admin } => {
+            load_child(deps, address)?;
+            deps.api.addr_validate(new_admin)?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn execute_update_timelock(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    delay: u64,
+    guardian: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_timelock")?;
+    assert_not_timelocked(deps.storage)?;
+
+    save_timelock(deps, delay, guardian)
+}
+
+fn save_timelock(
+    deps: DepsMut<ArchwayQuery>,
+    delay: u64,
+    guardian: String,
+) -> ArchwayResult<ContractError> {
+    let guardian = deps.api.addr_validate(&guardian)?;
+    TIMELOCK.save(deps.storage, &Timelock { delay, guardian })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_timelock")
+        .add_attribute("delay", delay.to_string()))
+}
+
+fn execute_propose_action(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    action: QueuedAction,
+) -> ArchwayResult<ContractError> {
+    // Proposing needs the same permission as running the action directly
+    assert_allowed(deps.storage, &info.sender, queued_action_name(&action))?;
+
+    let timelock = TIMELOCK
+        .may_load(deps.storage)?
+        .ok_or(ContractError::TimelockNotSet {})?;
+    check_queued_action(deps.as_ref(), &action)?;
+
+    let id = LAST_PROPOSAL_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    LAST_PROPOSAL_ID.save(deps.storage, &id)?;
+    let proposal = Proposal {
+        id,
+        proposer: info.sender,
+        action,
+        executable_at: env.block.time.plus_seconds(timelock.delay),
+    };
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_action")
+        .add_attribute("id", id.to_string())
+        .add_attribute("proposed_action", queued_action_name(&proposal.action))
+        .add_attribute("executable_at", proposal.executable_at.to_string()))
+}
+
+fn execute_execute_proposal(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ArchwayResult<ContractError> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    assert_allowed(
+        deps.storage,
+        &info.sender,
+        queued_action_name(&proposal.action),
+    )?;
+
+    if env.block.time < proposal.executable_at {
+        return Err(ContractError::ProposalNotReady {});
+    }
+    PROPOSALS.remove(deps.storage, id);
+
+    let response = match proposal.action {
+        QueuedAction::UpdateSplitterCodeId { code_id } => update_splitter_code_id(deps, code_id)?,
+        QueuedAction::UpdateReferralPercentage { percentage } => {
+            update_referral_percentage(deps, percentage)?
+        }
+        QueuedAction::MigrateTag { tag, code_id, msg } => migrate_tag(deps, tag, code_id, msg)?,
+        QueuedAction::UpdateTimelock { delay, guardian } => save_timelock(deps, delay, guardian)?,
+        QueuedAction::RemoveTimelock {} => {
+            TIMELOCK.remove(deps.storage);
+            Response::new().add_attribute("action", "remove_timelock")
+        }
+        QueuedAction::MigrateChildren {
+            code_id_from,
+            code_id_to,
+            msg,
+            limit,
+        } => migrate_children(deps, code_id_from, code_id_to, msg, limit)?,
+        QueuedAction::CreateRewardsSplitter { shares, label } => {
+            create_rewards_splitter(deps, &env, shares, label)?
+        }
+        QueuedAction::UpdateCreationFee { fee } => update_creation_fee(deps, fee)?,
+        QueuedAction::UpdateFeeSplitter { address, threshold } => {
+            update_fee_splitter(deps, address, threshold)?
+        }
+        QueuedAction::TransferChildAdmin { address, new_admin } => {
+            transfer_child_admin(deps, &env, address, new_admin)?
+        }
+    };
+
+    Ok(response.add_attribute("proposal_id", id.to_string()))
+}
+
+fn execute_cancel_proposal(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    id: u64,
+) -> ArchwayResult<ContractError> {
+    let is_guardian = TIMELOCK
+        .may_load(deps.storage)?
+        .map_or(false, |timelock| timelock.guardian == info.sender);
+    if !is_guardian {
+        assert_allowed(deps.storage, &info.sender, "cancel_proposal")?;
     }
+
+    PROPOSALS.load(deps.storage, id)?;
+    PROPOSALS.remove(deps.storage, id);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_proposal")
+        .add_attribute("id", id.to_string()))
 }
 
 fn execute_update_splitter_code_id(
@@ -60,41 +541,82 @@ fn execute_update_splitter_code_id(
     info: MessageInfo,
     code_id: u64,
 ) -> ArchwayResult<ContractError> {
-    let config = CONFIG.load(deps.storage)?;
-    if config.admin != info.sender {
-        return Err(ContractError::Unauthorized {});
-    }
+    assert_allowed(deps.storage, &info.sender, "update_splitter_code_id")?;
+    assert_not_timelocked(deps.storage)?;
+
+    update_splitter_code_id(deps, code_id)
+}
 
+fn update_splitter_code_id(
+    deps: DepsMut<ArchwayQuery>,
+    code_id: u64,
+) -> ArchwayResult<ContractError> {
     SPLITTER_CODE_ID.save(deps.storage, &code_id)?;
 
     Ok(Response::new().add_attribute("action", "update_splitter_code_id"))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn execute_create_splitter(
     deps: DepsMut<ArchwayQuery>,
     env: Env,
     info: MessageInfo,
     shares: Vec<Share>,
     mutable: bool,
+    transfer_requires_approval: bool,
     label: String,
+    tags: Vec<String>,
+    retain_admin: bool,
+    referral_code: Option<String>,
+    salt: Option<Binary>,
 ) -> ArchwayResult<ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    let referrer = load_referrer(deps.storage, referral_code)?;
+    collect_creation_fee(deps.storage, &config, &info.funds, referrer.as_ref())?;
+
     let code_id = SPLITTER_CODE_ID.load(deps.storage)?;
 
+    // Invalid parameters are rejected here instead of failing the instantiation of the child
+    validate_splitter_params(deps.as_ref(), &shares, &label)?;
+
     let msg = to_binary(&SplitterInstantiateMsg {
         shares,
         mutable,
         admin: info.sender.to_string(),
+        transfer_requires_approval,
+        disable_emergency_withdraw: false,
+        restore: None,
     })?;
 
-    let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
-    let CodeInfoResponse { checksum, .. } = deps.querier.query_wasm_code_info(code_id)?;
-    let salt = to_binary(&format!("{}{}", env.block.height, info.sender))?;
-    let address = deps
-        .api
-        .addr_humanize(&instantiate2_address(&checksum, &creator, &salt)?)?;
+    let salt = child_salt(deps.api, &env, &info.sender, salt)?;
+    let address = child_address(deps.as_ref(), &env, code_id, &salt)?;
 
-    Ok(Response::new()
+    let admin = if retain_admin {
+        env.contract.address.clone()
+    } else {
+        info.sender.clone()
+    };
+
+    CHILDREN.save(
+        deps.storage,
+        address.clone(),
+        &Child {
+            address: address.clone(),
+            creator: info.sender.clone(),
+            admin: admin.clone(),
+            label: label.clone(),
+            suspended: false,
+            tags: vec![],
+            referrer,
+            registered: false,
+            archived_at: None,
+            premium: None,
+        },
+    )?;
+    save_child_tags(deps.storage, &address, tags)?;
+
+    // The factory keeps the metadata ownership so it can suspend the child
+    let mut response = Response::new()
         .add_message(WasmMsg::Instantiate2 {
             admin: Some(env.contract.address.to_string()),
             code_id,
@@ -105,23 +627,1516 @@ fn execute_create_splitter(
         })
         .add_message(ArchwayMsg::UpdateContractMetadata {
             contract_address: Some(address.to_string()),
-            owner_address: Some(config.admin.to_string()),
-            rewards_address: Some(config.admin.to_string()),
-        })
-        .add_message(WasmMsg::UpdateAdmin {
+            owner_address: Some(env.contract.address.to_string()),
+            rewards_address: Some(rewards_address(deps.as_ref(), &env)?.to_string()),
+        });
+    if !retain_admin {
+        response = response.add_message(WasmMsg::UpdateAdmin {
             contract_addr: address.to_string(),
-            admin: info.sender.to_string(),
-        }))
+            admin: admin.to_string(),
+        });
+    }
+
+    if let Some(msg) = fee_threshold_msg(deps.storage, config)? {
+        response = response.add_message(msg);
+    }
+
+    Ok(response)
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps<ArchwayQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::SplitterCodeID {} => to_binary(&query_splitter_code_id(deps, env)?),
+// The custom contract is instantiated first, its reply instantiates the splitter and the reply of
+// the splitter wires the rewards of the contract to it, so a failing step reverts the whole creation
+#[allow(clippy::too_many_arguments)]
+fn execute_create_contract_with_splitter(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    code_id: u64,
+    msg: Binary,
+    label: String,
+    shares: Vec<Share>,
+    mutable: bool,
+    transfer_requires_approval: bool,
+    tags: Vec<String>,
+    retain_admin: bool,
+    referral_code: Option<String>,
+    salt: Option<Binary>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let referrer = load_referrer(deps.storage, referral_code)?;
+    collect_creation_fee(deps.storage, &config, &info.funds, referrer.as_ref())?;
+
+    let splitter_code_id = SPLITTER_CODE_ID.load(deps.storage)?;
+    validate_splitter_params(deps.as_ref(), &shares, &label)?;
+
+    let salt = child_salt(deps.api, &env, &info.sender, salt)?;
+    let contract = child_address(deps.as_ref(), &env, code_id, &salt)?;
+
+    // Each contract has its own splitter, even when both use the same code
+    let splitter_salt = Binary::from(deps.api.addr_canonicalize(contract.as_str())?.as_slice());
+    let splitter = child_address(deps.as_ref(), &env, splitter_code_id, &splitter_salt)?;
+
+    let splitter_msg = to_binary(&SplitterInstantiateMsg {
+        shares,
+        mutable,
+        admin: info.sender.to_string(),
+        transfer_requires_approval,
+        disable_emergency_withdraw: false,
+        restore: None,
+    })?;
+
+    let admin = if retain_admin {
+        env.contract.address.clone()
+    } else {
+        info.sender.clone()
+    };
+
+    PENDING_CONTRACT_WITH_SPLITTER.save(
+        deps.storage,
+        &PendingContractWithSplitter {
+            creator: info.sender,
+            admin,
+            contract: contract.clone(),
+            label: label.clone(),
+            splitter: splitter.clone(),
+            splitter_code_id,
+            splitter_msg,
+            splitter_salt,
+            tags,
+            referrer,
+        },
+    )?;
+
+    let mut response = Response::new()
+        .add_submessage(SubMsg::reply_on_success(
+            WasmMsg::Instantiate2 {
+                admin: Some(env.contract.address.to_string()),
+                code_id,
+                msg,
+                funds: vec![],
+                label,
+                salt,
+            },
+            CUSTOM_CONTRACT_REPLY_ID,
+        ))
+        .add_attribute("action", "create_contract_with_splitter")
+        .add_attribute("contract", contract)
+        .add_attribute("splitter", splitter);
+
+    if let Some(msg) = fee_threshold_msg(deps.storage, config)? {
+        response = response.add_message(msg);
     }
+
+    Ok(response)
 }
 
-fn query_splitter_code_id(deps: Deps<ArchwayQuery>, _env: Env) -> StdResult<u64> {
+// Only the exact creation fee is accepted
+// The referrer of the child is credited with its part of the fee, the rest is kept for the fee splitter
+fn collect_creation_fee(
+    storage: &mut dyn Storage,
+    config: &Config,
+    funds: &[Coin],
+    referrer: Option<&Addr>,
+) -> Result<(), ContractError> {
+    if let Some(fee) = &config.creation_fee {
+        if funds != [fee.clone()] {
+            return Err(ContractError::InvalidCreationFee {
+                fee: fee.to_string(),
+            });
+        }
+
+        let mut collected_fee = fee.amount;
+        if let Some(referrer) = referrer {
+            let referral = fee.amount * config.referral_percentage;
+            collected_fee = collected_fee.checked_sub(referral)?;
+
+            if !referral.is_zero() {
+                REFERRAL_REWARDS.update(
+                    storage,
+                    (referrer.clone(), fee.denom.clone()),
+                    |credited| -> StdResult<_> {
+                        Ok(credited.unwrap_or_default().checked_add(referral)?)
+                    },
+                )?;
+            }
+        }
+
+        COLLECTED_FEES.update(storage, fee.denom.clone(), |collected| -> StdResult<_> {
+            Ok(collected.unwrap_or_default().checked_add(collected_fee)?)
+        })?;
+    }
+    Ok(())
+}
+
+// Forwards the collected fees once they reach the threshold
+fn fee_threshold_msg(storage: &mut dyn Storage, config: Config) -> StdResult<Option<WasmMsg>> {
+    if let (Some(fee), Some(fee_splitter), Some(threshold)) = (
+        config.creation_fee,
+        config.fee_splitter,
+        config.fee_threshold,
+    ) {
+        let collected = COLLECTED_FEES.load(storage, fee.denom)?;
+        if collected >= threshold {
+            return forward_fees_msg(storage, &fee_splitter);
+        }
+    }
+    Ok(None)
+}
+
+fn load_referrer(
+    storage: &dyn Storage,
+    referral_code: Option<String>,
+) -> Result<Option<Addr>, ContractError> {
+    referral_code
+        .map(|code| {
+            REFERRAL_CODES
+                .may_load(storage, code.clone())?
+                .ok_or(ContractError::ReferralCodeNotFound { code })
+        })
+        .transpose()
+}
+
+// Salts are hashed together with the sender, so nobody else can take the address of the child
+// Hashing keeps the salt at 32 bytes, whatever the length of the sender address
+fn child_salt(
+    api: &dyn Api,
+    env: &Env,
+    sender: &Addr,
+    salt: Option<Binary>,
+) -> Result<Binary, ContractError> {
+    let salt = match salt {
+        Some(salt) => {
+            if salt.len() > MAX_SALT_LENGTH {
+                return Err(ContractError::InvalidSalt {
+                    max: MAX_SALT_LENGTH,
+                });
+            }
+            salt.to_vec()
+        }
+        None => env.block.height.to_be_bytes().to_vec(),
+    };
+    let sender = api.addr_canonicalize(sender.as_str())?;
+    Ok(Binary::from(
+        Sha256::new()
+            .chain_update(sender.as_slice())
+            .chain_update(salt)
+            .finalize()
+            .as_slice(),
+    ))
+}
+
+// Predicts the address of a child instantiated by the factory with Instantiate2
+fn child_address(
+    deps: Deps<ArchwayQuery>,
+    env: &Env,
+    code_id: u64,
+    salt: &Binary,
+) -> Result<Addr, ContractError> {
+    let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
+    let CodeInfoResponse { checksum, .. } = deps.querier.query_wasm_code_info(code_id)?;
+    let address = deps
+        .api
+        .addr_humanize(&instantiate2_address(&checksum, &creator, salt)?)?;
+
+    // Instantiate2 fails without a clear reason when the address is taken
+    if deps
+        .querier
+        .query_wasm_contract_info(address.to_string())
+        .is_ok()
+    {
+        return Err(ContractError::AddressOccupied {
+            address: address.to_string(),
+        });
+    }
+
+    Ok(address)
+}
+
+fn execute_create_rewards_splitter(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    shares: Vec<Share>,
+    label: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "create_rewards_splitter")?;
+    assert_not_timelocked(deps.storage)?;
+
+    create_rewards_splitter(deps, &env, shares, label)
+}
+
+// The reply points the rewards of the factory to the splitter once it is instantiated
+fn create_rewards_splitter(
+    deps: DepsMut<ArchwayQuery>,
+    env: &Env,
+    shares: Vec<Share>,
+    label: String,
+) -> ArchwayResult<ContractError> {
+    validate_splitter_params(deps.as_ref(), &shares, &label)?;
+
     let code_id = SPLITTER_CODE_ID.load(deps.storage)?;
-    Ok(code_id)
+    let owner = get_ownership(deps.storage)?
+        .owner
+        .unwrap_or_else(|| env.contract.address.clone());
+    let msg = to_binary(&SplitterInstantiateMsg {
+        shares,
+        mutable: true,
+        admin: owner.to_string(),
+        transfer_requires_approval: false,
+        disable_emergency_withdraw: false,
+        restore: None,
+    })?;
+
+    let salt = to_binary(&format!("rewards{}", env.block.height))?;
+    let address = child_address(deps.as_ref(), env, code_id, &salt)?;
+
+    CHILDREN.save(
+        deps.storage,
+        address.clone(),
+        &Child {
+            address: address.clone(),
+            creator: env.contract.address.clone(),
+            admin: env.contract.address.clone(),
+            label: label.clone(),
+            suspended: false,
+            tags: vec![],
+            referrer: None,
+            registered: false,
+            archived_at: None,
+            premium: None,
+        },
+    )?;
+    PENDING_REWARDS_SPLITTER.save(deps.storage, &address)?;
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(
+            WasmMsg::Instantiate2 {
+                admin: Some(env.contract.address.to_string()),
+                code_id,
+                msg,
+                funds: vec![],
+                label,
+                salt,
+            },
+            REWARDS_SPLITTER_REPLY_ID,
+        ))
+        .add_attribute("action", "create_rewards_splitter")
+        .add_attribute("address", address))
+}
+
+// Checks the parameters the splitter would reject on instantiation
+fn validate_splitter_params(
+    deps: Deps<ArchwayQuery>,
+    shares: &[Share],
+    label: &str,
+) -> Result<(), ContractError> {
+    if label.trim().is_empty() {
+        return Err(ContractError::EmptyLabel {});
+    }
+    if shares.is_empty() {
+        return Err(ContractError::NoShares {});
+    }
+
+    let mut recipients: BTreeSet<Addr> = BTreeSet::new();
+    for share in shares {
+        if !recipients.insert(deps.api.addr_validate(&share.recipient)?) {
+            return Err(ContractError::RecipientExists {});
+        }
+    }
+
+    let total_percentage = shares.iter().try_fold(Decimal::zero(), |acc, share| {
+        acc.checked_add(share.percentage)
+    })?;
+    if total_percentage > Decimal::one() {
+        return Err(ContractError::PercentageLimitExceeded {});
+    }
+    if total_percentage < Decimal::one() {
+        return Err(ContractError::PercentageLimitNotMet {});
+    }
+
+    Ok(())
+}
+
+fn execute_register_contract(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "register_contract")?;
+
+    let address = deps.api.addr_validate(&address)?;
+    // Archived children can not be registered again so their record is kept
+    if CHILDREN.has(deps.storage, address.clone())
+        || ARCHIVED_CHILDREN.has(deps.storage, address.clone())
+    {
+        return Err(ContractError::ChildAlreadyRegistered {});
+    }
+
+    let metadata: ContractMetadataResponse =
+        deps.querier
+            .query(&QueryRequest::Custom(ArchwayQuery::ContractMetadata {
+                contract_address: address.to_string(),
+            }))?;
+    if metadata.owner_address != env.contract.address.as_str() {
+        return Err(ContractError::NotMetadataOwner {
+            address: address.to_string(),
+        });
+    }
+
+    let contract_info = deps.querier.query_wasm_contract_info(address.to_string())?;
+    let admin = contract_info
+        .admin
+        .ok_or(ContractError::NoContractAdmin {})?;
+
+    CHILDREN.save(
+        deps.storage,
+        address.clone(),
+        &Child {
+            address: address.clone(),
+            creator: deps.api.addr_validate(&contract_info.creator)?,
+            admin: deps.api.addr_validate(&admin)?,
+            label: String::new(),
+            suspended: false,
+            tags: vec![],
+            referrer: None,
+            registered: true,
+            archived_at: None,
+            premium: None,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_contract")
+        .add_attribute("address", address))
+}
+
+fn execute_deregister_child(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "deregister_child")?;
+
+    let mut child = load_child(deps.as_ref(), &address)?;
+
+    // The archived record keeps the tags but the child leaves the tag index
+    for tag in &child.tags {
+        CHILD_TAGS.remove(deps.storage, (tag.clone(), child.address.clone()));
+    }
+    CHILDREN.remove(deps.storage, child.address.clone());
+
+    child.archived_at = Some(env.block.time);
+    ARCHIVED_CHILDREN.save(deps.storage, child.address.clone(), &child)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "deregister_child")
+        .add_attribute("address", child.address))
+}
+
+fn execute_update_creation_fee(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    fee: Option<Coin>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_creation_fee")?;
+    assert_not_timelocked(deps.storage)?;
+
+    update_creation_fee(deps, fee)
+}
+
+fn update_creation_fee(
+    deps: DepsMut<ArchwayQuery>,
+    fee: Option<Coin>,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if let Some(fee) = &fee {
+        if fee.amount.is_zero() {
+            return Err(ContractError::InvalidCreationFee {
+                fee: fee.to_string(),
+            });
+        }
+    }
+    config.creation_fee = fee;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_creation_fee"))
+}
+
+fn execute_update_fee_splitter(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    address: Option<String>,
+    threshold: Option<Uint128>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_fee_splitter")?;
+    assert_not_timelocked(deps.storage)?;
+
+    update_fee_splitter(deps, address, threshold)
+}
+
+fn update_fee_splitter(
+    deps: DepsMut<ArchwayQuery>,
+    address: Option<String>,
+    threshold: Option<Uint128>,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    config.fee_splitter = address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    config.fee_threshold = threshold;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_fee_splitter"))
+}
+
+// Anyone can forward the fees, they only go to the fee splitter
+fn execute_forward_fees(deps: DepsMut<ArchwayQuery>) -> ArchwayResult<ContractError> {
+    let fee_splitter = CONFIG
+        .load(deps.storage)?
+        .fee_splitter
+        .ok_or(ContractError::FeeSplitterNotSet {})?;
+
+    let msg =
+        forward_fees_msg(deps.storage, &fee_splitter)?.ok_or(ContractError::NoFeesToForward {})?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "forward_fees")
+        .add_attribute("fee_splitter", fee_splitter))
+}
+
+// Deposits every collected fee in the fee splitter, where it is recorded with the factory as sender
+fn forward_fees_msg(storage: &mut dyn Storage, fee_splitter: &Addr) -> StdResult<Option<WasmMsg>> {
+    let fees = COLLECTED_FEES
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+    if fees.is_empty() {
+        return Ok(None);
+    }
+
+    for fee in &fees {
+        COLLECTED_FEES.remove(storage, fee.denom.clone());
+    }
+
+    Ok(Some(WasmMsg::Execute {
+        contract_addr: fee_splitter.to_string(),
+        msg: to_binary(&SplitterExecuteMsg::Deposit {
+            memo: Some("factory fees".to_string()),
+        })?,
+        funds: fees,
+    }))
+}
+
+fn execute_register_referral_code(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    code: String,
+) -> ArchwayResult<ContractError> {
+    if REFERRAL_CODES.has(deps.storage, code.clone()) {
+        return Err(ContractError::ReferralCodeTaken { code });
+    }
+    REFERRAL_CODES.save(deps.storage, code.clone(), &info.sender)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_referral_code")
+        .add_attribute("code", code)
+        .add_attribute("referrer", info.sender))
+}
+
+fn execute_update_referral_percentage(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    percentage: Decimal,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_referral_percentage")?;
+    assert_not_timelocked(deps.storage)?;
+
+    update_referral_percentage(deps, percentage)
+}
+
+fn update_referral_percentage(
+    deps: DepsMut<ArchwayQuery>,
+    percentage: Decimal,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if percentage > Decimal::one() {
+        return Err(ContractError::InvalidReferralPercentage {});
+    }
+    config.referral_percentage = percentage;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_referral_percentage")
+        .add_attribute("percentage", percentage.to_string()))
+}
+
+fn execute_claim_referral_rewards(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let rewards = query_referral_rewards(deps.as_ref(), info.sender.to_string())?;
+    if rewards.is_empty() {
+        return Err(ContractError::NoReferralRewards {});
+    }
+
+    for reward in &rewards {
+        REFERRAL_REWARDS.remove(deps.storage, (info.sender.clone(), reward.denom.clone()));
+    }
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: rewards,
+        })
+        .add_attribute("action", "claim_referral_rewards")
+        .add_attribute("referrer", info.sender))
+}
+
+fn execute_transfer_child_admin(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    new_admin: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "transfer_child_admin")?;
+    assert_not_timelocked(deps.storage)?;
+
+    transfer_child_admin(deps, &env, address, new_admin)
+}
+
+fn transfer_child_admin(
+    deps: DepsMut<ArchwayQuery>,
+    env: &Env,
+    address: String,
+    new_admin: String,
+) -> ArchwayResult<ContractError> {
+    let mut child = load_child(deps.as_ref(), &address)?;
+    if child.admin != env.contract.address {
+        return Err(ContractError::ChildAdminNotRetained {});
+    }
+
+    child.admin = deps.api.addr_validate(&new_admin)?;
+    CHILDREN.save(deps.storage, child.address.clone(), &child)?;
+
+    Ok(Response::new()
+        .add_message(WasmMsg::UpdateAdmin {
+            contract_addr: child.address.to_string(),
+            admin: child.admin.to_string(),
+        })
+        .add_event(
+            Event::new("child_admin_transferred")
+                .add_attribute("address", child.address.clone())
+                .add_attribute("new_admin", child.admin),
+        )
+        .add_attribute("action", "transfer_child_admin")
+        .add_attribute("address", child.address))
+}
+
+fn execute_update_quarantine_address(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    address: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    assert_allowed(deps.storage, &info.sender, "update_quarantine_address")?;
+
+    config.quarantine_address = address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_quarantine_address"))
+}
+
+fn execute_suspend_child(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    assert_allowed(deps.storage, &info.sender, "suspend_child")?;
+
+    let mut child = load_child(deps.as_ref(), &address)?;
+    if child.suspended {
+        return Err(ContractError::ChildSuspended {});
+    }
+
+    let quarantine_address = config.quarantine_address.unwrap_or(env.contract.address);
+    let msg = suspend_child(deps.storage, &mut child, &quarantine_address)?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "suspend_child")
+        .add_attribute("address", child.address)
+        .add_attribute("quarantine_address", quarantine_address))
+}
+
+fn execute_suspend_tag(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    tag: String,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    assert_allowed(deps.storage, &info.sender, "suspend_tag")?;
+
+    let quarantine_address = config.quarantine_address.unwrap_or(env.contract.address);
+
+    // Children that are already suspended are skipped
+    let mut msgs: Vec<ArchwayMsg> = vec![];
+    for address in tag_children(deps.as_ref(), &tag)? {
+        let mut child = CHILDREN.load(deps.storage, address)?;
+        if !child.suspended {
+            msgs.push(suspend_child(
+                deps.storage,
+                &mut child,
+                &quarantine_address,
+            )?);
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "suspend_tag")
+        .add_attribute("tag", tag)
+        .add_attribute("suspended", msgs.len().to_string())
+        .add_messages(msgs))
+}
+
+// Marks the child as suspended and returns the message sending its rewards to the quarantine address
+fn suspend_child(
+    storage: &mut dyn Storage,
+    child: &mut Child,
+    quarantine_address: &Addr,
+) -> StdResult<ArchwayMsg> {
+    child.suspended = true;
+    CHILDREN.save(storage, child.address.clone(), child)?;
+
+    Ok(ArchwayMsg::UpdateContractMetadata {
+        contract_address: Some(child.address.to_string()),
+        owner_address: None,
+        rewards_address: Some(quarantine_address.to_string()),
+    })
+}
+
+fn execute_migrate_tag(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    tag: String,
+    code_id: u64,
+    msg: Binary,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "migrate_tag")?;
+    assert_not_timelocked(deps.storage)?;
+
+    migrate_tag(deps, tag, code_id, msg)
+}
+
+fn migrate_tag(
+    deps: DepsMut<ArchwayQuery>,
+    tag: String,
+    code_id: u64,
+    msg: Binary,
+) -> ArchwayResult<ContractError> {
+    let msgs = tag_children(deps.as_ref(), &tag)?
+        .into_iter()
+        .map(|address| WasmMsg::Migrate {
+            contract_addr: address.to_string(),
+            new_code_id: code_id,
+            msg: msg.clone(),
+        })
+        .collect::<Vec<WasmMsg>>();
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate_tag")
+        .add_attribute("tag", tag)
+        .add_attribute("migrated", msgs.len().to_string())
+        .add_messages(msgs))
+}
+
+fn execute_migrate_children(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    code_id_from: u64,
+    code_id_to: u64,
+    msg: Binary,
+    limit: Option<u8>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "migrate_children")?;
+
+    // Only starting a migration is timelocked, its later batches were approved with the first one
+    if started_migration(deps.storage, code_id_from, code_id_to, &msg)?.is_none() {
+        assert_not_timelocked(deps.storage)?;
+    }
+
+    migrate_children(deps, code_id_from, code_id_to, msg, limit)
+}
+
+// Returns the unfinished migration with the same codes and message
+fn started_migration(
+    storage: &dyn Storage,
+    code_id_from: u64,
+    code_id_to: u64,
+    msg: &Binary,
+) -> StdResult<Option<ChildrenMigration>> {
+    Ok(CHILDREN_MIGRATION.may_load(storage)?.filter(|migration| {
+        !migration.done
+            && migration.code_id_from == code_id_from
+            && migration.code_id_to == code_id_to
+            && migration.msg == *msg
+    }))
+}
+
+fn migrate_children(
+    deps: DepsMut<ArchwayQuery>,
+    code_id_from: u64,
+    code_id_to: u64,
+    msg: Binary,
+    limit: Option<u8>,
+) -> ArchwayResult<ContractError> {
+    let limit = limit.unwrap_or(10) as usize;
+    let mut migration = started_migration(deps.storage, code_id_from, code_id_to, &msg)?.unwrap_or(
+        ChildrenMigration {
+            code_id_from,
+            code_id_to,
+            msg,
+            last_visited: None,
+            migrated: 0,
+            failed: vec![],
+            done: false,
+        },
+    );
+
+    let start = migration.last_visited.clone().map(Bound::exclusive);
+    let mut addresses = CHILDREN
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit + 1)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    migration.done = addresses.len() <= limit;
+    addresses.truncate(limit);
+    if let Some(address) = addresses.last() {
+        migration.last_visited = Some(address.clone());
+    }
+
+    // Children running another code, e.g. the ones already migrated, are skipped
+    let mut children: Vec<Addr> = vec![];
+    for address in addresses {
+        let info = deps.querier.query_wasm_contract_info(address.to_string())?;
+        if info.code_id == code_id_from {
+            children.push(address);
+        }
+    }
+
+    // Failed migrations are recorded by the reply instead of reverting the batch
+    let msgs = children
+        .iter()
+        .map(|address| {
+            SubMsg::reply_always(
+                WasmMsg::Migrate {
+                    contract_addr: address.to_string(),
+                    new_code_id: code_id_to,
+                    msg: migration.msg.clone(),
+                },
+                MIGRATE_REPLY_ID,
+            )
+        })
+        .collect::<Vec<SubMsg<ArchwayMsg>>>();
+    PENDING_MIGRATIONS.save(deps.storage, &children)?;
+    CHILDREN_MIGRATION.save(deps.storage, &migration)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate_children")
+        .add_attribute("batch", msgs.len().to_string())
+        .add_attribute("done", migration.done.to_string())
+        .add_submessages(msgs))
+}
+
+// The factory owns the reward metadata of its children so it sets their premium
+fn execute_update_child_premium(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    fee: Coin,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_child_premium")?;
+
+    let mut child = load_child(deps.as_ref(), &address)?;
+    child.premium = if fee.amount.is_zero() {
+        None
+    } else {
+        Some(fee.clone())
+    };
+    CHILDREN.save(deps.storage, child.address.clone(), &child)?;
+
+    Ok(Response::new()
+        .add_message(set_premium(
+            env.contract.address.to_string(),
+            child.address.to_string(),
+            fee.clone(),
+        ))
+        .add_attribute("action", "update_child_premium")
+        .add_attribute("address", child.address)
+        .add_attribute("premium", fee.to_string()))
+}
+
+fn execute_update_child_tags(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    address: String,
+    tags: Vec<String>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_child_tags")?;
+
+    let child = load_child(deps.as_ref(), &address)?;
+    save_child_tags(deps.storage, &child.address, tags)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_child_tags")
+        .add_attribute("address", child.address))
+}
+
+// Replaces the tags of a child and keeps the tag index in sync
+fn save_child_tags(storage: &mut dyn Storage, address: &Addr, tags: Vec<String>) -> StdResult<()> {
+    let mut child = CHILDREN.load(storage, address.clone())?;
+    for tag in &child.tags {
+        CHILD_TAGS.remove(storage, (tag.clone(), address.clone()));
+    }
+
+    let mut tags = tags;
+    tags.sort();
+    tags.dedup();
+    for tag in &tags {
+        CHILD_TAGS.save(storage, (tag.clone(), address.clone()), &Empty {})?;
+    }
+
+    child.tags = tags;
+    CHILDREN.save(storage, address.clone(), &child)
+}
+
+fn tag_children(deps: Deps<ArchwayQuery>, tag: &str) -> StdResult<Vec<Addr>> {
+    CHILD_TAGS
+        .prefix(tag.to_string())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+fn execute_resume_child(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "resume_child")?;
+
+    let mut child = load_child(deps.as_ref(), &address)?;
+    if !child.suspended {
+        return Err(ContractError::ChildNotSuspended {});
+    }
+    child.suspended = false;
+    CHILDREN.save(deps.storage, child.address.clone(), &child)?;
+
+    Ok(Response::new()
+        .add_message(ArchwayMsg::UpdateContractMetadata {
+            contract_address: Some(child.address.to_string()),
+            owner_address: None,
+            rewards_address: Some(rewards_address(deps.as_ref(), &env)?.to_string()),
+        })
+        .add_attribute("action", "resume_child")
+        .add_attribute("address", child.address))
+}
+
+fn execute_withdraw_child_rewards(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    addresses: Vec<String>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "withdraw_child_rewards")?;
+
+    let mut children: Vec<Addr> = vec![];
+    for address in addresses {
+        let child = load_child(deps.as_ref(), &address)?;
+
+        let metadata: ContractMetadataResponse =
+            deps.querier
+                .query(&QueryRequest::Custom(ArchwayQuery::ContractMetadata {
+                    contract_address: child.address.to_string(),
+                }))?;
+        if metadata.rewards_address != env.contract.address.as_str() {
+            return Err(ContractError::NotRewardsAddress { address });
+        }
+
+        children.push(child.address);
+    }
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(
+            ArchwayMsg::WithdrawRewards {
+                records_limit: Some(0),
+                record_ids: vec![],
+            },
+            WITHDRAW_REPLY_ID,
+        ))
+        .add_attribute("action", "withdraw_child_rewards")
+        .add_attribute("children", children.len().to_string()))
+}
+
+fn load_child(deps: Deps<ArchwayQuery>, address: &str) -> Result<Child, ContractError> {
+    let address = deps.api.addr_validate(address)?;
+    CHILDREN
+        .may_load(deps.storage, address)?
+        .ok_or(ContractError::ChildNotFound {})
+}
+
+fn execute_register_interchain_account(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    connection_id: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "register_interchain_account")?;
+
+    let account = InterchainAccount {
+        connection_id: connection_id.clone(),
+        address: None,
+    };
+    INTERCHAIN_ACCOUNTS.save(deps.storage, connection_id.clone(), &account)?;
+
+    Ok(Response::new()
+        .add_message(register_interchain_account(
+            env.contract.address.to_string(),
+            connection_id.clone(),
+        ))
+        .add_attribute("action", "register_interchain_account")
+        .add_attribute("connection_id", connection_id))
+}
+
+fn execute_set_interchain_account_address(
+    deps: DepsMut<ArchwayQuery>,
+    info: MessageInfo,
+    connection_id: String,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "set_interchain_account_address")?;
+
+    let mut account = INTERCHAIN_ACCOUNTS
+        .may_load(deps.storage, connection_id.clone())?
+        .ok_or(ContractError::InterchainAccountNotRegistered {})?;
+
+    // The address belongs to another chain so it can not be validated here
+    account.address = Some(address.clone());
+    INTERCHAIN_ACCOUNTS.save(deps.storage, connection_id.clone(), &account)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_interchain_account_address")
+        .add_attribute("connection_id", connection_id)
+        .add_attribute("address", address))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn execute_update_remote_contract_metadata(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    connection_id: String,
+    contract_address: String,
+    owner_address: Option<String>,
+    rewards_address: Option<String>,
+    timeout_seconds: u64,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(
+        deps.storage,
+        &info.sender,
+        "update_remote_contract_metadata",
+    )?;
+
+    let account = INTERCHAIN_ACCOUNTS
+        .may_load(deps.storage, connection_id.clone())?
+        .ok_or(ContractError::InterchainAccountNotRegistered {})?;
+    let interchain_account = account
+        .address
+        .ok_or(ContractError::InterchainAccountNotReady {})?;
+
+    Ok(Response::new()
+        .add_message(set_remote_contract_metadata(
+            env.contract.address.to_string(),
+            connection_id.clone(),
+            interchain_account,
+            contract_address.clone(),
+            owner_address,
+            rewards_address,
+            timeout_seconds,
+        ))
+        .add_attribute("action", "update_remote_contract_metadata")
+        .add_attribute("connection_id", connection_id)
+        .add_attribute("contract_address", contract_address))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut<ArchwayQuery>, env: Env, msg: Reply) -> ArchwayResult<ContractError> {
+    match msg.id {
+        WITHDRAW_REPLY_ID => reply_withdraw_rewards(deps, msg),
+        MIGRATE_REPLY_ID => reply_migrate_child(deps, msg),
+        REWARDS_SPLITTER_REPLY_ID => reply_rewards_splitter(deps, env),
+        CUSTOM_CONTRACT_REPLY_ID => reply_custom_contract(deps, env),
+        CUSTOM_SPLITTER_REPLY_ID => reply_custom_splitter(deps, env),
+        _ => Ok(Response::new()),
+    }
+}
+
+// The splitter keeps its own rewards, the factory owns its metadata like for the other children
+fn reply_rewards_splitter(deps: DepsMut<ArchwayQuery>, env: Env) -> ArchwayResult<ContractError> {
+    let address = PENDING_REWARDS_SPLITTER.load(deps.storage)?;
+    PENDING_REWARDS_SPLITTER.remove(deps.storage);
+
+    CONFIG.update(deps.storage, |mut config| -> StdResult<_> {
+        config.rewards_splitter = Some(address.clone());
+        Ok(config)
+    })?;
+
+    Ok(Response::new()
+        .add_message(ArchwayMsg::UpdateContractMetadata {
+            contract_address: None,
+            owner_address: None,
+            rewards_address: Some(address.to_string()),
+        })
+        .add_message(ArchwayMsg::UpdateContractMetadata {
+            contract_address: Some(address.to_string()),
+            owner_address: Some(env.contract.address.to_string()),
+            rewards_address: Some(address.to_string()),
+        })
+        .add_attribute("action", "create_rewards_splitter_reply")
+        .add_attribute("address", address))
+}
+
+// The custom contract exists, its splitter is instantiated next
+fn reply_custom_contract(deps: DepsMut<ArchwayQuery>, env: Env) -> ArchwayResult<ContractError> {
+    let pending = PENDING_CONTRACT_WITH_SPLITTER.load(deps.storage)?;
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(
+            WasmMsg::Instantiate2 {
+                admin: Some(env.contract.address.to_string()),
+                code_id: pending.splitter_code_id,
+                msg: pending.splitter_msg,
+                funds: vec![],
+                label: format!("{} Splitter", pending.label),
+                salt: pending.splitter_salt,
+            },
+            CUSTOM_SPLITTER_REPLY_ID,
+        ))
+        .add_attribute("action", "create_custom_contract_reply")
+        .add_attribute("address", pending.contract))
+}
+
+// Both children exist, the rewards of the contract go to its splitter
+fn reply_custom_splitter(deps: DepsMut<ArchwayQuery>, env: Env) -> ArchwayResult<ContractError> {
+    let pending = PENDING_CONTRACT_WITH_SPLITTER.load(deps.storage)?;
+    PENDING_CONTRACT_WITH_SPLITTER.remove(deps.storage);
+
+    let rewards = rewards_address(deps.as_ref(), &env)?;
+    let mut response = Response::new()
+        .add_attribute("action", "create_custom_splitter_reply")
+        .add_attribute("contract", pending.contract.clone())
+        .add_attribute("splitter", pending.splitter.clone());
+
+    for (address, label, rewards) in [
+        (
+            pending.contract,
+            pending.label.clone(),
+            pending.splitter.clone(),
+        ),
+        (
+            pending.splitter,
+            format!("{} Splitter", pending.label),
+            rewards.clone(),
+        ),
+    ] {
+        CHILDREN.save(
+            deps.storage,
+            address.clone(),
+            &Child {
+                address: address.clone(),
+                creator: pending.creator.clone(),
+                admin: pending.admin.clone(),
+                label,
+                suspended: false,
+                tags: vec![],
+                referrer: pending.referrer.clone(),
+                registered: false,
+                archived_at: None,
+                premium: None,
+            },
+        )?;
+        save_child_tags(deps.storage, &address, pending.tags.clone())?;
+
+        // The factory keeps the metadata ownership so it can suspend the children
+        response = response.add_message(ArchwayMsg::UpdateContractMetadata {
+            contract_address: Some(address.to_string()),
+            owner_address: Some(env.contract.address.to_string()),
+            rewards_address: Some(rewards.to_string()),
+        });
+        if pending.admin != env.contract.address {
+            response = response.add_message(WasmMsg::UpdateAdmin {
+                contract_addr: address.to_string(),
+                admin: pending.admin.to_string(),
+            });
+        }
+    }
+
+    Ok(response)
+}
+
+// The replies arrive in the order of the migrations of the batch
+fn reply_migrate_child(deps: DepsMut<ArchwayQuery>, msg: Reply) -> ArchwayResult<ContractError> {
+    let mut pending = PENDING_MIGRATIONS.load(deps.storage)?;
+    let address = pending.remove(0);
+    PENDING_MIGRATIONS.save(deps.storage, &pending)?;
+
+    let mut migration = CHILDREN_MIGRATION.load(deps.storage)?;
+    let response = Response::new()
+        .add_attribute("action", "migrate_child_reply")
+        .add_attribute("address", address.clone());
+    let response = match msg.result.into_result() {
+        Ok(_) => {
+            migration.migrated += 1;
+            response.add_attribute("result", "migrated")
+        }
+        Err(err) => {
+            migration.failed.push(address);
+            response
+                .add_attribute("result", "failed")
+                .add_attribute("error", err)
+        }
+    };
+    CHILDREN_MIGRATION.save(deps.storage, &migration)?;
+
+    Ok(response)
+}
+
+// Archway rewards records do not identify the contract that earned them,
+// so the withdrawn rewards are only recorded in total, not per child
+fn reply_withdraw_rewards(deps: DepsMut<ArchwayQuery>, msg: Reply) -> ArchwayResult<ContractError> {
+    let data = msg
+        .result
+        .into_result()
+        .map_err(StdError::generic_err)?
+        .data
+        .unwrap_or_default();
+    if data.is_empty() {
+        return Ok(Response::new().add_attribute("action", "withdraw_child_rewards_reply"));
+    }
+    let res: WithdrawRewardsResponse = from_binary(&data)?;
+
+    for reward in res.total_rewards {
+        WITHDRAWN_REWARDS.update(deps.storage, reward.denom, |withdrawn| -> StdResult<_> {
+            Ok(withdrawn.unwrap_or_default().checked_add(reward.amount)?)
+        })?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "withdraw_child_rewards_reply")
+        .add_attribute("records", res.records_num.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::SplitterCodeID {} => to_binary(&query_splitter_code_id(deps, env)?),
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::CollectedFees {} => to_binary(&query_collected_fees(deps)?),
+        QueryMsg::Ownership {} => to_binary(&get_ownership(deps.storage)?),
+        QueryMsg::InterchainAccount { connection_id } => {
+            to_binary(&INTERCHAIN_ACCOUNTS.load(deps.storage, connection_id)?)
+        }
+        QueryMsg::Child { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            match CHILDREN.may_load(deps.storage, address.clone())? {
+                Some(child) => to_binary(&child),
+                None => to_binary(&ARCHIVED_CHILDREN.load(deps.storage, address)?),
+            }
+        }
+        QueryMsg::Children {
+            tag,
+            start_after,
+            limit,
+            include_archived,
+        } => {
+            let mut children = query_children(deps, tag.clone(), start_after.clone(), limit)?;
+            // Both pages are sorted by address, the merged page keeps the first children
+            if include_archived.unwrap_or_default() {
+                children.extend(query_archived_children(deps, tag, start_after, limit)?);
+                children.sort_by(|a, b| a.address.cmp(&b.address));
+                children.truncate(limit.unwrap_or(10) as usize);
+            }
+            to_binary(&children)
+        }
+        QueryMsg::ChildHealth { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            let child = CHILDREN.load(deps.storage, address)?;
+            to_binary(&child_health(deps, &env, child)?)
+        }
+        QueryMsg::ChildrenHealth { start_after, limit } => {
+            to_binary(&query_children_health(deps, env, start_after, limit)?)
+        }
+        QueryMsg::WithdrawnRewards {} => to_binary(&query_withdrawn_rewards(deps)?),
+        QueryMsg::ReferralCode { code } => to_binary(&REFERRAL_CODES.load(deps.storage, code)?),
+        QueryMsg::ReferralRewards { referrer } => {
+            to_binary(&query_referral_rewards(deps, referrer)?)
+        }
+        QueryMsg::OutstandingRewards { start_after, limit } => {
+            to_binary(&query_outstanding_rewards(deps, start_after, limit)?)
+        }
+        QueryMsg::RoleMembers { role } => to_binary(&RBAC.role_members(deps.storage, &role)?),
+        QueryMsg::RoleActions { role } => to_binary(&RBAC.role_actions(deps.storage, &role)?),
+        QueryMsg::Timelock {} => to_binary(&TIMELOCK.may_load(deps.storage)?),
+        QueryMsg::Proposal { id } => to_binary(&PROPOSALS.load(deps.storage, id)?),
+        QueryMsg::Proposals { start_after, limit } => {
+            to_binary(&query_proposals(deps, start_after, limit)?)
+        }
+        QueryMsg::ChildrenMigration {} => to_binary(&CHILDREN_MIGRATION.may_load(deps.storage)?),
+    }
+}
+
+fn query_proposals(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Proposal>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    PROPOSALS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, proposal) = item?;
+            Ok(proposal)
+        })
+        .collect()
+}
+
+fn query_splitter_code_id(deps: Deps<ArchwayQuery>, _env: Env) -> StdResult<u64> {
+    let code_id = SPLITTER_CODE_ID.load(deps.storage)?;
+    Ok(code_id)
+}
+
+fn query_children(
+    deps: Deps<ArchwayQuery>,
+    tag: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Child>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    match tag {
+        Some(tag) => CHILD_TAGS
+            .prefix(tag)
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|address| CHILDREN.load(deps.storage, address?))
+            .collect::<StdResult<Vec<Child>>>(),
+        None => CHILDREN
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (_, child) = item?;
+                Ok(child)
+            })
+            .collect::<StdResult<Vec<Child>>>(),
+    }
+}
+
+fn query_children_health(
+    deps: Deps<ArchwayQuery>,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<ChildHealthResponse>> {
+    query_children(deps, None, start_after, limit)?
+        .into_iter()
+        .map(|child| child_health(deps, &env, child))
+        .collect()
+}
+
+// Checks the wiring set up by the factory, e.g. after an admin changed the metadata outside of it
+fn child_health(
+    deps: Deps<ArchwayQuery>,
+    env: &Env,
+    child: Child,
+) -> StdResult<ChildHealthResponse> {
+    let info = deps
+        .querier
+        .query_wasm_contract_info(child.address.to_string())?;
+    let metadata: ContractMetadataResponse =
+        deps.querier
+            .query(&QueryRequest::Custom(ArchwayQuery::ContractMetadata {
+                contract_address: child.address.to_string(),
+            }))?;
+    let contract_version = cw2::CONTRACT
+        .query(&deps.querier, child.address.clone())
+        .ok();
+
+    let expected_rewards_address = if child.suspended {
+        CONFIG
+            .load(deps.storage)?
+            .quarantine_address
+            .unwrap_or_else(|| env.contract.address.clone())
+    } else {
+        rewards_address(deps, env)?
+    };
+
+    Ok(ChildHealthResponse {
+        admin_ok: info.admin.as_deref() == Some(child.admin.as_str()),
+        owner_ok: metadata.owner_address == env.contract.address.as_str(),
+        rewards_ok: metadata.rewards_address == expected_rewards_address.as_str(),
+        address: child.address,
+        code_id: info.code_id,
+        contract_version,
+        wasm_admin: info.admin,
+        owner_address: metadata.owner_address,
+        rewards_address: metadata.rewards_address,
+        premium: child.premium,
+        expected_rewards_address,
+    })
+}
+
+// Archived children are not in the tag index, they are filtered by the tags of their record
+fn query_archived_children(
+    deps: Deps<ArchwayQuery>,
+    tag: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Child>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    ARCHIVED_CHILDREN
+        .range(deps.storage, start, None, Order::Ascending)
+        .map(|item| {
+            let (_, child) = item?;
+            Ok(child)
+        })
+        .filter(|child: &StdResult<Child>| match (child, &tag) {
+            (Ok(child), Some(tag)) => child.tags.contains(tag),
+            _ => true,
+        })
+        .take(limit)
+        .collect()
+}
+
+// Sums the rewards records of the rewards address of every child in the page
+// Children of the page sharing a rewards address are grouped, so its rewards are reported once
+fn query_outstanding_rewards(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<OutstandingRewardsResponse>> {
+    let mut outstanding: Vec<OutstandingRewardsResponse> = vec![];
+    for child in query_children(deps, None, start_after, limit)? {
+        let metadata: ContractMetadataResponse =
+            deps.querier
+                .query(&QueryRequest::Custom(ArchwayQuery::ContractMetadata {
+                    contract_address: child.address.to_string(),
+                }))?;
+
+        match outstanding
+            .iter_mut()
+            .find(|res| res.rewards_address == metadata.rewards_address)
+        {
+            Some(res) => res.children.push(child.address),
+            None => {
+                let (rewards, records) = rewards_records(deps, &metadata.rewards_address)?;
+                outstanding.push(OutstandingRewardsResponse {
+                    rewards_address: metadata.rewards_address,
+                    children: vec![child.address],
+                    rewards,
+                    records,
+                });
+            }
+        }
+    }
+    Ok(outstanding)
+}
+
+// Sums the rewards of every record of a rewards address, reading the records a page at a time
+fn rewards_records(deps: Deps<ArchwayQuery>, rewards_address: &str) -> StdResult<(Vec<Coin>, u64)> {
+    let mut rewards: Vec<Coin> = vec![];
+    let mut records = 0u64;
+    let mut pagination: Option<PageRequest> = None;
+
+    loop {
+        let res: RewardsRecordsResponse =
+            deps.querier
+                .query(&QueryRequest::Custom(ArchwayQuery::RewardsRecords {
+                    rewards_address: rewards_address.to_string(),
+                    pagination,
+                }))?;
+
+        records += res.records.len() as u64;
+        for record in &res.records {
+            for coin in &record.rewards {
+                match rewards.iter_mut().find(|reward| reward.denom == coin.denom) {
+                    Some(reward) => reward.amount = reward.amount.checked_add(coin.amount)?,
+                    None => rewards.push(coin.clone()),
+                }
+            }
+        }
+
+        match res.pagination.and_then(|page| page.next_key) {
+            Some(key) => pagination = Some(PageRequest::new().key(key)),
+            None => return Ok((rewards, records)),
+        }
+    }
+}
+
+fn query_withdrawn_rewards(deps: Deps<ArchwayQuery>) -> StdResult<Vec<Coin>> {
+    WITHDRAWN_REWARDS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()
+}
+
+fn query_collected_fees(deps: Deps<ArchwayQuery>) -> StdResult<Vec<Coin>> {
+    COLLECTED_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()
+}
+
+fn query_referral_rewards(deps: Deps<ArchwayQuery>, referrer: String) -> StdResult<Vec<Coin>> {
+    let referrer = deps.api.addr_validate(&referrer)?;
+
+    REFERRAL_REWARDS
+        .prefix(referrer)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()
 }
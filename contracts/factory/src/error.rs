@@ -1,4 +1,7 @@
-use cosmwasm_std::{Instantiate2AddressError, StdError};
+use cosmwasm_std::{DivideByZeroError, Instantiate2AddressError, OverflowError, StdError};
+use cw2::VersionError;
+use cw_ownable::OwnershipError;
+use pantheon_utils::RbacError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -9,9 +12,111 @@ pub enum ContractError {
     #[error("{0}")]
     Instantiate2AddressError(#[from] Instantiate2AddressError),
 
+    #[error("{0}")]
+    DivideByZeroError(#[from] DivideByZeroError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
+    #[error("{0}")]
+    Version(#[from] VersionError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("A contract already exists at the predicted address {address}")]
+    AddressOccupied { address: String },
+
+    #[error("Salt can not be longer than {max} bytes")]
+    InvalidSalt { max: usize },
+
+    #[error("Label can not be empty")]
+    EmptyLabel {},
+
+    #[error("Splitter must have at least one share")]
+    NoShares {},
+
+    #[error("Percentage limit exceeded")]
+    PercentageLimitExceeded {},
+
+    #[error("Percentage limit not met")]
+    PercentageLimitNotMet {},
+
+    #[error("Recipient already has a share")]
+    RecipientExists {},
+
     #[error("Could not instantiate contract")]
     InstantiateError {},
+
+    #[error("Contract was not created by or registered in the factory")]
+    ChildNotFound {},
+
+    #[error("Contract is already registered in the factory")]
+    ChildAlreadyRegistered {},
+
+    #[error("Contract has no wasm admin")]
+    NoContractAdmin {},
+
+    #[error("Metadata of {address} is not owned by the factory")]
+    NotMetadataOwner { address: String },
+
+    #[error("Wasm admin of the child is not retained by the factory")]
+    ChildAdminNotRetained {},
+
+    #[error("Child is already suspended")]
+    ChildSuspended {},
+
+    #[error("Child is not suspended")]
+    ChildNotSuspended {},
+
+    #[error("Rewards of {address} are not sent to the factory")]
+    NotRewardsAddress { address: String },
+
+    #[error("Invalid creation fee, expected {fee}")]
+    InvalidCreationFee { fee: String },
+
+    #[error("Fee splitter is not set")]
+    FeeSplitterNotSet {},
+
+    #[error("No collected fees to forward")]
+    NoFeesToForward {},
+
+    #[error("Referral code {code} is already registered")]
+    ReferralCodeTaken { code: String },
+
+    #[error("Referral code {code} does not exist")]
+    ReferralCodeNotFound { code: String },
+
+    #[error("Referral percentage can not be more than 100%")]
+    InvalidReferralPercentage {},
+
+    #[error("No referral rewards to claim")]
+    NoReferralRewards {},
+
+    #[error("Interchain account is not registered")]
+    InterchainAccountNotRegistered {},
+
+    #[error("Interchain account address is not set")]
+    InterchainAccountNotReady {},
+
+    #[error("Action must be proposed while the timelock is set")]
+    TimelockRequired {},
+
+    #[error("Timelock is not set")]
+    TimelockNotSet {},
+
+    #[error("Delay of the proposal has not passed")]
+    ProposalNotReady {},
+}
+
+impl From<RbacError> for ContractError {
+    fn from(err: RbacError) -> Self {
+        match err {
+            RbacError::Std(err) => ContractError::Std(err),
+            RbacError::Unauthorized {} => ContractError::Unauthorized {},
+        }
+    }
 }
@@ -0,0 +1,134 @@
+use archway_bindings::ArchwayMsg;
+use cosmwasm_std::{Binary, CosmosMsg};
+use prost::Message;
+
+// Interchain accounts are managed through the ICA controller module of ibc-go
+const MSG_REGISTER_INTERCHAIN_ACCOUNT_TYPE_URL: &str =
+    "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount";
+const MSG_SEND_TX_TYPE_URL: &str = "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx";
+const MSG_SET_CONTRACT_METADATA_TYPE_URL: &str = "/archway.rewards.v1.MsgSetContractMetadata";
+
+// InterchainAccountPacketData type for executing transactions on the host chain
+const TYPE_EXECUTE_TX: i32 = 1;
+
+#[derive(Clone, PartialEq, Message)]
+struct Any {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MsgRegisterInterchainAccount {
+    #[prost(string, tag = "1")]
+    owner: String,
+    #[prost(string, tag = "2")]
+    connection_id: String,
+    #[prost(string, tag = "3")]
+    version: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct InterchainAccountPacketData {
+    #[prost(int32, tag = "1")]
+    r#type: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    data: Vec<u8>,
+    #[prost(string, tag = "3")]
+    memo: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CosmosTx {
+    #[prost(message, repeated, tag = "1")]
+    messages: Vec<Any>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MsgSendTx {
+    #[prost(string, tag = "1")]
+    owner: String,
+    #[prost(string, tag = "2")]
+    connection_id: String,
+    #[prost(message, optional, tag = "3")]
+    packet_data: Option<InterchainAccountPacketData>,
+    #[prost(uint64, tag = "4")]
+    relative_timeout: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ContractMetadata {
+    #[prost(string, tag = "1")]
+    contract_address: String,
+    #[prost(string, tag = "2")]
+    owner_address: String,
+    #[prost(string, tag = "3")]
+    rewards_address: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MsgSetContractMetadata {
+    #[prost(string, tag = "1")]
+    sender_address: String,
+    #[prost(message, optional, tag = "2")]
+    metadata: Option<ContractMetadata>,
+}
+
+// Registers an interchain account owned by this contract on the given connection
+pub fn register_interchain_account(owner: String, connection_id: String) -> CosmosMsg<ArchwayMsg> {
+    let msg = MsgRegisterInterchainAccount {
+        owner,
+        connection_id,
+        version: String::new(),
+    };
+
+    CosmosMsg::Stargate {
+        type_url: MSG_REGISTER_INTERCHAIN_ACCOUNT_TYPE_URL.to_string(),
+        value: Binary::from(msg.encode_to_vec()),
+    }
+}
+
+// Sends a MsgSetContractMetadata signed by the interchain account to the host chain
+// Empty owner or rewards addresses are left unchanged by the rewards module
+pub fn set_remote_contract_metadata(
+    owner: String,
+    connection_id: String,
+    interchain_account: String,
+    contract_address: String,
+    owner_address: Option<String>,
+    rewards_address: Option<String>,
+    timeout_seconds: u64,
+) -> CosmosMsg<ArchwayMsg> {
+    let metadata = MsgSetContractMetadata {
+        sender_address: interchain_account,
+        metadata: Some(ContractMetadata {
+            contract_address,
+            owner_address: owner_address.unwrap_or_default(),
+            rewards_address: rewards_address.unwrap_or_default(),
+        }),
+    };
+
+    let tx = CosmosTx {
+        messages: vec![Any {
+            type_url: MSG_SET_CONTRACT_METADATA_TYPE_URL.to_string(),
+            value: metadata.encode_to_vec(),
+        }],
+    };
+
+    let msg = MsgSendTx {
+        owner,
+        connection_id,
+        packet_data: Some(InterchainAccountPacketData {
+            r#type: TYPE_EXECUTE_TX,
+            data: tx.encode_to_vec(),
+            memo: String::new(),
+        }),
+        relative_timeout: timeout_seconds * 1_000_000_000,
+    };
+
+    CosmosMsg::Stargate {
+        type_url: MSG_SEND_TX_TYPE_URL.to_string(),
+        value: Binary::from(msg.encode_to_vec()),
+    }
+}
@@ -1,11 +1,35 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use pantheon_utils::Share;
 
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Uint128};
+use cw2::ContractVersion;
+use cw_ownable::{cw_ownable_execute, cw_ownable_query};
+
+use crate::state::{
+    Child, ChildrenMigration, Config, InterchainAccount, Proposal, QueuedAction, Timelock,
+};
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub splitter_code_id: u64,
+    // Splitter created with the factory to share its rewards from the start
+    #[serde(default)]
+    pub rewards_splitter: Option<RewardsSplitterMsg>,
 }
 
+#[cw_serde]
+pub struct RewardsSplitterMsg {
+    pub shares: Vec<Share>,
+    pub label: String,
+}
+
+// Deployments with the legacy layout are converted to the current layout by the migration
+// Only deployments of the factory with an older or the same version can be migrated
+#[cw_serde]
+pub struct MigrateMsg {}
+
+// UpdateOwnership is added by cw_ownable to transfer, accept or renounce the ownership
+#[cw_ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
     UpdateSplitterCodeId {
@@ -14,14 +38,271 @@ pub enum ExecuteMsg {
     CreateSplitter {
         shares: Vec<Share>,
         mutable: bool,
+        transfer_requires_approval: bool,
         label: String,
-        /* TODO: Add sender here */
+        tags: Vec<String>,
+        // Keep the factory as wasm admin instead of the sender, e.g. to migrate the child by tag
+        retain_admin: bool,
+        // Code of the referrer credited with a part of the rewards of the child
+        referral_code: Option<String>,
+        // Makes the address of the child predictable, the salt is hashed together with the sender
+        // Defaults to the block height, so a sender can create one child per block
+        salt: Option<Binary>,
+    },
+    // Instantiate a custom contract and a splitter receiving its rewards in the same transaction
+    // The splitter is created like with CreateSplitter and both children are registered together
+    CreateContractWithSplitter {
+        code_id: u64,
+        msg: Binary,
+        label: String,
+        shares: Vec<Share>,
+        mutable: bool,
+        transfer_requires_approval: bool,
+        tags: Vec<String>,
+        retain_admin: bool,
+        referral_code: Option<String>,
+        salt: Option<Binary>,
+    },
+    // Add a contract created outside of the factory to the registry
+    // The factory must own the reward metadata of the contract so it can manage it like its children
+    RegisterContract {
+        address: String,
+    },
+    // Move a child out of the registry, its record is archived
+    DeregisterChild {
+        address: String,
+    },
+    // Set the fee paid for each splitter created by the factory
+    UpdateCreationFee {
+        fee: Option<Coin>,
+    },
+    // Set the splitter receiving the collected fees and the amount forwarding them automatically
+    UpdateFeeSplitter {
+        address: Option<String>,
+        threshold: Option<Uint128>,
+    },
+    // Send the collected fees to the fee splitter
+    ForwardFees {},
+    // Register a referral code owned by the sender
+    RegisterReferralCode {
+        code: String,
+    },
+    // Set the part of the creation fee of referred children credited to their referrer
+    UpdateReferralPercentage {
+        percentage: Decimal,
+    },
+    // Send the rewards credited to the sender as a referrer
+    ClaimReferralRewards {},
+    // Hand the wasm admin of a child retained by the factory over to a new admin
+    TransferChildAdmin {
+        address: String,
+        new_admin: String,
+    },
+    // Set the premium paid by every transaction calling a child, a zero amount removes it
+    UpdateChildPremium {
+        address: String,
+        fee: Coin,
+    },
+    // Replace the tags of a child
+    UpdateChildTags {
+        address: String,
+        tags: Vec<String>,
+    },
+    // Suspend every child with the tag
+    SuspendTag {
+        tag: String,
+    },
+    // Withdraw the rewards of every child with the tag
+    WithdrawTagRewards {
+        tag: String,
+    },
+    // Migrate every child with the tag, the factory must be the wasm admin of the children
+    MigrateTag {
+        tag: String,
+        code_id: u64,
+        msg: Binary,
+    },
+    // Migrate the next page of children running code_id_from, the factory must be their wasm admin
+    // Call again with the same codes and message until the migration is done
+    MigrateChildren {
+        code_id_from: u64,
+        code_id_to: u64,
+        msg: Binary,
+        limit: Option<u8>,
+    },
+    // Create a splitter receiving the rewards of the factory and of the children created afterwards
+    // The factory is the wasm admin of the splitter and the owner of the factory its admin
+    CreateRewardsSplitter {
+        shares: Vec<Share>,
+        label: String,
+    },
+    // Set the address receiving the rewards of suspended children
+    UpdateQuarantineAddress {
+        address: Option<String>,
+    },
+    // Send the rewards of a child to the quarantine address, e.g. when the child is compromised
+    SuspendChild {
+        address: String,
+    },
+    // Send the rewards of a suspended child to the admin again
+    ResumeChild {
+        address: String,
+    },
+    // Withdraw the rewards records of the factory, the children must send their rewards to it
+    // The withdrawn rewards are recorded in total, the records do not tell which child earned them
+    WithdrawChildRewards {
+        addresses: Vec<String>,
+    },
+    // Register an interchain account on another chain through an IBC connection
+    RegisterInterchainAccount {
+        connection_id: String,
+    },
+    // Record the address of the interchain account once the channel is open
+    SetInterchainAccountAddress {
+        connection_id: String,
+        address: String,
+    },
+    // Add an address to a role
+    GrantRole {
+        role: String,
+        address: String,
+    },
+    // Remove an address from a role
+    RevokeRole {
+        role: String,
+        address: String,
+    },
+    // Replace the actions the members of a role are permitted to run
+    // Actions are named after the execute messages, e.g. suspend_child
+    UpdateRoleActions {
+        role: String,
+        actions: Vec<String>,
+    },
+    // Set the delay of the sensitive admin actions and the guardian allowed to cancel them
+    // Once set, the timelock itself is only changed through a proposal
+    UpdateTimelock {
+        delay: u64,
+        guardian: String,
+    },
+    // Queue a sensitive admin action, it can be executed once the delay has passed
+    ProposeAction {
+        action: QueuedAction,
+    },
+    // Run a queued action whose delay has passed
+    ExecuteProposal {
+        id: u64,
+    },
+    // Drop a queued action, allowed to the guardian and the owner
+    CancelProposal {
+        id: u64,
+    },
+    // Update the reward metadata of a contract on the chain of the interchain account
+    UpdateRemoteContractMetadata {
+        connection_id: String,
+        contract_address: String,
+        owner_address: Option<String>,
+        rewards_address: Option<String>,
+        timeout_seconds: u64,
     },
 }
 
+// Ownership is added by cw_ownable to return the owner and the pending ownership transfer
+#[cw_ownable_query]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
     #[returns(u64)]
     SplitterCodeID {},
+    #[returns(Config)]
+    Config {},
+    // Returns the creation fees not forwarded to the fee splitter yet
+    #[returns(Vec<Coin>)]
+    CollectedFees {},
+    #[returns(InterchainAccount)]
+    InterchainAccount { connection_id: String },
+    // Returns a child of the factory, archived or not
+    #[returns(Child)]
+    Child { address: String },
+    // Returns the children of the factory, only the children with the tag when set
+    // Archived children are left out unless include_archived is set
+    #[returns(Vec<Child>)]
+    Children {
+        tag: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u8>,
+        include_archived: Option<bool>,
+    },
+    // Compares the wasm admin and the reward metadata of a child to the registry
+    #[returns(ChildHealthResponse)]
+    ChildHealth { address: String },
+    // Returns the health of a page of children
+    #[returns(Vec<ChildHealthResponse>)]
+    ChildrenHealth {
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+    // Returns the rewards withdrawn by the factory, for its children and itself
+    #[returns(Vec<Coin>)]
+    WithdrawnRewards {},
+    // Returns the owner of a referral code
+    #[returns(Addr)]
+    ReferralCode { code: String },
+    // Returns the rewards credited to a referrer and not claimed yet
+    #[returns(Vec<Coin>)]
+    ReferralRewards { referrer: String },
+    // Returns the outstanding Archway rewards of the children, grouped by rewards address
+    #[returns(Vec<OutstandingRewardsResponse>)]
+    OutstandingRewards {
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+    // Returns the members of a role
+    #[returns(Vec<Addr>)]
+    RoleMembers { role: String },
+    // Returns the actions permitted to a role
+    #[returns(Vec<String>)]
+    RoleActions { role: String },
+    // Returns the delay and the guardian of the sensitive admin actions
+    #[returns(Option<Timelock>)]
+    Timelock {},
+    // Returns a queued action
+    #[returns(Proposal)]
+    Proposal { id: u64 },
+    // Returns the queued actions, oldest first
+    #[returns(Vec<Proposal>)]
+    Proposals {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+    // Returns the progress of the last batch migration of the children
+    #[returns(Option<ChildrenMigration>)]
+    ChildrenMigration {},
+}
+
+// State of a child on the chain, the checks are false when it drifted from the registry
+#[cw_serde]
+pub struct ChildHealthResponse {
+    pub address: Addr,
+    pub code_id: u64,
+    // None when the child does not store a cw2 version
+    pub contract_version: Option<ContractVersion>,
+    pub wasm_admin: Option<String>,
+    pub owner_address: String,
+    pub rewards_address: String,
+    pub premium: Option<Coin>,
+    // Quarantine address of suspended children, the rewards address of the factory otherwise
+    pub expected_rewards_address: Addr,
+    pub admin_ok: bool,
+    pub owner_ok: bool,
+    pub rewards_ok: bool,
+}
+
+// Outstanding rewards of a rewards address and the children sending their rewards to it
+// A rewards address shared by children of different pages is reported on each page
+#[cw_serde]
+pub struct OutstandingRewardsResponse {
+    pub rewards_address: String,
+    pub children: Vec<Addr>,
+    pub rewards: Vec<Coin>,
+    pub records: u64,
 }
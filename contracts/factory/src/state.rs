@@ -1,13 +1,200 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Empty, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use pantheon_utils::{Rbac, Share};
 
-// Contract configuration
+// Contract configuration, the owner is stored by cw_ownable
 #[cw_serde]
 pub struct Config {
-    pub admin: Addr,
+    // Receives the rewards of suspended children, the factory itself when not set
+    pub quarantine_address: Option<Addr>,
+    // Part of the creation fee of a referred child that is credited to its referrer
+    pub referral_percentage: Decimal,
+    // Paid by the creator of each splitter, creating splitters is free when not set
+    #[serde(default)]
+    pub creation_fee: Option<Coin>,
+    // Splitter receiving the collected fees, e.g. the splitter of the team
+    #[serde(default)]
+    pub fee_splitter: Option<Addr>,
+    // Collected fees of a denom are forwarded once they reach the threshold, only on demand when not set
+    #[serde(default)]
+    pub fee_threshold: Option<Uint128>,
+    // Splitter created by the factory receiving its own rewards and the rewards of its next children
+    // The rewards go to the owner when not set
+    #[serde(default)]
+    pub rewards_splitter: Option<Addr>,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
+// Layout of the deployments from before the owner moved to cw_ownable
+// Only read by the migration, which converts it to the current layout
+#[cw_serde]
+pub struct LegacyConfig {
+    pub admin: Addr,
+}
+
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+
+// Roles allowed to run some of the admin actions, the actions are named after the execute messages
+pub const RBAC: Rbac = Rbac::new("role_members", "role_permissions");
+
 pub const SPLITTER_CODE_ID: Item<u64> = Item::new("splitter_code_id");
+
+// Delay in seconds of the sensitive admin actions and the address allowed to cancel them
+#[cw_serde]
+pub struct Timelock {
+    pub delay: u64,
+    pub guardian: Addr,
+}
+
+pub const TIMELOCK: Item<Timelock> = Item::new("timelock");
+
+// Admin actions that have to be proposed and wait for the delay while the timelock is set
+#[cw_serde]
+pub enum QueuedAction {
+    UpdateSplitterCodeId {
+        code_id: u64,
+    },
+    UpdateReferralPercentage {
+        percentage: Decimal,
+    },
+    MigrateTag {
+        tag: String,
+        code_id: u64,
+        msg: Binary,
+    },
+    UpdateTimelock {
+        delay: u64,
+        guardian: String,
+    },
+    RemoveTimelock {},
+    MigrateChildren {
+        code_id_from: u64,
+        code_id_to: u64,
+        msg: Binary,
+        limit: Option<u8>,
+    },
+    CreateRewardsSplitter {
+        shares: Vec<Share>,
+        label: String,
+    },
+    UpdateCreationFee {
+        fee: Option<Coin>,
+    },
+    UpdateFeeSplitter {
+        address: Option<String>,
+        threshold: Option<Uint128>,
+    },
+    TransferChildAdmin {
+        address: String,
+        new_admin: String,
+    },
+}
+
+#[cw_serde]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Addr,
+    pub action: QueuedAction,
+    pub executable_at: Timestamp,
+}
+
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+pub const LAST_PROPOSAL_ID: Item<u64> = Item::new("last_proposal_id");
+
+// Splitter created by the factory, or contract created before it and registered afterwards
+// Suspended children have their rewards sent to the quarantine address
+#[cw_serde]
+pub struct Child {
+    pub address: Addr,
+    pub creator: Addr,
+    // Wasm admin of the child, the factory when it retained the admin
+    pub admin: Addr,
+    pub label: String,
+    pub suspended: bool,
+    pub tags: Vec<String>,
+    // Owner of the referral code used to create the child
+    pub referrer: Option<Addr>,
+    // Registered with RegisterContract instead of created by the factory
+    #[serde(default)]
+    pub registered: bool,
+    // Time the child was deregistered, only set on archived children
+    #[serde(default)]
+    pub archived_at: Option<Timestamp>,
+    // Premium set by the factory on the transactions calling the child
+    #[serde(default)]
+    pub premium: Option<Coin>,
+}
+
+pub const CHILDREN: Map<Addr, Child> = Map::new("children");
+
+// Deregistered children, kept out of the registry but available for audits
+pub const ARCHIVED_CHILDREN: Map<Addr, Child> = Map::new("archived_children");
+
+// Migration of the children running a code to another code, one page of the registry per batch
+// A finished migration can be started again to retry the children that failed
+#[cw_serde]
+pub struct ChildrenMigration {
+    pub code_id_from: u64,
+    pub code_id_to: u64,
+    pub msg: Binary,
+    // Last child of the registry visited, the next batch starts after it
+    pub last_visited: Option<Addr>,
+    pub migrated: u64,
+    pub failed: Vec<Addr>,
+    pub done: bool,
+}
+
+pub const CHILDREN_MIGRATION: Item<ChildrenMigration> = Item::new("children_migration");
+
+// Children of the batch waiting for the reply of their migration, in the order of the messages
+pub const PENDING_MIGRATIONS: Item<Vec<Addr>> = Item::new("pending_migrations");
+
+// Children by tag, e.g. "game", "defi" or "v2"
+pub const CHILD_TAGS: Map<(String, Addr), Empty> = Map::new("child_tags");
+
+// Rewards splitter waiting for the reply of its instantiation to be wired to the factory
+pub const PENDING_REWARDS_SPLITTER: Item<Addr> = Item::new("pending_rewards_splitter");
+
+// Custom contract and splitter created together, the splitter is instantiated in the reply of the
+// contract and both are registered in the reply of the splitter
+#[cw_serde]
+pub struct PendingContractWithSplitter {
+    pub creator: Addr,
+    pub admin: Addr,
+    pub contract: Addr,
+    pub label: String,
+    pub splitter: Addr,
+    pub splitter_code_id: u64,
+    pub splitter_msg: Binary,
+    pub splitter_salt: Binary,
+    pub tags: Vec<String>,
+    pub referrer: Option<Addr>,
+}
+
+pub const PENDING_CONTRACT_WITH_SPLITTER: Item<PendingContractWithSplitter> =
+    Item::new("pending_contract_with_splitter");
+
+// Rewards withdrawn from the rewards records of the factory, keyed by the denom
+// The records include the rewards of the factory itself and can not be attributed to a child
+pub const WITHDRAWN_REWARDS: Map<String, Uint128> = Map::new("withdrawn_rewards");
+
+// Creation fees not forwarded to the fee splitter yet, keyed by the denom
+pub const COLLECTED_FEES: Map<String, Uint128> = Map::new("collected_fees");
+
+// Owners of the referral codes, keyed by the code
+pub const REFERRAL_CODES: Map<String, Addr> = Map::new("referral_codes");
+
+// Rewards credited to a referrer and not claimed yet, keyed by the referrer and the denom
+pub const REFERRAL_REWARDS: Map<(Addr, String), Uint128> = Map::new("referral_rewards");
+
+// Interchain account registered by the factory on another chain
+// Address is empty until the ICA channel is open and the address is recorded
+#[cw_serde]
+pub struct InterchainAccount {
+    pub connection_id: String,
+    pub address: Option<String>,
+}
+
+pub const INTERCHAIN_ACCOUNTS: Map<String, InterchainAccount> = Map::new("interchain_accounts");
@@ -0,0 +1,349 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    coin, coins, from_binary, Addr, Binary, Coin, OwnedDeps, Reply, StdResult, SubMsgResponse,
+    SubMsgResult,
+};
+use cw_multi_test::Executor;
+
+use pantheon_factory::{
+    contract::{instantiate, query, reply},
+    msg::{ChildHealthResponse, ExecuteMsg, InstantiateMsg, OutstandingRewardsResponse, QueryMsg},
+    state::Child,
+    ContractError,
+};
+
+#[test]
+fn test_suspend_unknown_child() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::SuspendChild {
+                address: "splitter".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    // Only the splitters created by the factory can be suspended
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::SuspendChild {
+                address: "splitter".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ChildNotFound {}.to_string()
+    );
+
+    let res: Vec<Child> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::Children {
+                tag: None,
+                start_after: None,
+                limit: None,
+                include_archived: None,
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+
+    let res: Vec<OutstandingRewardsResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address,
+            &QueryMsg::OutstandingRewards {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn test_withdraw_unknown_child_rewards() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::WithdrawChildRewards {
+                addresses: vec!["splitter".to_string()],
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ChildNotFound {}.to_string()
+    );
+
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(factory_address, &QueryMsg::WithdrawnRewards {})
+        .unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn test_unknown_child_health() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let res: StdResult<ChildHealthResponse> = app.wrap().query_wasm_smart(
+        factory_address.clone(),
+        &QueryMsg::ChildHealth {
+            address: "splitter".to_string(),
+        },
+    );
+    assert!(res.is_err());
+
+    let res: Vec<ChildHealthResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address,
+            &QueryMsg::ChildrenHealth {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn test_tag_unknown_child() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::UpdateChildTags {
+                address: "splitter".to_string(),
+                tags: vec!["game".to_string()],
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ChildNotFound {}.to_string()
+    );
+
+    // Batch operations on a tag without children do nothing
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::SuspendTag {
+            tag: "game".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<Child> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address,
+            &QueryMsg::Children {
+                tag: Some("game".to_string()),
+                start_after: None,
+                limit: None,
+                include_archived: None,
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn test_transfer_unknown_child_admin() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::TransferChildAdmin {
+                address: "splitter".to_string(),
+                new_admin: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address,
+            &ExecuteMsg::TransferChildAdmin {
+                address: "splitter".to_string(),
+                new_admin: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ChildNotFound {}.to_string()
+    );
+}
+
+#[test]
+fn test_unknown_child_premium() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::UpdateChildPremium {
+                address: "splitter".to_string(),
+                fee: coin(1_000, DENOM),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address,
+            &ExecuteMsg::UpdateChildPremium {
+                address: "splitter".to_string(),
+                fee: coin(1_000, DENOM),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ChildNotFound {}.to_string()
+    );
+}
+
+#[test]
+fn test_withdraw_rewards_reply() {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData::<ArchwayQuery>,
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            splitter_code_id: 1,
+            rewards_splitter: None,
+        },
+    )
+    .unwrap();
+
+    // The rewards of every withdrawal add up, whichever children were given
+    for _ in 0..2 {
+        reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: 1,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: Some(Binary::from(
+                        br#"{"records_num":2,"total_rewards":[{"denom":"aconst","amount":"150"}]}"#,
+                    )),
+                }),
+            },
+        )
+        .unwrap();
+    }
+
+    let res: Vec<Coin> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::WithdrawnRewards {}).unwrap())
+            .unwrap();
+    assert_eq!(res, vec![coin(300, DENOM)]);
+}
+
+#[test]
+fn test_outstanding_rewards_by_rewards_address() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    // Both splitters send their rewards to the admin
+    let mut splitters = vec![];
+    for _ in 0..2 {
+        let splitter_address = external_splitter(&mut app, &factory_address, &factory_address);
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::RegisterContract {
+                address: splitter_address.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap();
+        splitters.push(splitter_address);
+    }
+    app.init_modules(|router, _, storage| {
+        router
+            .custom
+            .add_rewards(storage, ADMIN, coins(500, DENOM))
+            .unwrap();
+    });
+
+    let res: Vec<OutstandingRewardsResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address,
+            &QueryMsg::OutstandingRewards {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![OutstandingRewardsResponse {
+            rewards_address: ADMIN.to_string(),
+            children: splitters,
+            rewards: coins(500, DENOM),
+            records: 1,
+        }]
+    );
+}
@@ -0,0 +1,173 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    Addr, Binary, CosmosMsg, Decimal, OwnedDeps, Reply, SubMsg, SubMsgResponse, SubMsgResult,
+    WasmMsg,
+};
+use cw_multi_test::Executor;
+use pantheon_factory::{
+    contract::{instantiate, reply},
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::{PendingContractWithSplitter, CHILDREN, PENDING_CONTRACT_WITH_SPLITTER},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+fn success() -> SubMsgResult {
+    SubMsgResult::Ok(SubMsgResponse {
+        events: vec![],
+        data: None,
+    })
+}
+
+#[test]
+fn test_create_contract_with_splitter_errors() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address,
+            &ExecuteMsg::CreateContractWithSplitter {
+                code_id: 1,
+                msg: Binary::default(),
+                label: "".to_string(),
+                shares: vec![Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::from_str("1").unwrap(),
+                    ibc: None,
+                    burn: false,
+                }],
+                mutable: false,
+                transfer_requires_approval: false,
+                tags: vec![],
+                retain_admin: false,
+                referral_code: None,
+                salt: None,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::EmptyLabel {}.to_string()
+    );
+}
+
+#[test]
+fn test_contract_with_splitter_replies() {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData::<ArchwayQuery>,
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            splitter_code_id: 1,
+            rewards_splitter: None,
+        },
+    )
+    .unwrap();
+    PENDING_CONTRACT_WITH_SPLITTER
+        .save(
+            deps.as_mut().storage,
+            &PendingContractWithSplitter {
+                creator: Addr::unchecked(USER),
+                admin: Addr::unchecked(USER),
+                contract: Addr::unchecked("game"),
+                label: "Game".to_string(),
+                splitter: Addr::unchecked("splitter"),
+                splitter_code_id: 1,
+                splitter_msg: Binary::from(b"{}"),
+                splitter_salt: Binary::from(b"game"),
+                tags: vec!["game".to_string()],
+                referrer: None,
+            },
+        )
+        .unwrap();
+
+    // The reply of the contract instantiates the splitter
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 4,
+            result: success(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_on_success(
+            WasmMsg::Instantiate2 {
+                admin: Some(mock_env().contract.address.to_string()),
+                code_id: 1,
+                msg: Binary::from(b"{}"),
+                funds: vec![],
+                label: "Game Splitter".to_string(),
+                salt: Binary::from(b"game"),
+            },
+            5
+        )]
+    );
+    assert!(CHILDREN
+        .may_load(deps.as_ref().storage, Addr::unchecked("game"))
+        .unwrap()
+        .is_none());
+
+    // The reply of the splitter wires the rewards of the contract and registers both
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 5,
+            result: success(),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Custom(ArchwayMsg::UpdateContractMetadata {
+                contract_address: Some("game".to_string()),
+                owner_address: Some(mock_env().contract.address.to_string()),
+                rewards_address: Some("splitter".to_string()),
+            })),
+            SubMsg::new(WasmMsg::UpdateAdmin {
+                contract_addr: "game".to_string(),
+                admin: USER.to_string(),
+            }),
+            SubMsg::new(CosmosMsg::Custom(ArchwayMsg::UpdateContractMetadata {
+                contract_address: Some("splitter".to_string()),
+                owner_address: Some(mock_env().contract.address.to_string()),
+                rewards_address: Some(ADMIN.to_string()),
+            })),
+            SubMsg::new(WasmMsg::UpdateAdmin {
+                contract_addr: "splitter".to_string(),
+                admin: USER.to_string(),
+            }),
+        ]
+    );
+
+    let child = CHILDREN
+        .load(deps.as_ref().storage, Addr::unchecked("game"))
+        .unwrap();
+    assert_eq!(child.creator, Addr::unchecked(USER));
+    assert_eq!(child.tags, vec!["game".to_string()]);
+    let child = CHILDREN
+        .load(deps.as_ref().storage, Addr::unchecked("splitter"))
+        .unwrap();
+    assert_eq!(child.label, "Game Splitter");
+    assert!(!PENDING_CONTRACT_WITH_SPLITTER.exists(deps.as_ref().storage));
+}
@@ -3,10 +3,11 @@ use helpers::*;
 
 use std::str::FromStr;
 
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{Addr, Binary, Decimal};
 use cw_multi_test::Executor;
 
-use pantheon_factory::msg::ExecuteMsg;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_factory::{msg::ExecuteMsg, ContractError};
 use pantheon_utils::Share;
 
 // #[test]
@@ -17,6 +18,8 @@ fn test_happy_path() {
     let shares = vec![Share {
         recipient: USER.to_string(),
         percentage: Decimal::from_str("1").unwrap(),
+        ibc: None,
+        burn: false,
     }];
 
     app.execute_contract(
@@ -25,7 +28,12 @@ fn test_happy_path() {
         &ExecuteMsg::CreateSplitter {
             shares,
             mutable: false,
+            transfer_requires_approval: false,
             label: "My First Splitter".to_string(),
+            tags: vec![],
+            retain_admin: false,
+            referral_code: None,
+            salt: None,
         },
         &vec![],
     )
@@ -37,3 +45,124 @@ fn test_happy_path() {
     assert_eq!(res.admin, Some(Addr::unchecked(USER)));
     assert_eq!(res.label, "Pantheon Splitter");
 }
+
+fn create_splitter(
+    app: &mut ArchwayApp,
+    factory_address: &Addr,
+    shares: Vec<Share>,
+    label: &str,
+    salt: Option<Binary>,
+) -> String {
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::CreateSplitter {
+                shares,
+                mutable: false,
+                transfer_requires_approval: false,
+                label: label.to_string(),
+                tags: vec![],
+                retain_admin: false,
+                referral_code: None,
+                salt,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    err.source().unwrap().to_string()
+}
+
+fn share(recipient: &str, percentage: &str) -> Share {
+    Share {
+        recipient: recipient.to_string(),
+        percentage: Decimal::from_str(percentage).unwrap(),
+        ibc: None,
+        burn: false,
+    }
+}
+
+#[test]
+fn test_invalid_params() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = create_splitter(
+        &mut app,
+        &factory_address,
+        vec![share(USER, "1")],
+        " ",
+        None,
+    );
+    assert_eq!(err, ContractError::EmptyLabel {}.to_string());
+
+    let err = create_splitter(&mut app, &factory_address, vec![], "Splitter", None);
+    assert_eq!(err, ContractError::NoShares {}.to_string());
+
+    let err = create_splitter(
+        &mut app,
+        &factory_address,
+        vec![share(USER, "0.5"), share(USER, "0.5")],
+        "Splitter",
+        None,
+    );
+    assert_eq!(err, ContractError::RecipientExists {}.to_string());
+
+    let err = create_splitter(
+        &mut app,
+        &factory_address,
+        vec![share(USER, "0.5"), share(ADMIN, "0.4")],
+        "Splitter",
+        None,
+    );
+    assert_eq!(err, ContractError::PercentageLimitNotMet {}.to_string());
+
+    let err = create_splitter(
+        &mut app,
+        &factory_address,
+        vec![share(USER, "0.5"), share(ADMIN, "0.6")],
+        "Splitter",
+        None,
+    );
+    assert_eq!(err, ContractError::PercentageLimitExceeded {}.to_string());
+
+    let err = create_splitter(
+        &mut app,
+        &factory_address,
+        vec![share(USER, "1")],
+        "Splitter",
+        Some(Binary::from(vec![1; 65])),
+    );
+    assert_eq!(err, ContractError::InvalidSalt { max: 64 }.to_string());
+}
+
+#[test]
+fn test_salt_of_contract_sender() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    // Contract addresses take 66 characters, the longest salt must still be accepted from them
+    let sender = Addr::unchecked(format!("archway1{}", "q".repeat(58)));
+    let err = app
+        .execute_contract(
+            sender,
+            factory_address,
+            &ExecuteMsg::CreateSplitter {
+                shares: vec![share(USER, "1")],
+                mutable: false,
+                transfer_requires_approval: false,
+                label: "Splitter".to_string(),
+                tags: vec![],
+                retain_admin: false,
+                referral_code: None,
+                salt: Some(Binary::from(vec![1; 64])),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    // multi-test can not predict the Instantiate2 address, the salt is accepted before that
+    assert_ne!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidSalt { max: 64 }.to_string()
+    );
+}
@@ -0,0 +1,110 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::Addr;
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_factory::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::Child,
+    ContractError,
+};
+
+fn children(app: &ArchwayApp, factory_address: &Addr, include_archived: bool) -> Vec<Child> {
+    app.wrap()
+        .query_wasm_smart(
+            factory_address,
+            &QueryMsg::Children {
+                tag: Some("game".to_string()),
+                start_after: None,
+                limit: None,
+                include_archived: Some(include_archived),
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_deregister_child() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+    let splitter_address = external_splitter(&mut app, &factory_address, &factory_address);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::RegisterContract {
+            address: splitter_address.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateChildTags {
+            address: splitter_address.to_string(),
+            tags: vec!["game".to_string()],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::DeregisterChild {
+                address: splitter_address.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::DeregisterChild {
+            address: splitter_address.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    assert!(children(&app, &factory_address, false).is_empty());
+    let res = children(&app, &factory_address, true);
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].address, splitter_address);
+    assert!(res[0].archived_at.is_some());
+
+    // The archived record stays available
+    let res: Child = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::Child {
+                address: splitter_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.archived_at.is_some());
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address,
+            &ExecuteMsg::SuspendChild {
+                address: splitter_address.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ChildNotFound {}.to_string()
+    );
+}
@@ -0,0 +1,147 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{coin, coins, Addr, CosmosMsg, OwnedDeps, Uint128, WasmMsg};
+use cw_multi_test::Executor;
+use pantheon_factory::{
+    contract::{execute, instantiate},
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::COLLECTED_FEES,
+    ContractError,
+};
+
+#[test]
+fn test_creation_fee() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateCreationFee {
+            fee: Some(coin(100, DENOM)),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::CreateSplitter {
+                shares: vec![],
+                mutable: false,
+                transfer_requires_approval: false,
+                label: "Unpaid Splitter".to_string(),
+                tags: vec![],
+                retain_admin: false,
+                referral_code: None,
+                salt: None,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidCreationFee {
+            fee: coin(100, DENOM).to_string()
+        }
+        .to_string()
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::ForwardFees {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::FeeSplitterNotSet {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateFeeSplitter {
+            address: Some("splitter".to_string()),
+            threshold: None,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address,
+            &ExecuteMsg::ForwardFees {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NoFeesToForward {}.to_string()
+    );
+}
+
+#[test]
+fn test_forward_fees() {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData::<ArchwayQuery>,
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            splitter_code_id: 1,
+            rewards_splitter: None,
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateFeeSplitter {
+            address: Some("splitter".to_string()),
+            threshold: None,
+        },
+    )
+    .unwrap();
+
+    COLLECTED_FEES
+        .save(deps.as_mut().storage, DENOM.to_string(), &Uint128::new(300))
+        .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(USER, &[]),
+        ExecuteMsg::ForwardFees {},
+    )
+    .unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            funds,
+            ..
+        }) => {
+            assert_eq!(contract_addr, "splitter");
+            assert_eq!(funds, &coins(300, DENOM));
+        }
+        msg => panic!("unexpected message {:?}", msg),
+    }
+    assert!(!COLLECTED_FEES.has(deps.as_ref().storage, DENOM.to_string()));
+}
@@ -1,9 +1,10 @@
 use archway_bindings::{ArchwayMsg, ArchwayQuery};
-use cosmwasm_std::{testing::MockApi, Addr, Coin, MemoryStorage, Uint128};
-use cw_multi_test::{
-    custom_app, App, BankKeeper, Contract, ContractWrapper, Executor, FailingModule, WasmKeeper,
-};
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Decimal, Uint128};
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+use pantheon_archway_mock::{archway_app, ArchwayApp};
 use pantheon_factory::msg::InstantiateMsg;
+use pantheon_splitter::msg::InstantiateMsg as SplitterInstantiateMsg;
+use pantheon_utils::Share;
 
 pub fn factory_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
     let contract = ContractWrapper::new(
@@ -27,47 +28,76 @@ pub const USER: &str = "user";
 
 pub const DENOM: &str = "aconst";
 
-pub fn mock_app() -> App<
-    BankKeeper,
-    MockApi,
-    MemoryStorage,
-    FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
-    WasmKeeper<ArchwayMsg, ArchwayQuery>,
-> {
-    custom_app::<ArchwayMsg, ArchwayQuery, _>(|router, _, storage| {
-        router
-            .bank
-            .init_balance(
-                storage,
-                &Addr::unchecked(ADMIN),
-                vec![Coin {
-                    denom: DENOM.to_string(),
-                    amount: Uint128::new(1),
-                }],
-            )
-            .unwrap();
+pub fn mock_app() -> ArchwayApp {
+    archway_app(|bank, _, storage| {
+        bank.init_balance(
+            storage,
+            &Addr::unchecked(ADMIN),
+            vec![Coin {
+                denom: DENOM.to_string(),
+                amount: Uint128::new(1),
+            }],
+        )
+        .unwrap();
     })
 }
 
-pub fn proper_instantiate(
-    app: &mut App<
-        BankKeeper,
-        MockApi,
-        MemoryStorage,
-        FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
-        WasmKeeper<ArchwayMsg, ArchwayQuery>,
-    >,
-) -> Addr {
+pub fn proper_instantiate(app: &mut ArchwayApp) -> Addr {
     let factory_code_id = app.store_code(factory_contract());
     let splitter_code_id = app.store_code(splitter_contract());
 
     app.instantiate_contract(
         factory_code_id,
         Addr::unchecked(ADMIN),
-        &InstantiateMsg { splitter_code_id },
+        &InstantiateMsg {
+            splitter_code_id,
+            rewards_splitter: None,
+        },
         &vec![],
         "Pantheon Factory",
         None,
     )
     .unwrap()
 }
+
+// Instantiates a splitter outside of the factory and hands its metadata over to the factory
+pub fn external_splitter(
+    app: &mut ArchwayApp,
+    factory_address: &Addr,
+    metadata_owner: &Addr,
+) -> Addr {
+    let splitter_address = app
+        .instantiate_contract(
+            2,
+            Addr::unchecked(ADMIN),
+            &SplitterInstantiateMsg {
+                admin: ADMIN.to_string(),
+                shares: vec![Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::one(),
+                    ibc: None,
+                    burn: false,
+                }],
+                mutable: false,
+                transfer_requires_approval: false,
+                disable_emergency_withdraw: false,
+                restore: None,
+            },
+            &vec![],
+            "External Splitter",
+            Some(factory_address.to_string()),
+        )
+        .unwrap();
+
+    app.execute(
+        splitter_address.clone(),
+        CosmosMsg::Custom(ArchwayMsg::UpdateContractMetadata {
+            contract_address: None,
+            owner_address: Some(metadata_owner.to_string()),
+            rewards_address: Some(ADMIN.to_string()),
+        }),
+    )
+    .unwrap();
+
+    splitter_address
+}
@@ -16,7 +16,10 @@ fn test_happy_path() {
     app.instantiate_contract(
         factory_code_id,
         Addr::unchecked(ADMIN),
-        &InstantiateMsg { splitter_code_id },
+        &InstantiateMsg {
+            splitter_code_id,
+            rewards_splitter: None,
+        },
         &vec![],
         "Pantheon Factory",
         None,
@@ -0,0 +1,53 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::Addr;
+use cw_multi_test::Executor;
+
+use pantheon_factory::{msg::ExecuteMsg, ContractError};
+
+#[test]
+fn test_not_registered() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::UpdateRemoteContractMetadata {
+                connection_id: "connection-0".to_string(),
+                contract_address: "archway1contract".to_string(),
+                owner_address: None,
+                rewards_address: Some("archway1rewards".to_string()),
+                timeout_seconds: 600,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InterchainAccountNotRegistered {}.to_string()
+    )
+}
+
+#[test]
+fn test_invalid_admin() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::RegisterInterchainAccount {
+                connection_id: "connection-0".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    )
+}
@@ -0,0 +1,89 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{from_binary, Addr, OwnedDeps, Storage};
+use cw2::{get_contract_version, set_contract_version, VersionError};
+use cw_ownable::Ownership;
+use pantheon_factory::{
+    contract::{migrate, query},
+    msg::{MigrateMsg, QueryMsg},
+    state::{LegacyConfig, CONFIG, LEGACY_CONFIG},
+    ContractError,
+};
+
+const CONTRACT_NAME: &str = "crates.io:pantheon-factory";
+
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery> {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData::<ArchwayQuery>,
+    }
+}
+
+#[test]
+fn test_migrate_legacy_layout() {
+    let mut deps = mock_deps();
+    set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.1").unwrap();
+
+    LEGACY_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &LegacyConfig {
+                admin: Addr::unchecked(ADMIN),
+            },
+        )
+        .unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let res: Ownership<Addr> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Ownership {}).unwrap()).unwrap();
+    assert_eq!(res.owner, Some(Addr::unchecked(ADMIN)));
+
+    let config = CONFIG.load(deps.as_ref().storage).unwrap();
+    assert_eq!(config.quarantine_address, None);
+
+    let res = get_contract_version(deps.as_ref().storage).unwrap();
+    assert_eq!(res.version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn test_migrate_unrelated_contract() {
+    let mut deps = mock_deps();
+    set_contract_version(deps.as_mut().storage, "crates.io:cw20-base", "0.0.1").unwrap();
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Version(VersionError::WrongContract { .. })
+    ));
+}
+
+#[test]
+fn test_migrate_newer_version() {
+    let mut deps = mock_deps();
+    set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0").unwrap();
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Version(VersionError::WrongVersion { .. })
+    ));
+}
+
+#[test]
+fn test_migrate_unreadable_config() {
+    let mut deps = mock_deps();
+    set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.1").unwrap();
+
+    // A config in neither layout is not skipped as if there was nothing to convert
+    deps.storage.set(b"config", b"{\"admin\":1}");
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert!(matches!(err, ContractError::Std(_)));
+}
@@ -0,0 +1,151 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Addr, Binary, OwnedDeps, Reply, SubMsgResponse, SubMsgResult};
+use cw_multi_test::Executor;
+use pantheon_factory::{
+    contract::reply,
+    msg::{ExecuteMsg, QueryMsg},
+    state::{ChildrenMigration, CHILDREN_MIGRATION, PENDING_MIGRATIONS},
+    ContractError,
+};
+
+fn migrate_children_msg() -> ExecuteMsg {
+    ExecuteMsg::MigrateChildren {
+        code_id_from: 2,
+        code_id_to: 3,
+        msg: Binary::default(),
+        limit: None,
+    }
+}
+
+#[test]
+fn test_migrate_empty_registry() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &migrate_children_msg(),
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &migrate_children_msg(),
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<ChildrenMigration> = app
+        .wrap()
+        .query_wasm_smart(factory_address, &QueryMsg::ChildrenMigration {})
+        .unwrap();
+    let migration = res.unwrap();
+    assert!(migration.done);
+    assert_eq!(migration.migrated, 0);
+    assert_eq!(migration.last_visited, None);
+}
+
+#[test]
+fn test_migrate_children_timelocked() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateTimelock {
+            delay: 60,
+            guardian: USER.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address,
+            &migrate_children_msg(),
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::TimelockRequired {}.to_string()
+    );
+}
+
+#[test]
+fn test_migration_replies() {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData::<ArchwayQuery>,
+    };
+
+    CHILDREN_MIGRATION
+        .save(
+            deps.as_mut().storage,
+            &ChildrenMigration {
+                code_id_from: 2,
+                code_id_to: 3,
+                msg: Binary::default(),
+                last_visited: Some(Addr::unchecked("child2")),
+                migrated: 0,
+                failed: vec![],
+                done: true,
+            },
+        )
+        .unwrap();
+    PENDING_MIGRATIONS
+        .save(
+            deps.as_mut().storage,
+            &vec![Addr::unchecked("child1"), Addr::unchecked("child2")],
+        )
+        .unwrap();
+
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 2,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 2,
+            result: SubMsgResult::Err("unauthorized".to_string()),
+        },
+    )
+    .unwrap();
+
+    let migration = CHILDREN_MIGRATION.load(deps.as_ref().storage).unwrap();
+    assert_eq!(migration.migrated, 1);
+    assert_eq!(migration.failed, vec![Addr::unchecked("child2")]);
+    assert!(PENDING_MIGRATIONS
+        .load(deps.as_ref().storage)
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,61 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::Addr;
+use cw_multi_test::Executor;
+use cw_ownable::{Action, Ownership, OwnershipError};
+
+use pantheon_factory::msg::{ExecuteMsg, QueryMsg};
+
+#[test]
+fn test_transfer_ownership() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let res: Ownership<Addr> = app
+        .wrap()
+        .query_wasm_smart(factory_address.clone(), &QueryMsg::Ownership {})
+        .unwrap();
+    assert_eq!(res.owner, Some(Addr::unchecked(ADMIN)));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+            new_owner: USER.to_string(),
+            expiry: None,
+        }),
+        &vec![],
+    )
+    .unwrap();
+
+    // Only the pending owner can accept the transfer
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        OwnershipError::NotPendingOwner.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_address,
+        &ExecuteMsg::UpdateSplitterCodeId { code_id: 1 },
+        &vec![],
+    )
+    .unwrap();
+}
@@ -0,0 +1,241 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{coin, coins, Addr, Coin, Decimal};
+use cw_multi_test::Executor;
+
+use pantheon_factory::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+#[test]
+fn test_register_referral_code() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_address.clone(),
+        &ExecuteMsg::RegisterReferralCode {
+            code: "pantheon".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::ReferralCode {
+                code: "pantheon".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Addr::unchecked(USER));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::RegisterReferralCode {
+                code: "pantheon".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ReferralCodeTaken {
+            code: "pantheon".to_string()
+        }
+        .to_string()
+    );
+
+    // Nothing is credited until a referred child pays a creation fee
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::ReferralRewards {
+                referrer: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address,
+            &ExecuteMsg::ClaimReferralRewards {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NoReferralRewards {}.to_string()
+    );
+}
+
+#[test]
+fn test_unknown_referral_code() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address,
+            &ExecuteMsg::CreateSplitter {
+                shares: vec![],
+                mutable: false,
+                transfer_requires_approval: false,
+                label: "Referred Splitter".to_string(),
+                tags: vec![],
+                retain_admin: false,
+                referral_code: Some("unknown".to_string()),
+                salt: None,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ReferralCodeNotFound {
+            code: "unknown".to_string()
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_update_referral_percentage() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::UpdateReferralPercentage {
+                percentage: Decimal::from_str("0.1").unwrap(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::UpdateReferralPercentage {
+                percentage: Decimal::from_str("1.1").unwrap(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidReferralPercentage {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address,
+        &ExecuteMsg::UpdateReferralPercentage {
+            percentage: Decimal::from_str("0.1").unwrap(),
+        },
+        &vec![],
+    )
+    .unwrap();
+}
+
+// multi-test can not execute the instantiate2 message of the factory
+// #[test]
+fn test_referral_fee() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_address.clone(),
+        &ExecuteMsg::RegisterReferralCode {
+            code: "pantheon".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateReferralPercentage {
+            percentage: Decimal::one(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateCreationFee {
+            fee: Some(coin(1, DENOM)),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::CreateSplitter {
+            shares: vec![Share {
+                recipient: ADMIN.to_string(),
+                percentage: Decimal::one(),
+                ibc: None,
+                burn: false,
+            }],
+            mutable: false,
+            transfer_requires_approval: false,
+            label: "Referred Splitter".to_string(),
+            tags: vec![],
+            retain_admin: false,
+            referral_code: Some("pantheon".to_string()),
+            salt: None,
+        },
+        &coins(1, DENOM),
+    )
+    .unwrap();
+
+    // The whole fee goes to the referrer, nothing is left to forward
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::ReferralRewards {
+                referrer: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, coins(1, DENOM));
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_address,
+        &ExecuteMsg::ClaimReferralRewards {},
+        &vec![],
+    )
+    .unwrap();
+    assert_eq!(
+        app.wrap().query_balance(USER, DENOM).unwrap(),
+        coin(1, DENOM)
+    );
+}
@@ -0,0 +1,111 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::Addr;
+use cw_multi_test::Executor;
+use pantheon_factory::{
+    msg::{ChildHealthResponse, ExecuteMsg, QueryMsg},
+    state::Child,
+    ContractError,
+};
+
+#[test]
+fn test_register_contract() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+    let splitter_address = external_splitter(&mut app, &factory_address, &factory_address);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::RegisterContract {
+                address: splitter_address.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::RegisterContract {
+            address: splitter_address.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Child = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::Child {
+                address: splitter_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.registered);
+    assert_eq!(res.creator, Addr::unchecked(ADMIN));
+    assert_eq!(res.admin, factory_address);
+
+    // Registered contracts are reported alongside the children created by the factory
+    let res: ChildHealthResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::ChildHealth {
+                address: splitter_address.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.admin_ok && res.owner_ok && res.rewards_ok);
+    assert_eq!(
+        res.contract_version.unwrap().contract,
+        "crates.io:pantheon-splitter"
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address,
+            &ExecuteMsg::RegisterContract {
+                address: splitter_address.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ChildAlreadyRegistered {}.to_string()
+    );
+}
+
+#[test]
+fn test_register_contract_not_owned() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+    let splitter_address = external_splitter(&mut app, &factory_address, &Addr::unchecked(ADMIN));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address,
+            &ExecuteMsg::RegisterContract {
+                address: splitter_address.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NotMetadataOwner {
+            address: splitter_address.to_string()
+        }
+        .to_string()
+    );
+}
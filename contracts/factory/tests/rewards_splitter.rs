@@ -0,0 +1,144 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    Addr, CosmosMsg, Decimal, OwnedDeps, Reply, SubMsg, SubMsgResponse, SubMsgResult,
+};
+use cw_multi_test::Executor;
+use pantheon_factory::{
+    contract::{instantiate, reply},
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::{CONFIG, PENDING_REWARDS_SPLITTER},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+fn create_rewards_splitter_msg(label: &str) -> ExecuteMsg {
+    ExecuteMsg::CreateRewardsSplitter {
+        shares: vec![Share {
+            recipient: USER.to_string(),
+            percentage: Decimal::from_str("1").unwrap(),
+            ibc: None,
+            burn: false,
+        }],
+        label: label.to_string(),
+    }
+}
+
+#[test]
+fn test_create_rewards_splitter_errors() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &create_rewards_splitter_msg("Team"),
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &create_rewards_splitter_msg(""),
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::EmptyLabel {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateTimelock {
+            delay: 3_600,
+            guardian: ADMIN.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address,
+            &create_rewards_splitter_msg("Team"),
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::TimelockRequired {}.to_string()
+    );
+}
+
+#[test]
+fn test_rewards_splitter_reply() {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData::<ArchwayQuery>,
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            splitter_code_id: 1,
+            rewards_splitter: None,
+        },
+    )
+    .unwrap();
+    PENDING_REWARDS_SPLITTER
+        .save(deps.as_mut().storage, &Addr::unchecked("splitter"))
+        .unwrap();
+
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 3,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+
+    // The factory and the splitter send their rewards to the splitter
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(CosmosMsg::Custom(ArchwayMsg::UpdateContractMetadata {
+                contract_address: None,
+                owner_address: None,
+                rewards_address: Some("splitter".to_string()),
+            })),
+            SubMsg::new(CosmosMsg::Custom(ArchwayMsg::UpdateContractMetadata {
+                contract_address: Some("splitter".to_string()),
+                owner_address: Some(mock_env().contract.address.to_string()),
+                rewards_address: Some("splitter".to_string()),
+            })),
+        ]
+    );
+
+    let config = CONFIG.load(deps.as_ref().storage).unwrap();
+    assert_eq!(config.rewards_splitter, Some(Addr::unchecked("splitter")));
+    assert!(!PENDING_REWARDS_SPLITTER.exists(deps.as_ref().storage));
+}
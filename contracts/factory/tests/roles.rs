@@ -0,0 +1,123 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::Addr;
+use cw_multi_test::Executor;
+
+use pantheon_factory::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+
+const CURATOR: &str = "curator";
+
+#[test]
+fn test_role_permissions() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::UpdateQuarantineAddress {
+                address: Some(USER.to_string()),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::GrantRole {
+            role: CURATOR.to_string(),
+            address: USER.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateRoleActions {
+            role: CURATOR.to_string(),
+            actions: vec!["update_quarantine_address".to_string()],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::RoleMembers {
+                role: CURATOR.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec![Addr::unchecked(USER)]);
+    let res: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::RoleActions {
+                role: CURATOR.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec!["update_quarantine_address".to_string()]);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateQuarantineAddress {
+            address: Some(USER.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // Members are limited to the actions of their role
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::UpdateSplitterCodeId { code_id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::RevokeRole {
+            role: CURATOR.to_string(),
+            address: USER.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address,
+            &ExecuteMsg::UpdateQuarantineAddress { address: None },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+}
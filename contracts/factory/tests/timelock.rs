@@ -0,0 +1,250 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, Addr};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_factory::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::{Config, Proposal, QueuedAction, Timelock},
+    ContractError,
+};
+
+const GUARDIAN: &str = "guardian";
+
+fn set_timelock(app: &mut ArchwayApp, factory_address: &Addr) {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::UpdateTimelock {
+            delay: 3_600,
+            guardian: GUARDIAN.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_queued_action() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+    set_timelock(&mut app, &factory_address);
+
+    // Sensitive actions can no longer be run directly
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::UpdateSplitterCodeId { code_id: 42 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::TimelockRequired {}.to_string()
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::ProposeAction {
+                action: QueuedAction::UpdateSplitterCodeId { code_id: 42 },
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::ProposeAction {
+            action: QueuedAction::UpdateSplitterCodeId { code_id: 42 },
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<Proposal> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_address.clone(),
+            &QueryMsg::Proposals {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(
+        res[0].action,
+        QueuedAction::UpdateSplitterCodeId { code_id: 42 }
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::ExecuteProposal { id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ProposalNotReady {}.to_string()
+    );
+
+    app.update_block(|block| block.time = block.time.plus_seconds(3_600));
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::ExecuteProposal { id: 1 },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: u64 = app
+        .wrap()
+        .query_wasm_smart(factory_address, &QueryMsg::SplitterCodeID {})
+        .unwrap();
+    assert_eq!(res, 42);
+}
+
+#[test]
+fn test_guardian_cancels() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+    set_timelock(&mut app, &factory_address);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::ProposeAction {
+            action: QueuedAction::RemoveTimelock {},
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_address.clone(),
+            &ExecuteMsg::CancelProposal { id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(GUARDIAN),
+        factory_address.clone(),
+        &ExecuteMsg::CancelProposal { id: 1 },
+        &vec![],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(3_600));
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::ExecuteProposal { id: 1 },
+        &vec![],
+    )
+    .unwrap_err();
+
+    let res: Option<Timelock> = app
+        .wrap()
+        .query_wasm_smart(factory_address, &QueryMsg::Timelock {})
+        .unwrap();
+    assert_eq!(res.unwrap().guardian, Addr::unchecked(GUARDIAN));
+}
+
+#[test]
+fn test_queued_fee_actions() {
+    let mut app = mock_app();
+    let factory_address = proper_instantiate(&mut app);
+    set_timelock(&mut app, &factory_address);
+
+    for msg in [
+        ExecuteMsg::UpdateCreationFee {
+            fee: Some(coin(100, DENOM)),
+        },
+        ExecuteMsg::UpdateFeeSplitter {
+            address: Some("splitter".to_string()),
+            threshold: None,
+        },
+        ExecuteMsg::TransferChildAdmin {
+            address: "child".to_string(),
+            new_admin: USER.to_string(),
+        },
+    ] {
+        let err = app
+            .execute_contract(
+                Addr::unchecked(ADMIN),
+                factory_address.clone(),
+                &msg,
+                &vec![],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            ContractError::TimelockRequired {}.to_string()
+        );
+    }
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_address.clone(),
+            &ExecuteMsg::ProposeAction {
+                action: QueuedAction::UpdateCreationFee {
+                    fee: Some(coin(0, DENOM)),
+                },
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidCreationFee {
+            fee: coin(0, DENOM).to_string()
+        }
+        .to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::ProposeAction {
+            action: QueuedAction::UpdateCreationFee {
+                fee: Some(coin(100, DENOM)),
+            },
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(3_600));
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_address.clone(),
+        &ExecuteMsg::ExecuteProposal { id: 1 },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Config = app
+        .wrap()
+        .query_wasm_smart(factory_address, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.creation_fee, Some(coin(100, DENOM)));
+}
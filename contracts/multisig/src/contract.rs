@@ -0,0 +1,287 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{
+    AdminAction, Config, Proposal, ProposalStatus, APPROVALS, CONFIG, PROPOSALS, PROPOSAL_COUNT,
+};
+
+use archway_bindings::{ArchwayQuery, ArchwayResult};
+
+use pantheon_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use pantheon_splitter::msg::ExecuteMsg as SplitterExecuteMsg;
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:pantheon-multisig";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ArchwayResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.members.is_empty() {
+        return Err(ContractError::NoMembers {});
+    }
+
+    let mut members: Vec<Addr> = vec![];
+    for member in msg.members {
+        let member = deps.api.addr_validate(&member)?;
+        if members.contains(&member) {
+            return Err(ContractError::DuplicateMember {});
+        }
+        members.push(member);
+    }
+
+    if msg.threshold == 0 || msg.threshold > members.len() as u64 {
+        return Err(ContractError::InvalidThreshold {});
+    }
+
+    let config = Config {
+        members,
+        threshold: msg.threshold,
+        voting_period: msg.voting_period,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    PROPOSAL_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::new().add_attribute("threshold", config.threshold.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ArchwayResult<ContractError> {
+    match msg {
+        ExecuteMsg::Propose { title, action } => execute_propose(deps, env, info, title, action),
+        ExecuteMsg::Approve { id } => execute_approve(deps, env, info, id),
+        ExecuteMsg::Execute { id } => execute_execute(deps, env, info, id),
+    }
+}
+
+fn execute_propose(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    action: AdminAction,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only members can propose
+    if !config.members.contains(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let id = PROPOSAL_COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+    let proposal = Proposal {
+        id,
+        title,
+        action,
+        proposer: info.sender.clone(),
+        approvals: 1,
+        expires: env.block.time.plus_seconds(config.voting_period),
+        status: ProposalStatus::Open,
+    };
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    // The proposer approves its own proposal
+    APPROVALS.save(deps.storage, (id, info.sender), &true)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose")
+        .add_attribute("proposal_id", id.to_string()))
+}
+
+fn execute_approve(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !config.members.contains(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut proposal = PROPOSALS.load(deps.storage, id)?;
+
+    if proposal.status != ProposalStatus::Open {
+        return Err(ContractError::ProposalNotOpen {});
+    }
+
+    if env.block.time >= proposal.expires {
+        return Err(ContractError::ProposalExpired {});
+    }
+
+    if APPROVALS.has(deps.storage, (id, info.sender.clone())) {
+        return Err(ContractError::AlreadyApproved {});
+    }
+
+    APPROVALS.save(deps.storage, (id, info.sender), &true)?;
+    proposal.approvals += 1;
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "approve")
+        .add_attribute("proposal_id", id.to_string()))
+}
+
+fn execute_execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !config.members.contains(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut proposal = PROPOSALS.load(deps.storage, id)?;
+
+    if proposal.status != ProposalStatus::Open {
+        return Err(ContractError::ProposalNotOpen {});
+    }
+
+    if env.block.time >= proposal.expires {
+        return Err(ContractError::ProposalExpired {});
+    }
+
+    if proposal.approvals < config.threshold {
+        return Err(ContractError::ThresholdNotReached {});
+    }
+
+    proposal.status = ProposalStatus::Executed;
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    let msg = action_to_msg(deps.as_ref(), proposal.action)?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "execute")
+        .add_attribute("proposal_id", id.to_string()))
+}
+
+// Converts a typed admin action to the message sent to the splitter or the factory
+fn action_to_msg(deps: Deps<ArchwayQuery>, action: AdminAction) -> StdResult<WasmMsg> {
+    let (contract_addr, msg) = match action {
+        AdminAction::UpdateShares { splitter, shares } => (
+            splitter,
+            to_binary(&SplitterExecuteMsg::UpdateShares { shares })?,
+        ),
+        AdminAction::LockContract { splitter } => (
+            splitter,
+            to_binary(&SplitterExecuteMsg::LockContract { until: None })?,
+        ),
+        AdminAction::UpdateCustomContractRewardMetadata {
+            splitter,
+            address,
+            owner_address,
+            rewards_address,
+        } => (
+            splitter,
+            to_binary(&SplitterExecuteMsg::UpdateCustomContractRewardMetadata {
+                address,
+                owner_address,
+                rewards_address,
+            })?,
+        ),
+        AdminAction::WithdrawRewards { splitter } => (
+            splitter,
+            to_binary(&SplitterExecuteMsg::WithdrawRewards {})?,
+        ),
+        AdminAction::DistributeNativeTokens { splitter } => (
+            splitter,
+            to_binary(&SplitterExecuteMsg::DistributeNativeTokens {})?,
+        ),
+        AdminAction::UpdateSplitterCodeId { factory, code_id } => (
+            factory,
+            to_binary(&FactoryExecuteMsg::UpdateSplitterCodeId { code_id })?,
+        ),
+        AdminAction::MigrateChildren {
+            factory,
+            code_id_from,
+            code_id_to,
+            msg,
+            limit,
+        } => (
+            factory,
+            to_binary(&FactoryExecuteMsg::MigrateChildren {
+                code_id_from,
+                code_id_to,
+                msg,
+                limit,
+            })?,
+        ),
+        AdminAction::TransferChildAdmin {
+            factory,
+            address,
+            new_admin,
+        } => (
+            factory,
+            to_binary(&FactoryExecuteMsg::TransferChildAdmin { address, new_admin })?,
+        ),
+        AdminAction::SuspendChild { factory, address } => (
+            factory,
+            to_binary(&FactoryExecuteMsg::SuspendChild { address })?,
+        ),
+        AdminAction::ResumeChild { factory, address } => (
+            factory,
+            to_binary(&FactoryExecuteMsg::ResumeChild { address })?,
+        ),
+    };
+
+    Ok(WasmMsg::Execute {
+        contract_addr: deps.api.addr_validate(&contract_addr)?.to_string(),
+        msg,
+        funds: vec![],
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Proposal { id } => to_binary(&PROPOSALS.load(deps.storage, id)?),
+        QueryMsg::Proposals { start_after, limit } => {
+            to_binary(&query_proposals(deps, start_after, limit)?)
+        }
+    }
+}
+
+fn query_proposals(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Proposal>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let proposals = PROPOSALS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, proposal) = item?;
+            Ok(proposal)
+        })
+        .collect::<StdResult<Vec<Proposal>>>()?;
+
+    Ok(proposals)
+}
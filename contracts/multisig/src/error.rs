@@ -0,0 +1,32 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Members cannot be empty")]
+    NoMembers {},
+
+    #[error("Duplicate member")]
+    DuplicateMember {},
+
+    #[error("Invalid threshold")]
+    InvalidThreshold {},
+
+    #[error("Proposal is not open")]
+    ProposalNotOpen {},
+
+    #[error("Proposal has expired")]
+    ProposalExpired {},
+
+    #[error("Already approved")]
+    AlreadyApproved {},
+
+    #[error("Threshold not reached")]
+    ThresholdNotReached {},
+}
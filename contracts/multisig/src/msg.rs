@@ -0,0 +1,39 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+
+use crate::state::{AdminAction, Config, Proposal};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub members: Vec<String>,
+    // Number of approvals needed to execute a proposal
+    pub threshold: u64,
+    // Number of seconds a proposal stays open for approvals
+    pub voting_period: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Propose an admin action, counts as the proposer's approval
+    Propose { title: String, action: AdminAction },
+    // Approve an open proposal
+    Approve { id: u64 },
+    // Execute a proposal that reached the threshold
+    Execute { id: u64 },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Returns the contract config
+    #[returns(Config)]
+    Config {},
+    // Returns a single proposal
+    #[returns(Proposal)]
+    Proposal { id: u64 },
+    // Returns all the proposals
+    #[returns(Vec<Proposal>)]
+    Proposals {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+}
@@ -0,0 +1,87 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary, Timestamp};
+use cw_storage_plus::{Item, Map};
+use pantheon_utils::Share;
+
+// Contract configuration
+// Threshold is the number of member approvals needed to execute a proposal
+// Voting period is the number of seconds a proposal stays open
+#[cw_serde]
+pub struct Config {
+    pub members: Vec<Addr>,
+    pub threshold: u64,
+    pub voting_period: u64,
+}
+
+// Admin actions that can be proposed for a splitter or a factory
+#[cw_serde]
+pub enum AdminAction {
+    UpdateShares {
+        splitter: String,
+        shares: Vec<Share>,
+    },
+    LockContract {
+        splitter: String,
+    },
+    UpdateCustomContractRewardMetadata {
+        splitter: String,
+        address: String,
+        owner_address: Option<String>,
+        rewards_address: Option<String>,
+    },
+    WithdrawRewards {
+        splitter: String,
+    },
+    DistributeNativeTokens {
+        splitter: String,
+    },
+    UpdateSplitterCodeId {
+        factory: String,
+        code_id: u64,
+    },
+    MigrateChildren {
+        factory: String,
+        code_id_from: u64,
+        code_id_to: u64,
+        msg: Binary,
+        limit: Option<u8>,
+    },
+    TransferChildAdmin {
+        factory: String,
+        address: String,
+        new_admin: String,
+    },
+    SuspendChild {
+        factory: String,
+        address: String,
+    },
+    ResumeChild {
+        factory: String,
+        address: String,
+    },
+}
+
+#[cw_serde]
+pub enum ProposalStatus {
+    Open,
+    Executed,
+}
+
+#[cw_serde]
+pub struct Proposal {
+    pub id: u64,
+    pub title: String,
+    pub action: AdminAction,
+    pub proposer: Addr,
+    pub approvals: u64,
+    pub expires: Timestamp,
+    pub status: ProposalStatus,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+
+pub const APPROVALS: Map<(u64, Addr), bool> = Map::new("approvals");
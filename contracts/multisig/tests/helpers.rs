@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{testing::MockApi, Addr, Coin, Decimal, MemoryStorage, Uint128};
+use cw_multi_test::{
+    custom_app, App, BankKeeper, Contract, ContractWrapper, Executor, FailingModule, WasmKeeper,
+};
+use pantheon_multisig::msg::InstantiateMsg;
+use pantheon_splitter::msg::InstantiateMsg as SplitterInstantiateMsg;
+use pantheon_utils::Share;
+
+pub fn multisig_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(
+        pantheon_multisig::contract::execute,
+        pantheon_multisig::contract::instantiate,
+        pantheon_multisig::contract::query,
+    );
+    Box::new(contract)
+}
+pub fn splitter_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(
+        pantheon_splitter::contract::execute,
+        pantheon_splitter::contract::instantiate,
+        pantheon_splitter::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub const ADMIN: &str = "admin";
+pub const USER: &str = "user";
+pub const USER2: &str = "user2";
+pub const OUTSIDER: &str = "outsider";
+
+pub const DENOM: &str = "aconst";
+
+pub const VOTING_PERIOD: u64 = 86_400;
+
+pub fn mock_app() -> App<
+    BankKeeper,
+    MockApi,
+    MemoryStorage,
+    FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+    WasmKeeper<ArchwayMsg, ArchwayQuery>,
+> {
+    custom_app::<ArchwayMsg, ArchwayQuery, _>(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(ADMIN),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: Uint128::new(1_000_000_000_000_000_000),
+                }],
+            )
+            .unwrap();
+    })
+}
+
+// Instantiates a 2-of-3 multisig and a splitter administered by it
+pub fn proper_instantiate(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MemoryStorage,
+        FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+        WasmKeeper<ArchwayMsg, ArchwayQuery>,
+    >,
+) -> (Addr, Addr) {
+    let multisig_code_id = app.store_code(multisig_contract());
+    let splitter_code_id = app.store_code(splitter_contract());
+
+    let multisig_addr = app
+        .instantiate_contract(
+            multisig_code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                members: vec![ADMIN.to_string(), USER.to_string(), USER2.to_string()],
+                threshold: 2,
+                voting_period: VOTING_PERIOD,
+            },
+            &vec![],
+            "Pantheon Multisig",
+            None,
+        )
+        .unwrap();
+
+    let splitter_addr = app
+        .instantiate_contract(
+            splitter_code_id,
+            Addr::unchecked(ADMIN),
+            &SplitterInstantiateMsg {
+                shares: vec![Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::from_str("1").unwrap(),
+                    ibc: None,
+                    burn: false,
+                }],
+                mutable: true,
+                admin: multisig_addr.to_string(),
+                transfer_requires_approval: false,
+                disable_emergency_withdraw: false,
+                restore: None,
+            },
+            &vec![],
+            "Pantheon Splitter",
+            None,
+        )
+        .unwrap();
+
+    (multisig_addr, splitter_addr)
+}
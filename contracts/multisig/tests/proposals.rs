@@ -0,0 +1,198 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{from_binary, to_binary, Addr, CosmosMsg, Empty, OwnedDeps, WasmMsg};
+use cw_multi_test::Executor;
+use pantheon_factory::msg::ExecuteMsg as FactoryExecuteMsg;
+use pantheon_multisig::{
+    contract::{execute, instantiate},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::{AdminAction, Proposal, ProposalStatus},
+    ContractError,
+};
+use pantheon_splitter::{msg::QueryMsg as SplitterQueryMsg, state::Config as SplitterConfig};
+
+#[test]
+fn test_happy_path() {
+    let mut app = mock_app();
+    let (multisig_addr, splitter_addr) = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        multisig_addr.clone(),
+        &ExecuteMsg::Propose {
+            title: "Lock the splitter".to_string(),
+            action: AdminAction::LockContract {
+                splitter: splitter_addr.to_string(),
+            },
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            multisig_addr.clone(),
+            &ExecuteMsg::Execute { id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ThresholdNotReached {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        multisig_addr.clone(),
+        &ExecuteMsg::Approve { id: 1 },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER2),
+        multisig_addr.clone(),
+        &ExecuteMsg::Execute { id: 1 },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: SplitterConfig = app
+        .wrap()
+        .query_wasm_smart(splitter_addr, &SplitterQueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.mutable, false);
+
+    let res: Proposal = app
+        .wrap()
+        .query_wasm_smart(multisig_addr, &QueryMsg::Proposal { id: 1 })
+        .unwrap();
+    assert_eq!(res.status, ProposalStatus::Executed);
+    assert_eq!(res.approvals, 2);
+}
+
+#[test]
+fn test_already_approved() {
+    let mut app = mock_app();
+    let (multisig_addr, splitter_addr) = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        multisig_addr.clone(),
+        &ExecuteMsg::Propose {
+            title: "Lock the splitter".to_string(),
+            action: AdminAction::LockContract {
+                splitter: splitter_addr.to_string(),
+            },
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            multisig_addr.clone(),
+            &ExecuteMsg::Approve { id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::AlreadyApproved {}.to_string()
+    )
+}
+
+#[test]
+fn test_invalid_member() {
+    let mut app = mock_app();
+    let (multisig_addr, splitter_addr) = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(OUTSIDER),
+            multisig_addr.clone(),
+            &ExecuteMsg::Propose {
+                title: "Lock the splitter".to_string(),
+                action: AdminAction::LockContract {
+                    splitter: splitter_addr.to_string(),
+                },
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    )
+}
+
+#[test]
+fn test_migrate_children_action() {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData,
+    };
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            members: vec![ADMIN.to_string(), USER.to_string()],
+            threshold: 1,
+            voting_period: VOTING_PERIOD,
+        },
+    )
+    .unwrap();
+
+    let migrate_msg = to_binary(&Empty {}).unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::Propose {
+            title: "Migrate the children".to_string(),
+            action: AdminAction::MigrateChildren {
+                factory: "factory".to_string(),
+                code_id_from: 1,
+                code_id_to: 2,
+                msg: migrate_msg.clone(),
+                limit: None,
+            },
+        },
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(USER, &[]),
+        ExecuteMsg::Execute { id: 1 },
+    )
+    .unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => {
+            assert_eq!(contract_addr, "factory");
+            assert_eq!(
+                from_binary::<FactoryExecuteMsg>(msg).unwrap(),
+                FactoryExecuteMsg::MigrateChildren {
+                    code_id_from: 1,
+                    code_id_to: 2,
+                    msg: migrate_msg,
+                    limit: None,
+                }
+            );
+        }
+        msg => panic!("unexpected message {:?}", msg),
+    }
+}
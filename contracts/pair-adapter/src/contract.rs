@@ -0,0 +1,213 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    coins, to_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Reply, Response,
+    StdResult, SubMsg, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use pantheon_swap_adapter::QuoteResponse;
+
+use crate::error::ContractError;
+use crate::msg::{
+    Asset, AssetInfo, ExecuteMsg, InstantiateMsg, PairExecuteMsg, PairQueryMsg, QueryMsg,
+    SimulationResponse,
+};
+use crate::state::{Config, PendingSwap, CONFIG, PAIRS, PENDING_SWAP};
+
+use archway_bindings::{ArchwayQuery, ArchwayResult};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:pantheon-pair-adapter";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const SWAP_REPLY_ID: u64 = 1;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ArchwayResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        admin: deps.api.addr_validate(&msg.admin)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("admin", config.admin))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ArchwayResult<ContractError> {
+    match msg {
+        ExecuteMsg::Swap {
+            ask_denom,
+            min_receive,
+        } => execute_swap(deps, env, info, ask_denom, min_receive),
+        ExecuteMsg::UpdatePair { denoms, pair } => {
+            execute_update_pair(deps, env, info, denoms, pair)
+        }
+    }
+}
+
+fn execute_swap(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    ask_denom: String,
+    min_receive: Uint128,
+) -> ArchwayResult<ContractError> {
+    if info.funds.len() != 1 || info.funds[0].denom == ask_denom || info.funds[0].amount.is_zero() {
+        return Err(ContractError::InvalidFunds {});
+    }
+    let offer = info.funds[0].clone();
+
+    let pair = PAIRS
+        .may_load(deps.storage, (&offer.denom, &ask_denom))?
+        .ok_or_else(|| ContractError::PairNotFound {
+            offer_denom: offer.denom.clone(),
+            ask_denom: ask_denom.clone(),
+        })?;
+
+    // The result is checked against the minimum in the reply, so the pair spread checks are not used
+    let balance_before = deps
+        .querier
+        .query_balance(env.contract.address, ask_denom.clone())?
+        .amount;
+    PENDING_SWAP.save(
+        deps.storage,
+        &PendingSwap {
+            recipient: info.sender.clone(),
+            ask_denom: ask_denom.clone(),
+            min_receive,
+            balance_before,
+        },
+    )?;
+
+    let swap = WasmMsg::Execute {
+        contract_addr: pair.to_string(),
+        msg: to_binary(&PairExecuteMsg::Swap {
+            offer_asset: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: offer.denom.clone(),
+                },
+                amount: offer.amount,
+            },
+            belief_price: None,
+            max_spread: None,
+            to: None,
+        })?,
+        funds: vec![offer.clone()],
+    };
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(swap, SWAP_REPLY_ID))
+        .add_attribute("action", "swap")
+        .add_attribute("sender", info.sender)
+        .add_attribute("offer", offer.to_string())
+        .add_attribute("ask_denom", ask_denom))
+}
+
+fn execute_update_pair(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    denoms: (String, String),
+    pair: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (first, second) = denoms;
+    match pair {
+        Some(pair) => {
+            let pair = deps.api.addr_validate(&pair)?;
+            PAIRS.save(deps.storage, (&first, &second), &pair)?;
+            PAIRS.save(deps.storage, (&second, &first), &pair)?;
+        }
+        None => {
+            PAIRS.remove(deps.storage, (&first, &second));
+            PAIRS.remove(deps.storage, (&second, &first));
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_pair")
+        .add_attribute("denoms", format!("{},{}", first, second)))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut<ArchwayQuery>, env: Env, msg: Reply) -> ArchwayResult<ContractError> {
+    if msg.id != SWAP_REPLY_ID {
+        return Err(ContractError::UnknownReplyId { id: msg.id });
+    }
+
+    let swap = PENDING_SWAP.load(deps.storage)?;
+    PENDING_SWAP.remove(deps.storage);
+
+    // Failing here reverts the swap
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address, swap.ask_denom.clone())?
+        .amount;
+    let received = balance.checked_sub(swap.balance_before)?;
+    if received < swap.min_receive {
+        return Err(ContractError::MinReceiveNotMet {
+            received,
+            min_receive: swap.min_receive,
+        });
+    }
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: swap.recipient.to_string(),
+            amount: coins(received.u128(), swap.ask_denom),
+        })
+        .add_attribute("action", "swap_reply")
+        .add_attribute("recipient", swap.recipient)
+        .add_attribute("received", received))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Quote { offer, ask_denom } => to_binary(&query_quote(deps, offer, ask_denom)?),
+        QueryMsg::Pair {
+            offer_denom,
+            ask_denom,
+        } => to_binary(&PAIRS.may_load(deps.storage, (&offer_denom, &ask_denom))?),
+    }
+}
+
+fn query_quote(
+    deps: Deps<ArchwayQuery>,
+    offer: Coin,
+    ask_denom: String,
+) -> StdResult<QuoteResponse> {
+    let pair = PAIRS.load(deps.storage, (&offer.denom, &ask_denom))?;
+
+    let res: SimulationResponse = deps.querier.query_wasm_smart(
+        pair,
+        &PairQueryMsg::Simulation {
+            offer_asset: Asset {
+                info: AssetInfo::NativeToken { denom: offer.denom },
+                amount: offer.amount,
+            },
+        },
+    )?;
+
+    Ok(QuoteResponse {
+        amount: res.return_amount,
+    })
+}
@@ -0,0 +1,32 @@
+use cosmwasm_std::{OverflowError, StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("A single coin other than the ask denom must be sent")]
+    InvalidFunds {},
+
+    #[error("No pair registered for {offer_denom} to {ask_denom}")]
+    PairNotFound {
+        offer_denom: String,
+        ask_denom: String,
+    },
+
+    #[error("Swap returned {received}, less than the minimum of {min_receive}")]
+    MinReceiveNotMet {
+        received: Uint128,
+        min_receive: Uint128,
+    },
+
+    #[error("Unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+}
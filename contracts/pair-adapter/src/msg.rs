@@ -0,0 +1,79 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use pantheon_swap_adapter::QuoteResponse;
+
+use crate::state::Config;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin: String,
+}
+
+// Swap and Quote follow the pantheon-swap-adapter interface
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Swap the sent funds through the registered pair and send the result back to the sender
+    Swap {
+        ask_denom: String,
+        min_receive: Uint128,
+    },
+    // Register the pair contract swapping between the two denoms, None removes it
+    UpdatePair {
+        denoms: (String, String),
+        pair: Option<String>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Returns the contract config
+    #[returns(Config)]
+    Config {},
+    // Returns the amount of the ask denom the offer currently swaps to
+    #[returns(QuoteResponse)]
+    Quote { offer: Coin, ask_denom: String },
+    // Returns the pair contract swapping the offer denom to the ask denom
+    #[returns(Option<Addr>)]
+    Pair {
+        offer_denom: String,
+        ask_denom: String,
+    },
+}
+
+// Messages of the Terraswap style pair contracts deployed by the Archway AMMs
+#[cw_serde]
+pub enum AssetInfo {
+    Token { contract_addr: String },
+    NativeToken { denom: String },
+}
+
+#[cw_serde]
+pub struct Asset {
+    pub info: AssetInfo,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum PairExecuteMsg {
+    Swap {
+        offer_asset: Asset,
+        belief_price: Option<Decimal>,
+        max_spread: Option<Decimal>,
+        to: Option<String>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PairQueryMsg {
+    #[returns(SimulationResponse)]
+    Simulation { offer_asset: Asset },
+}
+
+#[cw_serde]
+pub struct SimulationResponse {
+    pub return_amount: Uint128,
+    pub spread_amount: Uint128,
+    pub commission_amount: Uint128,
+}
@@ -0,0 +1,27 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+
+// Contract configuration
+// Admin registers the pair contracts used for the swaps
+#[cw_serde]
+pub struct Config {
+    pub admin: Addr,
+}
+
+// Swap waiting for the result of the pair contract
+// Balance before is the ask denom balance of the adapter before the swap
+#[cw_serde]
+pub struct PendingSwap {
+    pub recipient: Addr,
+    pub ask_denom: String,
+    pub min_receive: Uint128,
+    pub balance_before: Uint128,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+// Pair contracts by the offer and ask denoms, saved for both directions
+pub const PAIRS: Map<(&str, &str), Addr> = Map::new("pairs");
+
+pub const PENDING_SWAP: Item<PendingSwap> = Item::new("pending_swap");
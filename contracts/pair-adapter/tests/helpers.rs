@@ -0,0 +1,168 @@
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{
+    coins, testing::MockApi, to_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Empty, Env,
+    MemoryStorage, MessageInfo, Response, StdError, StdResult, Uint128,
+};
+use cw_multi_test::{
+    custom_app, App, BankKeeper, Contract, ContractWrapper, Executor, FailingModule, WasmKeeper,
+};
+use pantheon_pair_adapter::msg::{
+    AssetInfo, ExecuteMsg, InstantiateMsg, PairExecuteMsg, PairQueryMsg, SimulationResponse,
+};
+
+pub fn adapter_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(
+        pantheon_pair_adapter::contract::execute,
+        pantheon_pair_adapter::contract::instantiate,
+        pantheon_pair_adapter::contract::query,
+    )
+    .with_reply(pantheon_pair_adapter::contract::reply);
+    Box::new(contract)
+}
+
+// Pair returning two of the other denom for every offered token
+fn pair_instantiate(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    Ok(Response::new())
+}
+
+fn pair_execute(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    msg: PairExecuteMsg,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    match msg {
+        PairExecuteMsg::Swap { offer_asset, .. } => {
+            Ok(Response::new().add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(offer_asset.amount.u128() * 2, ask_denom(&offer_asset.info)),
+            }))
+        }
+    }
+}
+
+fn pair_query(_deps: Deps<ArchwayQuery>, _env: Env, msg: PairQueryMsg) -> StdResult<Binary> {
+    match msg {
+        PairQueryMsg::Simulation { offer_asset } => to_binary(&SimulationResponse {
+            return_amount: offer_asset.amount * Uint128::new(2),
+            spread_amount: Uint128::zero(),
+            commission_amount: Uint128::zero(),
+        }),
+    }
+}
+
+fn ask_denom(info: &AssetInfo) -> &'static str {
+    match info {
+        AssetInfo::NativeToken { denom } if denom == DENOM => OTHER_DENOM,
+        _ => DENOM,
+    }
+}
+
+fn pair_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(pair_execute, pair_instantiate, pair_query);
+    Box::new(contract)
+}
+
+pub const ADMIN: &str = "admin";
+pub const USER: &str = "user";
+
+pub const DENOM: &str = "aconst";
+pub const OTHER_DENOM: &str = "uother";
+
+pub type AdapterApp = App<
+    BankKeeper,
+    MockApi,
+    MemoryStorage,
+    FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+    WasmKeeper<ArchwayMsg, ArchwayQuery>,
+>;
+
+pub fn mock_app() -> AdapterApp {
+    custom_app::<ArchwayMsg, ArchwayQuery, _>(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(ADMIN),
+                vec![
+                    Coin {
+                        denom: DENOM.to_string(),
+                        amount: Uint128::new(1_000_000_000),
+                    },
+                    Coin {
+                        denom: OTHER_DENOM.to_string(),
+                        amount: Uint128::new(1_000_000_000),
+                    },
+                ],
+            )
+            .unwrap();
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(USER),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: Uint128::new(1_000_000),
+                }],
+            )
+            .unwrap();
+    })
+}
+
+// Instantiates the adapter with a funded pair registered for the two denoms
+// Returns the adapter and the pair addresses
+pub fn proper_instantiate(app: &mut AdapterApp) -> (Addr, Addr) {
+    let code_id = app.store_code(adapter_contract());
+    let adapter = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: ADMIN.to_string(),
+            },
+            &vec![],
+            "Pantheon Pair Adapter",
+            None,
+        )
+        .unwrap();
+
+    let code_id = app.store_code(pair_contract());
+    let pair = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &Empty {},
+            &vec![],
+            "Pair",
+            None,
+        )
+        .unwrap();
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        pair.clone(),
+        &[
+            Coin::new(100_000_000, DENOM),
+            Coin::new(100_000_000, OTHER_DENOM),
+        ],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        adapter.clone(),
+        &ExecuteMsg::UpdatePair {
+            denoms: (DENOM.to_string(), OTHER_DENOM.to_string()),
+            pair: Some(pair.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    (adapter, pair)
+}
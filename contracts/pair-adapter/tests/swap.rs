@@ -0,0 +1,141 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_pair_adapter::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+use pantheon_swap_adapter::{QuoteResponse, SwapAdapterExecuteMsg, SwapAdapterQueryMsg};
+
+#[test]
+fn test_swap() {
+    let mut app = mock_app();
+    let (adapter, _) = proper_instantiate(&mut app);
+
+    // The adapter is used through the generic interface
+    let res: QuoteResponse = app
+        .wrap()
+        .query_wasm_smart(
+            adapter.clone(),
+            &SwapAdapterQueryMsg::Quote {
+                offer: coin(1_000, DENOM),
+                ask_denom: OTHER_DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.amount, Uint128::new(2_000));
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        adapter.clone(),
+        &SwapAdapterExecuteMsg::Swap {
+            ask_denom: OTHER_DENOM.to_string(),
+            min_receive: Uint128::new(2_000),
+        },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, OTHER_DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(2_000));
+    let res = app.wrap().query_balance(adapter, OTHER_DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+}
+
+#[test]
+fn test_min_receive() {
+    let mut app = mock_app();
+    let (adapter, _) = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            adapter.clone(),
+            &ExecuteMsg::Swap {
+                ask_denom: OTHER_DENOM.to_string(),
+                min_receive: Uint128::new(2_001),
+            },
+            &coins(1_000, DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::MinReceiveNotMet {
+            received: Uint128::new(2_000),
+            min_receive: Uint128::new(2_001),
+        }
+        .to_string()
+    );
+
+    // The swap is reverted
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_000_000));
+}
+
+#[test]
+fn test_update_pair() {
+    let mut app = mock_app();
+    let (adapter, pair) = proper_instantiate(&mut app);
+
+    // Pairs are registered for both directions
+    let res: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            adapter.clone(),
+            &QueryMsg::Pair {
+                offer_denom: OTHER_DENOM.to_string(),
+                ask_denom: DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Some(pair));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            adapter.clone(),
+            &ExecuteMsg::UpdatePair {
+                denoms: (DENOM.to_string(), OTHER_DENOM.to_string()),
+                pair: None,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        adapter.clone(),
+        &ExecuteMsg::UpdatePair {
+            denoms: (DENOM.to_string(), OTHER_DENOM.to_string()),
+            pair: None,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            adapter,
+            &ExecuteMsg::Swap {
+                ask_denom: OTHER_DENOM.to_string(),
+                min_receive: Uint128::zero(),
+            },
+            &coins(1_000, DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::PairNotFound {
+            offer_denom: DENOM.to_string(),
+            ask_denom: OTHER_DENOM.to_string(),
+        }
+        .to_string()
+    );
+}
@@ -0,0 +1,326 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    coins, to_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    Storage, Timestamp, Uint128,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{Config, Employee, Payment, CONFIG, EMPLOYEES, PAYMENTS};
+
+use archway_bindings::{ArchwayQuery, ArchwayResult};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:pantheon-payroll";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ArchwayResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let admin = deps.api.addr_validate(&msg.admin)?;
+    let config = Config {
+        admin,
+        denom: msg.denom,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("admin", config.admin))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ArchwayResult<ContractError> {
+    match msg {
+        ExecuteMsg::AddEmployee {
+            address,
+            salary,
+            pay_period,
+        } => execute_add_employee(deps, env, info, address, salary, pay_period),
+        ExecuteMsg::UpdateEmployee {
+            address,
+            salary,
+            pay_period,
+        } => execute_update_employee(deps, env, info, address, salary, pay_period),
+        ExecuteMsg::RemoveEmployee { address } => execute_remove_employee(deps, env, info, address),
+        ExecuteMsg::RunPayroll {} => execute_run_payroll(deps, env, info),
+    }
+}
+
+fn execute_add_employee(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    salary: Uint128,
+    pay_period: u64,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if pay_period == 0 {
+        return Err(ContractError::InvalidPayPeriod {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+    if EMPLOYEES.has(deps.storage, address.clone()) {
+        return Err(ContractError::EmployeeExists {});
+    }
+
+    let employee = Employee {
+        address: address.clone(),
+        salary,
+        pay_period,
+        next_payment: env.block.time.plus_seconds(pay_period),
+    };
+    EMPLOYEES.save(deps.storage, address.clone(), &employee)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_employee")
+        .add_attribute("employee", address))
+}
+
+fn execute_update_employee(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    salary: Uint128,
+    pay_period: u64,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if pay_period == 0 {
+        return Err(ContractError::InvalidPayPeriod {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+    let mut employee = EMPLOYEES.load(deps.storage, address.clone())?;
+
+    // The periods that are already due are paid at the old terms,
+    // the new values are applied starting from the next payment
+    let amount = pay_due_periods(deps.storage, &mut employee, env.block.time)?;
+    assert_funds(deps.as_ref(), &env, &config.denom, amount)?;
+
+    employee.salary = salary;
+    employee.pay_period = pay_period;
+    EMPLOYEES.save(deps.storage, address.clone(), &employee)?;
+
+    Ok(Response::new()
+        .add_messages(payment_msg(&employee, &config.denom, amount))
+        .add_attribute("action", "update_employee")
+        .add_attribute("employee", address))
+}
+
+fn execute_remove_employee(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let address = deps.api.addr_validate(&address)?;
+    let mut employee = EMPLOYEES.load(deps.storage, address.clone())?;
+
+    // The wages that are already due are paid out before the employee is removed
+    let amount = pay_due_periods(deps.storage, &mut employee, env.block.time)?;
+    assert_funds(deps.as_ref(), &env, &config.denom, amount)?;
+
+    EMPLOYEES.remove(deps.storage, address.clone());
+
+    Ok(Response::new()
+        .add_messages(payment_msg(&employee, &config.denom, amount))
+        .add_attribute("action", "remove_employee")
+        .add_attribute("employee", address))
+}
+
+// Anyone can run the payroll, only employees with a due payment are paid
+fn execute_run_payroll(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    _info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let now = env.block.time;
+
+    let due_employees = EMPLOYEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((_, employee)) if employee.next_payment <= now => Some(Ok(employee)),
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<Employee>>>()?;
+
+    let mut msgs: Vec<BankMsg> = vec![];
+    let mut total = Uint128::zero();
+
+    for mut employee in due_employees {
+        let amount = pay_due_periods(deps.storage, &mut employee, now)?;
+        total = total.checked_add(amount)?;
+        EMPLOYEES.save(deps.storage, employee.address.clone(), &employee)?;
+
+        msgs.extend(payment_msg(&employee, &config.denom, amount));
+    }
+
+    assert_funds(deps.as_ref(), &env, &config.denom, total)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_attribute("action", "run_payroll")
+        .add_attribute("total", total))
+}
+
+// Paying every period that passed since the last payment and moving the next payment after now
+// The caller saves the employee
+fn pay_due_periods(
+    storage: &mut dyn Storage,
+    employee: &mut Employee,
+    now: Timestamp,
+) -> Result<Uint128, ContractError> {
+    if employee.next_payment > now {
+        return Ok(Uint128::zero());
+    }
+
+    let periods = (now.seconds() - employee.next_payment.seconds()) / employee.pay_period + 1;
+    let amount = employee.salary.checked_mul(Uint128::from(periods))?;
+
+    employee.next_payment = employee
+        .next_payment
+        .plus_seconds(periods * employee.pay_period);
+
+    PAYMENTS.save(
+        storage,
+        (employee.address.clone(), now.seconds()),
+        &Payment {
+            amount,
+            periods,
+            time: now,
+        },
+    )?;
+
+    Ok(amount)
+}
+
+fn payment_msg(employee: &Employee, denom: &str, amount: Uint128) -> Option<BankMsg> {
+    if amount.is_zero() {
+        return None;
+    }
+
+    Some(BankMsg::Send {
+        to_address: employee.address.to_string(),
+        amount: coins(amount.u128(), denom),
+    })
+}
+
+fn assert_funds(
+    deps: Deps<ArchwayQuery>,
+    env: &Env,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom)?;
+    if balance.amount < amount {
+        return Err(ContractError::InsufficientFunds {});
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Employee { address } => to_binary(&query_employee(deps, address)?),
+        QueryMsg::Employees { start_after, limit } => {
+            to_binary(&query_employees(deps, start_after, limit)?)
+        }
+        QueryMsg::PaymentHistory {
+            address,
+            start_after,
+            limit,
+        } => to_binary(&query_payment_history(deps, address, start_after, limit)?),
+    }
+}
+
+fn query_employee(deps: Deps<ArchwayQuery>, address: String) -> StdResult<Employee> {
+    let address = deps.api.addr_validate(&address)?;
+    let employee = EMPLOYEES.load(deps.storage, address)?;
+    Ok(employee)
+}
+
+fn query_employees(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Employee>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let employees = EMPLOYEES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, employee) = item?;
+            Ok(employee)
+        })
+        .collect::<StdResult<Vec<Employee>>>()?;
+
+    Ok(employees)
+}
+
+fn query_payment_history(
+    deps: Deps<ArchwayQuery>,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Payment>> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let payments = PAYMENTS
+        .prefix(address)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, payment) = item?;
+            Ok(payment)
+        })
+        .collect::<StdResult<Vec<Payment>>>()?;
+
+    Ok(payments)
+}
@@ -0,0 +1,23 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Employee already exists")]
+    EmployeeExists {},
+
+    #[error("Pay period cannot be zero")]
+    InvalidPayPeriod {},
+
+    #[error("Insufficient funds to run payroll")]
+    InsufficientFunds {},
+}
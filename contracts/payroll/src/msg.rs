@@ -0,0 +1,56 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+
+use crate::state::{Config, Employee, Payment};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin: String,
+    pub denom: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Add an employee, the first payment is due after one pay period
+    AddEmployee {
+        address: String,
+        salary: Uint128,
+        pay_period: u64,
+    },
+    // Update the salary and pay period of an employee, the periods that are due are paid first
+    UpdateEmployee {
+        address: String,
+        salary: Uint128,
+        pay_period: u64,
+    },
+    // Remove an employee from the payroll after paying the periods that are due
+    RemoveEmployee {
+        address: String,
+    },
+    // Pay every employee whose payment is due
+    RunPayroll {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Returns the contract config
+    #[returns(Config)]
+    Config {},
+    // Returns a single employee
+    #[returns(Employee)]
+    Employee { address: String },
+    // Returns all the employees
+    #[returns(Vec<Employee>)]
+    Employees {
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+    // Returns the payment history of an employee
+    #[returns(Vec<Payment>)]
+    PaymentHistory {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+}
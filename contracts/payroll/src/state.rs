@@ -0,0 +1,37 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+// Contract configuration
+// Denom is the token salaries are paid in
+#[cw_serde]
+pub struct Config {
+    pub admin: Addr,
+    pub denom: String,
+}
+
+// Employee consists of an address, a salary and a pay period
+// Salary is the amount paid for every pay period
+// Pay period is the number of seconds between payments
+#[cw_serde]
+pub struct Employee {
+    pub address: Addr,
+    pub salary: Uint128,
+    pub pay_period: u64,
+    pub next_payment: Timestamp,
+}
+
+// A single payment made to an employee
+#[cw_serde]
+pub struct Payment {
+    pub amount: Uint128,
+    pub periods: u64,
+    pub time: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+pub const EMPLOYEES: Map<Addr, Employee> = Map::new("employees");
+
+// Payment history keyed by employee and payment time in seconds
+pub const PAYMENTS: Map<(Addr, u64), Payment> = Map::new("payments");
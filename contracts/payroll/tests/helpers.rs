@@ -0,0 +1,70 @@
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{testing::MockApi, Addr, Coin, MemoryStorage, Uint128};
+use cw_multi_test::{
+    custom_app, App, BankKeeper, Contract, ContractWrapper, Executor, FailingModule, WasmKeeper,
+};
+use pantheon_payroll::msg::InstantiateMsg;
+
+pub fn payroll_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(
+        pantheon_payroll::contract::execute,
+        pantheon_payroll::contract::instantiate,
+        pantheon_payroll::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub const ADMIN: &str = "admin";
+pub const USER: &str = "user";
+pub const USER2: &str = "user2";
+
+pub const DENOM: &str = "aconst";
+
+pub const PAY_PERIOD: u64 = 2_592_000;
+
+pub fn mock_app() -> App<
+    BankKeeper,
+    MockApi,
+    MemoryStorage,
+    FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+    WasmKeeper<ArchwayMsg, ArchwayQuery>,
+> {
+    custom_app::<ArchwayMsg, ArchwayQuery, _>(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &Addr::unchecked(ADMIN),
+                vec![Coin {
+                    denom: DENOM.to_string(),
+                    amount: Uint128::new(1_000_000_000_000_000_000),
+                }],
+            )
+            .unwrap();
+    })
+}
+
+pub fn proper_instantiate(
+    app: &mut App<
+        BankKeeper,
+        MockApi,
+        MemoryStorage,
+        FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
+        WasmKeeper<ArchwayMsg, ArchwayQuery>,
+    >,
+) -> Addr {
+    let code_id = app.store_code(payroll_contract());
+
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(ADMIN),
+        &InstantiateMsg {
+            admin: ADMIN.to_string(),
+            denom: DENOM.to_string(),
+        },
+        &vec![],
+        "Pantheon Payroll",
+        None,
+    )
+    .unwrap()
+}
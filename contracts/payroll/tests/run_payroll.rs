@@ -0,0 +1,261 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_payroll::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::Payment,
+    ContractError,
+};
+
+#[test]
+fn test_happy_path() {
+    let mut app = mock_app();
+    let payroll_addr = proper_instantiate(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &coins(10_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &ExecuteMsg::AddEmployee {
+            address: USER.to_string(),
+            salary: Uint128::new(1_000),
+            pay_period: PAY_PERIOD,
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &ExecuteMsg::AddEmployee {
+            address: USER2.to_string(),
+            salary: Uint128::new(500),
+            pay_period: PAY_PERIOD * 2,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // Nobody is due before the first pay period ends
+    app.execute_contract(
+        Addr::unchecked(USER),
+        payroll_addr.clone(),
+        &ExecuteMsg::RunPayroll {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+
+    app.update_block(|block| block.time = block.time.plus_seconds(PAY_PERIOD * 2));
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        payroll_addr.clone(),
+        &ExecuteMsg::RunPayroll {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(2_000));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(500));
+
+    let res: Vec<Payment> = app
+        .wrap()
+        .query_wasm_smart(
+            payroll_addr,
+            &QueryMsg::PaymentHistory {
+                address: USER.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].amount, Uint128::new(2_000));
+    assert_eq!(res[0].periods, 2);
+}
+
+#[test]
+fn test_insufficient_funds() {
+    let mut app = mock_app();
+    let payroll_addr = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &ExecuteMsg::AddEmployee {
+            address: USER.to_string(),
+            salary: Uint128::new(1_000),
+            pay_period: PAY_PERIOD,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(PAY_PERIOD));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            payroll_addr.clone(),
+            &ExecuteMsg::RunPayroll {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InsufficientFunds {}.to_string()
+    )
+}
+
+#[test]
+fn test_invalid_admin() {
+    let mut app = mock_app();
+    let payroll_addr = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            payroll_addr.clone(),
+            &ExecuteMsg::AddEmployee {
+                address: USER.to_string(),
+                salary: Uint128::new(1_000),
+                pay_period: PAY_PERIOD,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    )
+}
+
+#[test]
+fn test_update_employee_pays_due_periods() {
+    let mut app = mock_app();
+    let payroll_addr = proper_instantiate(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &coins(10_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &ExecuteMsg::AddEmployee {
+            address: USER.to_string(),
+            salary: Uint128::new(1_000),
+            pay_period: PAY_PERIOD,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(PAY_PERIOD * 2));
+
+    // The two due periods are paid with the old salary
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &ExecuteMsg::UpdateEmployee {
+            address: USER.to_string(),
+            salary: Uint128::new(5_000),
+            pay_period: PAY_PERIOD / 2,
+        },
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(2_000));
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        payroll_addr.clone(),
+        &ExecuteMsg::RunPayroll {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(2_000));
+
+    // The next payment is the first one with the new values
+    app.update_block(|block| block.time = block.time.plus_seconds(PAY_PERIOD));
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        payroll_addr.clone(),
+        &ExecuteMsg::RunPayroll {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(7_000));
+}
+
+#[test]
+fn test_remove_employee_pays_due_periods() {
+    let mut app = mock_app();
+    let payroll_addr = proper_instantiate(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &coins(10_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &ExecuteMsg::AddEmployee {
+            address: USER.to_string(),
+            salary: Uint128::new(1_000),
+            pay_period: PAY_PERIOD,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(PAY_PERIOD));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        payroll_addr.clone(),
+        &ExecuteMsg::RemoveEmployee {
+            address: USER.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_000));
+
+    let res: Vec<Payment> = app
+        .wrap()
+        .query_wasm_smart(
+            payroll_addr,
+            &QueryMsg::PaymentHistory {
+                address: USER.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].periods, 1);
+}
@@ -0,0 +1,494 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    coins, to_binary, Addr, BankMsg, Binary, BlockInfo, CosmosMsg, Decimal, Deps, DepsMut, Env,
+    MessageInfo, Response, StdError, StdResult, Storage, Uint128,
+};
+use cw2::set_contract_version;
+use cw20::{AllowanceResponse, BalanceResponse, Cw20ReceiveMsg, Expiration, TokenInfoResponse};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, RewardsResponse};
+use crate::state::{
+    Config, HolderRewards, TokenInfo, ALLOWANCES, BALANCES, CONFIG, HOLDER_REWARDS,
+    RESERVED_REWARDS, REWARD_INDEX, TOKEN_INFO,
+};
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery, ArchwayResult};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:pantheon-share-token";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ArchwayResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let mut total_supply = Uint128::zero();
+    for coin in msg.initial_balances {
+        let address = deps.api.addr_validate(&coin.address)?;
+        BALANCES.update(deps.storage, address, |balance| -> StdResult<_> {
+            Ok(balance.unwrap_or_default().checked_add(coin.amount)?)
+        })?;
+        total_supply = total_supply.checked_add(coin.amount)?;
+    }
+
+    let token_info = TokenInfo {
+        name: msg.name,
+        symbol: msg.symbol,
+        decimals: msg.decimals,
+        total_supply,
+    };
+    TOKEN_INFO.save(deps.storage, &token_info)?;
+
+    CONFIG.save(deps.storage, &Config { denom: msg.denom })?;
+
+    REWARD_INDEX.save(deps.storage, &Decimal::zero())?;
+    RESERVED_REWARDS.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new().add_attribute("total_supply", total_supply))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ArchwayResult<ContractError> {
+    match msg {
+        ExecuteMsg::Transfer { recipient, amount } => {
+            execute_transfer(deps, env, info, recipient, amount)
+        }
+        ExecuteMsg::Send {
+            contract,
+            amount,
+            msg,
+        } => execute_send(deps, env, info, contract, amount, msg),
+        ExecuteMsg::Burn { amount } => execute_burn(deps, env, info, amount),
+        ExecuteMsg::IncreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => execute_increase_allowance(deps, env, info, spender, amount, expires),
+        ExecuteMsg::DecreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => execute_decrease_allowance(deps, env, info, spender, amount, expires),
+        ExecuteMsg::TransferFrom {
+            owner,
+            recipient,
+            amount,
+        } => execute_transfer_from(deps, env, info, owner, recipient, amount),
+        ExecuteMsg::SendFrom {
+            owner,
+            contract,
+            amount,
+            msg,
+        } => execute_send_from(deps, env, info, owner, contract, amount, msg),
+        ExecuteMsg::Distribute {} => execute_distribute(deps, env, info),
+        ExecuteMsg::Claim {} => execute_claim(deps, env, info),
+    }
+}
+
+fn execute_transfer(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> ArchwayResult<ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    move_tokens(deps.storage, &info.sender, &recipient, amount)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "transfer")
+        .add_attribute("from", info.sender)
+        .add_attribute("to", recipient)
+        .add_attribute("amount", amount))
+}
+
+fn execute_send(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    contract: String,
+    amount: Uint128,
+    msg: Binary,
+) -> ArchwayResult<ContractError> {
+    let contract = deps.api.addr_validate(&contract)?;
+    move_tokens(deps.storage, &info.sender, &contract, amount)?;
+
+    let msg: CosmosMsg<ArchwayMsg> = Cw20ReceiveMsg {
+        sender: info.sender.to_string(),
+        amount,
+        msg,
+    }
+    .into_cosmos_msg(contract.clone())?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "send")
+        .add_attribute("from", info.sender)
+        .add_attribute("to", contract)
+        .add_attribute("amount", amount))
+}
+
+fn execute_burn(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> ArchwayResult<ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    // Burned tokens stop earning rewards, the remaining holders earn more
+    settle_rewards(deps.storage, &info.sender)?;
+    BALANCES.update(
+        deps.storage,
+        info.sender.clone(),
+        |balance| -> StdResult<_> { Ok(balance.unwrap_or_default().checked_sub(amount)?) },
+    )?;
+    TOKEN_INFO.update(deps.storage, |mut token_info| -> StdResult<_> {
+        token_info.total_supply = token_info.total_supply.checked_sub(amount)?;
+        Ok(token_info)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "burn")
+        .add_attribute("from", info.sender)
+        .add_attribute("amount", amount))
+}
+
+fn execute_increase_allowance(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    amount: Uint128,
+    expires: Option<Expiration>,
+) -> ArchwayResult<ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+    if spender == info.sender {
+        return Err(ContractError::CannotSetOwnAccount {});
+    }
+
+    ALLOWANCES.update(
+        deps.storage,
+        (info.sender.clone(), spender.clone()),
+        |allowance| -> Result<_, ContractError> {
+            let mut allowance = allowance.unwrap_or(AllowanceResponse {
+                allowance: Uint128::zero(),
+                expires: Expiration::Never {},
+            });
+            if let Some(expires) = expires {
+                if expires.is_expired(&env.block) {
+                    return Err(ContractError::Expired {});
+                }
+                allowance.expires = expires;
+            }
+            allowance.allowance = allowance.allowance.checked_add(amount)?;
+            Ok(allowance)
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "increase_allowance")
+        .add_attribute("owner", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("amount", amount))
+}
+
+fn execute_decrease_allowance(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    amount: Uint128,
+    expires: Option<Expiration>,
+) -> ArchwayResult<ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+    if spender == info.sender {
+        return Err(ContractError::CannotSetOwnAccount {});
+    }
+
+    let key = (info.sender.clone(), spender.clone());
+    let mut allowance = ALLOWANCES
+        .may_load(deps.storage, key.clone())?
+        .ok_or(ContractError::NoAllowance {})?;
+
+    if amount < allowance.allowance {
+        allowance.allowance = allowance.allowance.checked_sub(amount)?;
+        if let Some(expires) = expires {
+            if expires.is_expired(&env.block) {
+                return Err(ContractError::Expired {});
+            }
+            allowance.expires = expires;
+        }
+        ALLOWANCES.save(deps.storage, key, &allowance)?;
+    } else {
+        ALLOWANCES.remove(deps.storage, key);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "decrease_allowance")
+        .add_attribute("owner", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("amount", amount))
+}
+
+fn execute_transfer_from(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    recipient: String,
+    amount: Uint128,
+) -> ArchwayResult<ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    deduct_allowance(deps.storage, &owner, &info.sender, &env.block, amount)?;
+    move_tokens(deps.storage, &owner, &recipient, amount)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "transfer_from")
+        .add_attribute("from", owner)
+        .add_attribute("to", recipient)
+        .add_attribute("by", info.sender)
+        .add_attribute("amount", amount))
+}
+
+fn execute_send_from(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    contract: String,
+    amount: Uint128,
+    msg: Binary,
+) -> ArchwayResult<ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let contract = deps.api.addr_validate(&contract)?;
+
+    deduct_allowance(deps.storage, &owner, &info.sender, &env.block, amount)?;
+    move_tokens(deps.storage, &owner, &contract, amount)?;
+
+    let msg: CosmosMsg<ArchwayMsg> = Cw20ReceiveMsg {
+        sender: info.sender.to_string(),
+        amount,
+        msg,
+    }
+    .into_cosmos_msg(contract.clone())?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "send_from")
+        .add_attribute("from", owner)
+        .add_attribute("to", contract)
+        .add_attribute("by", info.sender)
+        .add_attribute("amount", amount))
+}
+
+// Anyone can distribute, the new balance is shared between the current holders
+fn execute_distribute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    _info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let token_info = TOKEN_INFO.load(deps.storage)?;
+
+    if token_info.total_supply.is_zero() {
+        return Err(ContractError::NoSupply {});
+    }
+
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address, &config.denom)?;
+    let reserved = RESERVED_REWARDS.load(deps.storage)?;
+    let amount = balance.amount.checked_sub(reserved)?;
+
+    // Rounding dust stays in the contract for the next distribution
+    let increase = Decimal::from_ratio(amount, token_info.total_supply);
+    let distributed = token_info.total_supply * increase;
+    if distributed.is_zero() {
+        return Err(ContractError::NothingToDistribute {});
+    }
+
+    REWARD_INDEX.update(deps.storage, |index| -> StdResult<_> {
+        Ok(index.checked_add(increase)?)
+    })?;
+    RESERVED_REWARDS.save(deps.storage, &reserved.checked_add(distributed)?)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "distribute")
+        .add_attribute("amount", distributed))
+}
+
+fn execute_claim(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut rewards = settle_rewards(deps.storage, &info.sender)?;
+    if rewards.pending.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    let amount = rewards.pending;
+    rewards.pending = Uint128::zero();
+    HOLDER_REWARDS.save(deps.storage, info.sender.clone(), &rewards)?;
+
+    RESERVED_REWARDS.update(deps.storage, |reserved| -> StdResult<_> {
+        Ok(reserved.checked_sub(amount)?)
+    })?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(amount.u128(), config.denom),
+        })
+        .add_attribute("action", "claim")
+        .add_attribute("holder", info.sender)
+        .add_attribute("amount", amount))
+}
+
+// Moves tokens after settling the rewards of both parties at their current balances
+fn move_tokens(
+    storage: &mut dyn Storage,
+    from: &Addr,
+    to: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    settle_rewards(storage, from)?;
+    settle_rewards(storage, to)?;
+
+    BALANCES.update(storage, from.clone(), |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    BALANCES.update(storage, to.clone(), |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+
+    Ok(())
+}
+
+fn deduct_allowance(
+    storage: &mut dyn Storage,
+    owner: &Addr,
+    spender: &Addr,
+    block: &BlockInfo,
+    amount: Uint128,
+) -> Result<AllowanceResponse, ContractError> {
+    ALLOWANCES.update(
+        storage,
+        (owner.clone(), spender.clone()),
+        |allowance| -> Result<_, ContractError> {
+            let mut allowance = allowance.ok_or(ContractError::NoAllowance {})?;
+            if allowance.expires.is_expired(block) {
+                return Err(ContractError::Expired {});
+            }
+            allowance.allowance = allowance.allowance.checked_sub(amount)?;
+            Ok(allowance)
+        },
+    )
+}
+
+// Adds the rewards earned since the last settlement to the pending rewards of a holder
+fn settle_rewards(storage: &mut dyn Storage, holder: &Addr) -> StdResult<HolderRewards> {
+    let rewards = pending_rewards(storage, holder)?;
+    HOLDER_REWARDS.save(storage, holder.clone(), &rewards)?;
+    Ok(rewards)
+}
+
+fn pending_rewards(storage: &dyn Storage, holder: &Addr) -> StdResult<HolderRewards> {
+    let index = REWARD_INDEX.load(storage)?;
+    let balance = BALANCES
+        .may_load(storage, holder.clone())?
+        .unwrap_or_default();
+    let mut rewards = HOLDER_REWARDS
+        .may_load(storage, holder.clone())?
+        .unwrap_or(HolderRewards {
+            index: Decimal::zero(),
+            pending: Uint128::zero(),
+        });
+
+    let earned = balance * (index - rewards.index);
+    rewards.pending = rewards
+        .pending
+        .checked_add(earned)
+        .map_err(StdError::overflow)?;
+    rewards.index = index;
+
+    Ok(rewards)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::TokenInfo {} => to_binary(&query_token_info(deps)?),
+        QueryMsg::Balance { address } => to_binary(&query_balance(deps, address)?),
+        QueryMsg::Allowance { owner, spender } => {
+            to_binary(&query_allowance(deps, owner, spender)?)
+        }
+        QueryMsg::Rewards { address } => to_binary(&query_rewards(deps, address)?),
+    }
+}
+
+fn query_token_info(deps: Deps<ArchwayQuery>) -> StdResult<TokenInfoResponse> {
+    let token_info = TOKEN_INFO.load(deps.storage)?;
+    Ok(TokenInfoResponse {
+        name: token_info.name,
+        symbol: token_info.symbol,
+        decimals: token_info.decimals,
+        total_supply: token_info.total_supply,
+    })
+}
+
+fn query_balance(deps: Deps<ArchwayQuery>, address: String) -> StdResult<BalanceResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let balance = BALANCES
+        .may_load(deps.storage, address)?
+        .unwrap_or_default();
+    Ok(BalanceResponse { balance })
+}
+
+fn query_allowance(
+    deps: Deps<ArchwayQuery>,
+    owner: String,
+    spender: String,
+) -> StdResult<AllowanceResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let spender = deps.api.addr_validate(&spender)?;
+    let allowance = ALLOWANCES
+        .may_load(deps.storage, (owner, spender))?
+        .unwrap_or(AllowanceResponse {
+            allowance: Uint128::zero(),
+            expires: Expiration::Never {},
+        });
+    Ok(allowance)
+}
+
+fn query_rewards(deps: Deps<ArchwayQuery>, address: String) -> StdResult<RewardsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let rewards = pending_rewards(deps.storage, &address)?;
+    Ok(RewardsResponse {
+        pending: rewards.pending,
+    })
+}
@@ -0,0 +1,32 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Invalid zero amount")]
+    InvalidZeroAmount {},
+
+    #[error("Cannot set allowance to own account")]
+    CannotSetOwnAccount {},
+
+    #[error("Allowance is expired")]
+    Expired {},
+
+    #[error("No allowance for this account")]
+    NoAllowance {},
+
+    #[error("Token supply is zero")]
+    NoSupply {},
+
+    #[error("Nothing to distribute")]
+    NothingToDistribute {},
+
+    #[error("Nothing to claim")]
+    NothingToClaim {},
+}
@@ -0,0 +1,80 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, Uint128};
+use cw20::{AllowanceResponse, BalanceResponse, Cw20Coin, Expiration, TokenInfoResponse};
+
+use crate::state::Config;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    // Share holders, the supply is fixed after instantiation
+    pub initial_balances: Vec<Cw20Coin>,
+    // Native token distributed to the holders
+    pub denom: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // CW20 messages
+    Transfer {
+        recipient: String,
+        amount: Uint128,
+    },
+    Send {
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+    },
+    Burn {
+        amount: Uint128,
+    },
+    IncreaseAllowance {
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    },
+    DecreaseAllowance {
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    },
+    TransferFrom {
+        owner: String,
+        recipient: String,
+        amount: Uint128,
+    },
+    SendFrom {
+        owner: String,
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+    },
+    // Snapshot the undistributed balance to the current holders pro-rata
+    Distribute {},
+    // Withdraw the distributed rewards of the sender
+    Claim {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Returns the contract config
+    #[returns(Config)]
+    Config {},
+    #[returns(TokenInfoResponse)]
+    TokenInfo {},
+    #[returns(BalanceResponse)]
+    Balance { address: String },
+    #[returns(AllowanceResponse)]
+    Allowance { owner: String, spender: String },
+    // Returns the claimable rewards of a holder
+    #[returns(RewardsResponse)]
+    Rewards { address: String },
+}
+
+#[cw_serde]
+pub struct RewardsResponse {
+    pub pending: Uint128,
+}
@@ -0,0 +1,44 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw20::AllowanceResponse;
+use cw_storage_plus::{Item, Map};
+
+// Token information of the share token
+#[cw_serde]
+pub struct TokenInfo {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: Uint128,
+}
+
+// Contract configuration
+// Denom is the native token distributed to the token holders
+#[cw_serde]
+pub struct Config {
+    pub denom: String,
+}
+
+// Reward state of a holder
+// Index is the global reward index the pending rewards were last settled at
+#[cw_serde]
+pub struct HolderRewards {
+    pub index: Decimal,
+    pub pending: Uint128,
+}
+
+pub const TOKEN_INFO: Item<TokenInfo> = Item::new("token_info");
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+pub const BALANCES: Map<Addr, Uint128> = Map::new("balances");
+
+pub const ALLOWANCES: Map<(Addr, Addr), AllowanceResponse> = Map::new("allowances");
+
+// Rewards distributed per token since the instantiation
+pub const REWARD_INDEX: Item<Decimal> = Item::new("reward_index");
+
+// Rewards distributed to the holders but not yet claimed
+pub const RESERVED_REWARDS: Item<Uint128> = Item::new("reserved_rewards");
+
+pub const HOLDER_REWARDS: Map<Addr, HolderRewards> = Map::new("holder_rewards");
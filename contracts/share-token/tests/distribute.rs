@@ -0,0 +1,128 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw20::BalanceResponse;
+use cw_multi_test::Executor;
+use pantheon_share_token::{
+    msg::{ExecuteMsg, QueryMsg, RewardsResponse},
+    ContractError,
+};
+
+#[test]
+fn test_happy_path() {
+    let mut app = mock_app();
+    let token_addr = proper_instantiate(&mut app);
+
+    // Simulating a splitter payout to the token contract
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        token_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        token_addr.clone(),
+        &ExecuteMsg::Distribute {},
+        &vec![],
+    )
+    .unwrap();
+
+    // Shares are transferable, the next distribution uses the new balances
+    app.execute_contract(
+        Addr::unchecked(USER),
+        token_addr.clone(),
+        &ExecuteMsg::Transfer {
+            recipient: USER2.to_string(),
+            amount: Uint128::new(20),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        token_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        token_addr.clone(),
+        &ExecuteMsg::Distribute {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res: RewardsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            token_addr.clone(),
+            &QueryMsg::Rewards {
+                address: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.pending, Uint128::new(1_000));
+
+    let res: BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            token_addr.clone(),
+            &QueryMsg::Balance {
+                address: USER2.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::new(60));
+
+    app.execute_contract(
+        Addr::unchecked(USER2),
+        token_addr.clone(),
+        &ExecuteMsg::Claim {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_001_000));
+}
+
+#[test]
+fn test_nothing_to_distribute() {
+    let mut app = mock_app();
+    let token_addr = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            token_addr.clone(),
+            &ExecuteMsg::Distribute {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToDistribute {}.to_string()
+    )
+}
+
+#[test]
+fn test_nothing_to_claim() {
+    let mut app = mock_app();
+    let token_addr = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            token_addr.clone(),
+            &ExecuteMsg::Claim {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToClaim {}.to_string()
+    )
+}
@@ -1,11 +1,30 @@
-use cosmwasm_schema::write_api;
+use std::env::current_dir;
+use std::fs::create_dir_all;
 
-use pantheon_splitter::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use cosmwasm_schema::{export_schema, schema_for, write_api};
+
+use pantheon_splitter::msg::{
+    Cw20HookMsg, Cw721HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SplitterAck,
+    SplitterPacketMsg, SudoMsg,
+};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        migrate: MigrateMsg,
+        sudo: SudoMsg,
     }
+
+    // Messages that are not sent to an entry point directly
+    let mut out_dir = current_dir().unwrap();
+    out_dir.push("schema");
+    out_dir.push("raw");
+    create_dir_all(&out_dir).unwrap();
+
+    export_schema(&schema_for!(Cw20HookMsg), &out_dir);
+    export_schema(&schema_for!(Cw721HookMsg), &out_dir);
+    export_schema(&schema_for!(SplitterPacketMsg), &out_dir);
+    export_schema(&schema_for!(SplitterAck), &out_dir);
 }
@@ -1,41 +1,139 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coins, instantiate2_address, to_binary, BankMsg, Binary, CodeInfoResponse, Decimal, Deps,
-    DepsMut, Env, MessageInfo, Order, Response, StdResult, WasmMsg,
+    coin, from_binary, from_slice, instantiate2_address, to_binary, to_vec, Addr, BankMsg, Binary,
+    BlockInfo, CodeInfoResponse, Coin, CosmosMsg, Decimal, Decimal256, Deps, DepsMut, Empty, Env,
+    Event, HexBinary, IbcMsg, IbcTimeout, MessageInfo, Order, QueryRequest, Reply, Response,
+    StdError, StdResult, Storage, SubMsg, SubMsgResult, Timestamp, Uint128, Uint256, WasmMsg,
 };
-use cw2::set_contract_version;
-use cw_storage_plus::Bound;
-use std::ops::Mul;
+use cw2::{ensure_from_older_version, get_contract_version, set_contract_version, ContractVersion};
+use cw20::{
+    AllowanceResponse, BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg,
+};
+use cw4::{Cw4QueryMsg, Member, MemberChangedHookMsg, MemberListResponse};
+use cw721::{Cw721ExecuteMsg, Cw721ReceiveMsg};
+use cw_ownable::{
+    assert_owner, get_ownership, initialize_owner, update_ownership, Action, Ownership,
+    OwnershipError,
+};
+use cw_storage_plus::{Bound, Map, PrefixBound};
+use cw_utils::{nonpayable, Duration, Expiration};
+use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
 
+use crate::croncat::{create_task_msg, refill_task_msg};
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::{Config, CONFIG, SHARES};
+use crate::ibc::transfer_with_memo;
+use crate::msg::{
+    AtRiskBalance, ClaimDelegationMsg, CloneOverrides, ConfigResponse, CronInterval, Cw20HookMsg,
+    Cw20SourceMsg, Cw721HookMsg, DistributionCostResponse, EpochRecipientAmounts,
+    EpochReportResponse, EstimatedPayoutsResponse, ExecuteMsg, ExportCounters, ImportResponse,
+    InstantiateMsg, MigrateMsg, PayoutRedirectMsg, PriorityPaymentMsg, QueryMsg,
+    ShareAuthorityResponse, ShareUpdatePayload, SharesResponse, SimulateDistributionResponse,
+    SimulatedPayout, StateExport, SudoMsg, SwapConfigMsg, WaterfallTierMsg,
+};
+use crate::simulation::SimulatedStorage;
+use crate::state::{
+    shares, AuditChange, AuditEntry, ClaimDeadline, ClaimDelegation, Config, Cw20Source,
+    Cw20Stream, DenomInfo, DepositRecord, DepositSource, DistributionGasCosts, DistributionPayout,
+    DistributionRecord, EpochInfo, ExpiredSharePolicy, IbcController, ImportMode, Listing,
+    MerkleDistribution, Payout, PayoutKind, PayoutRedirect, PendingApproversUpdate, PendingDeposit,
+    PendingShareUpdate, PriorityPayment, Receipt, ReceiptDenom, ShareApprovers, ShareCap,
+    SwapConfig, ThresholdAmount, WaterfallTier, WithdrawalPolicy, ACC_PER_SHARE, AUDIT_LOG,
+    CARRIED_BALANCES, CLAIM_BOUNTY, CLAIM_DEADLINE, CLAIM_DELEGATIONS, CLAWBACK_WINDOW,
+    CODE_CHECKSUMS, CONFIG, CURRENT_EPOCH, CW20_BALANCES, CW20_SOURCES, CW20_STREAMS, DENOMS,
+    DENOM_SHARES, DEPOSIT_ALLOWLIST, DEPOSIT_HISTORY, DEPOSIT_TOTALS, DISTRIBUTION_GAS_COSTS,
+    DISTRIBUTION_HEIGHTS, DISTRIBUTION_HISTORY, EPOCHS, EPOCH_PAYOUTS, EPOCH_TOTALS,
+    FAILED_PAYOUTS, FROZEN_RECIPIENTS, HELD_BALANCES, IMPORTED_SHARES, IMPORT_MODE, LAST_AUDIT_ID,
+    LAST_DEPOSIT_ID, LAST_DEPOSIT_RECORD_ID, LAST_DISTRIBUTION_ID, LAST_MERKLE_DISTRIBUTION_ID,
+    LAST_RECEIPT_ID, LAST_SHARE_UPDATE, LAST_WITHDRAWAL_HEIGHT, LEGACY_CONFIG, LEGACY_SHARES,
+    LISTINGS, MERKLE_CLAIMS, MERKLE_DISTRIBUTIONS, MIN_DISTRIBUTIONS, MIN_PAYOUTS,
+    OPERATOR_ACTIONS, OPERATOR_ROLE, PAUSED, PAYOUTS, PAYOUT_REDIRECTS, PENDING_APPROVERS_UPDATE,
+    PENDING_DEPOSITS, PENDING_ROTATIONS, PENDING_SHARE_UPDATE, PENDING_TRANSFERS, PREFERRED_DENOMS,
+    PREMIUM, PRICE_ORACLE, PRIORITY_PAYMENTS, RBAC, RECEIPTS, RECEIPT_DENOM,
+    RECIPIENT_CLAIM_DEADLINES, REDIRECTED_PAYOUTS, RESERVED_BALANCES, REWARD_INDEXES,
+    SHARE_APPROVERS, SHARE_AUTHORITY, SHARE_AUTHORITY_NONCE, SHARE_CAPS, SHARE_EXPIRATIONS,
+    SHARE_RECIPIENTS, STREAM_ACC_PER_SHARE, STREAM_INDEXES, SUB_SHARES, SWAP_CONFIG, SWEEP_ADDRESS,
+    UNCLAIMED_REWARDS, UNCLAIMED_STREAMS, UNDISTRIBUTED_DEPOSITS, WATERFALL, WITHDRAWAL_POLICY,
+};
+use crate::tokenfactory::{
+    create_denom_msg, is_token_factory_denom, mint_msg, query_denom_metadata, TOKEN_FACTORY_PREFIX,
+};
 
+use archway_bindings::types::rewards::{ContractMetadataResponse, RewardsRecordsResponse};
 use archway_bindings::{ArchwayMsg, ArchwayQuery, ArchwayResult};
 
-use pantheon_utils::Share;
+use pantheon_price_oracle::query_price;
+use pantheon_swap_adapter::{query_quote, swap_msg};
+use pantheon_utils::premium::set_premium;
+use pantheon_utils::{IbcRoute, Share};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:pantheon-splitter";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// Bumped whenever the layout of the state export changes
+const EXPORT_FORMAT_VERSION: u32 = 2;
+
+// Reply ids of the payouts in a distribution start from this id
+// The ids are reused by every distribution, so the saved payouts are overwritten
+pub const PAYOUT_REPLY_ID_START: u64 = 1_000;
+
+// Maximum page size of the cw4 group member list
+const GROUP_PAGE_LIMIT: u32 = 30;
+
+// Levels of nested splitters followed when looking for share cycles
+const MAX_NESTING_DEPTH: u32 = 3;
+const NESTED_SHARES_PAGE_LIMIT: u8 = 30;
+
+// Native token registered for distribution on instantiation
+const NATIVE_DENOM: &str = "aconst";
+const NATIVE_DENOM_DISPLAY: &str = "CONST";
+const NATIVE_DENOM_EXPONENT: u32 = 18;
+
+// Claim bounties are capped at 5% so keepers cannot take a large part of the rewards
+const MAX_CLAIM_BOUNTY_PERCENT: u64 = 5;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut<ArchwayQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> ArchwayResult<ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    let admin = deps.api.addr_validate(&msg.admin)?;
+    initialize_owner(deps.storage, deps.api, Some(&msg.admin))?;
+
     let config = Config {
-        admin,
         mutable: msg.mutable,
+        transfer_requires_approval: msg.transfer_requires_approval,
+        ibc_controllers: vec![],
+        cron_agent: None,
+        group: None,
+        overflow_address: None,
+        expired_share_policy: ExpiredSharePolicy::Redistribute,
+        fallback_address: None,
+        locked_until: None,
+        emergency_withdraw_disabled: msg.disable_emergency_withdraw,
+    };
+    // A restored config keeps the settings of the exporting splitter
+    let config = match &msg.restore {
+        Some(restore) => Config {
+            mutable: msg.mutable,
+            transfer_requires_approval: msg.transfer_requires_approval,
+            emergency_withdraw_disabled: msg.disable_emergency_withdraw,
+            ..restore.config.clone()
+        },
+        None => config,
     };
     CONFIG.save(deps.storage, &config)?;
 
+    let operator_actions = OPERATOR_ACTIONS.map(|action| action.to_string());
+    RBAC.set_role_actions(deps.storage, OPERATOR_ROLE, &operator_actions)?;
+
     check_share_percentages(&msg.shares)?;
 
     // Processing each share
@@ -43,8 +141,37 @@ pub fn instantiate(
         // Validating the recipient address
         let recipient = deps.api.addr_validate(&share.recipient)?;
 
+        // Validating the IBC route of remote recipients, burn shares are never sent
+        if let Some(ibc) = &share.ibc {
+            if share.burn {
+                return Err(ContractError::InvalidBurnShare {});
+            }
+            check_ibc_route(ibc)?;
+        }
+
         // Saving the share
-        SHARES.save(deps.storage, recipient, &share)?;
+        save_share(deps.storage, recipient, &share, env.block.height)?;
+    }
+    LAST_SHARE_UPDATE.save(deps.storage, &env.block.height)?;
+
+    DENOMS.save(
+        deps.storage,
+        NATIVE_DENOM.to_string(),
+        &DenomInfo {
+            denom: NATIVE_DENOM.to_string(),
+            display: NATIVE_DENOM_DISPLAY.to_string(),
+            exponent: NATIVE_DENOM_EXPONENT,
+        },
+    )?;
+
+    if let Some(restore) = msg.restore {
+        for denom in restore.denoms {
+            DENOMS.save(deps.storage, denom.denom.clone(), &denom)?;
+        }
+        if let Some(counters) = restore.counters {
+            LAST_DISTRIBUTION_ID.save(deps.storage, &counters.last_distribution_id)?;
+            LAST_DEPOSIT_RECORD_ID.save(deps.storage, &counters.last_deposit_record_id)?;
+        }
     }
 
     Ok(Response::new().add_attribute("admin", info.sender))
@@ -57,11 +184,58 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> ArchwayResult<ContractError> {
+    // Emergency withdrawals rescue the funds of paused contracts too
+    if PAUSED.may_load(deps.storage)?.unwrap_or_default()
+        && !matches!(msg, ExecuteMsg::EmergencyWithdraw { .. })
+    {
+        return Err(ContractError::Paused {});
+    }
+
+    // Funds are only accepted by the messages that expect them, the others would absorb them
+    // BuyNft checks the payment against the listing price
+    if !matches!(
+        msg,
+        ExecuteMsg::Deposit { .. }
+            | ExecuteMsg::DepositRefundable {}
+            | ExecuteMsg::BuyNft { .. }
+            | ExecuteMsg::CreateCronTask { .. }
+            | ExecuteMsg::RefillCronTask { .. }
+    ) {
+        nonpayable(&info)?;
+    }
+
     match msg {
         ExecuteMsg::UpdateShares { shares } => execute_update_shares(deps, env, info, shares),
+        ExecuteMsg::UpdateShareApprovers {
+            approvers,
+            threshold,
+        } => execute_update_share_approvers(deps, env, info, approvers, threshold),
+        ExecuteMsg::ConfirmShareUpdate {} => execute_confirm_share_update(deps, env, info),
+        ExecuteMsg::ConfirmApproversUpdate {} => execute_confirm_approvers_update(deps, env, info),
+        ExecuteMsg::CancelShareUpdate {} => execute_cancel_share_update(deps, env, info),
+        ExecuteMsg::ImportShares { shares, mode } => {
+            execute_import_shares(deps, env, info, shares, mode)
+        }
+        ExecuteMsg::CommitImport {} => execute_commit_import(deps, env, info),
+        ExecuteMsg::CancelImport {} => execute_cancel_import(deps, env, info),
+        ExecuteMsg::UpdateShareAuthority { pubkey } => {
+            execute_update_share_authority(deps, env, info, pubkey)
+        }
+        ExecuteMsg::UpdateSharesWithSignature {
+            shares,
+            nonce,
+            signature,
+        } => execute_update_shares_with_signature(deps, env, info, shares, nonce, signature),
+        ExecuteMsg::UpdateDenomShares { denom, shares } => {
+            execute_update_denom_shares(deps, env, info, denom, shares)
+        }
         ExecuteMsg::AddCustomContract { code_id, msg } => {
             execute_add_custom_contract(deps, env, info, code_id, msg)
         }
+        ExecuteMsg::Clone {
+            new_admin,
+            overrides,
+        } => execute_clone(deps, env, info, new_admin, overrides),
         ExecuteMsg::UpdateCustomContractRewardMetadata {
             address,
             owner_address,
@@ -74,247 +248,6633 @@ pub fn execute(
             owner_address,
             rewards_address,
         ),
-        ExecuteMsg::LockContract {} => execute_lock_contract(deps, env, info),
+        ExecuteMsg::UpdatePremium { fee } => execute_update_premium(deps, env, info, fee),
+        ExecuteMsg::LockContract { until } => execute_lock_contract(deps, env, info, until),
+        ExecuteMsg::TransferShare { new_recipient } => {
+            execute_transfer_share(deps, env, info, new_recipient)
+        }
+        ExecuteMsg::ApproveShareTransfer { recipient } => {
+            execute_approve_share_transfer(deps, env, info, recipient)
+        }
+        ExecuteMsg::RotateAddress { new_address } => {
+            execute_rotate_address(deps, env, info, new_address)
+        }
+        ExecuteMsg::UpdateSubShares { shares } => {
+            execute_update_sub_shares(deps, env, info, shares)
+        }
+        ExecuteMsg::RegisterDenom {
+            denom,
+            display,
+            exponent,
+        } => execute_register_denom(deps, env, info, denom, display, exponent),
+        ExecuteMsg::RegisterTokenFactoryDenom { denom } => {
+            execute_register_token_factory_denom(deps, env, info, denom)
+        }
+        ExecuteMsg::CreateReceiptDenom {
+            subdenom,
+            deposit_denom,
+        } => execute_create_receipt_denom(deps, env, info, subdenom, deposit_denom),
+        ExecuteMsg::RemoveDenom { denom } => execute_remove_denom(deps, env, info, denom),
+        ExecuteMsg::UpdateGroup { group } => execute_update_group(deps, env, info, group),
+        ExecuteMsg::MemberChangedHook(msg) => execute_member_changed_hook(deps, env, info, msg),
+        ExecuteMsg::UpdateShareExpiration {
+            recipient,
+            expires_at,
+        } => execute_update_share_expiration(deps, env, info, recipient, expires_at),
+        ExecuteMsg::UpdateExpiredSharePolicy { policy } => {
+            execute_update_expired_share_policy(deps, env, info, policy)
+        }
+        ExecuteMsg::UpdateFallbackAddress { address } => {
+            execute_update_fallback_address(deps, env, info, address)
+        }
+        ExecuteMsg::UpdateFrozenRecipient { recipient, frozen } => {
+            execute_update_frozen_recipient(deps, env, info, recipient, frozen)
+        }
+        ExecuteMsg::UpdateShareCap {
+            recipient,
+            denom,
+            cap,
+        } => execute_update_share_cap(deps, env, info, recipient, denom, cap),
+        ExecuteMsg::UpdateMinPayout {
+            recipient,
+            denom,
+            amount,
+        } => execute_update_min_payout(deps, env, info, recipient, denom, amount),
+        ExecuteMsg::UpdateOverflowAddress { address } => {
+            execute_update_overflow_address(deps, env, info, address)
+        }
+        ExecuteMsg::UpdateDistributionGasCosts { costs } => {
+            execute_update_distribution_gas_costs(deps, env, info, costs)
+        }
+        ExecuteMsg::UpdateSwapConfig { swap } => execute_update_swap_config(deps, env, info, swap),
+        ExecuteMsg::UpdatePriceOracle { oracle } => {
+            execute_update_price_oracle(deps, env, info, oracle)
+        }
+        ExecuteMsg::UpdateMinDistribution { denom, amount } => {
+            execute_update_min_distribution(deps, env, info, denom, amount)
+        }
+        ExecuteMsg::UpdatePriorityPayments { payments } => {
+            execute_update_priority_payments(deps, env, info, payments)
+        }
+        ExecuteMsg::UpdateWaterfall { tiers } => execute_update_waterfall(deps, env, info, tiers),
+        ExecuteMsg::AddOperator { operator } => execute_add_operator(deps, env, info, operator),
+        ExecuteMsg::RemoveOperator { operator } => {
+            execute_remove_operator(deps, env, info, operator)
+        }
+        ExecuteMsg::UpdateOwnership(action) => execute_update_ownership(deps, env, info, action),
+        ExecuteMsg::GrantRole { role, address } => {
+            execute_grant_role(deps, env, info, role, address)
+        }
+        ExecuteMsg::RevokeRole { role, address } => {
+            execute_revoke_role(deps, env, info, role, address)
+        }
+        ExecuteMsg::UpdateRoleActions { role, actions } => {
+            execute_update_role_actions(deps, env, info, role, actions)
+        }
+        ExecuteMsg::UpdateCronAgent { agent } => execute_update_cron_agent(deps, env, info, agent),
+        ExecuteMsg::Cron {} => execute_cron(deps, env, info),
+        ExecuteMsg::CreateCronTask {
+            tasks_contract,
+            interval,
+            gas_limit,
+        } => execute_create_cron_task(deps, env, info, tasks_contract, interval, gas_limit),
+        ExecuteMsg::RefillCronTask {
+            manager_contract,
+            task_hash,
+        } => execute_refill_cron_task(deps, env, info, manager_contract, task_hash),
+        ExecuteMsg::PinCodeChecksum { code_id, checksum } => {
+            execute_pin_code_checksum(deps, env, info, code_id, checksum)
+        }
+        ExecuteMsg::UnpinCodeChecksum { code_id } => {
+            execute_unpin_code_checksum(deps, env, info, code_id)
+        }
+        ExecuteMsg::EmergencyWithdraw { denom, to } => {
+            execute_emergency_withdraw(deps, env, info, denom, to)
+        }
         ExecuteMsg::WithdrawRewards {} => execute_withdraw_rewards(deps, env, info),
+        ExecuteMsg::UpdateWithdrawalPolicy { policy } => {
+            execute_update_withdrawal_policy(deps, env, info, policy)
+        }
         ExecuteMsg::DistributeNativeTokens {} => execute_distribute_native_tokens(deps, env, info),
+        ExecuteMsg::CloseEpoch {} => execute_close_epoch(deps, env, info),
+        ExecuteMsg::DistributeSwappedTokens {} => {
+            execute_distribute_swapped_tokens(deps, env, info)
+        }
+        ExecuteMsg::ClaimFailedPayouts {} => execute_claim_failed_payouts(deps, env, info),
+        ExecuteMsg::UpdatePreferredDenoms { denoms } => {
+            execute_update_preferred_denoms(deps, env, info, denoms)
+        }
+        ExecuteMsg::ClaimHeldBalances {} => execute_claim_held_balances(deps, env, info),
+        ExecuteMsg::ClaimCarriedBalances {} => execute_claim_carried_balances(deps, env, info),
+        ExecuteMsg::UpdatePayoutRedirect { redirect } => {
+            execute_update_payout_redirect(deps, env, info, redirect)
+        }
+        ExecuteMsg::Deposit { memo } => execute_deposit(deps, env, info, memo),
+        ExecuteMsg::ClaimRewards {} => execute_claim_rewards(deps, env, info),
+        ExecuteMsg::ClaimFor { recipient } => execute_claim_for(deps, env, info, recipient),
+        ExecuteMsg::UpdateClaimDelegation { delegation } => {
+            execute_update_claim_delegation(deps, env, info, delegation)
+        }
+        ExecuteMsg::ClaimAsDelegate { recipient } => {
+            execute_claim_as_delegate(deps, env, info, recipient)
+        }
+        ExecuteMsg::UpdateClaimBounty { bounty } => {
+            execute_update_claim_bounty(deps, env, info, bounty)
+        }
+        ExecuteMsg::UpdateClaimDeadline { period } => {
+            execute_update_claim_deadline(deps, env, info, period)
+        }
+        ExecuteMsg::ForfeitUnclaimed { recipient } => {
+            execute_forfeit_unclaimed(deps, env, info, recipient)
+        }
+        ExecuteMsg::UpdateSweepAddress { address } => {
+            execute_update_sweep_address(deps, env, info, address)
+        }
+        ExecuteMsg::Sweep { denom } => execute_sweep(deps, env, info, denom),
+        ExecuteMsg::UpdateClawbackWindow { window } => {
+            execute_update_clawback_window(deps, env, info, window)
+        }
+        ExecuteMsg::DepositRefundable {} => execute_deposit_refundable(deps, env, info),
+        ExecuteMsg::UpdateDepositAllowlist { senders } => {
+            execute_update_deposit_allowlist(deps, env, info, senders)
+        }
+        ExecuteMsg::ReclaimDeposit { id } => execute_reclaim_deposit(deps, env, info, id),
+        ExecuteMsg::RegisterMerkleDistribution {
+            merkle_root,
+            amount,
+        } => execute_register_merkle_distribution(deps, env, info, merkle_root, amount),
+        ExecuteMsg::ClaimMerkleDistribution { id, amount, proof } => {
+            execute_claim_merkle_distribution(deps, env, info, id, amount, proof)
+        }
+        ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
+        ExecuteMsg::DistributeCw20 { token } => execute_distribute_cw20(deps, env, info, token),
+        ExecuteMsg::UpdateCw20Sources { sources } => {
+            execute_update_cw20_sources(deps, env, info, sources)
+        }
+        ExecuteMsg::StreamCw20 { token, duration } => {
+            execute_stream_cw20(deps, env, info, token, duration)
+        }
+        ExecuteMsg::ClaimStreamed { token } => execute_claim_streamed(deps, env, info, token),
+        ExecuteMsg::UpdateIbcControllers { controllers } => {
+            execute_update_ibc_controllers(deps, env, info, controllers)
+        }
+        ExecuteMsg::ReceiveNft(msg) => execute_receive_nft(deps, env, info, msg),
+        ExecuteMsg::BuyNft {
+            collection,
+            token_id,
+        } => execute_buy_nft(deps, env, info, collection, token_id),
+        ExecuteMsg::DelistNft {
+            collection,
+            token_id,
+            recipient,
+        } => execute_delist_nft(deps, env, info, collection, token_id, recipient),
     }
 }
 
-fn execute_update_shares(
+pub fn execute_update_shares(
     deps: DepsMut<ArchwayQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     shares: Vec<Share>,
 ) -> ArchwayResult<ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     // Only mutable contracts can add a share
-    if config.mutable == false {
+    if !is_mutable(&config, &env.block) {
         return Err(ContractError::ContractNotMutable {});
     }
 
-    // Only the admin can add a share
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
-    }
+    // Only the admin or a permitted role can add a share
+    assert_allowed(deps.storage, &info.sender, "update_shares")?;
 
-    check_share_percentages(&shares)?;
+    apply_share_update(deps, &env, info.sender, "update_shares", shares)
+}
 
-    // Clearing the existing shares
-    SHARES.clear(deps.storage);
+// The denom shares, the group and the expirations change the distributed shares without a
+// proposal, so they are only available while no approvers have to confirm the share changes
+fn assert_no_share_approvers(storage: &dyn Storage) -> Result<(), ContractError> {
+    if SHARE_APPROVERS.exists(storage) {
+        return Err(ContractError::ShareApprovalRequired {});
+    }
+    Ok(())
+}
 
-    // Processing each share
-    for share in shares {
-        // Validating the recipient address
-        let recipient = deps.api.addr_validate(&share.recipient)?;
+// With share approvers the update is only proposed until enough approvers confirm it
+fn apply_share_update(
+    mut deps: DepsMut<ArchwayQuery>,
+    env: &Env,
+    proposer: Addr,
+    action: &str,
+    shares: Vec<Share>,
+) -> ArchwayResult<ContractError> {
+    if SHARE_APPROVERS.exists(deps.storage) {
+        validate_shares(deps.as_ref(), env, shares.clone())?;
+        let old = PENDING_SHARE_UPDATE.may_load(deps.storage)?;
+        let pending = PendingShareUpdate {
+            shares,
+            proposer: proposer.clone(),
+            confirmations: vec![],
+        };
+        PENDING_SHARE_UPDATE.save(deps.storage, &pending)?;
+        record_audit_entry(
+            deps.storage,
+            env,
+            &proposer,
+            action,
+            vec![audit_change(
+                "pending_share_update",
+                old.as_ref(),
+                Some(&pending),
+            )?],
+        )?;
 
-        // Saving the share
-        SHARES.save(deps.storage, recipient, &share)?;
+        return Ok(Response::new().add_attribute("action", "propose_share_update"));
     }
 
+    let changes = replace_shares(deps.branch(), env, shares)?;
+    record_audit_entry(deps.storage, env, &proposer, action, changes)?;
+
     Ok(Response::new())
 }
 
-fn execute_add_custom_contract(
+pub fn execute_import_shares(
     deps: DepsMut<ArchwayQuery>,
     env: Env,
     info: MessageInfo,
-    code_id: u64,
-    msg: Binary,
+    shares: Vec<Share>,
+    mode: ImportMode,
 ) -> ArchwayResult<ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    if config.mutable == false {
+    if !is_mutable(&config, &env.block) {
         return Err(ContractError::ContractNotMutable {});
     }
 
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
+    assert_allowed(deps.storage, &info.sender, "import_shares")?;
+
+    match IMPORT_MODE.may_load(deps.storage)? {
+        Some(current) if current != mode => return Err(ContractError::ImportModeMismatch {}),
+        Some(_) => {}
+        None => IMPORT_MODE.save(deps.storage, &mode)?,
     }
 
-    let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
-    let CodeInfoResponse { checksum, .. } = deps.querier.query_wasm_code_info(code_id)?;
-    let salt = msg.clone();
-    let address = deps
-        .api
-        .addr_humanize(&instantiate2_address(&checksum, &creator, &salt)?)?;
+    // Validation is deferred to the commit, so the batches only need to fit in a message
+    let count = shares.len();
+    for share in shares {
+        IMPORTED_SHARES.save(deps.storage, share.recipient.clone(), &share)?;
+    }
 
     Ok(Response::new()
-        .add_message(WasmMsg::Instantiate2 {
-            admin: Some(env.contract.address.to_string()),
-            code_id,
-            label: "instantiate2".to_string(),
-            msg,
-            funds: vec![],
-            salt,
-        })
-        .add_message(ArchwayMsg::UpdateContractMetadata {
-            contract_address: Some(address.to_string()),
-            owner_address: Some(env.contract.address.to_string()),
-            rewards_address: Some(env.contract.address.to_string()),
-        })
-        .add_message(WasmMsg::UpdateAdmin {
-            contract_addr: address.to_string(),
-            admin: info.sender.to_string(),
-        }))
+        .add_attribute("action", "import_shares")
+        .add_attribute("count", count.to_string()))
 }
 
-fn execute_update_custom_contract_reward_metadata(
+pub fn execute_commit_import(
     deps: DepsMut<ArchwayQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    address: String,
-    owner_address: Option<String>,
-    rewards_address: Option<String>,
 ) -> ArchwayResult<ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    if config.mutable == false {
+    if !is_mutable(&config, &env.block) {
         return Err(ContractError::ContractNotMutable {});
     }
 
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
+    assert_allowed(deps.storage, &info.sender, "commit_import")?;
+
+    let mode = IMPORT_MODE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoImport {})?;
+    let imported = IMPORTED_SHARES
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Share)>>>()?;
+
+    let mut new_shares: BTreeMap<String, Share> = BTreeMap::new();
+    if mode == ImportMode::Append {
+        for item in shares().range(deps.storage, None, None, Order::Ascending) {
+            let (_, share) = item?;
+            new_shares.insert(share.recipient.clone(), share);
+        }
     }
+    new_shares.extend(imported);
 
-    let msg = ArchwayMsg::UpdateContractMetadata {
-        contract_address: Some(address),
-        owner_address,
-        rewards_address,
-    };
+    clear_import(deps.storage)?;
 
-    Ok(Response::new().add_message(msg))
+    // The imported table is applied like any other share update, including the share approvers
+    let response = apply_share_update(
+        deps,
+        &env,
+        info.sender,
+        "commit_import",
+        new_shares.into_values().collect(),
+    )?;
+
+    Ok(response.add_attribute("action", "commit_import"))
 }
 
-fn execute_lock_contract(
+pub fn execute_cancel_import(
     deps: DepsMut<ArchwayQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
 ) -> ArchwayResult<ContractError> {
-    let mut config = CONFIG.load(deps.storage)?;
+    assert_allowed(deps.storage, &info.sender, "cancel_import")?;
 
-    // Only the admin can lock the contract
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
+    let old = IMPORT_MODE.may_load(deps.storage)?;
+    clear_import(deps.storage)?;
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "cancel_import",
+        vec![audit_change("import_mode", old.as_ref(), None)?],
+    )?;
+
+    Ok(Response::new().add_attribute("action", "cancel_import"))
+}
+
+fn clear_import(storage: &mut dyn Storage) -> StdResult<()> {
+    let recipients = IMPORTED_SHARES
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for recipient in recipients {
+        IMPORTED_SHARES.remove(storage, recipient);
     }
+    IMPORT_MODE.remove(storage);
 
-    // Updating the contract to be immutable
-    config.mutable = false;
-    CONFIG.save(deps.storage, &config)?;
+    Ok(())
+}
 
-    Ok(Response::new())
+pub fn execute_update_share_authority(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    pubkey: Option<Binary>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_share_authority")?;
+
+    let old = SHARE_AUTHORITY.may_load(deps.storage)?;
+    match pubkey.clone() {
+        Some(pubkey) => {
+            // Compressed or uncompressed secp256k1 keys
+            if pubkey.len() != 33 && pubkey.len() != 65 {
+                return Err(ContractError::InvalidPublicKey {});
+            }
+            SHARE_AUTHORITY.save(deps.storage, &pubkey)?;
+        }
+        None => SHARE_AUTHORITY.remove(deps.storage),
+    }
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_share_authority",
+        vec![audit_change(
+            "share_authority",
+            old.as_ref(),
+            pubkey.as_ref(),
+        )?],
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_share_authority"))
 }
 
-fn execute_withdraw_rewards(
+pub fn execute_update_shares_with_signature(
     deps: DepsMut<ArchwayQuery>,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
+    shares: Vec<Share>,
+    nonce: u64,
+    signature: Binary,
 ) -> ArchwayResult<ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
     }
 
-    let msg = ArchwayMsg::WithdrawRewards {
-        records_limit: Some(0),
-        record_ids: vec![],
+    let pubkey = SHARE_AUTHORITY
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoShareAuthority {})?;
+
+    let expected = SHARE_AUTHORITY_NONCE
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    if nonce != expected {
+        return Err(ContractError::InvalidNonce { expected });
+    }
+
+    let payload = ShareUpdatePayload {
+        chain_id: env.block.chain_id.clone(),
+        contract: env.contract.address.to_string(),
+        nonce,
+        shares: shares.clone(),
     };
+    let hash = Sha256::digest(to_vec(&payload)?);
+    let verified = deps
+        .api
+        .secp256k1_verify(&hash, &signature, &pubkey)
+        .map_err(StdError::from)?;
+    if !verified {
+        return Err(ContractError::InvalidSignature {});
+    }
+    SHARE_AUTHORITY_NONCE.save(deps.storage, &(nonce + 1))?;
 
-    Ok(Response::new().add_message(msg))
+    let response = apply_share_update(
+        deps,
+        &env,
+        info.sender,
+        "update_shares_with_signature",
+        shares,
+    )?;
+
+    Ok(response
+        .add_attribute("action", "update_shares_with_signature")
+        .add_attribute("nonce", nonce.to_string()))
 }
 
-fn execute_distribute_native_tokens(
+pub fn execute_update_share_approvers(
     deps: DepsMut<ArchwayQuery>,
     env: Env,
     info: MessageInfo,
+    approvers: Vec<String>,
+    threshold: u32,
 ) -> ArchwayResult<ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
-    if info.sender != config.admin {
-        return Err(ContractError::Unauthorized {});
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
     }
 
-    let mut msgs: Vec<BankMsg> = vec![];
+    assert_allowed(deps.storage, &info.sender, "update_share_approvers")?;
 
-    // Get the contract's native ARCH balance
-    let balance = deps.querier.query_balance(env.contract.address, "aconst")?;
+    let new_approvers = if approvers.is_empty() {
+        None
+    } else {
+        let mut validated: Vec<Addr> = vec![];
+        for approver in approvers {
+            let approver = deps.api.addr_validate(&approver)?;
+            if !validated.contains(&approver) {
+                validated.push(approver);
+            }
+        }
 
-    // Get the total share percentage
-    let shares = SHARES
-        .range(deps.storage, None, None, Order::Ascending)
-        .map(|item| {
-            let (_, share) = item?;
-            Ok(share)
+        if threshold == 0 || threshold as usize > validated.len() {
+            return Err(ContractError::InvalidApprovalThreshold {});
+        }
+
+        Some(ShareApprovers {
+            approvers: validated,
+            threshold,
         })
-        .collect::<Result<Vec<Share>, ContractError>>()?;
+    };
 
-    // Calculate the amount of rewards to send to each recipient
-    for share in shares {
-        let amount = balance.amount.mul(share.percentage);
+    // The current approvers have to confirm the change, otherwise the admin could remove them alone
+    if SHARE_APPROVERS.exists(deps.storage) {
+        let old = PENDING_APPROVERS_UPDATE.may_load(deps.storage)?;
+        let pending = PendingApproversUpdate {
+            approvers: new_approvers,
+            proposer: info.sender.clone(),
+            confirmations: vec![],
+        };
+        PENDING_APPROVERS_UPDATE.save(deps.storage, &pending)?;
+        record_audit_entry(
+            deps.storage,
+            &env,
+            &info.sender,
+            "update_share_approvers",
+            vec![audit_change(
+                "pending_approvers_update",
+                old.as_ref(),
+                Some(&pending),
+            )?],
+        )?;
 
-        // Create bank messages to send rewards to each recipient
-        msgs.push(BankMsg::Send {
-            to_address: share.recipient.to_string(),
-            amount: coins(amount.u128(), "aconst"),
-        });
+        return Ok(Response::new().add_attribute("action", "propose_approvers_update"));
     }
 
-    Ok(Response::new().add_messages(msgs))
+    let changes = save_share_approvers(deps.storage, new_approvers)?;
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_share_approvers",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_share_approvers")
+        .add_attribute("threshold", threshold.to_string()))
 }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps<ArchwayQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
-        QueryMsg::Share { recipient } => to_binary(&query_share(deps, recipient)?),
-        QueryMsg::Shares { start_after, limit } => {
-            to_binary(&query_shares(deps, start_after, limit)?)
-        }
+// Returns the changes of the approvers and of the dropped proposals
+fn save_share_approvers(
+    storage: &mut dyn Storage,
+    approvers: Option<ShareApprovers>,
+) -> StdResult<Vec<Option<AuditChange>>> {
+    let changes = vec![
+        audit_change(
+            "share_approvers",
+            SHARE_APPROVERS.may_load(storage)?.as_ref(),
+            approvers.as_ref(),
+        )?,
+        audit_change(
+            "pending_share_update",
+            PENDING_SHARE_UPDATE.may_load(storage)?.as_ref(),
+            None,
+        )?,
+        audit_change(
+            "pending_approvers_update",
+            PENDING_APPROVERS_UPDATE.may_load(storage)?.as_ref(),
+            None,
+        )?,
+    ];
+
+    // Confirmations given under the previous approvers are dropped
+    PENDING_SHARE_UPDATE.remove(storage);
+    PENDING_APPROVERS_UPDATE.remove(storage);
+
+    match approvers {
+        Some(approvers) => SHARE_APPROVERS.save(storage, &approvers)?,
+        None => SHARE_APPROVERS.remove(storage),
     }
+    Ok(changes)
 }
 
-fn query_share(deps: Deps<ArchwayQuery>, recipient: String) -> StdResult<Share> {
-    let recipient = deps.api.addr_validate(&recipient)?;
-    let share = SHARES.load(deps.storage, recipient)?;
-    Ok(share)
+pub fn execute_confirm_approvers_update(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    let approvers = SHARE_APPROVERS
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingApproversUpdate {})?;
+    if !approvers.approvers.contains(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut pending = PENDING_APPROVERS_UPDATE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingApproversUpdate {})?;
+    if pending.confirmations.contains(&info.sender) {
+        return Err(ContractError::AlreadyConfirmed {});
+    }
+    let old_confirmations = pending.confirmations.clone();
+    pending.confirmations.push(info.sender.clone());
+    let mut changes = vec![audit_change(
+        "pending_approvers_update.confirmations",
+        Some(&old_confirmations),
+        Some(&pending.confirmations),
+    )?];
+
+    let response = Response::new()
+        .add_attribute("action", "confirm_approvers_update")
+        .add_attribute("confirmations", pending.confirmations.len().to_string());
+
+    if pending.confirmations.len() < approvers.threshold as usize {
+        PENDING_APPROVERS_UPDATE.save(deps.storage, &pending)?;
+        record_audit_entry(
+            deps.storage,
+            &env,
+            &info.sender,
+            "confirm_approvers_update",
+            changes,
+        )?;
+        return Ok(response);
+    }
+
+    changes.extend(save_share_approvers(deps.storage, pending.approvers)?);
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "confirm_approvers_update",
+        changes,
+    )?;
+
+    Ok(response.add_attribute("applied", "true"))
 }
 
-fn query_shares(
-    deps: Deps<ArchwayQuery>,
-    start_after: Option<String>,
-    limit: Option<u8>,
-) -> StdResult<Vec<Share>> {
-    let limit = limit.unwrap_or(10) as usize;
-    let start = start_after.map(|s| {
-        let recipient = deps.api.addr_validate(&s).unwrap();
-        Bound::ExclusiveRaw(recipient.as_bytes().to_vec())
-    });
+pub fn execute_confirm_share_update(
+    mut deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
 
-    let shares = SHARES
-        .range(deps.storage, start, None, Order::Ascending)
-        .take(limit)
-        .map(|item| {
-            let (_, share) = item?;
-            Ok(share)
-        })
-        .collect::<StdResult<Vec<Share>>>()?;
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    let approvers = SHARE_APPROVERS
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingShareUpdate {})?;
+    if !approvers.approvers.contains(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut pending = PENDING_SHARE_UPDATE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingShareUpdate {})?;
+    if pending.confirmations.contains(&info.sender) {
+        return Err(ContractError::AlreadyConfirmed {});
+    }
+    let old_confirmations = pending.confirmations.clone();
+    pending.confirmations.push(info.sender.clone());
+    let mut changes = vec![audit_change(
+        "pending_share_update.confirmations",
+        Some(&old_confirmations),
+        Some(&pending.confirmations),
+    )?];
 
-    Ok(shares)
+    let response = Response::new()
+        .add_attribute("action", "confirm_share_update")
+        .add_attribute("confirmations", pending.confirmations.len().to_string());
+
+    if pending.confirmations.len() < approvers.threshold as usize {
+        PENDING_SHARE_UPDATE.save(deps.storage, &pending)?;
+        record_audit_entry(
+            deps.storage,
+            &env,
+            &info.sender,
+            "confirm_share_update",
+            changes,
+        )?;
+        return Ok(response);
+    }
+
+    // The applied proposal is recorded with the share changes it made
+    PENDING_SHARE_UPDATE.remove(deps.storage);
+    changes.extend(replace_shares(deps.branch(), &env, pending.shares)?);
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "confirm_share_update",
+        changes,
+    )?;
+
+    Ok(response.add_attribute("applied", "true"))
 }
 
-// Used to validate that the total percentage does not exceed 100% and does not fall below 100%
-fn check_share_percentages(shares: &Vec<Share>) -> Result<(), ContractError> {
-    let total_percentage = shares
-        .iter()
-        .fold(Decimal::zero(), |acc, share| acc + share.percentage);
+pub fn execute_cancel_share_update(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "cancel_share_update")?;
 
-    if total_percentage > Decimal::one() {
-        return Err(ContractError::PercentageLimitExceeded {});
-    };
-    if total_percentage < Decimal::one() {
-        return Err(ContractError::PercentageLimitNotMet {});
-    };
+    let pending = PENDING_SHARE_UPDATE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoPendingShareUpdate {})?;
+    PENDING_SHARE_UPDATE.remove(deps.storage);
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "cancel_share_update",
+        vec![audit_change("pending_share_update", Some(&pending), None)?],
+    )?;
+
+    Ok(Response::new().add_attribute("action", "cancel_share_update"))
+}
+
+pub fn execute_update_denom_shares(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    new_shares: Vec<Share>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_denom_shares")?;
+    assert_no_share_approvers(deps.storage)?;
+
+    let validated = if new_shares.is_empty() {
+        vec![]
+    } else {
+        validate_shares(deps.as_ref(), &env, new_shares)?
+    };
+
+    let old_shares = DENOM_SHARES
+        .prefix(denom.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Share)>>>()?;
+    let mut changes = vec![];
+    for (recipient, old) in &old_shares {
+        let new = validated
+            .iter()
+            .find(|(new_recipient, _)| new_recipient == recipient)
+            .map(|(_, share)| share);
+        changes.push(audit_change(
+            format!("denom_shares[{},{}]", denom, recipient),
+            Some(old),
+            new,
+        )?);
+        DENOM_SHARES.remove(deps.storage, (denom.clone(), recipient.clone()));
+    }
+    for (recipient, share) in &validated {
+        if !old_shares
+            .iter()
+            .any(|(old_recipient, _)| old_recipient == recipient)
+        {
+            changes.push(audit_change(
+                format!("denom_shares[{},{}]", denom, recipient),
+                None,
+                Some(share),
+            )?);
+        }
+        DENOM_SHARES.save(deps.storage, (denom.clone(), recipient.clone()), share)?;
+    }
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_denom_shares",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_denom_shares")
+        .add_attribute("denom", denom)
+        .add_attribute("shares", validated.len().to_string()))
+}
+
+// Validates the new shares before replacing the existing ones
+// Only the removed, added and changed recipients are written to storage and returned as changes
+pub(crate) fn replace_shares(
+    deps: DepsMut<ArchwayQuery>,
+    env: &Env,
+    new_shares: Vec<Share>,
+) -> Result<Vec<Option<AuditChange>>, ContractError> {
+    let height = env.block.height;
+    let validated = validate_shares(deps.as_ref(), env, new_shares)?;
+    checkpoint_streams(deps.storage, env)?;
+    let new_recipients: BTreeSet<Addr> = validated
+        .iter()
+        .map(|(recipient, _)| recipient.clone())
+        .collect();
+
+    let mut changes = vec![];
+
+    // Removing the recipients missing from the new shares
+    let old_shares = shares()
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Share)>>>()?;
+    for (recipient, old) in old_shares {
+        if !new_recipients.contains(&recipient) {
+            changes.push(audit_change(
+                format!("shares[{}]", recipient),
+                Some(&old),
+                None,
+            )?);
+            settle_rewards(deps.storage, &recipient)?;
+            shares().remove(deps.storage, recipient.clone(), height)?;
+            SHARE_EXPIRATIONS.remove(deps.storage, recipient.clone());
+            PENDING_TRANSFERS.remove(deps.storage, recipient.clone());
+            PENDING_ROTATIONS.remove(deps.storage, recipient);
+        }
+    }
+
+    // Saving the added and changed shares
+    for (recipient, share) in validated {
+        let old = shares().may_load(deps.storage, recipient.clone())?;
+        if old.as_ref() != Some(&share) {
+            changes.push(audit_change(
+                format!("shares[{}]", recipient),
+                old.as_ref(),
+                Some(&share),
+            )?);
+            save_share(deps.storage, recipient, &share, height)?;
+        }
+    }
+
+    if !changes.is_empty() {
+        LAST_SHARE_UPDATE.save(deps.storage, &height)?;
+    }
+
+    Ok(changes)
+}
+
+// Checks the percentages, recipients, IBC routes and nested splitters of a share table
+fn validate_shares(
+    deps: Deps<ArchwayQuery>,
+    env: &Env,
+    new_shares: Vec<Share>,
+) -> Result<Vec<(Addr, Share)>, ContractError> {
+    check_share_percentages(&new_shares)?;
+
+    // Processing each share
+    let mut validated: Vec<(Addr, Share)> = vec![];
+    let mut new_recipients: BTreeSet<Addr> = BTreeSet::new();
+    for share in new_shares {
+        // Validating the recipient address
+        let recipient = deps.api.addr_validate(&share.recipient)?;
+        if !new_recipients.insert(recipient.clone()) {
+            return Err(ContractError::RecipientExists {});
+        }
+
+        // Validating the IBC route of remote recipients, burn shares are never sent
+        if let Some(ibc) = &share.ibc {
+            if share.burn {
+                return Err(ContractError::InvalidBurnShare {});
+            }
+            check_ibc_route(ibc)?;
+        }
+
+        validated.push((recipient, share));
+    }
+
+    check_share_cycles(
+        deps,
+        &env.contract.address,
+        validated
+            .iter()
+            .filter(|(_, share)| share.ibc.is_none() && !share.burn)
+            .map(|(recipient, _)| recipient.clone())
+            .collect(),
+    )?;
+
+    Ok(validated)
+}
+
+// Rejects shares paying a pantheon splitter that pays this contract back, directly or through
+// other splitters, as the funds would go around forever
+// Only MAX_NESTING_DEPTH levels of nested splitters are followed
+fn check_share_cycles(
+    deps: Deps<ArchwayQuery>,
+    contract: &Addr,
+    recipients: Vec<Addr>,
+) -> Result<(), ContractError> {
+    let mut visited: BTreeSet<Addr> = BTreeSet::new();
+    let mut level = recipients;
+
+    for depth in 0..=MAX_NESTING_DEPTH {
+        let mut next_level: Vec<Addr> = vec![];
+        for recipient in level {
+            if recipient == *contract {
+                return Err(ContractError::ShareCycle {});
+            }
+
+            if depth < MAX_NESTING_DEPTH
+                && visited.insert(recipient.clone())
+                && is_splitter(deps, &recipient)
+            {
+                // Remote and burn shares never pay a contract here
+                for share in nested_shares(deps, &recipient)? {
+                    if share.ibc.is_none() && !share.burn {
+                        next_level.push(deps.api.addr_validate(&share.recipient)?);
+                    }
+                }
+            }
+        }
+        level = next_level;
+    }
+
+    Ok(())
+}
+
+// Wallets and other contracts have no pantheon splitter cw2 info
+fn is_splitter(deps: Deps<ArchwayQuery>, address: &Addr) -> bool {
+    deps.querier
+        .query_wasm_raw(address, b"contract_info".as_slice())
+        .ok()
+        .flatten()
+        .and_then(|data| from_slice::<ContractVersion>(&data).ok())
+        .map(|version| version.contract == CONTRACT_NAME)
+        .unwrap_or(false)
+}
+
+fn nested_shares(deps: Deps<ArchwayQuery>, splitter: &Addr) -> StdResult<Vec<Share>> {
+    let mut share_list: Vec<Share> = vec![];
+    let mut start_after: Option<String> = None;
+    loop {
+        let res: SharesResponse = deps.querier.query_wasm_smart(
+            splitter,
+            &QueryMsg::Shares {
+                start_after,
+                limit: Some(NESTED_SHARES_PAGE_LIMIT),
+                order: None,
+            },
+        )?;
+        share_list.extend(res.shares);
+
+        start_after = res.next_start_after;
+        if start_after.is_none() {
+            break;
+        }
+    }
+
+    Ok(share_list)
+}
+
+// Saves a share and records the recipient for historical lookups
+fn save_share(
+    storage: &mut dyn Storage,
+    recipient: Addr,
+    share: &Share,
+    height: u64,
+) -> StdResult<()> {
+    settle_rewards(storage, &recipient)?;
+    SHARE_RECIPIENTS.save(storage, recipient.clone(), &Empty {})?;
+    shares().save(storage, recipient, share, height)
+}
+
+// Settles the deposited rewards of a recipient at its current share
+// Must run before every change to the share of the recipient
+fn settle_rewards(storage: &mut dyn Storage, recipient: &Addr) -> StdResult<()> {
+    let percentage = shares()
+        .may_load(storage, recipient.clone())?
+        .map(|share| Decimal256::from(share.percentage))
+        .unwrap_or_default();
+
+    let accumulators = ACC_PER_SHARE
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Decimal256)>>>()?;
+    for (denom, acc) in accumulators {
+        let key = (recipient.clone(), denom);
+        let index = REWARD_INDEXES
+            .may_load(storage, key.clone())?
+            .unwrap_or_default();
+
+        let accrued = percentage.checked_mul(acc.checked_sub(index)?)?;
+        if !accrued.is_zero() {
+            UNCLAIMED_REWARDS.update(storage, key.clone(), |unclaimed| -> StdResult<_> {
+                Ok(unclaimed.unwrap_or_default().checked_add(accrued)?)
+            })?;
+        }
+        REWARD_INDEXES.save(storage, key, &acc)?;
+    }
+
+    // The streams are settled up to their last checkpoint
+    let accumulators = STREAM_ACC_PER_SHARE
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Decimal256)>>>()?;
+    for (token, acc) in accumulators {
+        let key = (recipient.clone(), token);
+        let index = STREAM_INDEXES
+            .may_load(storage, key.clone())?
+            .unwrap_or_default();
+
+        let accrued = percentage.checked_mul(acc.checked_sub(index)?)?;
+        if !accrued.is_zero() {
+            UNCLAIMED_STREAMS.update(storage, key.clone(), |unclaimed| -> StdResult<_> {
+                Ok(unclaimed.unwrap_or_default().checked_add(accrued)?)
+            })?;
+        }
+        STREAM_INDEXES.save(storage, key, &acc)?;
+    }
+
+    Ok(())
+}
+
+// Rounds down a reward to a token amount
+fn reward_amount(reward: Decimal256) -> StdResult<Uint128> {
+    Ok(Uint128::try_from(Uint256::one() * reward)?)
+}
+
+pub fn execute_register_denom(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    display: String,
+    exponent: u32,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "register_denom")?;
+
+    // Registering an existing denom updates its metadata
+    let old = DENOMS.may_load(deps.storage, denom.clone())?;
+    let denom_info = DenomInfo {
+        denom: denom.clone(),
+        display,
+        exponent,
+    };
+    DENOMS.save(deps.storage, denom.clone(), &denom_info)?;
+    let changes = vec![audit_change(
+        format!("denoms[{}]", denom),
+        old.as_ref(),
+        Some(&denom_info),
+    )?];
+    record_audit_entry(deps.storage, &env, &info.sender, "register_denom", changes)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_denom")
+        .add_attribute("denom", denom))
+}
+
+pub fn execute_register_token_factory_denom(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "register_denom")?;
+
+    if !is_token_factory_denom(&denom) {
+        return Err(ContractError::InvalidTokenFactoryDenom {});
+    }
+    let metadata = query_denom_metadata(&deps.querier, denom.clone())?;
+
+    let old = DENOMS.may_load(deps.storage, denom.clone())?;
+    let denom_info = DenomInfo {
+        denom: denom.clone(),
+        display: metadata.display,
+        exponent: metadata.exponent,
+    };
+    DENOMS.save(deps.storage, denom.clone(), &denom_info)?;
+    let changes = vec![audit_change(
+        format!("denoms[{}]", denom),
+        old.as_ref(),
+        Some(&denom_info),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "register_token_factory_denom",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_token_factory_denom")
+        .add_attribute("denom", denom))
+}
+
+pub fn execute_create_receipt_denom(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    subdenom: String,
+    deposit_denom: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "create_receipt_denom")?;
+
+    if RECEIPT_DENOM.exists(deps.storage) {
+        return Err(ContractError::ReceiptDenomExists {});
+    }
+    if !DENOMS.has(deps.storage, deposit_denom.clone()) {
+        return Err(ContractError::DenomNotRegistered {});
+    }
+
+    let denom = format!(
+        "{}/{}/{}",
+        TOKEN_FACTORY_PREFIX, env.contract.address, subdenom
+    );
+    let receipt_denom = ReceiptDenom {
+        denom: denom.clone(),
+        deposit_denom,
+    };
+    RECEIPT_DENOM.save(deps.storage, &receipt_denom)?;
+    let changes = vec![audit_change("receipt_denom", None, Some(&receipt_denom))?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "create_receipt_denom",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_message(create_denom_msg(env.contract.address.to_string(), subdenom))
+        .add_attribute("action", "create_receipt_denom")
+        .add_attribute("denom", denom))
+}
+
+pub fn execute_remove_denom(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "remove_denom")?;
+
+    let old = DENOMS
+        .may_load(deps.storage, denom.clone())?
+        .ok_or(ContractError::DenomNotRegistered {})?;
+    DENOMS.remove(deps.storage, denom.clone());
+    let changes = vec![audit_change(
+        format!("denoms[{}]", denom),
+        Some(&old),
+        None,
+    )?];
+    record_audit_entry(deps.storage, &env, &info.sender, "remove_denom", changes)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_denom")
+        .add_attribute("denom", denom))
+}
+
+pub fn execute_update_group(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    group: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_group")?;
+    assert_no_share_approvers(deps.storage)?;
+
+    let old = config.group.clone();
+    config.group = group
+        .map(|group| deps.api.addr_validate(&group))
+        .transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+    let changes = vec![audit_change(
+        "config.group",
+        old.as_ref(),
+        config.group.as_ref(),
+    )?];
+    record_audit_entry(deps.storage, &env, &info.sender, "update_group", changes)?;
+
+    Ok(Response::new().add_attribute("action", "update_group"))
+}
+
+pub fn execute_update_share_expiration(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    expires_at: Option<Expiration>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_share_expiration")?;
+    assert_no_share_approvers(deps.storage)?;
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    shares().load(deps.storage, recipient.clone())?;
+
+    let old = SHARE_EXPIRATIONS.may_load(deps.storage, recipient.clone())?;
+    match expires_at {
+        Some(expires_at) => SHARE_EXPIRATIONS.save(deps.storage, recipient.clone(), &expires_at)?,
+        None => SHARE_EXPIRATIONS.remove(deps.storage, recipient.clone()),
+    }
+    let changes = vec![audit_change(
+        format!("share_expirations[{}]", recipient),
+        old.as_ref(),
+        expires_at.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_share_expiration",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_share_expiration")
+        .add_attribute("recipient", recipient))
+}
+
+pub fn execute_update_expired_share_policy(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    policy: ExpiredSharePolicy,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_expired_share_policy")?;
+    assert_no_share_approvers(deps.storage)?;
+
+    let old = config.expired_share_policy.clone();
+    config.expired_share_policy = policy;
+    CONFIG.save(deps.storage, &config)?;
+    let changes = vec![audit_change(
+        "config.expired_share_policy",
+        Some(&old),
+        Some(&config.expired_share_policy),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_expired_share_policy",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_expired_share_policy"))
+}
+
+pub fn execute_update_fallback_address(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // The fallback address receives the undeliverable portions, so it is locked with the shares
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_fallback_address")?;
+
+    let old = config.fallback_address.clone();
+    config.fallback_address = address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+    let changes = vec![audit_change(
+        "config.fallback_address",
+        old.as_ref(),
+        config.fallback_address.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_fallback_address",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_fallback_address"))
+}
+
+pub fn execute_update_frozen_recipient(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    frozen: bool,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_frozen_recipient")?;
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let old = FROZEN_RECIPIENTS.has(deps.storage, recipient.clone());
+    if frozen {
+        FROZEN_RECIPIENTS.save(deps.storage, recipient.clone(), &Empty {})?;
+    } else {
+        FROZEN_RECIPIENTS.remove(deps.storage, recipient.clone());
+    }
+    let changes = vec![audit_change(
+        format!("frozen_recipients[{}]", recipient),
+        Some(&old),
+        Some(&frozen),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_frozen_recipient",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_frozen_recipient")
+        .add_attribute("recipient", recipient)
+        .add_attribute("frozen", frozen.to_string()))
+}
+
+pub fn execute_update_share_cap(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    denom: String,
+    cap: Option<Uint128>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_share_cap")?;
+
+    let recipient = deps.api.addr_validate(&recipient)?.to_string();
+    let key = (recipient.clone(), denom.clone());
+    let old = SHARE_CAPS
+        .may_load(deps.storage, key.clone())?
+        .map(|share_cap| share_cap.cap);
+    match cap {
+        // The amount paid so far is kept when the cap is changed
+        Some(cap) => {
+            SHARE_CAPS.update(deps.storage, key, |share_cap| -> StdResult<_> {
+                Ok(ShareCap {
+                    cap,
+                    paid: share_cap
+                        .map(|share_cap| share_cap.paid)
+                        .unwrap_or_default(),
+                })
+            })?;
+        }
+        None => SHARE_CAPS.remove(deps.storage, key),
+    }
+    let changes = vec![audit_change(
+        format!("share_caps[{},{}].cap", recipient, denom),
+        old.as_ref(),
+        cap.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_share_cap",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_share_cap")
+        .add_attribute("recipient", recipient)
+        .add_attribute("denom", denom))
+}
+
+pub fn execute_update_min_payout(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    denom: String,
+    amount: Option<Uint128>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // A minimum can hold back the payouts of a recipient, so it is locked with the shares
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_min_payout")?;
+
+    // Amounts carried over so far are paid with the next portion once the minimum is lowered
+    let recipient = deps.api.addr_validate(&recipient)?.to_string();
+    let key = (recipient.clone(), denom.clone());
+    let old = MIN_PAYOUTS.may_load(deps.storage, key.clone())?;
+    match amount {
+        Some(amount) => MIN_PAYOUTS.save(deps.storage, key, &amount)?,
+        None => MIN_PAYOUTS.remove(deps.storage, key),
+    }
+    let changes = vec![audit_change(
+        format!("min_payouts[{},{}]", recipient, denom),
+        old.as_ref(),
+        amount.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_min_payout",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_min_payout")
+        .add_attribute("recipient", recipient)
+        .add_attribute("denom", denom))
+}
+
+pub fn execute_update_overflow_address(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // The overflow address receives the capped portions, so it is locked with the caps
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_overflow_address")?;
+
+    let old = config.overflow_address.clone();
+    config.overflow_address = address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+    let changes = vec![audit_change(
+        "config.overflow_address",
+        old.as_ref(),
+        config.overflow_address.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_overflow_address",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_overflow_address"))
+}
+
+pub fn execute_update_swap_config(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    swap: Option<SwapConfigMsg>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // The adapter receives the whole balance before the distributions
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_swap_config")?;
+
+    let old = SWAP_CONFIG.may_load(deps.storage)?;
+    match swap {
+        Some(swap) => {
+            if swap.max_slippage > Decimal::one() {
+                return Err(ContractError::InvalidSlippage {});
+            }
+
+            SWAP_CONFIG.save(
+                deps.storage,
+                &SwapConfig {
+                    adapter: deps.api.addr_validate(&swap.adapter)?,
+                    target_denom: swap.target_denom,
+                    max_slippage: swap.max_slippage,
+                },
+            )?;
+        }
+        None => SWAP_CONFIG.remove(deps.storage),
+    }
+    let changes = vec![audit_change(
+        "swap_config",
+        old.as_ref(),
+        SWAP_CONFIG.may_load(deps.storage)?.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_swap_config",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_swap_config"))
+}
+
+pub fn execute_update_price_oracle(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    oracle: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_price_oracle")?;
+
+    let old = PRICE_ORACLE.may_load(deps.storage)?;
+    match &oracle {
+        Some(oracle) => PRICE_ORACLE.save(deps.storage, &deps.api.addr_validate(oracle)?)?,
+        None => PRICE_ORACLE.remove(deps.storage),
+    }
+    let changes = vec![audit_change(
+        "price_oracle",
+        old.as_ref(),
+        PRICE_ORACLE.may_load(deps.storage)?.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_price_oracle",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_price_oracle")
+        .add_attribute("oracle", oracle.unwrap_or_default()))
+}
+
+pub fn execute_update_min_distribution(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: Option<ThresholdAmount>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_min_distribution")?;
+
+    if !DENOMS.has(deps.storage, denom.clone()) {
+        return Err(ContractError::DenomNotRegistered {});
+    }
+
+    let old = MIN_DISTRIBUTIONS.may_load(deps.storage, denom.clone())?;
+    match &amount {
+        Some(amount) => {
+            if matches!(amount, ThresholdAmount::Usd(_)) && !PRICE_ORACLE.exists(deps.storage) {
+                return Err(ContractError::PriceOracleNotSet {});
+            }
+            MIN_DISTRIBUTIONS.save(deps.storage, denom.clone(), amount)?;
+        }
+        None => MIN_DISTRIBUTIONS.remove(deps.storage, denom.clone()),
+    }
+    let changes = vec![audit_change(
+        format!("min_distributions[{}]", denom),
+        old.as_ref(),
+        amount.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_min_distribution",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_min_distribution")
+        .add_attribute("denom", denom))
+}
+
+pub fn execute_update_distribution_gas_costs(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    costs: DistributionGasCosts,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_distribution_gas_costs")?;
+
+    let old = DISTRIBUTION_GAS_COSTS.may_load(deps.storage)?;
+    DISTRIBUTION_GAS_COSTS.save(deps.storage, &costs)?;
+    let changes = vec![audit_change(
+        "distribution_gas_costs",
+        old.as_ref(),
+        Some(&costs),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_distribution_gas_costs",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_distribution_gas_costs"))
+}
+
+pub fn execute_update_priority_payments(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    payments: Vec<PriorityPaymentMsg>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_priority_payments")?;
+
+    let payments = payments
+        .into_iter()
+        .map(|payment| {
+            let zero = match payment.usd_amount {
+                Some(usd_amount) => usd_amount.is_zero(),
+                None => payment.amount.amount.is_zero(),
+            };
+            if zero || !DENOMS.has(deps.storage, payment.amount.denom.clone()) {
+                return Err(ContractError::InvalidPriorityPayment {});
+            }
+            if payment.usd_amount.is_some() && !PRICE_ORACLE.exists(deps.storage) {
+                return Err(ContractError::PriceOracleNotSet {});
+            }
+
+            Ok(PriorityPayment {
+                recipient: deps.api.addr_validate(&payment.recipient)?,
+                amount: payment.amount,
+                usd_amount: payment.usd_amount,
+            })
+        })
+        .collect::<Result<Vec<PriorityPayment>, ContractError>>()?;
+    let old = PRIORITY_PAYMENTS.may_load(deps.storage)?;
+    PRIORITY_PAYMENTS.save(deps.storage, &payments)?;
+    let changes = vec![audit_change(
+        "priority_payments",
+        old.as_ref(),
+        Some(&payments),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_priority_payments",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_priority_payments")
+        .add_attribute("payments", payments.len().to_string()))
+}
+
+pub fn execute_update_waterfall(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    tiers: Vec<WaterfallTierMsg>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_waterfall")?;
+
+    let tiers = tiers
+        .into_iter()
+        .map(|tier| {
+            if tier.target.amount.is_zero() || !DENOMS.has(deps.storage, tier.target.denom.clone())
+            {
+                return Err(ContractError::InvalidWaterfallTier {});
+            }
+
+            Ok(WaterfallTier {
+                recipient: deps.api.addr_validate(&tier.recipient)?,
+                target: tier.target,
+                filled: Uint128::zero(),
+            })
+        })
+        .collect::<Result<Vec<WaterfallTier>, ContractError>>()?;
+    let old = WATERFALL.may_load(deps.storage)?;
+    WATERFALL.save(deps.storage, &tiers)?;
+    let changes = vec![audit_change("waterfall", old.as_ref(), Some(&tiers))?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_waterfall",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_waterfall")
+        .add_attribute("tiers", tiers.len().to_string()))
+}
+
+// The sender of the message is the linked cw4 group
+pub fn execute_member_changed_hook(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: MemberChangedHookMsg,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if config.group.as_ref() != Some(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    let response = Response::new()
+        .add_attribute("action", "member_changed_hook")
+        .add_attribute("diffs", msg.diffs.len().to_string());
+
+    let mut new_shares = group_shares(deps.as_ref(), &info.sender)?;
+
+    // A group without weighted members keeps the previous table
+    if new_shares.is_empty() {
+        return Ok(response);
+    }
+
+    // The rounding dust of the percentages goes to the last member so the table adds up to 100%
+    let total_percentage = new_shares.iter().try_fold(Decimal::zero(), |acc, share| {
+        acc.checked_add(share.percentage)
+    })?;
+    if let Some(last) = new_shares.last_mut() {
+        last.percentage = last
+            .percentage
+            .checked_add(Decimal::one().checked_sub(total_percentage)?)?;
+    }
+
+    let old_shares = shares()
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Share)>>>()?;
+
+    replace_shares(deps, &env, new_shares.clone())?;
+
+    // Describing every added, removed and changed share
+    let mut events: Vec<Event> = vec![];
+    for share in &new_shares {
+        let old_percentage = old_shares
+            .iter()
+            .find(|(recipient, _)| recipient.as_str() == share.recipient)
+            .map(|(_, old)| old.percentage);
+        if old_percentage != Some(share.percentage) {
+            events.push(
+                Event::new("share_changed")
+                    .add_attribute("recipient", share.recipient.clone())
+                    .add_attribute(
+                        "old_percentage",
+                        old_percentage.unwrap_or_default().to_string(),
+                    )
+                    .add_attribute("new_percentage", share.percentage.to_string()),
+            );
+        }
+    }
+    for (recipient, old) in old_shares {
+        if !new_shares
+            .iter()
+            .any(|share| share.recipient == recipient.as_str())
+        {
+            events.push(
+                Event::new("share_changed")
+                    .add_attribute("recipient", recipient)
+                    .add_attribute("old_percentage", old.percentage.to_string())
+                    .add_attribute("new_percentage", Decimal::zero().to_string()),
+            );
+        }
+    }
+
+    Ok(response.add_events(events))
+}
+
+pub fn execute_add_operator(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    operator: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "add_operator")?;
+
+    let operator = deps.api.addr_validate(&operator)?;
+    let old = RBAC.role_members(deps.storage, OPERATOR_ROLE)?;
+    RBAC.grant_role(deps.storage, OPERATOR_ROLE, &operator)?;
+    let changes = vec![audit_change(
+        format!("role_members[{}]", OPERATOR_ROLE),
+        Some(&old),
+        Some(&RBAC.role_members(deps.storage, OPERATOR_ROLE)?),
+    )?];
+    record_audit_entry(deps.storage, &env, &info.sender, "add_operator", changes)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_operator")
+        .add_attribute("operator", operator))
+}
+
+pub fn execute_remove_operator(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    operator: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "remove_operator")?;
+
+    let operator = deps.api.addr_validate(&operator)?;
+    let old = RBAC.role_members(deps.storage, OPERATOR_ROLE)?;
+    RBAC.revoke_role(deps.storage, OPERATOR_ROLE, &operator);
+    let changes = vec![audit_change(
+        format!("role_members[{}]", OPERATOR_ROLE),
+        Some(&old),
+        Some(&RBAC.role_members(deps.storage, OPERATOR_ROLE)?),
+    )?];
+    record_audit_entry(deps.storage, &env, &info.sender, "remove_operator", changes)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_operator")
+        .add_attribute("operator", operator))
+}
+
+// Old and new value of a field changed by an admin action, None when the field is not set
+// Unchanged fields are dropped, so the handlers can list every field they may change
+fn audit_change<T: Serialize + PartialEq>(
+    field: impl Into<String>,
+    old: Option<&T>,
+    new: Option<&T>,
+) -> StdResult<Option<AuditChange>> {
+    if old == new {
+        return Ok(None);
+    }
+    Ok(Some(AuditChange {
+        field: field.into(),
+        old: old.map(to_json_string).transpose()?,
+        new: new.map(to_json_string).transpose()?,
+    }))
+}
+
+fn to_json_string<T: Serialize>(value: &T) -> StdResult<String> {
+    String::from_utf8(to_vec(value)?).map_err(|err| StdError::invalid_utf8(err.to_string()))
+}
+
+fn ownership_changes(
+    old: &Ownership<Addr>,
+    new: &Ownership<Addr>,
+) -> StdResult<Vec<Option<AuditChange>>> {
+    Ok(vec![
+        audit_change("ownership.owner", old.owner.as_ref(), new.owner.as_ref())?,
+        audit_change(
+            "ownership.pending_owner",
+            old.pending_owner.as_ref(),
+            new.pending_owner.as_ref(),
+        )?,
+        audit_change(
+            "ownership.pending_expiry",
+            old.pending_expiry.as_ref(),
+            new.pending_expiry.as_ref(),
+        )?,
+    ])
+}
+
+// Admin changes to the shares and the settings of the contract are recorded by their handlers
+// Operational actions like the distributions and the messages of the recipients are not recorded
+fn record_audit_entry(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: &Addr,
+    action: &str,
+    changes: Vec<Option<AuditChange>>,
+) -> StdResult<()> {
+    let id = LAST_AUDIT_ID.may_load(storage)?.unwrap_or_default() + 1;
+    LAST_AUDIT_ID.save(storage, &id)?;
+    AUDIT_LOG.save(
+        storage,
+        id,
+        &AuditEntry {
+            id,
+            sender: sender.clone(),
+            height: env.block.height,
+            time: env.block.time,
+            action: action.to_string(),
+            changes: changes.into_iter().flatten().collect(),
+        },
+    )
+}
+
+pub fn execute_update_ownership(
+    mut deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    action: Action,
+) -> ArchwayResult<ContractError> {
+    let old = get_ownership(deps.storage)?;
+    let ownership = update_ownership(deps.branch().into_empty(), &env.block, &info.sender, action)?;
+    let changes = ownership_changes(&old, &ownership)?;
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_ownership",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_ownership")
+        .add_attributes(ownership.into_attributes()))
+}
+
+// The owner can run every action, the members of a role only the actions permitted to the role
+fn assert_allowed(storage: &dyn Storage, sender: &Addr, action: &str) -> Result<(), ContractError> {
+    let owner = get_ownership(storage)?.owner;
+    RBAC.assert_allowed(storage, owner.as_ref(), sender, action)?;
+
+    Ok(())
+}
+
+pub fn execute_grant_role(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_roles")?;
+
+    let address = deps.api.addr_validate(&address)?;
+    let old = RBAC.role_members(deps.storage, &role)?;
+    RBAC.grant_role(deps.storage, &role, &address)?;
+    let new = RBAC.role_members(deps.storage, &role)?;
+    let changes = vec![audit_change(
+        format!("role_members[{}]", role),
+        Some(&old),
+        Some(&new),
+    )?];
+    record_audit_entry(deps.storage, &env, &info.sender, "grant_role", changes)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "grant_role")
+        .add_attribute("role", role)
+        .add_attribute("address", address))
+}
+
+pub fn execute_revoke_role(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+    address: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_roles")?;
+
+    let address = deps.api.addr_validate(&address)?;
+    let old = RBAC.role_members(deps.storage, &role)?;
+    RBAC.revoke_role(deps.storage, &role, &address);
+    let new = RBAC.role_members(deps.storage, &role)?;
+    let changes = vec![audit_change(
+        format!("role_members[{}]", role),
+        Some(&old),
+        Some(&new),
+    )?];
+    record_audit_entry(deps.storage, &env, &info.sender, "revoke_role", changes)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("role", role)
+        .add_attribute("address", address))
+}
+
+pub fn execute_update_role_actions(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    role: String,
+    actions: Vec<String>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_roles")?;
+
+    let old = RBAC.role_actions(deps.storage, &role)?;
+    RBAC.set_role_actions(deps.storage, &role, &actions)?;
+    let new = RBAC.role_actions(deps.storage, &role)?;
+    let changes = vec![audit_change(
+        format!("role_actions[{}]", role),
+        Some(&old),
+        Some(&new),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_role_actions",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_role_actions")
+        .add_attribute("role", role)
+        .add_attribute("actions", actions.join(",")))
+}
+
+pub fn execute_update_cron_agent(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    agent: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    assert_allowed(deps.storage, &info.sender, "update_cron_agent")?;
+
+    let old = config.cron_agent.clone();
+    config.cron_agent = agent
+        .map(|agent| deps.api.addr_validate(&agent))
+        .transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+    let changes = vec![audit_change(
+        "config.cron_agent",
+        old.as_ref(),
+        config.cron_agent.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_cron_agent",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_cron_agent"))
+}
+
+pub fn execute_cron(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if config.cron_agent != Some(info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let swaps = swap_msgs(deps.as_ref(), &env)?;
+    if !swaps.is_empty() {
+        return Ok(Response::new()
+            .add_messages(swaps)
+            .add_attribute("action", "cron"));
+    }
+
+    let (msgs, events) = registry_distribution_msgs(deps, &env)?;
+
+    Ok(Response::new()
+        .add_submessages(msgs)
+        .add_events(events)
+        .add_attribute("action", "cron"))
+}
+
+pub fn execute_create_cron_task(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    tasks_contract: String,
+    interval: CronInterval,
+    gas_limit: Option<u64>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "create_cron_task")?;
+
+    let tasks_contract = deps.api.addr_validate(&tasks_contract)?;
+
+    Ok(Response::new()
+        .add_message(create_task_msg(
+            tasks_contract.to_string(),
+            env.contract.address.to_string(),
+            interval,
+            gas_limit,
+            info.funds,
+        )?)
+        .add_attribute("action", "create_cron_task")
+        .add_attribute("tasks_contract", tasks_contract))
+}
+
+pub fn execute_refill_cron_task(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    manager_contract: String,
+    task_hash: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "refill_cron_task")?;
+
+    let manager_contract = deps.api.addr_validate(&manager_contract)?;
+
+    Ok(Response::new()
+        .add_message(refill_task_msg(
+            manager_contract.to_string(),
+            task_hash.clone(),
+            info.funds,
+        )?)
+        .add_attribute("action", "refill_cron_task")
+        .add_attribute("task_hash", task_hash))
+}
+
+pub fn execute_pin_code_checksum(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    code_id: u64,
+    checksum: HexBinary,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "pin_code_checksum")?;
+
+    let old = CODE_CHECKSUMS.may_load(deps.storage, code_id)?;
+    CODE_CHECKSUMS.save(deps.storage, code_id, &checksum)?;
+    let changes = vec![audit_change(
+        format!("code_checksums[{}]", code_id),
+        old.as_ref(),
+        Some(&checksum),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "pin_code_checksum",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "pin_code_checksum")
+        .add_attribute("code_id", code_id.to_string())
+        .add_attribute("checksum", checksum.to_hex()))
+}
+
+pub fn execute_unpin_code_checksum(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    code_id: u64,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "unpin_code_checksum")?;
+
+    let old = CODE_CHECKSUMS.may_load(deps.storage, code_id)?;
+    CODE_CHECKSUMS.remove(deps.storage, code_id);
+    let changes = vec![audit_change(
+        format!("code_checksums[{}]", code_id),
+        old.as_ref(),
+        None,
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "unpin_code_checksum",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unpin_code_checksum")
+        .add_attribute("code_id", code_id.to_string()))
+}
+
+pub fn execute_update_ibc_controllers(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    controllers: Vec<IbcController>,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    assert_allowed(deps.storage, &info.sender, "update_ibc_controllers")?;
+
+    let old = std::mem::replace(&mut config.ibc_controllers, controllers);
+    CONFIG.save(deps.storage, &config)?;
+    let changes = vec![audit_change(
+        "config.ibc_controllers",
+        Some(&old),
+        Some(&config.ibc_controllers),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_ibc_controllers",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_ibc_controllers"))
+}
+
+pub fn execute_add_custom_contract(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    code_id: u64,
+    msg: Binary,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "add_custom_contract")?;
+
+    let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
+    let CodeInfoResponse { checksum, .. } = deps.querier.query_wasm_code_info(code_id)?;
+
+    // A pinned code id may only be instantiated with the expected code
+    if let Some(pinned) = CODE_CHECKSUMS.may_load(deps.storage, code_id)? {
+        if pinned != checksum {
+            return Err(ContractError::ChecksumMismatch { code_id });
+        }
+    }
+
+    let salt = msg.clone();
+    let address = deps
+        .api
+        .addr_humanize(&instantiate2_address(&checksum, &creator, &salt)?)?;
+
+    // Instantiate2 fails without a clear reason when the address is taken
+    if deps
+        .querier
+        .query_wasm_contract_info(address.to_string())
+        .is_ok()
+    {
+        return Err(ContractError::AddressOccupied {
+            address: address.to_string(),
+        });
+    }
+
+    Ok(Response::new()
+        .add_message(WasmMsg::Instantiate2 {
+            admin: Some(env.contract.address.to_string()),
+            code_id,
+            label: "instantiate2".to_string(),
+            msg,
+            funds: vec![],
+            salt,
+        })
+        .add_message(ArchwayMsg::UpdateContractMetadata {
+            contract_address: Some(address.to_string()),
+            owner_address: Some(env.contract.address.to_string()),
+            rewards_address: Some(env.contract.address.to_string()),
+        })
+        .add_message(WasmMsg::UpdateAdmin {
+            contract_addr: address.to_string(),
+            admin: info.sender.to_string(),
+        }))
+}
+
+pub fn execute_clone(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    new_admin: String,
+    overrides: CloneOverrides,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    assert_allowed(deps.storage, &info.sender, "clone")?;
+
+    let new_admin = deps.api.addr_validate(&new_admin)?;
+    let share_list = shares()
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (_, share) = item?;
+            Ok(share)
+        })
+        .collect::<StdResult<Vec<Share>>>()?;
+
+    let msg = to_binary(&InstantiateMsg {
+        admin: new_admin.to_string(),
+        shares: share_list,
+        mutable: overrides
+            .mutable
+            .unwrap_or_else(|| is_mutable(&config, &env.block)),
+        transfer_requires_approval: overrides
+            .transfer_requires_approval
+            .unwrap_or(config.transfer_requires_approval),
+        disable_emergency_withdraw: config.emergency_withdraw_disabled,
+        restore: None,
+    })?;
+
+    // The clone runs the code of this contract
+    let code_id = deps
+        .querier
+        .query_wasm_contract_info(env.contract.address.to_string())?
+        .code_id;
+    let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
+    let CodeInfoResponse { checksum, .. } = deps.querier.query_wasm_code_info(code_id)?;
+    let salt = to_binary(&format!("{}{}", env.block.height, new_admin))?;
+    let address = deps
+        .api
+        .addr_humanize(&instantiate2_address(&checksum, &creator, &salt)?)?;
+
+    // Instantiate2 fails without a clear reason when the address is taken
+    if deps
+        .querier
+        .query_wasm_contract_info(address.to_string())
+        .is_ok()
+    {
+        return Err(ContractError::AddressOccupied {
+            address: address.to_string(),
+        });
+    }
+
+    Ok(Response::new()
+        .add_message(WasmMsg::Instantiate2 {
+            admin: Some(new_admin.to_string()),
+            code_id,
+            label: overrides
+                .label
+                .unwrap_or_else(|| "Pantheon Splitter".to_string()),
+            msg,
+            funds: vec![],
+            salt,
+        })
+        .add_attribute("action", "clone")
+        .add_attribute("address", address)
+        .add_attribute("new_admin", new_admin))
+}
+
+pub fn execute_update_custom_contract_reward_metadata(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    owner_address: Option<String>,
+    rewards_address: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(
+        deps.storage,
+        &info.sender,
+        "update_custom_contract_reward_metadata",
+    )?;
+
+    // The metadata lives in the rewards module, the fields left unset are not changed
+    let old: Option<ContractMetadataResponse> = deps
+        .querier
+        .query(&QueryRequest::Custom(ArchwayQuery::ContractMetadata {
+            contract_address: address.clone(),
+        }))
+        .ok();
+    let mut changes = vec![];
+    if let Some(owner_address) = &owner_address {
+        changes.push(audit_change(
+            format!("contract_metadata[{}].owner_address", address),
+            old.as_ref().map(|old| &old.owner_address),
+            Some(owner_address),
+        )?);
+    }
+    if let Some(rewards_address) = &rewards_address {
+        changes.push(audit_change(
+            format!("contract_metadata[{}].rewards_address", address),
+            old.as_ref().map(|old| &old.rewards_address),
+            Some(rewards_address),
+        )?);
+    }
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_custom_contract_reward_metadata",
+        changes,
+    )?;
+
+    let msg = ArchwayMsg::UpdateContractMetadata {
+        contract_address: Some(address),
+        owner_address,
+        rewards_address,
+    };
+
+    Ok(Response::new().add_message(msg))
+}
+
+pub fn execute_update_premium(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    fee: Coin,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_premium")?;
+
+    let old = PREMIUM.may_load(deps.storage)?;
+    if fee.amount.is_zero() {
+        PREMIUM.remove(deps.storage);
+    } else {
+        PREMIUM.save(deps.storage, &fee)?;
+    }
+    let new = PREMIUM.may_load(deps.storage)?;
+    let changes = vec![audit_change("premium", old.as_ref(), new.as_ref())?];
+    record_audit_entry(deps.storage, &env, &info.sender, "update_premium", changes)?;
+
+    Ok(Response::new()
+        .add_message(set_premium(
+            env.contract.address.to_string(),
+            env.contract.address.to_string(),
+            fee.clone(),
+        ))
+        .add_attribute("action", "update_premium")
+        .add_attribute("premium", fee.to_string()))
+}
+
+pub fn execute_lock_contract(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    until: Option<Expiration>,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    // Only the admin or a permitted role can lock the contract
+    assert_allowed(deps.storage, &info.sender, "lock_contract")?;
+
+    // A lock can be made permanent but not shortened by a new timed lock
+    let until = until.filter(|until| *until != Expiration::Never);
+    if let Some(until) = until {
+        if !is_mutable(&config, &env.block) {
+            return Err(ContractError::ContractNotMutable {});
+        }
+        if until.is_expired(&env.block) {
+            return Err(ContractError::InvalidLockExpiration {});
+        }
+    }
+
+    // Updating the contract to be immutable
+    let old = config.clone();
+    config.mutable = false;
+    config.locked_until = until;
+    CONFIG.save(deps.storage, &config)?;
+    let changes = vec![
+        audit_change("config.mutable", Some(&old.mutable), Some(&config.mutable))?,
+        audit_change(
+            "config.locked_until",
+            old.locked_until.as_ref(),
+            config.locked_until.as_ref(),
+        )?,
+    ];
+    record_audit_entry(deps.storage, &env, &info.sender, "lock_contract", changes)?;
+
+    Ok(Response::new())
+}
+
+// Timed locks make the contract mutable again once they expire
+pub fn is_mutable(config: &Config, block: &BlockInfo) -> bool {
+    config.mutable
+        || config
+            .locked_until
+            .map_or(false, |until| until.is_expired(block))
+}
+
+// Share transfers only change the recipient address, so they are allowed on locked contracts
+pub fn execute_transfer_share(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    new_recipient: String,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only recipients can transfer their share
+    if !shares().has(deps.storage, info.sender.clone()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let new_recipient = deps.api.addr_validate(&new_recipient)?;
+    if shares().has(deps.storage, new_recipient.clone()) {
+        return Err(ContractError::RecipientExists {});
+    }
+
+    if config.transfer_requires_approval {
+        // Waiting for the admin, a new request overwrites the previous one
+        PENDING_TRANSFERS.save(deps.storage, info.sender.clone(), &new_recipient)?;
+        PENDING_ROTATIONS.remove(deps.storage, info.sender.clone());
+
+        return Ok(Response::new()
+            .add_attribute("action", "request_share_transfer")
+            .add_attribute("recipient", info.sender)
+            .add_attribute("new_recipient", new_recipient));
+    }
+
+    move_share(deps, &env, &info.sender, &new_recipient)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "transfer_share")
+        .add_attribute("recipient", info.sender)
+        .add_attribute("new_recipient", new_recipient))
+}
+
+pub fn execute_approve_share_transfer(
+    mut deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+) -> ArchwayResult<ContractError> {
+    // Only the admin or a permitted role can approve a share transfer
+    assert_allowed(deps.storage, &info.sender, "approve_share_transfer")?;
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let new_recipient = PENDING_TRANSFERS
+        .may_load(deps.storage, recipient.clone())?
+        .ok_or(ContractError::NoPendingTransfer {})?;
+    PENDING_TRANSFERS.remove(deps.storage, recipient.clone());
+
+    // The new recipient could have received a share after the request
+    if shares().has(deps.storage, new_recipient.clone()) {
+        return Err(ContractError::RecipientExists {});
+    }
+
+    let rotation = PENDING_ROTATIONS.has(deps.storage, recipient.clone());
+    PENDING_ROTATIONS.remove(deps.storage, recipient.clone());
+
+    let old = shares().load(deps.storage, recipient.clone())?;
+    move_share(deps.branch(), &env, &recipient, &new_recipient)?;
+    if rotation {
+        move_recipient_state(deps.storage, &recipient, &new_recipient)?;
+    }
+    let new = shares().load(deps.storage, new_recipient.clone())?;
+    let changes = vec![
+        audit_change(format!("shares[{}]", recipient), Some(&old), None)?,
+        audit_change(format!("shares[{}]", new_recipient), None, Some(&new))?,
+    ];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "approve_share_transfer",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "approve_share_transfer")
+        .add_attribute("recipient", recipient)
+        .add_attribute("new_recipient", new_recipient))
+}
+
+pub fn execute_rotate_address(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    new_address: String,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only recipients can rotate their address
+    if !shares().has(deps.storage, info.sender.clone()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let new_address = deps.api.addr_validate(&new_address)?;
+    if shares().has(deps.storage, new_address.clone()) {
+        return Err(ContractError::RecipientExists {});
+    }
+
+    // A rotation moves the share like a transfer, so it waits for the admin as well
+    if config.transfer_requires_approval {
+        PENDING_TRANSFERS.save(deps.storage, info.sender.clone(), &new_address)?;
+        PENDING_ROTATIONS.save(deps.storage, info.sender.clone(), &Empty {})?;
+
+        return Ok(Response::new()
+            .add_attribute("action", "request_address_rotation")
+            .add_attribute("recipient", info.sender)
+            .add_attribute("new_address", new_address));
+    }
+
+    // Rewards are settled on the old address before its accounting moves
+    move_share(deps.branch(), &env, &info.sender, &new_address)?;
+    move_recipient_state(deps.storage, &info.sender, &new_address)?;
+    PENDING_TRANSFERS.remove(deps.storage, info.sender.clone());
+    PENDING_ROTATIONS.remove(deps.storage, info.sender.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", "rotate_address")
+        .add_attribute("recipient", info.sender)
+        .add_attribute("new_address", new_address))
+}
+
+pub fn execute_update_sub_shares(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    sub_shares: Vec<Share>,
+) -> ArchwayResult<ContractError> {
+    // Only recipients with a share can split their slice
+    if !shares().has(deps.storage, info.sender.clone()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let count = sub_shares.len();
+    if sub_shares.is_empty() {
+        SUB_SHARES.remove(deps.storage, info.sender.clone());
+    } else {
+        let sub_shares = validate_shares(deps.as_ref(), &env, sub_shares)?
+            .into_iter()
+            .map(|(_, share)| share)
+            .collect::<Vec<Share>>();
+        SUB_SHARES.save(deps.storage, info.sender.clone(), &sub_shares)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_sub_shares")
+        .add_attribute("recipient", info.sender)
+        .add_attribute("sub_shares", count.to_string()))
+}
+
+// Moves the per recipient accounting and settings of a rotated address
+fn move_recipient_state(storage: &mut dyn Storage, from: &Addr, to: &Addr) -> StdResult<()> {
+    for map in [REDIRECTED_PAYOUTS, FAILED_PAYOUTS, HELD_BALANCES] {
+        let amounts = map
+            .prefix(from.clone())
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<(String, Uint128)>>>()?;
+        for (denom, amount) in amounts {
+            map.remove(storage, (from.clone(), denom.clone()));
+            map.update(storage, (to.clone(), denom), |existing| -> StdResult<_> {
+                Ok(existing.unwrap_or_default().checked_add(amount)?)
+            })?;
+        }
+    }
+
+    let unclaimed = UNCLAIMED_REWARDS
+        .prefix(from.clone())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Decimal256)>>>()?;
+    for (denom, amount) in unclaimed {
+        UNCLAIMED_REWARDS.remove(storage, (from.clone(), denom.clone()));
+        UNCLAIMED_REWARDS.update(storage, (to.clone(), denom), |existing| -> StdResult<_> {
+            Ok(existing.unwrap_or_default().checked_add(amount)?)
+        })?;
+    }
+
+    // Streamed tokens settled to the old address, both addresses are settled at the same index
+    let streams = UNCLAIMED_STREAMS
+        .prefix(from.clone())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Decimal256)>>>()?;
+    for (token, amount) in streams {
+        UNCLAIMED_STREAMS.remove(storage, (from.clone(), token.clone()));
+        UNCLAIMED_STREAMS.update(storage, (to.clone(), token), |existing| -> StdResult<_> {
+            Ok(existing.unwrap_or_default().checked_add(amount)?)
+        })?;
+    }
+    let indexes = STREAM_INDEXES
+        .prefix(from.clone())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Decimal256)>>>()?;
+    for (token, index) in indexes {
+        STREAM_INDEXES.remove(storage, (from.clone(), token.clone()));
+        STREAM_INDEXES.save(storage, (to.clone(), token), &index)?;
+    }
+
+    let carried = CARRIED_BALANCES
+        .prefix(from.to_string())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Uint128)>>>()?;
+    for (denom, amount) in carried {
+        CARRIED_BALANCES.remove(storage, (from.to_string(), denom.clone()));
+        CARRIED_BALANCES.update(
+            storage,
+            (to.to_string(), denom),
+            |existing| -> StdResult<_> { Ok(existing.unwrap_or_default().checked_add(amount)?) },
+        )?;
+    }
+
+    move_denom_settings(storage, SHARE_CAPS, from, to)?;
+    move_denom_settings(storage, MIN_PAYOUTS, from, to)?;
+
+    // Denom share tables keep the percentage under the new address
+    let denom_shares = DENOM_SHARES
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |((_, recipient), _)| recipient == from)
+        })
+        .collect::<StdResult<Vec<((String, Addr), Share)>>>()?;
+    for ((denom, _), mut share) in denom_shares {
+        DENOM_SHARES.remove(storage, (denom.clone(), from.clone()));
+        share.recipient = to.to_string();
+        DENOM_SHARES.save(storage, (denom, to.clone()), &share)?;
+    }
+
+    // The moved rewards keep their claim deadline instead of the initial one of the new address
+    if let Some(deadline) = RECIPIENT_CLAIM_DEADLINES.may_load(storage, from.clone())? {
+        RECIPIENT_CLAIM_DEADLINES.remove(storage, from.clone());
+        RECIPIENT_CLAIM_DEADLINES.save(storage, to.clone(), &deadline)?;
+    }
+
+    // The delegate keeps claiming for the recipient under its new address
+    if let Some(delegation) = CLAIM_DELEGATIONS.may_load(storage, from.clone())? {
+        CLAIM_DELEGATIONS.remove(storage, from.clone());
+        CLAIM_DELEGATIONS.save(storage, to.clone(), &delegation)?;
+    }
+
+    if let Some(denoms) = PREFERRED_DENOMS.may_load(storage, from.clone())? {
+        PREFERRED_DENOMS.remove(storage, from.clone());
+        PREFERRED_DENOMS.save(storage, to.clone(), &denoms)?;
+    }
+    if FROZEN_RECIPIENTS.has(storage, from.clone()) {
+        FROZEN_RECIPIENTS.remove(storage, from.clone());
+        FROZEN_RECIPIENTS.save(storage, to.clone(), &Empty {})?;
+    }
+    if let Some(redirect) = PAYOUT_REDIRECTS.may_load(storage, from.clone())? {
+        PAYOUT_REDIRECTS.remove(storage, from.clone());
+        PAYOUT_REDIRECTS.save(storage, to.clone(), &redirect)?;
+    }
+    if let Some(sub_shares) = SUB_SHARES.may_load(storage, from.clone())? {
+        SUB_SHARES.remove(storage, from.clone());
+        SUB_SHARES.save(storage, to.clone(), &sub_shares)?;
+    }
+
+    Ok(())
+}
+
+// Settings keyed by the recipient and the denom replace the ones of the new address
+fn move_denom_settings<T: Serialize + DeserializeOwned>(
+    storage: &mut dyn Storage,
+    map: Map<(String, String), T>,
+    from: &Addr,
+    to: &Addr,
+) -> StdResult<()> {
+    let settings = map
+        .prefix(from.to_string())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, T)>>>()?;
+    for (denom, setting) in settings {
+        map.remove(storage, (from.to_string(), denom.clone()));
+        map.save(storage, (to.to_string(), denom), &setting)?;
+    }
+    Ok(())
+}
+
+// Moves a share entry from one recipient to another keeping the percentage
+fn move_share(
+    deps: DepsMut<ArchwayQuery>,
+    env: &Env,
+    recipient: &Addr,
+    new_recipient: &Addr,
+) -> Result<(), ContractError> {
+    let height = env.block.height;
+    checkpoint_streams(deps.storage, env)?;
+
+    let mut share = shares().load(deps.storage, recipient.clone())?;
+    settle_rewards(deps.storage, recipient)?;
+    shares().remove(deps.storage, recipient.clone(), height)?;
+
+    share.recipient = new_recipient.to_string();
+    save_share(deps.storage, new_recipient.clone(), &share, height)?;
+
+    // The new recipient takes over the expiration of the share
+    if let Some(expires_at) = SHARE_EXPIRATIONS.may_load(deps.storage, recipient.clone())? {
+        SHARE_EXPIRATIONS.remove(deps.storage, recipient.clone());
+        SHARE_EXPIRATIONS.save(deps.storage, new_recipient.clone(), &expires_at)?;
+    }
+    LAST_SHARE_UPDATE.save(deps.storage, &height)?;
+
+    Ok(())
+}
+
+// The whole balance is sent, the balances reserved for the claims of recipients stay recorded
+pub fn execute_emergency_withdraw(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    to: String,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if config.emergency_withdraw_disabled {
+        return Err(ContractError::EmergencyWithdrawDisabled {});
+    }
+
+    // A locked splitter keeps its balance for the recipients, like a sweep to the sweep address
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    // Only the owner, roles can not be permitted to withdraw
+    assert_owner(deps.storage, &info.sender)?;
+
+    let to = deps.api.addr_validate(&to)?;
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address, denom.clone())?;
+
+    // Rewards already earned by the recipients stay claimable
+    let reserved = RESERVED_BALANCES
+        .may_load(deps.storage, denom.clone())?
+        .unwrap_or_default();
+    let balance = coin(balance.amount.saturating_sub(reserved).u128(), denom);
+    if balance.amount.is_zero() {
+        return Err(ContractError::NothingToWithdraw {});
+    }
+    let changes = vec![audit_change(
+        format!("withdrawals[{}]", to),
+        None,
+        Some(&balance),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "emergency_withdraw",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: to.to_string(),
+            amount: vec![balance.clone()],
+        })
+        .add_event(
+            Event::new("emergency_withdraw")
+                .add_attribute("sender", info.sender)
+                .add_attribute("to", to)
+                .add_attribute("amount", balance.to_string()),
+        )
+        .add_attribute("action", "emergency_withdraw"))
+}
+
+pub fn execute_withdraw_rewards(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "withdraw_rewards")?;
+
+    if let Some(policy) = WITHDRAWAL_POLICY.may_load(deps.storage)? {
+        if let (Some(min_interval), Some(last_height)) = (
+            policy.min_interval,
+            LAST_WITHDRAWAL_HEIGHT.may_load(deps.storage)?,
+        ) {
+            let height = last_height + min_interval;
+            if env.block.height < height {
+                return Err(ContractError::WithdrawalTooSoon { height });
+            }
+        }
+
+        if let Some(min_rewards) = policy.min_rewards {
+            if outstanding_rewards(deps.as_ref(), &env, &min_rewards.denom)? < min_rewards.amount {
+                return Err(ContractError::RewardsBelowMinimum {
+                    min_rewards: min_rewards.to_string(),
+                });
+            }
+        }
+    }
+    LAST_WITHDRAWAL_HEIGHT.save(deps.storage, &env.block.height)?;
+
+    let msg = ArchwayMsg::WithdrawRewards {
+        records_limit: Some(0),
+        record_ids: vec![],
+    };
+
+    Ok(Response::new().add_message(msg))
+}
+
+pub fn execute_update_withdrawal_policy(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    policy: Option<WithdrawalPolicy>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_withdrawal_policy")?;
+
+    let old = WITHDRAWAL_POLICY.may_load(deps.storage)?;
+    match &policy {
+        Some(policy) => WITHDRAWAL_POLICY.save(deps.storage, policy)?,
+        None => WITHDRAWAL_POLICY.remove(deps.storage),
+    }
+    let changes = vec![audit_change(
+        "withdrawal_policy",
+        old.as_ref(),
+        policy.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_withdrawal_policy",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_withdrawal_policy"))
+}
+
+pub fn execute_distribute_native_tokens(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "distribute_native_tokens")?;
+
+    // The distribution runs once the balances are swapped
+    let swaps = swap_msgs(deps.as_ref(), &env)?;
+    if !swaps.is_empty() {
+        return Ok(Response::new()
+            .add_messages(swaps)
+            .add_attribute("action", "swap_before_distribution"));
+    }
+
+    let (msgs, events) = registry_distribution_msgs(deps, &env)?;
+
+    Ok(Response::new().add_submessages(msgs).add_events(events))
+}
+
+// Epochs can be closed without distributions, so they can follow calendar periods
+pub fn execute_close_epoch(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "close_epoch")?;
+
+    let mut epoch = current_epoch(deps.storage, &env)?;
+    epoch.end_height = Some(env.block.height);
+    epoch.end_time = Some(env.block.time);
+    EPOCHS.save(deps.storage, epoch.id, &epoch)?;
+
+    CURRENT_EPOCH.save(deps.storage, &(epoch.id + 1))?;
+    let next = current_epoch(deps.storage, &env)?;
+    EPOCHS.save(deps.storage, next.id, &next)?;
+    let changes = vec![audit_change(
+        "current_epoch",
+        Some(&epoch.id),
+        Some(&next.id),
+    )?];
+    record_audit_entry(deps.storage, &env, &info.sender, "close_epoch", changes)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "close_epoch")
+        .add_attribute("epoch", epoch.id.to_string())
+        .add_attribute("next_epoch", next.id.to_string()))
+}
+
+pub fn execute_distribute_swapped_tokens(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (msgs, events) = registry_distribution_msgs(deps, &env)?;
+
+    Ok(Response::new()
+        .add_submessages(msgs)
+        .add_events(events)
+        .add_attribute("action", "distribute_swapped_tokens"))
+}
+
+// Swaps the available balance of every registered denom other than the target denom,
+// followed by the call distributing the swapped balance
+// Empty without a swap config or when there is nothing to swap
+fn swap_msgs(deps: Deps<ArchwayQuery>, env: &Env) -> Result<Vec<WasmMsg>, ContractError> {
+    let swap = match SWAP_CONFIG.may_load(deps.storage)? {
+        Some(swap) => swap,
+        None => return Ok(vec![]),
+    };
+
+    let mut msgs: Vec<WasmMsg> = vec![];
+    let denoms = DENOMS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for denom in denoms
+        .into_iter()
+        .filter(|denom| *denom != swap.target_denom)
+    {
+        let mut offer = deps
+            .querier
+            .query_balance(env.contract.address.clone(), denom.clone())?;
+        let reserved = RESERVED_BALANCES
+            .may_load(deps.storage, denom)?
+            .unwrap_or_default();
+        offer.amount = offer.amount.saturating_sub(reserved);
+        if offer.amount.is_zero() {
+            continue;
+        }
+
+        let quote = query_quote(
+            &deps.querier,
+            &swap.adapter,
+            offer.clone(),
+            swap.target_denom.clone(),
+        )?;
+        let min_receive = quote * (Decimal::one() - swap.max_slippage);
+
+        msgs.push(swap_msg(
+            &swap.adapter,
+            offer,
+            swap.target_denom.clone(),
+            min_receive,
+        )?);
+    }
+
+    if !msgs.is_empty() {
+        msgs.push(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&ExecuteMsg::DistributeSwappedTokens {})?,
+            funds: vec![],
+        });
+    }
+
+    Ok(msgs)
+}
+
+// Creates the distribution messages for the balance of every registered denom
+// Failed payouts waiting to be claimed are not distributed again
+pub(crate) fn registry_distribution_msgs(
+    deps: DepsMut<ArchwayQuery>,
+    env: &Env,
+) -> Result<(Vec<SubMsg<ArchwayMsg>>, Vec<Event>), ContractError> {
+    let mut msgs: Vec<SubMsg<ArchwayMsg>> = vec![];
+    let mut events: Vec<Event> = vec![];
+    let mut reply_id = PAYOUT_REPLY_ID_START;
+
+    release_expired_deposits(deps.storage, env)?;
+
+    let denoms = DENOMS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+
+    for denom in denoms {
+        let mut balance = deps
+            .querier
+            .query_balance(env.contract.address.clone(), denom.clone())?;
+        let reserved = RESERVED_BALANCES
+            .may_load(deps.storage, denom)?
+            .unwrap_or_default();
+        balance.amount = balance.amount.saturating_sub(reserved);
+
+        // Skipping denoms without a balance or below their minimum
+        if balance.amount.is_zero()
+            || balance.amount < min_distribution(deps.as_ref(), &balance.denom)?
+        {
+            continue;
+        }
+
+        let share_list = distribution_shares(deps.as_ref(), env, &balance.denom)?;
+        let priority_payments = priority_payouts(deps.as_ref(), &balance.denom)?;
+        let (denom_msgs, denom_events) = distribution_msgs(
+            deps.storage,
+            env,
+            &share_list,
+            priority_payments,
+            balance,
+            &mut reply_id,
+        )?;
+        msgs.extend(denom_msgs);
+        events.extend(denom_events);
+    }
+
+    Ok((msgs, events))
+}
+
+// Shares paid by the distributions of a denom, its own table when it has one
+// A linked cw4 group replaces every table, the shares are derived from the member weights
+// Slices split by their recipient are replaced by the sub-shares
+fn distribution_shares(deps: Deps<ArchwayQuery>, env: &Env, denom: &str) -> StdResult<Vec<Share>> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let share_list = match config.group {
+        Some(group) => group_shares(deps, &group)?,
+        None => {
+            let denom_shares = query_denom_shares(deps, denom.to_string())?;
+            if denom_shares.is_empty() {
+                shares()
+                    .range(deps.storage, None, None, Order::Ascending)
+                    .map(|item| {
+                        let (_, share) = item?;
+                        Ok(share)
+                    })
+                    .collect::<StdResult<Vec<Share>>>()?
+            } else {
+                denom_shares
+            }
+        }
+    };
+
+    // With the fallback policy, the expired shares are redirected by the distributions
+    if config.expired_share_policy == ExpiredSharePolicy::Fallback {
+        return expand_sub_shares(deps.storage, env, denom, share_list);
+    }
+
+    // Skipping the expired shares
+    let mut active: Vec<Share> = vec![];
+    for share in share_list {
+        if !is_expired(deps.storage, env, &share.recipient)? {
+            active.push(share);
+        }
+    }
+
+    // Scaling up the active shares so they add up to the full percentage again
+    let active_total = active.iter().try_fold(Decimal::zero(), |acc, share| {
+        acc.checked_add(share.percentage)
+    })?;
+    if config.expired_share_policy == ExpiredSharePolicy::Redistribute && !active_total.is_zero() {
+        for share in active.iter_mut() {
+            share.percentage =
+                Decimal::from_ratio(share.percentage.atomics(), active_total.atomics());
+        }
+    }
+
+    expand_sub_shares(deps.storage, env, denom, active)
+}
+
+// Replaces the share of every recipient splitting its slice with the scaled sub-shares
+// Sub-shares are not expanded again, a sub-recipient is paid its part directly
+// Undeliverable, capped and redirected recipients keep their share, so their own checks apply
+fn expand_sub_shares(
+    storage: &dyn Storage,
+    env: &Env,
+    denom: &str,
+    share_list: Vec<Share>,
+) -> StdResult<Vec<Share>> {
+    let mut expanded: Vec<Share> = vec![];
+    for share in share_list {
+        let keeps_share = share.burn
+            || is_undeliverable(storage, env, &share.recipient)?
+            || SHARE_CAPS.has(storage, (share.recipient.clone(), denom.to_string()))
+            || PAYOUT_REDIRECTS.has(storage, Addr::unchecked(&share.recipient));
+        let sub_shares = match keeps_share {
+            true => None,
+            false => SUB_SHARES.may_load(storage, Addr::unchecked(&share.recipient))?,
+        };
+        match sub_shares {
+            Some(sub_shares) => {
+                for sub_share in sub_shares {
+                    expanded.push(Share {
+                        percentage: share.percentage.checked_mul(sub_share.percentage)?,
+                        ..sub_share
+                    });
+                }
+            }
+            None => expanded.push(share),
+        }
+    }
+
+    Ok(expanded)
+}
+
+fn is_expired(storage: &dyn Storage, env: &Env, recipient: &str) -> StdResult<bool> {
+    Ok(SHARE_EXPIRATIONS
+        .may_load(storage, Addr::unchecked(recipient))?
+        .map_or(false, |expires_at| expires_at.is_expired(&env.block)))
+}
+
+// Frozen and opted out recipients and expired shares left in the share list can not be paid
+fn is_undeliverable(storage: &dyn Storage, env: &Env, recipient: &str) -> StdResult<bool> {
+    Ok(FROZEN_RECIPIENTS.has(storage, Addr::unchecked(recipient))
+        || PAYOUT_REDIRECTS.may_load(storage, Addr::unchecked(recipient))?
+            == Some(PayoutRedirect::OptOut)
+        || is_expired(storage, env, recipient)?)
+}
+
+// Address the recipient has its payouts redirected to
+fn redirect_address(storage: &dyn Storage, recipient: &str) -> StdResult<Option<Addr>> {
+    Ok(
+        match PAYOUT_REDIRECTS.may_load(storage, Addr::unchecked(recipient))? {
+            Some(PayoutRedirect::Address { address }) => Some(address),
+            _ => None,
+        },
+    )
+}
+
+fn is_preferred_denom(storage: &dyn Storage, recipient: &str, denom: &str) -> StdResult<bool> {
+    Ok(PREFERRED_DENOMS
+        .may_load(storage, Addr::unchecked(recipient))?
+        .map_or(true, |denoms| {
+            denoms.iter().any(|preferred| preferred == denom)
+        }))
+}
+
+// Held amounts are reserved so the next distributions do not split them again
+fn hold_payout(storage: &mut dyn Storage, recipient: Addr, amount: &Coin) -> StdResult<()> {
+    HELD_BALANCES.update(
+        storage,
+        (recipient, amount.denom.clone()),
+        |held| -> StdResult<_> { Ok(held.unwrap_or_default().checked_add(amount.amount)?) },
+    )?;
+    RESERVED_BALANCES.update(storage, amount.denom.clone(), |reserved| -> StdResult<_> {
+        Ok(reserved.unwrap_or_default().checked_add(amount.amount)?)
+    })?;
+    Ok(())
+}
+
+// Portions that stay below the minimum payout of the recipient with the carried amount
+fn carries_over(
+    storage: &dyn Storage,
+    recipient: &str,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<bool> {
+    let key = (recipient.to_string(), denom.to_string());
+    let min_payout = MIN_PAYOUTS.may_load(storage, key.clone())?;
+    let carried = CARRIED_BALANCES.may_load(storage, key)?.unwrap_or_default();
+    Ok(min_payout.map_or(false, |min_payout| {
+        amount.saturating_add(carried) < min_payout
+    }))
+}
+
+// Carried amounts are reserved so the next distributions do not split them again
+fn carry_payout(storage: &mut dyn Storage, recipient: &str, amount: &Coin) -> StdResult<()> {
+    CARRIED_BALANCES.update(
+        storage,
+        (recipient.to_string(), amount.denom.clone()),
+        |carried| -> StdResult<_> { Ok(carried.unwrap_or_default().checked_add(amount.amount)?) },
+    )?;
+    RESERVED_BALANCES.update(storage, amount.denom.clone(), |reserved| -> StdResult<_> {
+        Ok(reserved.unwrap_or_default().checked_add(amount.amount)?)
+    })?;
+    Ok(())
+}
+
+// Removes the carried amount of a recipient from the reserved balance and returns it
+fn release_carried_balance(
+    storage: &mut dyn Storage,
+    recipient: &str,
+    denom: &str,
+) -> StdResult<Uint128> {
+    let key = (recipient.to_string(), denom.to_string());
+    let carried = CARRIED_BALANCES
+        .may_load(storage, key.clone())?
+        .unwrap_or_default();
+    if carried.is_zero() {
+        return Ok(carried);
+    }
+
+    CARRIED_BALANCES.remove(storage, key);
+    RESERVED_BALANCES.update(storage, denom.to_string(), |reserved| -> StdResult<_> {
+        Ok(reserved.unwrap_or_default().checked_sub(carried)?)
+    })?;
+    Ok(carried)
+}
+
+// Records a payout sent to the fallback address instead of the recipient
+fn record_redirected_payout(
+    storage: &mut dyn Storage,
+    recipient: Addr,
+    amount: &Coin,
+) -> StdResult<()> {
+    REDIRECTED_PAYOUTS.update(
+        storage,
+        (recipient, amount.denom.clone()),
+        |redirected| -> StdResult<_> {
+            Ok(redirected.unwrap_or_default().checked_add(amount.amount)?)
+        },
+    )?;
+    Ok(())
+}
+
+// Converts the member weights of a cw4 group to shares
+// Rounding dust of the percentages stays in the contract
+fn group_shares(deps: Deps<ArchwayQuery>, group: &Addr) -> StdResult<Vec<Share>> {
+    let mut members: Vec<Member> = vec![];
+    let mut start_after: Option<String> = None;
+    loop {
+        let res: MemberListResponse = deps.querier.query_wasm_smart(
+            group,
+            &Cw4QueryMsg::ListMembers {
+                start_after,
+                limit: Some(GROUP_PAGE_LIMIT),
+            },
+        )?;
+        let count = res.members.len();
+        start_after = res.members.last().map(|member| member.addr.clone());
+        members.extend(res.members);
+
+        if count < GROUP_PAGE_LIMIT as usize {
+            break;
+        }
+    }
+
+    let total_weight: u128 = members.iter().map(|member| member.weight as u128).sum();
+
+    Ok(members
+        .into_iter()
+        .filter(|member| member.weight > 0)
+        .map(|member| Share {
+            recipient: member.addr,
+            percentage: Decimal::from_ratio(member.weight, total_weight),
+            ibc: None,
+            burn: false,
+        })
+        .collect())
+}
+
+// Creates the messages paying every share its part of the given amount
+// Local payouts are sent as submessages so a failing recipient does not revert the distribution
+fn distribution_msgs(
+    storage: &mut dyn Storage,
+    env: &Env,
+    share_list: &[Share],
+    priority_payments: Vec<Payout>,
+    balance: Coin,
+    reply_id: &mut u64,
+) -> Result<(Vec<SubMsg<ArchwayMsg>>, Vec<Event>), ContractError> {
+    let mut msgs: Vec<SubMsg<ArchwayMsg>> = vec![];
+    let mut events: Vec<Event> = vec![];
+    let mut distributed = Uint128::zero();
+
+    // Priority payments are deducted first and require the balance to cover all of them
+    for payment in priority_payments {
+        distributed = distributed.checked_add(payment.amount.amount)?;
+        if distributed > balance.amount {
+            return Err(ContractError::InsufficientBalance {});
+        }
+
+        events.push(
+            Event::new("priority_payout")
+                .add_attribute("recipient", payment.recipient.to_string())
+                .add_attribute("amount", payment.amount.amount),
+        );
+        msgs.push(payout_msg(storage, payment, reply_id)?);
+    }
+
+    // Fill the waterfall tiers in order, the overflow is split by the percentage shares
+    let mut tiers = WATERFALL.may_load(storage)?.unwrap_or_default();
+    for tier in tiers
+        .iter_mut()
+        .filter(|tier| tier.target.denom == balance.denom)
+    {
+        let amount = tier
+            .target
+            .amount
+            .saturating_sub(tier.filled)
+            .min(balance.amount.saturating_sub(distributed));
+        if amount.is_zero() {
+            continue;
+        }
+        tier.filled = tier.filled.checked_add(amount)?;
+        distributed = distributed.checked_add(amount)?;
+
+        events.push(
+            Event::new("waterfall_payout")
+                .add_attribute("recipient", tier.recipient.to_string())
+                .add_attribute("amount", amount)
+                .add_attribute("filled", tier.filled)
+                .add_attribute("target", tier.target.amount),
+        );
+        msgs.push(payout_msg(
+            storage,
+            Payout {
+                recipient: tier.recipient.clone(),
+                amount: coin(amount.u128(), &balance.denom),
+            },
+            reply_id,
+        )?);
+    }
+    if !tiers.is_empty() {
+        WATERFALL.save(storage, &tiers)?;
+    }
+    let overflow = balance.amount.saturating_sub(distributed);
+    let mut redirected = Uint128::zero();
+    let config = CONFIG.load(storage)?;
+
+    // Calculate the amount of rewards to send to each recipient
+    for share in share_list.iter().cloned() {
+        let mut amount = percentage_of(overflow, share.percentage)?;
+
+        // Undeliverable portions go to the fallback address, or stay in the contract without one
+        if is_undeliverable(storage, env, &share.recipient)? {
+            if let Some(fallback_address) = config.fallback_address.clone() {
+                if amount.is_zero() {
+                    continue;
+                }
+                distributed = distributed.checked_add(amount)?;
+
+                let amount = coin(amount.u128(), &balance.denom);
+                record_redirected_payout(storage, Addr::unchecked(&share.recipient), &amount)?;
+                events.push(
+                    Event::new("fallback_payout")
+                        .add_attribute("recipient", share.recipient)
+                        .add_attribute("fallback_address", fallback_address.to_string())
+                        .add_attribute("amount", amount.amount),
+                );
+                msgs.push(payout_msg(
+                    storage,
+                    Payout {
+                        recipient: fallback_address,
+                        amount,
+                    },
+                    reply_id,
+                )?);
+            }
+            continue;
+        }
+
+        // Portions above the payout cap of the recipient are redirected
+        let cap_key = (share.recipient.clone(), balance.denom.clone());
+        if let Some(mut share_cap) = SHARE_CAPS.may_load(storage, cap_key.clone())? {
+            let capped = amount.min(share_cap.cap.saturating_sub(share_cap.paid));
+            redirected = redirected.checked_add(amount - capped)?;
+            amount = capped;
+            share_cap.paid = share_cap.paid.checked_add(amount)?;
+            SHARE_CAPS.save(storage, cap_key, &share_cap)?;
+        }
+
+        if amount.is_zero() {
+            continue;
+        }
+        distributed = distributed.checked_add(amount)?;
+
+        // Burn shares destroy their portion instead of paying the recipient
+        if share.burn {
+            events.push(
+                Event::new("burn")
+                    .add_attribute("recipient", share.recipient)
+                    .add_attribute("amount", amount),
+            );
+            msgs.push(SubMsg::new(BankMsg::Burn {
+                amount: vec![coin(amount.u128(), &balance.denom)],
+            }));
+            continue;
+        }
+
+        // Redirected portions are paid locally to the address chosen by the recipient
+        if let Some(address) = redirect_address(storage, &share.recipient)? {
+            events.push(
+                Event::new("redirected_payout")
+                    .add_attribute("recipient", share.recipient)
+                    .add_attribute("address", address.to_string())
+                    .add_attribute("amount", amount),
+            );
+            msgs.push(payout_msg(
+                storage,
+                Payout {
+                    recipient: address,
+                    amount: coin(amount.u128(), &balance.denom),
+                },
+                reply_id,
+            )?);
+            continue;
+        }
+
+        // Portions in the denoms a local recipient does not prefer are held until claimed
+        if share.ibc.is_none() && !is_preferred_denom(storage, &share.recipient, &balance.denom)? {
+            let amount = coin(amount.u128(), &balance.denom);
+            hold_payout(storage, Addr::unchecked(&share.recipient), &amount)?;
+            events.push(
+                Event::new("held_payout")
+                    .add_attribute("recipient", share.recipient)
+                    .add_attribute("amount", amount.amount),
+            );
+            continue;
+        }
+
+        // Portions below the minimum payout are carried over, the next payout includes them
+        if carries_over(storage, &share.recipient, &balance.denom, amount)? {
+            let amount = coin(amount.u128(), &balance.denom);
+            carry_payout(storage, &share.recipient, &amount)?;
+            events.push(
+                Event::new("carried_payout")
+                    .add_attribute("recipient", share.recipient)
+                    .add_attribute("amount", amount.amount),
+            );
+            continue;
+        }
+        let amount = amount.checked_add(release_carried_balance(
+            storage,
+            &share.recipient,
+            &balance.denom,
+        )?)?;
+
+        match share.ibc {
+            // Create IBC transfers to send rewards to recipients on other chains
+            Some(ibc) => {
+                let mut event = Event::new("ibc_payout")
+                    .add_attribute("recipient", share.recipient)
+                    .add_attribute("channel_id", ibc.channel_id.clone())
+                    .add_attribute("remote_address", ibc.remote_address.clone())
+                    .add_attribute("amount", amount);
+
+                let timeout = env.block.time.plus_seconds(ibc.timeout_seconds);
+                match ibc.memo {
+                    Some(memo) => {
+                        event = event.add_attribute("memo", memo.clone());
+                        msgs.push(SubMsg::new(transfer_with_memo(
+                            ibc.channel_id,
+                            env.contract.address.to_string(),
+                            ibc.remote_address,
+                            coin(amount.u128(), &balance.denom),
+                            timeout,
+                            memo,
+                        )));
+                    }
+                    None => msgs.push(SubMsg::new(IbcMsg::Transfer {
+                        channel_id: ibc.channel_id,
+                        to_address: ibc.remote_address,
+                        amount: coin(amount.u128(), &balance.denom),
+                        timeout: IbcTimeout::with_timestamp(timeout),
+                    })),
+                }
+
+                events.push(event);
+            }
+            // Create bank messages to send rewards to each recipient
+            None => msgs.push(payout_msg(
+                storage,
+                Payout {
+                    recipient: Addr::unchecked(share.recipient),
+                    amount: coin(amount.u128(), &balance.denom),
+                },
+                reply_id,
+            )?),
+        }
+    }
+
+    // Without an overflow address the redirected portions are split again by the next distribution
+    if let Some(overflow_address) = config.overflow_address.filter(|_| !redirected.is_zero()) {
+        distributed = distributed.checked_add(redirected)?;
+
+        events.push(
+            Event::new("overflow_payout")
+                .add_attribute("recipient", overflow_address.to_string())
+                .add_attribute("amount", redirected),
+        );
+        msgs.push(payout_msg(
+            storage,
+            Payout {
+                recipient: overflow_address,
+                amount: coin(redirected.u128(), &balance.denom),
+            },
+            reply_id,
+        )?);
+    }
+
+    // The payouts must never spend more than the distributed balance
+    if distributed > balance.amount {
+        return Err(ContractError::DistributionExceedsBalance {});
+    }
+    let remainder = balance.amount - distributed;
+    let payouts = distribution_payouts(&msgs, &events)?;
+    let epoch = record_epoch_distribution(storage, env, &balance.denom, distributed, &payouts)?;
+    events.push(
+        Event::new("distribution")
+            .add_attribute("denom", balance.denom.clone())
+            .add_attribute("distributed", distributed)
+            .add_attribute("remainder", remainder)
+            .add_attribute("epoch", epoch.to_string()),
+    );
+
+    let sources = UNDISTRIBUTED_DEPOSITS
+        .prefix(balance.denom.clone())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, source)| source))
+        .collect::<StdResult<Vec<DepositSource>>>()?;
+    for source in &sources {
+        UNDISTRIBUTED_DEPOSITS.remove(storage, (balance.denom.clone(), source.deposit_id));
+    }
+
+    let id = LAST_DISTRIBUTION_ID.may_load(storage)?.unwrap_or_default() + 1;
+    LAST_DISTRIBUTION_ID.save(storage, &id)?;
+    DISTRIBUTION_HEIGHTS.save(storage, (env.block.height, id), &Empty {})?;
+    record_receipts(storage, env, id, epoch, &balance.denom, &payouts)?;
+    DISTRIBUTION_HISTORY.save(
+        storage,
+        id,
+        &DistributionRecord {
+            id,
+            height: env.block.height,
+            time: env.block.time,
+            amount: balance,
+            shares: share_list.to_vec(),
+            payouts,
+            remainder,
+            sources,
+            epoch,
+        },
+    )?;
+
+    Ok((msgs, events))
+}
+
+// Failed sends keep their receipt, the payout stays claimable with ClaimFailedPayouts
+fn record_receipts(
+    storage: &mut dyn Storage,
+    env: &Env,
+    distribution_id: u64,
+    epoch: u64,
+    denom: &str,
+    payouts: &[DistributionPayout],
+) -> StdResult<()> {
+    let mut id = LAST_RECEIPT_ID.may_load(storage)?.unwrap_or_default();
+    for payout in payouts.iter().filter(|payout| is_received(payout)) {
+        id += 1;
+        RECEIPTS.save(
+            storage,
+            (payout.recipient.clone(), id),
+            &Receipt {
+                id,
+                distribution_id,
+                epoch,
+                amount: coin(payout.amount.u128(), denom),
+                kind: payout.kind.clone(),
+                height: env.block.height,
+            },
+        )?;
+    }
+    LAST_RECEIPT_ID.save(storage, &id)
+}
+
+// Loads the current epoch, it starts at this block if it has no distribution yet
+fn current_epoch(storage: &dyn Storage, env: &Env) -> StdResult<EpochInfo> {
+    let id = CURRENT_EPOCH.may_load(storage)?.unwrap_or_default();
+    let epoch = EPOCHS.may_load(storage, id)?.unwrap_or(EpochInfo {
+        id,
+        start_height: env.block.height,
+        start_time: env.block.time,
+        end_height: None,
+        end_time: None,
+        distributions: 0,
+    });
+    Ok(epoch)
+}
+
+// Burned portions are paid to nobody and carried over portions are counted once they are paid
+fn is_received(payout: &DistributionPayout) -> bool {
+    !matches!(payout.kind, PayoutKind::Burn | PayoutKind::CarriedOver)
+}
+
+// Adds a distribution to the totals of the current epoch and to the amounts of its recipients
+fn record_epoch_distribution(
+    storage: &mut dyn Storage,
+    env: &Env,
+    denom: &str,
+    distributed: Uint128,
+    payouts: &[DistributionPayout],
+) -> StdResult<u64> {
+    let mut epoch = current_epoch(storage, env)?;
+    epoch.distributions += 1;
+    EPOCHS.save(storage, epoch.id, &epoch)?;
+
+    EPOCH_TOTALS.update(
+        storage,
+        (epoch.id, denom.to_string()),
+        |total| -> StdResult<_> { Ok(total.unwrap_or_default().checked_add(distributed)?) },
+    )?;
+
+    for payout in payouts.iter().filter(|payout| is_received(payout)) {
+        EPOCH_PAYOUTS.update(
+            storage,
+            (epoch.id, payout.recipient.clone()),
+            |amounts| -> StdResult<_> {
+                let mut amounts = amounts.unwrap_or_default();
+                match amounts.iter_mut().find(|amount| amount.denom == denom) {
+                    Some(amount) => amount.amount = amount.amount.checked_add(payout.amount)?,
+                    None => amounts.push(coin(payout.amount.u128(), denom)),
+                }
+                Ok(amounts)
+            },
+        )?;
+    }
+
+    Ok(epoch.id)
+}
+
+// Local payouts are read from the bank sends, the other payouts from their events
+fn distribution_payouts(
+    msgs: &[SubMsg<ArchwayMsg>],
+    events: &[Event],
+) -> StdResult<Vec<DistributionPayout>> {
+    let mut payouts: Vec<DistributionPayout> = vec![];
+    for msg in msgs {
+        if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = &msg.msg {
+            payouts.push(DistributionPayout {
+                recipient: to_address.clone(),
+                amount: amount.iter().map(|coin| coin.amount).sum(),
+                kind: PayoutKind::Send,
+                reply_id: Some(msg.id),
+                failed: false,
+            });
+        }
+    }
+
+    for event in events {
+        let attribute = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == key)
+                .map(|attribute| attribute.value.clone())
+                .unwrap_or_default()
+        };
+
+        let kind = match event.ty.as_str() {
+            "ibc_payout" => PayoutKind::IbcTransfer {
+                remote_address: attribute("remote_address"),
+            },
+            "burn" => PayoutKind::Burn,
+            "held_payout" => PayoutKind::Held,
+            "carried_payout" => PayoutKind::CarriedOver,
+            _ => continue,
+        };
+        payouts.push(DistributionPayout {
+            recipient: attribute("recipient"),
+            amount: Uint128::from_str(&attribute("amount"))?,
+            kind,
+            reply_id: None,
+            failed: false,
+        });
+    }
+
+    Ok(payouts)
+}
+
+// Marks the payout of a distribution at this height as failed
+// Reply ids are reused by every distribution, so only the distributions of this block are searched
+fn record_failed_payout(storage: &mut dyn Storage, env: &Env, reply_id: u64) -> StdResult<()> {
+    let ids = DISTRIBUTION_HEIGHTS
+        .prefix(env.block.height)
+        .keys(storage, None, None, Order::Descending)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    for id in ids {
+        let mut record = DISTRIBUTION_HISTORY.load(storage, id)?;
+        if let Some(payout) = record
+            .payouts
+            .iter_mut()
+            .find(|payout| payout.reply_id == Some(reply_id))
+        {
+            payout.failed = true;
+            return DISTRIBUTION_HISTORY.save(storage, id, &record);
+        }
+    }
+
+    Ok(())
+}
+
+// Creates a bank send for a payout
+// The payout is saved to make it claimable if the send fails
+fn payout_msg(
+    storage: &mut dyn Storage,
+    payout: Payout,
+    reply_id: &mut u64,
+) -> StdResult<SubMsg<ArchwayMsg>> {
+    PAYOUTS.save(storage, *reply_id, &payout)?;
+
+    let msg = SubMsg::reply_on_error(
+        BankMsg::Send {
+            to_address: payout.recipient.to_string(),
+            amount: vec![payout.amount],
+        },
+        *reply_id,
+    );
+    *reply_id += 1;
+
+    Ok(msg)
+}
+
+// Deposits are recorded in the accumulators only, so the cost does not grow with the recipients
+pub fn execute_deposit(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    memo: Option<String>,
+) -> ArchwayResult<ContractError> {
+    if info.funds.is_empty() {
+        return Err(ContractError::NothingToDistribute {});
+    }
+
+    for fund in &info.funds {
+        if !DENOMS.has(deps.storage, fund.denom.clone()) {
+            return Err(ContractError::DenomNotRegistered {});
+        }
+        assert_static_shares(deps.storage, &fund.denom)?;
+    }
+
+    // Deposits of senders outside the allowlist are held until their sender reclaims them
+    if let Some(allowlist) = DEPOSIT_ALLOWLIST.may_load(deps.storage)? {
+        if !allowlist.contains(&info.sender) {
+            let deposit =
+                hold_deposit(deps.storage, info.sender, info.funds, Expiration::Never {})?;
+
+            return Ok(Response::new()
+                .add_attribute("action", "hold_deposit")
+                .add_attribute("id", deposit.id.to_string()));
+        }
+    }
+
+    for fund in &info.funds {
+        // The shares add up to 100%, so the whole deposit is added per unit of share
+        ACC_PER_SHARE.update(deps.storage, fund.denom.clone(), |acc| -> StdResult<_> {
+            Ok(acc
+                .unwrap_or_default()
+                .checked_add(Decimal256::from_ratio(fund.amount, 1u128))?)
+        })?;
+
+        // Deposited funds are kept out of the push distributions
+        RESERVED_BALANCES.update(
+            deps.storage,
+            fund.denom.clone(),
+            |reserved| -> StdResult<_> {
+                Ok(reserved.unwrap_or_default().checked_add(fund.amount)?)
+            },
+        )?;
+    }
+
+    let amount = info
+        .funds
+        .iter()
+        .map(|fund| fund.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+    let receipt = RECEIPT_DENOM.may_load(deps.storage)?.and_then(|receipt| {
+        info.funds
+            .iter()
+            .find(|fund| fund.denom == receipt.deposit_denom)
+            .map(|fund| coin(fund.amount.u128(), receipt.denom))
+    });
+    let record = record_deposit(deps.storage, &env, info.sender, info.funds, memo)?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "deposit")
+        .add_attribute("id", record.id.to_string())
+        .add_attribute("amount", amount);
+    if let Some(receipt) = receipt {
+        response = response
+            .add_attribute("receipt", receipt.to_string())
+            .add_message(mint_msg(
+                env.contract.address.to_string(),
+                receipt,
+                record.sender.to_string(),
+            ));
+    }
+    if let Some(memo) = record.memo {
+        response = response.add_attribute("memo", memo);
+    }
+
+    Ok(response)
+}
+
+// Deposited rewards accrue to the share table, while the distributions pay the group, the shares
+// of the denom, the active shares and the sub-shares, so deposits would pay other recipients
+fn assert_static_shares(storage: &dyn Storage, denom: &str) -> Result<(), ContractError> {
+    let config = CONFIG.load(storage)?;
+    let has_denom_shares = DENOM_SHARES
+        .prefix(denom.to_string())
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    let has_expirations = SHARE_EXPIRATIONS
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+    let has_sub_shares = SUB_SHARES
+        .keys_raw(storage, None, None, Order::Ascending)
+        .next()
+        .is_some();
+
+    if config.group.is_some() || has_denom_shares || has_expirations || has_sub_shares {
+        return Err(ContractError::DepositsUnavailable {});
+    }
+    Ok(())
+}
+
+// Records the source of a deposit and adds it to the totals of the sender
+fn record_deposit(
+    storage: &mut dyn Storage,
+    env: &Env,
+    sender: Addr,
+    funds: Vec<Coin>,
+    memo: Option<String>,
+) -> StdResult<DepositRecord> {
+    for fund in &funds {
+        DEPOSIT_TOTALS.update(
+            storage,
+            (sender.clone(), fund.denom.clone()),
+            |total| -> StdResult<_> { Ok(total.unwrap_or_default().checked_add(fund.amount)?) },
+        )?;
+    }
+
+    let id = LAST_DEPOSIT_RECORD_ID
+        .may_load(storage)?
+        .unwrap_or_default()
+        + 1;
+    LAST_DEPOSIT_RECORD_ID.save(storage, &id)?;
+    let record = DepositRecord {
+        id,
+        sender,
+        funds,
+        memo,
+        height: env.block.height,
+        time: env.block.time,
+    };
+    DEPOSIT_HISTORY.save(storage, id, &record)?;
+
+    Ok(record)
+}
+
+pub fn execute_update_clawback_window(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    window: Option<Duration>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_clawback_window")?;
+
+    // Pending deposits keep the window they were made with
+    let old = CLAWBACK_WINDOW.may_load(deps.storage)?;
+    match window {
+        Some(window) => CLAWBACK_WINDOW.save(deps.storage, &window)?,
+        None => CLAWBACK_WINDOW.remove(deps.storage),
+    }
+    let changes = vec![audit_change(
+        "clawback_window",
+        old.as_ref(),
+        window.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_clawback_window",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_clawback_window"))
+}
+
+pub fn execute_deposit_refundable(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let window = CLAWBACK_WINDOW
+        .may_load(deps.storage)?
+        .ok_or(ContractError::ClawbackDisabled {})?;
+
+    if info.funds.is_empty() {
+        return Err(ContractError::NothingToDistribute {});
+    }
+
+    for fund in &info.funds {
+        if !DENOMS.has(deps.storage, fund.denom.clone()) {
+            return Err(ContractError::DenomNotRegistered {});
+        }
+    }
+
+    let deposit = hold_deposit(
+        deps.storage,
+        info.sender,
+        info.funds,
+        window.after(&env.block),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "deposit_refundable")
+        .add_attribute("id", deposit.id.to_string())
+        .add_attribute("reclaimable_until", deposit.reclaimable_until.to_string()))
+}
+
+pub fn execute_reclaim_deposit(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ArchwayResult<ContractError> {
+    let deposit = PENDING_DEPOSITS.load(deps.storage, id)?;
+
+    if info.sender != deposit.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if deposit.reclaimable_until.is_expired(&env.block) {
+        return Err(ContractError::ClawbackWindowClosed {});
+    }
+
+    release_deposit(deps.storage, &deposit)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: deposit.sender.to_string(),
+            amount: deposit.funds,
+        })
+        .add_attribute("action", "reclaim_deposit")
+        .add_attribute("id", id.to_string()))
+}
+
+// Saves a pending deposit and keeps its funds out of the distributions
+fn hold_deposit(
+    storage: &mut dyn Storage,
+    sender: Addr,
+    funds: Vec<Coin>,
+    reclaimable_until: Expiration,
+) -> StdResult<PendingDeposit> {
+    for fund in &funds {
+        RESERVED_BALANCES.update(storage, fund.denom.clone(), |reserved| -> StdResult<_> {
+            Ok(reserved.unwrap_or_default().checked_add(fund.amount)?)
+        })?;
+    }
+
+    let id = LAST_DEPOSIT_ID.may_load(storage)?.unwrap_or_default() + 1;
+    LAST_DEPOSIT_ID.save(storage, &id)?;
+    let deposit = PendingDeposit {
+        id,
+        sender,
+        funds,
+        reclaimable_until,
+    };
+    PENDING_DEPOSITS.save(storage, id, &deposit)?;
+
+    Ok(deposit)
+}
+
+pub fn execute_update_deposit_allowlist(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    senders: Option<Vec<String>>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_deposit_allowlist")?;
+
+    let old = DEPOSIT_ALLOWLIST.may_load(deps.storage)?;
+    let senders = senders
+        .map(|senders| {
+            senders
+                .iter()
+                .map(|sender| deps.api.addr_validate(sender))
+                .collect::<StdResult<Vec<Addr>>>()
+        })
+        .transpose()?;
+    match &senders {
+        Some(senders) => DEPOSIT_ALLOWLIST.save(deps.storage, senders)?,
+        None => DEPOSIT_ALLOWLIST.remove(deps.storage),
+    }
+    let changes = vec![audit_change(
+        "deposit_allowlist",
+        old.as_ref(),
+        senders.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_deposit_allowlist",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_deposit_allowlist"))
+}
+
+// Removes a pending deposit and un-reserves its funds
+fn release_deposit(storage: &mut dyn Storage, deposit: &PendingDeposit) -> StdResult<()> {
+    for fund in &deposit.funds {
+        RESERVED_BALANCES.update(storage, fund.denom.clone(), |reserved| -> StdResult<_> {
+            Ok(reserved.unwrap_or_default().checked_sub(fund.amount)?)
+        })?;
+    }
+    PENDING_DEPOSITS.remove(storage, deposit.id);
+
+    Ok(())
+}
+
+// Deposits whose clawback window ended become distributable
+fn release_expired_deposits(storage: &mut dyn Storage, env: &Env) -> StdResult<()> {
+    let expired = PENDING_DEPOSITS
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, deposit)) => deposit.reclaimable_until.is_expired(&env.block),
+            Err(_) => true,
+        })
+        .map(|item| item.map(|(_, deposit)| deposit))
+        .collect::<StdResult<Vec<PendingDeposit>>>()?;
+    for deposit in expired {
+        release_deposit(storage, &deposit)?;
+
+        // The next distribution of every denom is attributed to the released deposits
+        let record = record_deposit(storage, env, deposit.sender, deposit.funds, None)?;
+        for fund in &record.funds {
+            UNDISTRIBUTED_DEPOSITS.save(
+                storage,
+                (fund.denom.clone(), record.id),
+                &DepositSource {
+                    deposit_id: record.id,
+                    sender: record.sender.clone(),
+                    amount: fund.amount,
+                },
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn execute_claim_rewards(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let amount = claim_rewards(deps.storage, &env, &info.sender)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount,
+        })
+        .add_attribute("action", "claim_rewards")
+        .add_attribute("recipient", info.sender))
+}
+
+// Keepers pay the claim of recipients that do not claim themselves, the rewards never go to the
+// keeper except for the claim bounty
+pub fn execute_claim_for(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+) -> ArchwayResult<ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let mut amount = claim_rewards(deps.storage, &env, &recipient)?;
+
+    // Recipients claiming for themselves do not pay the bounty
+    let mut bounty: Vec<Coin> = vec![];
+    if let Some(rate) = CLAIM_BOUNTY.may_load(deps.storage)? {
+        if info.sender != recipient {
+            for reward in amount.iter_mut() {
+                let paid = reward.amount * rate;
+                if !paid.is_zero() {
+                    reward.amount -= paid;
+                    bounty.push(coin(paid.u128(), reward.denom.clone()));
+                }
+            }
+        }
+    }
+
+    let mut response = Response::new()
+        .add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount,
+        })
+        .add_attribute("action", "claim_for")
+        .add_attribute("recipient", recipient)
+        .add_attribute("keeper", info.sender.clone());
+    if !bounty.is_empty() {
+        response = response
+            .add_attribute(
+                "bounty",
+                bounty
+                    .iter()
+                    .map(Coin::to_string)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: bounty,
+            });
+    }
+
+    Ok(response)
+}
+
+pub fn execute_update_claim_delegation(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    delegation: Option<ClaimDelegationMsg>,
+) -> ArchwayResult<ContractError> {
+    let delegate = match delegation {
+        Some(ClaimDelegationMsg {
+            delegate,
+            payout_address,
+        }) => {
+            let delegation = ClaimDelegation {
+                delegate: deps.api.addr_validate(&delegate)?,
+                payout_address: payout_address
+                    .map(|address| deps.api.addr_validate(&address))
+                    .transpose()?,
+            };
+            CLAIM_DELEGATIONS.save(deps.storage, info.sender.clone(), &delegation)?;
+            delegation.delegate.to_string()
+        }
+        None => {
+            CLAIM_DELEGATIONS.remove(deps.storage, info.sender.clone());
+            "none".to_string()
+        }
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "update_claim_delegation")
+        .add_attribute("recipient", info.sender)
+        .add_attribute("delegate", delegate))
+}
+
+pub fn execute_claim_as_delegate(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+) -> ArchwayResult<ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let delegation = CLAIM_DELEGATIONS
+        .may_load(deps.storage, recipient.clone())?
+        .filter(|delegation| delegation.delegate == info.sender)
+        .ok_or(ContractError::Unauthorized {})?;
+
+    let amount = claim_rewards(deps.storage, &env, &recipient)?;
+    let payout_address = delegation
+        .payout_address
+        .unwrap_or_else(|| recipient.clone());
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: payout_address.to_string(),
+            amount,
+        })
+        .add_attribute("action", "claim_as_delegate")
+        .add_attribute("recipient", recipient)
+        .add_attribute("delegate", info.sender)
+        .add_attribute("payout_address", payout_address))
+}
+
+pub fn execute_update_claim_bounty(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    bounty: Option<Decimal>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_claim_bounty")?;
+
+    let old = CLAIM_BOUNTY.may_load(deps.storage)?;
+    match bounty {
+        Some(bounty) => {
+            let max = Decimal::percent(MAX_CLAIM_BOUNTY_PERCENT);
+            if bounty > max {
+                return Err(ContractError::InvalidClaimBounty { max });
+            }
+            CLAIM_BOUNTY.save(deps.storage, &bounty)?;
+        }
+        None => CLAIM_BOUNTY.remove(deps.storage),
+    }
+    let changes = vec![audit_change("claim_bounty", old.as_ref(), bounty.as_ref())?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_claim_bounty",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_claim_bounty"))
+}
+
+// Takes the deposited rewards of a recipient and renews its claim deadline
+fn claim_rewards(
+    storage: &mut dyn Storage,
+    env: &Env,
+    recipient: &Addr,
+) -> Result<Vec<Coin>, ContractError> {
+    let amount = take_unclaimed_rewards(storage, recipient)?;
+    if amount.is_empty() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    if let Some(claim_deadline) = CLAIM_DEADLINE.may_load(storage)? {
+        RECIPIENT_CLAIM_DEADLINES.save(
+            storage,
+            recipient.clone(),
+            &claim_deadline.period.after(&env.block),
+        )?;
+    }
+
+    Ok(amount)
+}
+
+// Settles the deposited rewards of a recipient and removes them from the reserved balances
+fn take_unclaimed_rewards(storage: &mut dyn Storage, recipient: &Addr) -> StdResult<Vec<Coin>> {
+    settle_rewards(storage, recipient)?;
+
+    let rewards = UNCLAIMED_REWARDS
+        .prefix(recipient.clone())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Decimal256)>>>()?;
+
+    let mut amount: Vec<Coin> = vec![];
+    for (denom, reward) in rewards {
+        let claimed = reward_amount(reward)?;
+        if claimed.is_zero() {
+            continue;
+        }
+
+        // The fraction below one token stays unclaimed
+        let key = (recipient.clone(), denom.clone());
+        let remainder = reward - Decimal256::from_ratio(claimed, 1u128);
+        if remainder.is_zero() {
+            UNCLAIMED_REWARDS.remove(storage, key);
+        } else {
+            UNCLAIMED_REWARDS.save(storage, key, &remainder)?;
+        }
+
+        RESERVED_BALANCES.update(storage, denom.clone(), |reserved| -> StdResult<_> {
+            Ok(reserved.unwrap_or_default().checked_sub(claimed)?)
+        })?;
+        amount.push(coin(claimed.u128(), denom));
+    }
+
+    Ok(amount)
+}
+
+pub fn execute_update_claim_deadline(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    period: Option<Duration>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_claim_deadline")?;
+
+    let recipients = RECIPIENT_CLAIM_DEADLINES
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for recipient in recipients {
+        RECIPIENT_CLAIM_DEADLINES.remove(deps.storage, recipient);
+    }
+
+    let old = CLAIM_DEADLINE.may_load(deps.storage)?;
+    let claim_deadline = period.map(|period| ClaimDeadline {
+        period,
+        initial_deadline: period.after(&env.block),
+    });
+    match &claim_deadline {
+        Some(claim_deadline) => CLAIM_DEADLINE.save(deps.storage, claim_deadline)?,
+        None => CLAIM_DEADLINE.remove(deps.storage),
+    }
+    let changes = vec![audit_change(
+        "claim_deadline",
+        old.as_ref(),
+        claim_deadline.as_ref(),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_claim_deadline",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_claim_deadline"))
+}
+
+fn recipient_claim_deadline(
+    storage: &dyn Storage,
+    claim_deadline: &ClaimDeadline,
+    recipient: &Addr,
+) -> StdResult<Expiration> {
+    Ok(RECIPIENT_CLAIM_DEADLINES
+        .may_load(storage, recipient.clone())?
+        .unwrap_or(claim_deadline.initial_deadline))
+}
+
+// Without a fallback address the forfeited rewards are no longer reserved,
+// so the next native distribution splits them between the shares
+pub fn execute_forfeit_unclaimed(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "forfeit_unclaimed")?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    let claim_deadline = CLAIM_DEADLINE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::ClaimDeadlineNotSet {})?;
+    let deadline = recipient_claim_deadline(deps.storage, &claim_deadline, &recipient)?;
+    if !deadline.is_expired(&env.block) {
+        return Err(ContractError::ClaimDeadlineNotReached {
+            deadline: deadline.to_string(),
+        });
+    }
+
+    let forfeited = take_unclaimed_rewards(deps.storage, &recipient)?;
+    if forfeited.is_empty() {
+        return Err(ContractError::NothingToForfeit {});
+    }
+    let changes = vec![audit_change(
+        format!("unclaimed_rewards[{}]", recipient),
+        Some(&forfeited),
+        None,
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "forfeit_unclaimed",
+        changes,
+    )?;
+
+    // The rewards deposited from now on can be claimed for a full period
+    RECIPIENT_CLAIM_DEADLINES.save(
+        deps.storage,
+        recipient.clone(),
+        &claim_deadline.period.after(&env.block),
+    )?;
+
+    let fallback_address = CONFIG.load(deps.storage)?.fallback_address;
+    let mut response = Response::new()
+        .add_attribute("action", "forfeit_unclaimed")
+        .add_attribute("recipient", recipient.to_string());
+    for amount in &forfeited {
+        let mut event = Event::new("forfeited_rewards")
+            .add_attribute("recipient", recipient.to_string())
+            .add_attribute("amount", amount.to_string());
+        if let Some(fallback_address) = &fallback_address {
+            event = event.add_attribute("fallback_address", fallback_address.to_string());
+        }
+        response = response.add_event(event);
+    }
+    if let Some(fallback_address) = fallback_address {
+        response = response.add_message(BankMsg::Send {
+            to_address: fallback_address.to_string(),
+            amount: forfeited,
+        });
+    }
+
+    Ok(response)
+}
+
+pub fn execute_update_sweep_address(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    address: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if !is_mutable(&config, &env.block) {
+        return Err(ContractError::ContractNotMutable {});
+    }
+
+    assert_allowed(deps.storage, &info.sender, "update_sweep_address")?;
+
+    let old = SWEEP_ADDRESS.may_load(deps.storage)?;
+    let new = address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    match &new {
+        Some(address) => SWEEP_ADDRESS.save(deps.storage, address)?,
+        None => SWEEP_ADDRESS.remove(deps.storage),
+    }
+    let changes = vec![audit_change("sweep_address", old.as_ref(), new.as_ref())?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_sweep_address",
+        changes,
+    )?;
+
+    Ok(Response::new().add_attribute("action", "update_sweep_address"))
+}
+
+// Only denoms that are not registered are swept, they are never distributed otherwise
+// The balance of a registered denom is revenue of the recipients, including the rounding dust
+// carried to the next distribution
+pub fn execute_sweep(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "sweep")?;
+
+    if DENOMS.has(deps.storage, denom.clone()) {
+        return Err(ContractError::SweepRegisteredDenom {});
+    }
+
+    // Sending the balance out of the contract is a withdrawal, the lock and the
+    // emergency withdraw setting apply to it
+    let sweep_address = SWEEP_ADDRESS.may_load(deps.storage)?;
+    if sweep_address.is_some() {
+        let config = CONFIG.load(deps.storage)?;
+        if !is_mutable(&config, &env.block) {
+            return Err(ContractError::ContractNotMutable {});
+        }
+        if config.emergency_withdraw_disabled {
+            return Err(ContractError::EmergencyWithdrawDisabled {});
+        }
+    }
+
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom.clone())?;
+    let reserved = RESERVED_BALANCES
+        .may_load(deps.storage, denom.clone())?
+        .unwrap_or_default();
+    let amount = coin(balance.amount.saturating_sub(reserved).u128(), &denom);
+    if amount.amount.is_zero() {
+        return Err(ContractError::NothingToSweep {});
+    }
+
+    // Swept funds either leave the contract or are reserved for the recipients
+    let changes = match &sweep_address {
+        Some(address) => vec![audit_change(
+            format!("withdrawals[{}]", address),
+            None,
+            Some(&amount),
+        )?],
+        None => vec![audit_change(
+            format!("reserved_balances[{}]", denom),
+            Some(&reserved),
+            Some(&reserved.checked_add(amount.amount)?),
+        )?],
+    };
+    record_audit_entry(deps.storage, &env, &info.sender, "sweep", changes)?;
+
+    let response = Response::new()
+        .add_attribute("action", "sweep")
+        .add_attribute("amount", amount.to_string());
+    match sweep_address {
+        Some(address) => Ok(response
+            .add_event(
+                Event::new("sweep")
+                    .add_attribute("denom", denom)
+                    .add_attribute("amount", amount.amount)
+                    .add_attribute("address", address.to_string()),
+            )
+            .add_message(BankMsg::Send {
+                to_address: address.to_string(),
+                amount: vec![amount],
+            })),
+        None => {
+            assert_static_shares(deps.storage, &denom)?;
+            ACC_PER_SHARE.update(deps.storage, denom.clone(), |acc| -> StdResult<_> {
+                Ok(acc
+                    .unwrap_or_default()
+                    .checked_add(Decimal256::from_ratio(amount.amount, 1u128))?)
+            })?;
+            RESERVED_BALANCES.update(deps.storage, denom.clone(), |reserved| -> StdResult<_> {
+                Ok(reserved.unwrap_or_default().checked_add(amount.amount)?)
+            })?;
+            let record = record_deposit(
+                deps.storage,
+                &env,
+                env.contract.address.clone(),
+                vec![amount.clone()],
+                Some("sweep".to_string()),
+            )?;
+
+            Ok(response.add_event(
+                Event::new("sweep")
+                    .add_attribute("denom", denom)
+                    .add_attribute("amount", amount.amount)
+                    .add_attribute("deposit_id", record.id.to_string()),
+            ))
+        }
+    }
+}
+
+pub fn execute_register_merkle_distribution(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    merkle_root: HexBinary,
+    amount: Coin,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "register_merkle_distribution")?;
+
+    if merkle_root.len() != 32 {
+        return Err(ContractError::InvalidMerkleRoot {});
+    }
+
+    if amount.amount.is_zero() {
+        return Err(ContractError::NothingToDistribute {});
+    }
+
+    // Only the balance that is not reserved yet can fund the distribution
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address, amount.denom.clone())?;
+    let reserved = RESERVED_BALANCES
+        .may_load(deps.storage, amount.denom.clone())?
+        .unwrap_or_default();
+    if balance.amount.saturating_sub(reserved) < amount.amount {
+        return Err(ContractError::InsufficientBalance {});
+    }
+    RESERVED_BALANCES.save(
+        deps.storage,
+        amount.denom.clone(),
+        &reserved.checked_add(amount.amount)?,
+    )?;
+
+    let id = LAST_MERKLE_DISTRIBUTION_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    LAST_MERKLE_DISTRIBUTION_ID.save(deps.storage, &id)?;
+    let distribution = MerkleDistribution {
+        merkle_root: merkle_root.clone(),
+        amount: amount.clone(),
+        claimed: Uint128::zero(),
+    };
+    MERKLE_DISTRIBUTIONS.save(deps.storage, id, &distribution)?;
+    let changes = vec![audit_change(
+        format!("merkle_distributions[{}]", id),
+        None,
+        Some(&distribution),
+    )?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "register_merkle_distribution",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_merkle_distribution")
+        .add_attribute("id", id.to_string())
+        .add_attribute("merkle_root", merkle_root.to_hex())
+        .add_attribute("amount", amount.to_string()))
+}
+
+pub fn execute_claim_merkle_distribution(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+    amount: Uint128,
+    proof: Vec<HexBinary>,
+) -> ArchwayResult<ContractError> {
+    let mut distribution = MERKLE_DISTRIBUTIONS.load(deps.storage, id)?;
+
+    if MERKLE_CLAIMS.has(deps.storage, (id, info.sender.clone())) {
+        return Err(ContractError::AlreadyClaimed {});
+    }
+
+    // Hashing the leaf of the sender up to the root, the pairs are hashed in sorted order
+    let leaf: [u8; 32] = Sha256::digest(format!("{}{}", info.sender, amount)).into();
+    let root = proof.iter().try_fold(leaf, |hash, sibling| {
+        let sibling: [u8; 32] = sibling
+            .as_slice()
+            .try_into()
+            .map_err(|_| ContractError::InvalidMerkleProof {})?;
+        let mut pair = [hash, sibling];
+        pair.sort_unstable();
+        Ok::<[u8; 32], ContractError>(Sha256::digest(pair.concat()).into())
+    })?;
+    if root.as_slice() != distribution.merkle_root.as_slice() {
+        return Err(ContractError::InvalidMerkleProof {});
+    }
+
+    distribution.claimed = distribution.claimed.checked_add(amount)?;
+    if distribution.claimed > distribution.amount.amount {
+        return Err(ContractError::DistributionExceedsBalance {});
+    }
+    MERKLE_DISTRIBUTIONS.save(deps.storage, id, &distribution)?;
+    MERKLE_CLAIMS.save(deps.storage, (id, info.sender.clone()), &Empty {})?;
+
+    let denom = distribution.amount.denom;
+    RESERVED_BALANCES.update(deps.storage, denom.clone(), |reserved| -> StdResult<_> {
+        Ok(reserved.unwrap_or_default().checked_sub(amount)?)
+    })?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(amount.u128(), denom)],
+        })
+        .add_attribute("action", "claim_merkle_distribution")
+        .add_attribute("id", id.to_string())
+        .add_attribute("recipient", info.sender)
+        .add_attribute("amount", amount))
+}
+
+pub fn execute_claim_failed_payouts(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let payouts = FAILED_PAYOUTS
+        .prefix(info.sender.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Uint128)>>>()?;
+
+    if payouts.is_empty() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    let mut amount: Vec<Coin> = vec![];
+    for (denom, denom_amount) in payouts {
+        FAILED_PAYOUTS.remove(deps.storage, (info.sender.clone(), denom.clone()));
+        RESERVED_BALANCES.update(deps.storage, denom.clone(), |reserved| -> StdResult<_> {
+            Ok(reserved.unwrap_or_default().checked_sub(denom_amount)?)
+        })?;
+        amount.push(coin(denom_amount.u128(), denom));
+    }
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount,
+        })
+        .add_attribute("action", "claim_failed_payouts")
+        .add_attribute("recipient", info.sender))
+}
+
+pub fn execute_update_preferred_denoms(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    denoms: Vec<String>,
+) -> ArchwayResult<ContractError> {
+    // Only recipients with a share have preferences
+    if !shares().has(deps.storage, info.sender.clone()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if denoms.is_empty() {
+        PREFERRED_DENOMS.remove(deps.storage, info.sender.clone());
+    } else {
+        PREFERRED_DENOMS.save(deps.storage, info.sender.clone(), &denoms)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "update_preferred_denoms")
+        .add_attribute("recipient", info.sender)
+        .add_attribute("denoms", denoms.join(",")))
+}
+
+pub fn execute_claim_held_balances(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let balances = HELD_BALANCES
+        .prefix(info.sender.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Uint128)>>>()?;
+
+    if balances.is_empty() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    let mut amount: Vec<Coin> = vec![];
+    for (denom, denom_amount) in balances {
+        HELD_BALANCES.remove(deps.storage, (info.sender.clone(), denom.clone()));
+        RESERVED_BALANCES.update(deps.storage, denom.clone(), |reserved| -> StdResult<_> {
+            Ok(reserved.unwrap_or_default().checked_sub(denom_amount)?)
+        })?;
+        amount.push(coin(denom_amount.u128(), denom));
+    }
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount,
+        })
+        .add_attribute("action", "claim_held_balances")
+        .add_attribute("recipient", info.sender))
+}
+
+pub fn execute_update_payout_redirect(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    redirect: Option<PayoutRedirectMsg>,
+) -> ArchwayResult<ContractError> {
+    // Only recipients with a share can redirect their payouts
+    if !shares().has(deps.storage, info.sender.clone()) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let redirect = match redirect {
+        Some(PayoutRedirectMsg::OptOut) => Some(PayoutRedirect::OptOut),
+        Some(PayoutRedirectMsg::Address { address }) => Some(PayoutRedirect::Address {
+            address: deps.api.addr_validate(&address)?,
+        }),
+        None => None,
+    };
+    match &redirect {
+        Some(redirect) => PAYOUT_REDIRECTS.save(deps.storage, info.sender.clone(), redirect)?,
+        None => PAYOUT_REDIRECTS.remove(deps.storage, info.sender.clone()),
+    }
+
+    let redirect = match redirect {
+        Some(PayoutRedirect::OptOut) => "opt_out".to_string(),
+        Some(PayoutRedirect::Address { address }) => address.to_string(),
+        None => "none".to_string(),
+    };
+    Ok(Response::new()
+        .add_attribute("action", "update_payout_redirect")
+        .add_attribute("recipient", info.sender)
+        .add_attribute("redirect", redirect))
+}
+
+pub fn execute_claim_carried_balances(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+) -> ArchwayResult<ContractError> {
+    let denoms = CARRIED_BALANCES
+        .prefix(info.sender.to_string())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+
+    if denoms.is_empty() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    let mut amount: Vec<Coin> = vec![];
+    for denom in denoms {
+        let carried = release_carried_balance(deps.storage, info.sender.as_str(), &denom)?;
+        amount.push(coin(carried.u128(), denom));
+    }
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount,
+        })
+        .add_attribute("action", "claim_carried_balances")
+        .add_attribute("recipient", info.sender))
+}
+
+// The sender of the message is the CW20 token contract
+pub fn execute_receive(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw20ReceiveMsg,
+) -> ArchwayResult<ContractError> {
+    let token = info.sender;
+    let mut response = Response::new()
+        .add_attribute("action", "receive_cw20")
+        .add_attribute("token", token.clone())
+        .add_attribute("sender", msg.sender)
+        .add_attribute("amount", msg.amount);
+
+    let held = match from_binary(&msg.msg)? {
+        Cw20HookMsg::Distribute {} => {
+            let (msgs, distributed) =
+                cw20_distribution_msgs(deps.as_ref(), &env, &token, msg.amount)?;
+
+            // Rounding dust is held for the next distribution
+            let remainder = msg.amount.checked_sub(distributed)?;
+            response = response
+                .add_messages(msgs)
+                .add_attribute("remainder", remainder);
+            remainder
+        }
+        Cw20HookMsg::Hold {} => msg.amount,
+        Cw20HookMsg::Stream { duration } => {
+            // Every stream is settled on each share change and claim, so only the registered
+            // sources and the senders allowed to stream can add one
+            let is_source = CW20_SOURCES
+                .may_load(deps.storage)?
+                .unwrap_or_default()
+                .iter()
+                .any(|source| source.token == token);
+            if !is_source {
+                let sender = deps.api.addr_validate(&msg.sender)?;
+                assert_allowed(deps.storage, &sender, "stream_cw20")?;
+            }
+
+            start_cw20_stream(deps.storage, &env, &token, msg.amount, duration)?;
+            response = response.add_attribute("duration", duration.to_string());
+            Uint128::zero()
+        }
+    };
+
+    CW20_BALANCES.update(deps.storage, token, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(held)?)
+    })?;
+
+    Ok(response)
+}
+
+pub fn execute_distribute_cw20(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    token: String,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "distribute_cw20")?;
+
+    let token = deps.api.addr_validate(&token)?;
+    let held = CW20_BALANCES
+        .may_load(deps.storage, token.clone())?
+        .unwrap_or_default();
+
+    // The pulls run before the transfers of the distribution
+    let (pull_msgs, pulled) = cw20_pull_msgs(deps.as_ref(), &env, &token)?;
+    let balance = held.checked_add(pulled)?;
+
+    let (msgs, distributed) = cw20_distribution_msgs(deps.as_ref(), &env, &token, balance)?;
+    if distributed.is_zero() {
+        return Err(ContractError::NothingToDistribute {});
+    }
+
+    let remainder = balance.checked_sub(distributed)?;
+    CW20_BALANCES.save(deps.storage, token.clone(), &remainder)?;
+
+    Ok(Response::new()
+        .add_messages(pull_msgs)
+        .add_messages(msgs)
+        .add_attribute("action", "distribute_cw20")
+        .add_attribute("token", token)
+        .add_attribute("pulled", pulled)
+        .add_attribute("amount", distributed)
+        .add_attribute("remainder", remainder))
+}
+
+pub fn execute_update_cw20_sources(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    sources: Vec<Cw20SourceMsg>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "update_cw20_sources")?;
+
+    let sources = sources
+        .iter()
+        .map(|source| {
+            Ok(Cw20Source {
+                token: deps.api.addr_validate(&source.token)?,
+                owner: deps.api.addr_validate(&source.owner)?,
+            })
+        })
+        .collect::<StdResult<Vec<Cw20Source>>>()?;
+    let old = CW20_SOURCES.may_load(deps.storage)?;
+    if sources.is_empty() {
+        CW20_SOURCES.remove(deps.storage);
+    } else {
+        CW20_SOURCES.save(deps.storage, &sources)?;
+    }
+    let new = CW20_SOURCES.may_load(deps.storage)?;
+    let changes = vec![audit_change("cw20_sources", old.as_ref(), new.as_ref())?];
+    record_audit_entry(
+        deps.storage,
+        &env,
+        &info.sender,
+        "update_cw20_sources",
+        changes,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_cw20_sources")
+        .add_attribute("sources", sources.len().to_string()))
+}
+
+// Creates a TransferFrom for every source of the token and returns the total amount pulled
+// Sources without a valid allowance or balance are skipped so they cannot block the distribution
+// Failing queries also skip the source, e.g. a token without allowances
+fn cw20_pull_msgs(
+    deps: Deps<ArchwayQuery>,
+    env: &Env,
+    token: &Addr,
+) -> StdResult<(Vec<WasmMsg>, Uint128)> {
+    let mut msgs: Vec<WasmMsg> = vec![];
+    let mut pulled = Uint128::zero();
+
+    let sources = CW20_SOURCES.may_load(deps.storage)?.unwrap_or_default();
+    for source in sources.iter().filter(|source| source.token == *token) {
+        let allowance: AllowanceResponse = match deps.querier.query_wasm_smart(
+            token,
+            &Cw20QueryMsg::Allowance {
+                owner: source.owner.to_string(),
+                spender: env.contract.address.to_string(),
+            },
+        ) {
+            Ok(allowance) => allowance,
+            Err(_) => continue,
+        };
+        if allowance.expires.is_expired(&env.block) {
+            continue;
+        }
+        let balance: BalanceResponse = match deps.querier.query_wasm_smart(
+            token,
+            &Cw20QueryMsg::Balance {
+                address: source.owner.to_string(),
+            },
+        ) {
+            Ok(balance) => balance,
+            Err(_) => continue,
+        };
+
+        let amount = allowance.allowance.min(balance.balance);
+        if amount.is_zero() {
+            continue;
+        }
+        pulled = pulled.checked_add(amount)?;
+        msgs.push(WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                owner: source.owner.to_string(),
+                recipient: env.contract.address.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        });
+    }
+
+    Ok((msgs, pulled))
+}
+
+pub fn execute_stream_cw20(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    token: String,
+    duration: u64,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "stream_cw20")?;
+
+    let token = deps.api.addr_validate(&token)?;
+    let balance = CW20_BALANCES
+        .may_load(deps.storage, token.clone())?
+        .unwrap_or_default();
+    if balance.is_zero() {
+        return Err(ContractError::NothingToDistribute {});
+    }
+
+    let stream = start_cw20_stream(deps.storage, &env, &token, balance, duration)?;
+    CW20_BALANCES.remove(deps.storage, token.clone());
+
+    Ok(Response::new()
+        .add_attribute("action", "stream_cw20")
+        .add_attribute("token", token)
+        .add_attribute("amount", stream.amount)
+        .add_attribute("end", stream.end.seconds().to_string()))
+}
+
+// Starts a stream of the amount from now, the unvested part of the running stream is added to it
+fn start_cw20_stream(
+    storage: &mut dyn Storage,
+    env: &Env,
+    token: &Addr,
+    amount: Uint128,
+    duration: u64,
+) -> Result<Cw20Stream, ContractError> {
+    if duration == 0 {
+        return Err(ContractError::InvalidStreamDuration {});
+    }
+
+    checkpoint_streams(storage, env)?;
+    let unvested = CW20_STREAMS
+        .may_load(storage, token.clone())?
+        .map(|stream| stream.amount.checked_sub(stream.vested))
+        .transpose()?
+        .unwrap_or_default();
+
+    let stream = Cw20Stream {
+        amount: amount.checked_add(unvested)?,
+        start: env.block.time,
+        end: env.block.time.plus_seconds(duration),
+        vested: Uint128::zero(),
+    };
+    CW20_STREAMS.save(storage, token.clone(), &stream)?;
+
+    Ok(stream)
+}
+
+// Amount of a stream vested at the given time
+fn vested_amount(stream: &Cw20Stream, time: Timestamp) -> StdResult<Uint128> {
+    if time >= stream.end {
+        return Ok(stream.amount);
+    }
+    let elapsed = time.seconds().saturating_sub(stream.start.seconds());
+    let duration = stream.end.seconds() - stream.start.seconds();
+    Ok(stream.amount.multiply_ratio(elapsed, duration))
+}
+
+// Adds the tokens vested since the last checkpoint of every stream to the accumulators
+// Must run before every change to the shares, like the settlement of the recipients
+fn checkpoint_streams(storage: &mut dyn Storage, env: &Env) -> StdResult<()> {
+    let streams = CW20_STREAMS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Cw20Stream)>>>()?;
+    for (token, mut stream) in streams {
+        let vested = vested_amount(&stream, env.block.time)?;
+        if vested == stream.vested {
+            continue;
+        }
+
+        // The shares add up to 100%, so the whole vested amount is added per unit of share
+        let added = vested.checked_sub(stream.vested)?;
+        STREAM_ACC_PER_SHARE.update(storage, token.clone(), |acc| -> StdResult<_> {
+            Ok(acc
+                .unwrap_or_default()
+                .checked_add(Decimal256::from_ratio(added, 1u128))?)
+        })?;
+        stream.vested = vested;
+        CW20_STREAMS.save(storage, token, &stream)?;
+    }
+
+    Ok(())
+}
+
+pub fn execute_claim_streamed(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    token: String,
+) -> ArchwayResult<ContractError> {
+    let token = deps.api.addr_validate(&token)?;
+
+    checkpoint_streams(deps.storage, &env)?;
+    settle_rewards(deps.storage, &info.sender)?;
+
+    let key = (info.sender.clone(), token.clone());
+    let unclaimed = UNCLAIMED_STREAMS
+        .may_load(deps.storage, key.clone())?
+        .unwrap_or_default();
+    let amount = reward_amount(unclaimed)?;
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    // The fraction below one token stays unclaimed
+    let remainder = unclaimed - Decimal256::from_ratio(amount, 1u128);
+    if remainder.is_zero() {
+        UNCLAIMED_STREAMS.remove(deps.storage, key);
+    } else {
+        UNCLAIMED_STREAMS.save(deps.storage, key, &remainder)?;
+    }
+
+    Ok(Response::new()
+        .add_message(WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: info.sender.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        })
+        .add_attribute("action", "claim_streamed")
+        .add_attribute("recipient", info.sender)
+        .add_attribute("token", token)
+        .add_attribute("amount", amount))
+}
+
+// Creates CW20 transfers for every share and returns the total amount transferred
+// CW20 tokens can not be sent through ICS-20, so IBC routed shares are paid locally
+fn cw20_distribution_msgs(
+    deps: Deps<ArchwayQuery>,
+    env: &Env,
+    token: &Addr,
+    amount: Uint128,
+) -> Result<(Vec<WasmMsg>, Uint128), ContractError> {
+    let mut msgs: Vec<WasmMsg> = vec![];
+    let mut distributed = Uint128::zero();
+
+    for share in distribution_shares(deps, env, token.as_str())? {
+        // Undeliverable portions stay held in the contract
+        if is_undeliverable(deps.storage, env, &share.recipient)? {
+            continue;
+        }
+
+        let share_amount = percentage_of(amount, share.percentage)?;
+        if share_amount.is_zero() {
+            continue;
+        }
+        distributed = distributed.checked_add(share_amount)?;
+
+        // Burn shares burn their portion of the token
+        let msg = if share.burn {
+            Cw20ExecuteMsg::Burn {
+                amount: share_amount,
+            }
+        } else {
+            let recipient = redirect_address(deps.storage, &share.recipient)?
+                .map_or(share.recipient, |address| address.to_string());
+            Cw20ExecuteMsg::Transfer {
+                recipient,
+                amount: share_amount,
+            }
+        };
+        msgs.push(WasmMsg::Execute {
+            contract_addr: token.to_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        });
+    }
+
+    // The transfers must never spend more than the distributed amount
+    if distributed > amount {
+        return Err(ContractError::DistributionExceedsBalance {});
+    }
+
+    Ok((msgs, distributed))
+}
+
+// The sender of the message is the CW721 collection contract
+pub fn execute_receive_nft(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    msg: Cw721ReceiveMsg,
+) -> ArchwayResult<ContractError> {
+    let Cw721HookMsg::List { price } = from_binary(&msg.msg)?;
+
+    if price.amount.is_zero() {
+        return Err(ContractError::InvalidPrice {});
+    }
+
+    let listing = Listing {
+        collection: info.sender.clone(),
+        token_id: msg.token_id.clone(),
+        donor: deps.api.addr_validate(&msg.sender)?,
+        price,
+    };
+    LISTINGS.save(deps.storage, (info.sender, msg.token_id), &listing)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "list_nft")
+        .add_attribute("collection", listing.collection)
+        .add_attribute("token_id", listing.token_id)
+        .add_attribute("price", listing.price.to_string()))
+}
+
+pub fn execute_buy_nft(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    collection: String,
+    token_id: String,
+) -> ArchwayResult<ContractError> {
+    let collection = deps.api.addr_validate(&collection)?;
+    let listing = LISTINGS.load(deps.storage, (collection.clone(), token_id.clone()))?;
+
+    // Only the exact listing price is accepted
+    if info.funds != vec![listing.price.clone()] {
+        return Err(ContractError::InvalidPayment {
+            price: listing.price.to_string(),
+        });
+    }
+
+    LISTINGS.remove(deps.storage, (collection.clone(), token_id.clone()));
+
+    let mut reply_id = PAYOUT_REPLY_ID_START;
+    let share_list = distribution_shares(deps.as_ref(), &env, &listing.price.denom)?;
+    let priority_payments = priority_payouts(deps.as_ref(), &listing.price.denom)?;
+    let (msgs, events) = distribution_msgs(
+        deps.storage,
+        &env,
+        &share_list,
+        priority_payments,
+        listing.price.clone(),
+        &mut reply_id,
+    )?;
+
+    Ok(Response::new()
+        .add_message(WasmMsg::Execute {
+            contract_addr: collection.to_string(),
+            msg: to_binary(&Cw721ExecuteMsg::TransferNft {
+                recipient: info.sender.to_string(),
+                token_id: token_id.clone(),
+            })?,
+            funds: vec![],
+        })
+        .add_submessages(msgs)
+        .add_events(events)
+        .add_attribute("action", "buy_nft")
+        .add_attribute("collection", collection)
+        .add_attribute("token_id", token_id)
+        .add_attribute("buyer", info.sender)
+        .add_attribute("price", listing.price.to_string()))
+}
+
+pub fn execute_delist_nft(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    collection: String,
+    token_id: String,
+    recipient: Option<String>,
+) -> ArchwayResult<ContractError> {
+    assert_allowed(deps.storage, &info.sender, "delist_nft")?;
+
+    let collection = deps.api.addr_validate(&collection)?;
+    let listing = LISTINGS.load(deps.storage, (collection.clone(), token_id.clone()))?;
+    LISTINGS.remove(deps.storage, (collection.clone(), token_id.clone()));
+    let changes = vec![audit_change(
+        format!("listings[{},{}]", collection, token_id),
+        Some(&listing),
+        None,
+    )?];
+    record_audit_entry(deps.storage, &env, &info.sender, "delist_nft", changes)?;
+
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => get_ownership(deps.storage)?
+            .owner
+            .ok_or(OwnershipError::NoOwner)?,
+    };
+
+    Ok(Response::new()
+        .add_message(WasmMsg::Execute {
+            contract_addr: collection.to_string(),
+            msg: to_binary(&Cw721ExecuteMsg::TransferNft {
+                recipient: recipient.to_string(),
+                token_id: token_id.clone(),
+            })?,
+            funds: vec![],
+        })
+        .add_attribute("action", "delist_nft")
+        .add_attribute("collection", collection)
+        .add_attribute("token_id", token_id)
+        .add_attribute("recipient", recipient))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    _msg: MigrateMsg,
+) -> ArchwayResult<ContractError> {
+    // Rejects other contracts and downgrades before touching the state
+    ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // The legacy config shares its key with the current one, a config in the current layout or
+    // no config has nothing to convert, and a config that reads as neither fails the migration
+    if CONFIG.load(deps.storage).is_ok() {
+        return Ok(Response::new().add_attribute("action", "migrate"));
+    }
+    let legacy = match LEGACY_CONFIG.may_load(deps.storage)? {
+        Some(legacy) => legacy,
+        None => return Ok(Response::new().add_attribute("action", "migrate")),
+    };
+
+    initialize_owner(deps.storage, deps.api, Some(legacy.admin.as_str()))?;
+
+    let config = Config {
+        mutable: legacy.mutable,
+        transfer_requires_approval: false,
+        ibc_controllers: vec![],
+        cron_agent: None,
+        group: None,
+        overflow_address: None,
+        expired_share_policy: ExpiredSharePolicy::Redistribute,
+        fallback_address: None,
+        locked_until: None,
+        emergency_withdraw_disabled: false,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let operator_actions = OPERATOR_ACTIONS.map(|action| action.to_string());
+    RBAC.set_role_actions(deps.storage, OPERATOR_ROLE, &operator_actions)?;
+
+    // Legacy shares read as shares without an IBC route that are not burnt
+    // Saving them again fills the percentage index, the snapshots and the recipients
+    let legacy_shares = LEGACY_SHARES
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Share)>>>()?;
+    for (recipient, share) in &legacy_shares {
+        save_share(deps.storage, recipient.clone(), share, env.block.height)?;
+    }
+    LAST_SHARE_UPDATE.save(deps.storage, &env.block.height)?;
+
+    if !DENOMS.has(deps.storage, NATIVE_DENOM.to_string()) {
+        DENOMS.save(
+            deps.storage,
+            NATIVE_DENOM.to_string(),
+            &DenomInfo {
+                denom: NATIVE_DENOM.to_string(),
+                display: NATIVE_DENOM_DISPLAY.to_string(),
+                exponent: NATIVE_DENOM_EXPONENT,
+            },
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("admin", legacy.admin)
+        .add_attribute("shares", legacy_shares.len().to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut<ArchwayQuery>, env: Env, msg: SudoMsg) -> ArchwayResult<ContractError> {
+    match msg {
+        SudoMsg::DistributeNativeTokens {} => {
+            let (msgs, events) = registry_distribution_msgs(deps, &env)?;
+
+            Ok(Response::new()
+                .add_submessages(msgs)
+                .add_events(events)
+                .add_attribute("action", "sudo_distribute_native_tokens"))
+        }
+        SudoMsg::Pause {} => {
+            PAUSED.save(deps.storage, &true)?;
+
+            Ok(Response::new().add_attribute("action", "sudo_pause"))
+        }
+        SudoMsg::Unpause {} => {
+            PAUSED.save(deps.storage, &false)?;
+
+            Ok(Response::new().add_attribute("action", "sudo_unpause"))
+        }
+        SudoMsg::UpdateAdmin { admin } => {
+            // Replaces the owner right away, dropping any pending ownership transfer
+            let admin = deps.api.addr_validate(&admin)?;
+            let old = get_ownership(deps.storage)?;
+            initialize_owner(deps.storage, deps.api, Some(admin.as_str()))?;
+            let changes = ownership_changes(&old, &get_ownership(deps.storage)?)?;
+            // Sudo has no sender, the change is recorded as made by the chain on the contract
+            record_audit_entry(
+                deps.storage,
+                &env,
+                &env.contract.address,
+                "sudo_update_admin",
+                changes,
+            )?;
+
+            Ok(Response::new()
+                .add_attribute("action", "sudo_update_admin")
+                .add_attribute("admin", admin))
+        }
+    }
+}
+
+// Only failed payouts reply, the failed amount stays in the contract until it is claimed
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut<ArchwayQuery>, env: Env, msg: Reply) -> ArchwayResult<ContractError> {
+    let error = match msg.result {
+        SubMsgResult::Err(error) => error,
+        SubMsgResult::Ok(_) => return Ok(Response::new()),
+    };
+
+    let payout = PAYOUTS.load(deps.storage, msg.id)?;
+    record_failed_payout(deps.storage, &env, msg.id)?;
+
+    // Failed payouts are sent to the fallback address, unless the fallback itself failed
+    let config = CONFIG.load(deps.storage)?;
+    if let Some(fallback_address) = config
+        .fallback_address
+        .filter(|fallback_address| *fallback_address != payout.recipient)
+    {
+        record_redirected_payout(deps.storage, payout.recipient.clone(), &payout.amount)?;
+
+        return Ok(Response::new()
+            .add_message(BankMsg::Send {
+                to_address: fallback_address.to_string(),
+                amount: vec![payout.amount.clone()],
+            })
+            .add_attribute("action", "failed_payout")
+            .add_attribute("recipient", payout.recipient)
+            .add_attribute("fallback_address", fallback_address)
+            .add_attribute("amount", payout.amount.to_string())
+            .add_attribute("error", error));
+    }
+
+    FAILED_PAYOUTS.update(
+        deps.storage,
+        (payout.recipient.clone(), payout.amount.denom.clone()),
+        |amount| -> StdResult<_> {
+            Ok(amount
+                .unwrap_or_default()
+                .checked_add(payout.amount.amount)?)
+        },
+    )?;
+    RESERVED_BALANCES.update(
+        deps.storage,
+        payout.amount.denom.clone(),
+        |reserved| -> StdResult<_> {
+            Ok(reserved
+                .unwrap_or_default()
+                .checked_add(payout.amount.amount)?)
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "failed_payout")
+        .add_attribute("recipient", payout.recipient)
+        .add_attribute("amount", payout.amount.to_string())
+        .add_attribute("error", error))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&query_config(deps, env)?),
+        QueryMsg::Ownership {} => to_binary(&get_ownership(deps.storage)?),
+        QueryMsg::Share { recipient } => to_binary(&query_share(deps, recipient)?),
+        QueryMsg::WithdrawalPolicy {} => to_binary(&WITHDRAWAL_POLICY.may_load(deps.storage)?),
+        QueryMsg::ClawbackWindow {} => to_binary(&CLAWBACK_WINDOW.may_load(deps.storage)?),
+        QueryMsg::DepositAllowlist {} => to_binary(&DEPOSIT_ALLOWLIST.may_load(deps.storage)?),
+        QueryMsg::PendingDeposits { start_after, limit } => {
+            to_binary(&query_pending_deposits(deps, start_after, limit)?)
+        }
+        QueryMsg::ShareApprovers {} => to_binary(&SHARE_APPROVERS.may_load(deps.storage)?),
+        QueryMsg::PendingShareUpdate {} => to_binary(&PENDING_SHARE_UPDATE.may_load(deps.storage)?),
+        QueryMsg::PendingApproversUpdate {} => {
+            to_binary(&PENDING_APPROVERS_UPDATE.may_load(deps.storage)?)
+        }
+        QueryMsg::Import { start_after, limit } => {
+            to_binary(&query_import(deps, start_after, limit)?)
+        }
+        QueryMsg::ShareAuthority {} => to_binary(&ShareAuthorityResponse {
+            pubkey: SHARE_AUTHORITY.may_load(deps.storage)?,
+            nonce: SHARE_AUTHORITY_NONCE
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+        }),
+        QueryMsg::PendingShareTransfer { recipient } => {
+            to_binary(&query_pending_share_transfer(deps, recipient)?)
+        }
+        QueryMsg::ShareExpiration { recipient } => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            to_binary(&SHARE_EXPIRATIONS.may_load(deps.storage, recipient)?)
+        }
+        QueryMsg::PayoutRedirect { recipient } => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            to_binary(&PAYOUT_REDIRECTS.may_load(deps.storage, recipient)?)
+        }
+        QueryMsg::ShareCap { recipient, denom } => {
+            to_binary(&SHARE_CAPS.may_load(deps.storage, (recipient, denom))?)
+        }
+        QueryMsg::MinPayout { recipient, denom } => {
+            to_binary(&MIN_PAYOUTS.may_load(deps.storage, (recipient, denom))?)
+        }
+        QueryMsg::CarriedBalances { recipient } => {
+            to_binary(&query_carried_balances(deps, recipient)?)
+        }
+        QueryMsg::PriorityPayments {} => to_binary(
+            &PRIORITY_PAYMENTS
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+        ),
+        QueryMsg::Waterfall {} => to_binary(&WATERFALL.may_load(deps.storage)?.unwrap_or_default()),
+        QueryMsg::MerkleDistribution { id } => {
+            to_binary(&MERKLE_DISTRIBUTIONS.load(deps.storage, id)?)
+        }
+        QueryMsg::MerkleClaimed { id, recipient } => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            to_binary(&MERKLE_CLAIMS.has(deps.storage, (id, recipient)))
+        }
+        QueryMsg::CodeChecksum { code_id } => {
+            to_binary(&CODE_CHECKSUMS.may_load(deps.storage, code_id)?)
+        }
+        QueryMsg::SupportedDenoms {} => to_binary(&query_supported_denoms(deps)?),
+        QueryMsg::FailedPayouts { recipient } => to_binary(&query_failed_payouts(deps, recipient)?),
+        QueryMsg::PreferredDenoms { recipient } => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            to_binary(
+                &PREFERRED_DENOMS
+                    .may_load(deps.storage, recipient)?
+                    .unwrap_or_default(),
+            )
+        }
+        QueryMsg::HeldBalances { recipient } => to_binary(&query_held_balances(deps, recipient)?),
+        QueryMsg::RedirectedPayouts { recipient } => {
+            to_binary(&query_redirected_payouts(deps, recipient)?)
+        }
+        QueryMsg::PendingRewards { recipient } => {
+            to_binary(&query_pending_rewards(deps, recipient)?)
+        }
+        QueryMsg::SweepAddress {} => to_binary(&SWEEP_ADDRESS.may_load(deps.storage)?),
+        QueryMsg::ClaimDeadline {} => to_binary(&CLAIM_DEADLINE.may_load(deps.storage)?),
+        QueryMsg::ClaimBounty {} => to_binary(&CLAIM_BOUNTY.may_load(deps.storage)?),
+        QueryMsg::ClaimDelegation { recipient } => to_binary(
+            &CLAIM_DELEGATIONS.may_load(deps.storage, deps.api.addr_validate(&recipient)?)?,
+        ),
+        QueryMsg::AtRiskBalances { start_after, limit } => {
+            to_binary(&query_at_risk_balances(deps, env, start_after, limit)?)
+        }
+        QueryMsg::Cw20Balances {} => to_binary(&query_cw20_balances(deps)?),
+        QueryMsg::DenomMetadata { denom } => {
+            to_binary(&query_denom_metadata(&deps.querier, denom)?)
+        }
+        QueryMsg::ReceiptDenom {} => to_binary(&RECEIPT_DENOM.may_load(deps.storage)?),
+        QueryMsg::Cw20Sources {} => {
+            to_binary(&CW20_SOURCES.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::Cw20Stream { token } => {
+            to_binary(&CW20_STREAMS.may_load(deps.storage, deps.api.addr_validate(&token)?)?)
+        }
+        QueryMsg::StreamedRewards { recipient, token } => {
+            to_binary(&query_streamed_rewards(deps, env, recipient, token)?)
+        }
+        QueryMsg::Listing {
+            collection,
+            token_id,
+        } => to_binary(&query_listing(deps, collection, token_id)?),
+        QueryMsg::Listings { start_after, limit } => {
+            to_binary(&query_listings(deps, start_after, limit)?)
+        }
+        QueryMsg::Shares {
+            start_after,
+            limit,
+            order,
+        } => to_binary(&query_shares(deps, start_after, limit, order)?),
+        QueryMsg::ExportState { start_after, limit } => {
+            to_binary(&query_export_state(deps, start_after, limit)?)
+        }
+        QueryMsg::DenomShares { denom } => to_binary(&query_denom_shares(deps, denom)?),
+        QueryMsg::SubShares { recipient } => {
+            let recipient = deps.api.addr_validate(&recipient)?;
+            to_binary(
+                &SUB_SHARES
+                    .may_load(deps.storage, recipient)?
+                    .unwrap_or_default(),
+            )
+        }
+        QueryMsg::SharesDenoms {} => to_binary(&query_shares_denoms(deps)?),
+        QueryMsg::SharesAt {
+            height,
+            start_after,
+            limit,
+        } => to_binary(&query_shares_at(deps, height, start_after, limit)?),
+        QueryMsg::SharesByPercentage {
+            min,
+            max,
+            limit,
+            order,
+        } => to_binary(&query_shares_by_percentage(deps, min, max, limit, order)?),
+        QueryMsg::FlattenedShares {} => to_binary(&query_flattened_shares(deps)?),
+        QueryMsg::DistributionAt { id, height } => {
+            to_binary(&query_distribution_at(deps, id, height)?)
+        }
+        QueryMsg::ReceiptsByRecipient {
+            recipient,
+            start_after,
+            limit,
+        } => to_binary(&query_receipts_by_recipient(
+            deps,
+            recipient,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::CurrentEpoch {} => {
+            to_binary(&CURRENT_EPOCH.may_load(deps.storage)?.unwrap_or_default())
+        }
+        QueryMsg::EpochReport {
+            epoch,
+            start_after,
+            limit,
+        } => to_binary(&query_epoch_report(deps, epoch, start_after, limit)?),
+        QueryMsg::EstimatedPayouts { denom } => {
+            to_binary(&query_estimated_payouts(deps, env, denom)?)
+        }
+        QueryMsg::SimulateDistribution { amount, denom } => {
+            to_binary(&query_simulate_distribution(deps, env, amount, denom)?)
+        }
+        QueryMsg::DistributionCost {} => to_binary(&query_distribution_cost(deps, env)?),
+        QueryMsg::PriceOracle {} => to_binary(&PRICE_ORACLE.may_load(deps.storage)?),
+        QueryMsg::MinDistribution { denom } => {
+            to_binary(&MIN_DISTRIBUTIONS.may_load(deps.storage, denom)?)
+        }
+        QueryMsg::AuditLog { start_after, limit } => {
+            to_binary(&query_audit_log(deps, start_after, limit)?)
+        }
+        QueryMsg::DepositHistory { start_after, limit } => {
+            to_binary(&query_deposit_history(deps, start_after, limit)?)
+        }
+        QueryMsg::DepositTotals { sender } => to_binary(&query_deposit_totals(deps, sender)?),
+        QueryMsg::Operators {} => to_binary(&RBAC.role_members(deps.storage, OPERATOR_ROLE)?),
+        QueryMsg::RoleMembers { role } => to_binary(&RBAC.role_members(deps.storage, &role)?),
+        QueryMsg::RoleActions { role } => to_binary(&RBAC.role_actions(deps.storage, &role)?),
+    }
+}
+
+pub fn query_config(deps: Deps<ArchwayQuery>, env: Env) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let share_list = shares()
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (_, share) = item?;
+            Ok(share)
+        })
+        .collect::<StdResult<Vec<Share>>>()?;
+    let total_percentage = share_list.iter().try_fold(Decimal::zero(), |acc, share| {
+        acc.checked_add(share.percentage)
+    })?;
+
+    Ok(ConfigResponse {
+        locked: !is_mutable(&config, &env.block),
+        paused: PAUSED.may_load(deps.storage)?.unwrap_or_default(),
+        config,
+        share_count: share_list.len() as u64,
+        total_percentage,
+        denoms: DENOMS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<String>>>()?,
+        last_share_update: LAST_SHARE_UPDATE.load(deps.storage)?,
+        version: get_contract_version(deps.storage)?,
+        premium: PREMIUM.may_load(deps.storage)?,
+    })
+}
+
+pub fn query_share(deps: Deps<ArchwayQuery>, recipient: String) -> StdResult<Share> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let share = shares().load(deps.storage, recipient)?;
+    Ok(share)
+}
+
+pub fn query_pending_share_transfer(
+    deps: Deps<ArchwayQuery>,
+    recipient: String,
+) -> StdResult<Option<Addr>> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let new_recipient = PENDING_TRANSFERS.may_load(deps.storage, recipient)?;
+    Ok(new_recipient)
+}
+
+pub fn query_supported_denoms(deps: Deps<ArchwayQuery>) -> StdResult<Vec<DenomInfo>> {
+    let denoms = DENOMS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (_, denom) = item?;
+            Ok(denom)
+        })
+        .collect::<StdResult<Vec<DenomInfo>>>()?;
+
+    Ok(denoms)
+}
+
+pub fn query_failed_payouts(deps: Deps<ArchwayQuery>, recipient: String) -> StdResult<Vec<Coin>> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    let payouts = FAILED_PAYOUTS
+        .prefix(recipient)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+
+    Ok(payouts)
+}
+
+pub fn query_held_balances(deps: Deps<ArchwayQuery>, recipient: String) -> StdResult<Vec<Coin>> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    HELD_BALANCES
+        .prefix(recipient)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()
+}
+
+pub fn query_carried_balances(deps: Deps<ArchwayQuery>, recipient: String) -> StdResult<Vec<Coin>> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    CARRIED_BALANCES
+        .prefix(recipient.to_string())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()
+}
+
+pub fn query_redirected_payouts(
+    deps: Deps<ArchwayQuery>,
+    recipient: String,
+) -> StdResult<Vec<Coin>> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    let payouts = REDIRECTED_PAYOUTS
+        .prefix(recipient)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+
+    Ok(payouts)
+}
+
+pub fn query_pending_rewards(deps: Deps<ArchwayQuery>, recipient: String) -> StdResult<Vec<Coin>> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    pending_rewards(deps.storage, &recipient)
+}
+
+// Includes the rewards accrued since the last settlement of the recipient
+fn pending_rewards(storage: &dyn Storage, recipient: &Addr) -> StdResult<Vec<Coin>> {
+    let percentage = shares()
+        .may_load(storage, recipient.clone())?
+        .map(|share| Decimal256::from(share.percentage))
+        .unwrap_or_default();
+
+    let mut rewards: Vec<Coin> = vec![];
+    for item in ACC_PER_SHARE.range(storage, None, None, Order::Ascending) {
+        let (denom, acc) = item?;
+        let key = (recipient.clone(), denom.clone());
+        let index = REWARD_INDEXES
+            .may_load(storage, key.clone())?
+            .unwrap_or_default();
+        let unclaimed = UNCLAIMED_REWARDS
+            .may_load(storage, key)?
+            .unwrap_or_default();
+
+        let accrued = percentage.checked_mul(acc.checked_sub(index)?)?;
+        let amount = reward_amount(unclaimed.checked_add(accrued)?)?;
+        if !amount.is_zero() {
+            rewards.push(coin(amount.u128(), denom));
+        }
+    }
+
+    Ok(rewards)
+}
+
+pub fn query_at_risk_balances(
+    deps: Deps<ArchwayQuery>,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<AtRiskBalance>> {
+    let claim_deadline = match CLAIM_DEADLINE.may_load(deps.storage)? {
+        Some(claim_deadline) => claim_deadline,
+        None => return Ok(vec![]),
+    };
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after
+        .map(|recipient| deps.api.addr_validate(&recipient))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let mut balances: Vec<AtRiskBalance> = vec![];
+    for recipient in SHARE_RECIPIENTS.keys(deps.storage, start, None, Order::Ascending) {
+        let recipient = recipient?;
+        let amounts = pending_rewards(deps.storage, &recipient)?;
+        if amounts.is_empty() {
+            continue;
+        }
+
+        let deadline = recipient_claim_deadline(deps.storage, &claim_deadline, &recipient)?;
+        balances.push(AtRiskBalance {
+            recipient,
+            amounts,
+            deadline,
+            expired: deadline.is_expired(&env.block),
+        });
+        if balances.len() == limit {
+            break;
+        }
+    }
+
+    Ok(balances)
+}
+
+pub fn query_cw20_balances(deps: Deps<ArchwayQuery>) -> StdResult<Vec<Cw20Coin>> {
+    let balances = CW20_BALANCES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (token, amount) = item?;
+            Ok(Cw20Coin {
+                address: token.to_string(),
+                amount,
+            })
+        })
+        .collect::<StdResult<Vec<Cw20Coin>>>()?;
+
+    Ok(balances)
+}
+
+// Simulates the checkpoint of the stream and the settlement of the recipient
+pub fn query_streamed_rewards(
+    deps: Deps<ArchwayQuery>,
+    env: Env,
+    recipient: String,
+    token: String,
+) -> StdResult<Uint128> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let token = deps.api.addr_validate(&token)?;
+
+    let mut acc = STREAM_ACC_PER_SHARE
+        .may_load(deps.storage, token.clone())?
+        .unwrap_or_default();
+    if let Some(stream) = CW20_STREAMS.may_load(deps.storage, token.clone())? {
+        let added = vested_amount(&stream, env.block.time)?.checked_sub(stream.vested)?;
+        acc = acc.checked_add(Decimal256::from_ratio(added, 1u128))?;
+    }
+
+    let key = (recipient.clone(), token);
+    let percentage = shares()
+        .may_load(deps.storage, recipient)?
+        .map(|share| Decimal256::from(share.percentage))
+        .unwrap_or_default();
+    let index = STREAM_INDEXES
+        .may_load(deps.storage, key.clone())?
+        .unwrap_or_default();
+    let unclaimed = UNCLAIMED_STREAMS
+        .may_load(deps.storage, key)?
+        .unwrap_or_default()
+        .checked_add(percentage.checked_mul(acc.checked_sub(index)?)?)?;
+
+    reward_amount(unclaimed)
+}
+
+pub fn query_listing(
+    deps: Deps<ArchwayQuery>,
+    collection: String,
+    token_id: String,
+) -> StdResult<Listing> {
+    let collection = deps.api.addr_validate(&collection)?;
+    let listing = LISTINGS.load(deps.storage, (collection, token_id))?;
+    Ok(listing)
+}
+
+pub fn query_import(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ImportResponse> {
+    let limit = limit.unwrap_or(100) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let shares = IMPORTED_SHARES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, share) = item?;
+            Ok(share)
+        })
+        .collect::<StdResult<Vec<Share>>>()?;
+
+    Ok(ImportResponse {
+        mode: IMPORT_MODE.may_load(deps.storage)?,
+        shares,
+    })
+}
+
+pub fn query_pending_deposits(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<PendingDeposit>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    PENDING_DEPOSITS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, deposit) = item?;
+            Ok(deposit)
+        })
+        .collect()
+}
+
+pub fn query_deposit_history(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<DepositRecord>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    DEPOSIT_HISTORY
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, record) = item?;
+            Ok(record)
+        })
+        .collect()
+}
+
+pub fn query_deposit_totals(deps: Deps<ArchwayQuery>, sender: String) -> StdResult<Vec<Coin>> {
+    let sender = deps.api.addr_validate(&sender)?;
+
+    DEPOSIT_TOTALS
+        .prefix(sender)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect()
+}
+
+pub fn query_audit_log(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<AuditEntry>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    AUDIT_LOG
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, entry) = item?;
+            Ok(entry)
+        })
+        .collect()
+}
+
+pub fn query_listings(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<(String, String)>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Listing>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after
+        .map(|(collection, token_id)| -> StdResult<_> {
+            Ok((deps.api.addr_validate(&collection)?, token_id))
+        })
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let listings = LISTINGS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, listing) = item?;
+            Ok(listing)
+        })
+        .collect::<StdResult<Vec<Listing>>>()?;
+
+    Ok(listings)
+}
+
+pub fn query_shares(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<String>,
+    limit: Option<u8>,
+    order: Option<Order>,
+) -> StdResult<SharesResponse> {
+    let limit = limit.unwrap_or(10) as usize;
+    let order = order.unwrap_or(Order::Ascending);
+    let start_after = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+
+    // Start after is exclusive in both directions
+    let (min, max) = match order {
+        Order::Ascending => (start_after.map(Bound::exclusive), None),
+        Order::Descending => (None, start_after.map(Bound::exclusive)),
+    };
+
+    // Loading one more share to know if there is a next page
+    let mut share_list = shares()
+        .range(deps.storage, min, max, order)
+        .take(limit + 1)
+        .map(|item| {
+            let (_, share) = item?;
+            Ok(share)
+        })
+        .collect::<StdResult<Vec<Share>>>()?;
+
+    let next_start_after = if share_list.len() > limit {
+        share_list.truncate(limit);
+        share_list.last().map(|share| share.recipient.clone())
+    } else {
+        None
+    };
+
+    let total_count = shares()
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count() as u64;
+
+    Ok(SharesResponse {
+        shares: share_list,
+        next_start_after,
+        total_count,
+    })
+}
+
+// Shares are exported in pages, the rest of the state is included in every page
+pub fn query_export_state(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<StateExport> {
+    let page = query_shares(deps, start_after, limit, Some(Order::Ascending))?;
+
+    Ok(StateExport {
+        format_version: EXPORT_FORMAT_VERSION,
+        contract_version: get_contract_version(deps.storage)?,
+        config: CONFIG.load(deps.storage)?,
+        last_share_update: LAST_SHARE_UPDATE.load(deps.storage)?,
+        cw20_balances: query_cw20_balances(deps)?,
+        shares: page.shares,
+        next_start_after: page.next_start_after,
+        total_count: page.total_count,
+        denoms: DENOMS
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, denom)| denom))
+            .collect::<StdResult<Vec<DenomInfo>>>()?,
+        counters: ExportCounters {
+            last_distribution_id: LAST_DISTRIBUTION_ID
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+            last_deposit_record_id: LAST_DEPOSIT_RECORD_ID
+                .may_load(deps.storage)?
+                .unwrap_or_default(),
+        },
+    })
+}
+
+// Shares are loaded as they were at the beginning of the block at the given height
+pub fn query_denom_shares(deps: Deps<ArchwayQuery>, denom: String) -> StdResult<Vec<Share>> {
+    DENOM_SHARES
+        .prefix(denom)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (_, share) = item?;
+            Ok(share)
+        })
+        .collect::<StdResult<Vec<Share>>>()
+}
+
+pub fn query_shares_denoms(deps: Deps<ArchwayQuery>) -> StdResult<Vec<String>> {
+    let mut denoms = DENOM_SHARES
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|key| {
+            let (denom, _) = key?;
+            Ok(denom)
+        })
+        .collect::<StdResult<Vec<String>>>()?;
+    denoms.dedup();
+    Ok(denoms)
+}
+
+pub fn query_shares_at(
+    deps: Deps<ArchwayQuery>,
+    height: u64,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Share>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?
+        .map(Bound::exclusive);
+
+    let recipients = SHARE_RECIPIENTS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    let mut share_list: Vec<Share> = vec![];
+    for recipient in recipients {
+        if let Some(share) = shares().may_load_at_height(deps.storage, recipient, height)? {
+            share_list.push(share);
+            if share_list.len() == limit {
+                break;
+            }
+        }
+    }
+
+    Ok(share_list)
+}
+
+// Runs the distribution on simulated storage so the caps, waterfall and priority payments
+// are applied exactly as they would be
+pub fn query_simulate_distribution(
+    deps: Deps<ArchwayQuery>,
+    env: Env,
+    amount: Uint128,
+    denom: String,
+) -> StdResult<SimulateDistributionResponse> {
+    let share_list = distribution_shares(deps, &env, &denom)?;
+    let mut storage = SimulatedStorage::new(deps.storage);
+    let mut reply_id = PAYOUT_REPLY_ID_START;
+
+    priority_payouts(deps, &denom)
+        .and_then(|priority_payments| {
+            distribution_msgs(
+                &mut storage,
+                &env,
+                &share_list,
+                priority_payments,
+                coin(amount.u128(), denom),
+                &mut reply_id,
+            )
+        })
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    // The simulated distribution is recorded in the simulated storage only
+    let id = LAST_DISTRIBUTION_ID.load(&storage)?;
+    let record = DISTRIBUTION_HISTORY.load(&storage, id)?;
+    let payouts = record
+        .payouts
+        .into_iter()
+        .map(|payout| match payout.kind {
+            PayoutKind::Send => SimulatedPayout::Send {
+                recipient: Addr::unchecked(payout.recipient),
+                amount: payout.amount,
+            },
+            PayoutKind::IbcTransfer { remote_address } => SimulatedPayout::IbcTransfer {
+                recipient: payout.recipient,
+                remote_address,
+                amount: payout.amount,
+            },
+            PayoutKind::Burn => SimulatedPayout::Burn {
+                recipient: payout.recipient,
+                amount: payout.amount,
+            },
+            PayoutKind::Held => SimulatedPayout::Held {
+                recipient: payout.recipient,
+                amount: payout.amount,
+            },
+            PayoutKind::CarriedOver => SimulatedPayout::CarriedOver {
+                recipient: payout.recipient,
+                amount: payout.amount,
+            },
+        })
+        .collect();
+
+    Ok(SimulateDistributionResponse {
+        payouts,
+        remainder: record.remainder,
+    })
+}
+
+// Simulates the distribution of the available balance and the rewards a withdrawal would add
+pub fn query_estimated_payouts(
+    deps: Deps<ArchwayQuery>,
+    env: Env,
+    denom: String,
+) -> StdResult<EstimatedPayoutsResponse> {
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), denom.clone())?;
+    let reserved = RESERVED_BALANCES
+        .may_load(deps.storage, denom.clone())?
+        .unwrap_or_default();
+    let balance = balance.amount.saturating_sub(reserved);
+
+    let outstanding_rewards = outstanding_rewards(deps, &env, &denom)?;
+
+    let amount = balance.checked_add(outstanding_rewards)?;
+    if amount.is_zero() {
+        return Ok(EstimatedPayoutsResponse {
+            balance,
+            outstanding_rewards,
+            payouts: vec![],
+            remainder: Uint128::zero(),
+        });
+    }
+
+    let simulation = query_simulate_distribution(deps, env, amount, denom)?;
+
+    Ok(EstimatedPayoutsResponse {
+        balance,
+        outstanding_rewards,
+        payouts: simulation.payouts,
+        remainder: simulation.remainder,
+    })
+}
+
+// Rewards of a denom in the records WithdrawRewards withdraws, the splitter is their rewards address
+fn outstanding_rewards(deps: Deps<ArchwayQuery>, env: &Env, denom: &str) -> StdResult<Uint128> {
+    let records: RewardsRecordsResponse =
+        deps.querier
+            .query(&QueryRequest::Custom(ArchwayQuery::RewardsRecords {
+                rewards_address: env.contract.address.to_string(),
+                pagination: None,
+            }))?;
+
+    records
+        .records
+        .iter()
+        .flat_map(|record| record.rewards.iter())
+        .filter(|reward| reward.denom == denom)
+        .try_fold(Uint128::zero(), |acc, reward| {
+            acc.checked_add(reward.amount)
+        })
+        .map_err(StdError::from)
+}
+
+// Looks up a distribution by id first, then by height
+pub fn query_distribution_at(
+    deps: Deps<ArchwayQuery>,
+    id: Option<u64>,
+    height: Option<u64>,
+) -> StdResult<Option<DistributionRecord>> {
+    let id = match (id, height) {
+        (Some(id), _) => Some(id),
+        (None, Some(height)) => DISTRIBUTION_HEIGHTS
+            .keys(
+                deps.storage,
+                None,
+                Some(Bound::inclusive((height, u64::MAX))),
+                Order::Descending,
+            )
+            .next()
+            .transpose()?
+            .map(|(_, id)| id),
+        (None, None) => return Err(StdError::generic_err("Either id or height must be set")),
+    };
+
+    match id {
+        Some(id) => DISTRIBUTION_HISTORY.may_load(deps.storage, id),
+        None => Ok(None),
+    }
+}
+
+pub fn query_receipts_by_recipient(
+    deps: Deps<ArchwayQuery>,
+    recipient: String,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Receipt>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    RECEIPTS
+        .prefix(recipient)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, receipt) = item?;
+            Ok(receipt)
+        })
+        .collect()
+}
+
+pub fn query_epoch_report(
+    deps: Deps<ArchwayQuery>,
+    epoch: u64,
+    start_after: Option<String>,
+    limit: Option<u8>,
+) -> StdResult<EpochReportResponse> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let totals = EPOCH_TOTALS
+        .prefix(epoch)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+
+    let recipients = EPOCH_PAYOUTS
+        .prefix(epoch)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (recipient, amounts) = item?;
+            Ok(EpochRecipientAmounts { recipient, amounts })
+        })
+        .collect::<StdResult<Vec<EpochRecipientAmounts>>>()?;
+
+    Ok(EpochReportResponse {
+        epoch: EPOCHS.may_load(deps.storage, epoch)?,
+        totals,
+        recipients,
+    })
+}
+
+// Counts the payouts distribution_msgs would create for the balance of every registered denom
+pub fn query_distribution_cost(
+    deps: Deps<ArchwayQuery>,
+    env: Env,
+) -> StdResult<DistributionCostResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let tiers = WATERFALL.may_load(deps.storage)?.unwrap_or_default();
+
+    let mut sends: u64 = 0;
+    let mut ibc_transfers: u64 = 0;
+    let mut recipients: BTreeSet<String> = BTreeSet::new();
+
+    let denoms = DENOMS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for denom in denoms {
+        let balance = deps
+            .querier
+            .query_balance(env.contract.address.clone(), denom.clone())?;
+        let reserved = RESERVED_BALANCES
+            .may_load(deps.storage, denom.clone())?
+            .unwrap_or_default();
+        let mut remaining = balance.amount.saturating_sub(reserved);
+        let min_distribution =
+            min_distribution(deps, &denom).map_err(|err| StdError::generic_err(err.to_string()))?;
+        if remaining.is_zero() || remaining < min_distribution {
+            continue;
+        }
+        let share_list = distribution_shares(deps, &env, &denom)?;
+        let priority_payments =
+            priority_payouts(deps, &denom).map_err(|err| StdError::generic_err(err.to_string()))?;
+
+        for payment in &priority_payments {
+            remaining = remaining.saturating_sub(payment.amount.amount);
+            sends += 1;
+            recipients.insert(payment.recipient.to_string());
+        }
+
+        for tier in tiers.iter().filter(|tier| tier.target.denom == denom) {
+            let amount = tier
+                .target
+                .amount
+                .saturating_sub(tier.filled)
+                .min(remaining);
+            if amount.is_zero() {
+                continue;
+            }
+            remaining -= amount;
+            sends += 1;
+            recipients.insert(tier.recipient.to_string());
+        }
+
+        for share in &share_list {
+            let amount =
+                remaining.multiply_ratio(share.percentage.atomics(), Decimal::one().atomics());
+            if amount.is_zero() {
+                continue;
+            }
+
+            if is_undeliverable(deps.storage, &env, &share.recipient)? {
+                if let Some(fallback_address) = &config.fallback_address {
+                    sends += 1;
+                    recipients.insert(fallback_address.to_string());
+                }
+                continue;
+            }
+
+            // Burn shares have no recipient
+            if share.burn {
+                sends += 1;
+                continue;
+            }
+
+            if let Some(address) = redirect_address(deps.storage, &share.recipient)? {
+                sends += 1;
+                recipients.insert(address.to_string());
+                continue;
+            }
+
+            // Carried over portions are not sent
+            if carries_over(deps.storage, &share.recipient, &denom, amount)? {
+                continue;
+            }
+
+            match &share.ibc {
+                Some(ibc) => {
+                    ibc_transfers += 1;
+                    recipients.insert(ibc.remote_address.clone());
+                }
+                None => {
+                    // Held portions are not sent
+                    if is_preferred_denom(deps.storage, &share.recipient, &denom)? {
+                        sends += 1;
+                        recipients.insert(share.recipient.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    let costs = DISTRIBUTION_GAS_COSTS
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let gas_estimate = costs
+        .base
+        .saturating_add(costs.per_send.saturating_mul(sends))
+        .saturating_add(costs.per_ibc_transfer.saturating_mul(ibc_transfers));
+
+    Ok(DistributionCostResponse {
+        sends,
+        ibc_transfers,
+        recipients: recipients.len() as u64,
+        gas_estimate,
+    })
+}
+
+// Replaces the shares paying pantheon splitters with the shares of those splitters
+// Splitters below MAX_NESTING_DEPTH levels are returned as they are
+pub fn query_flattened_shares(deps: Deps<ArchwayQuery>) -> StdResult<Vec<Share>> {
+    let share_list = shares()
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (_, share) = item?;
+            Ok(share)
+        })
+        .collect::<StdResult<Vec<Share>>>()?;
+
+    let mut leaves: Vec<Share> = vec![];
+    flatten_shares(deps, share_list, Decimal::one(), 0, &mut leaves)?;
+    leaves.sort_by(|a, b| a.recipient.cmp(&b.recipient));
+
+    Ok(leaves)
+}
+
+// Adds the shares scaled by the percentage of their splitter to the leaves
+// A recipient reached through several splitters gets a single leaf
+fn flatten_shares(
+    deps: Deps<ArchwayQuery>,
+    share_list: Vec<Share>,
+    scale: Decimal,
+    depth: u32,
+    leaves: &mut Vec<Share>,
+) -> StdResult<()> {
+    for mut share in share_list {
+        share.percentage = share.percentage.checked_mul(scale)?;
+
+        if depth < MAX_NESTING_DEPTH && share.ibc.is_none() && !share.burn {
+            let recipient = deps.api.addr_validate(&share.recipient)?;
+            if is_splitter(deps, &recipient) {
+                let nested = nested_shares(deps, &recipient)?;
+                flatten_shares(deps, nested, share.percentage, depth + 1, leaves)?;
+                continue;
+            }
+        }
+
+        match leaves.iter_mut().find(|leaf| {
+            leaf.recipient == share.recipient && leaf.ibc == share.ibc && leaf.burn == share.burn
+        }) {
+            Some(leaf) => leaf.percentage = leaf.percentage.checked_add(share.percentage)?,
+            None => leaves.push(share),
+        }
+    }
+
+    Ok(())
+}
+
+pub fn query_shares_by_percentage(
+    deps: Deps<ArchwayQuery>,
+    min: Option<Decimal>,
+    max: Option<Decimal>,
+    limit: Option<u8>,
+    order: Option<Order>,
+) -> StdResult<Vec<Share>> {
+    let limit = limit.unwrap_or(10) as usize;
+    // Largest shares come first by default
+    let order = order.unwrap_or(Order::Descending);
+    let min = min.map(|min| PrefixBound::inclusive(min.atomics().u128()));
+    let max = max.map(|max| PrefixBound::inclusive(max.atomics().u128()));
+
+    let share_list = shares()
+        .idx
+        .percentage
+        .prefix_range(deps.storage, min, max, order)
+        .take(limit)
+        .map(|item| {
+            let (_, share) = item?;
+            Ok(share)
+        })
+        .collect::<StdResult<Vec<Share>>>()?;
+
+    Ok(share_list)
+}
+
+// Used to validate that the total percentage does not exceed 100% and does not fall below 100%
+fn check_share_percentages(shares: &Vec<Share>) -> Result<(), ContractError> {
+    let total_percentage = shares.iter().try_fold(Decimal::zero(), |acc, share| {
+        acc.checked_add(share.percentage)
+    })?;
+
+    if total_percentage > Decimal::one() {
+        return Err(ContractError::PercentageLimitExceeded {});
+    };
+    if total_percentage < Decimal::one() {
+        return Err(ContractError::PercentageLimitNotMet {});
+    };
+
+    Ok(())
+}
+
+// Priority payments of a denom with the USD amounts converted to the denom
+fn priority_payouts(deps: Deps<ArchwayQuery>, denom: &str) -> Result<Vec<Payout>, ContractError> {
+    PRIORITY_PAYMENTS
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|payment| payment.amount.denom == denom)
+        .map(|payment| {
+            let amount = match payment.usd_amount {
+                Some(usd_amount) => usd_to_denom(deps, usd_amount, denom)?,
+                None => payment.amount.amount,
+            };
+            Ok(Payout {
+                recipient: payment.recipient,
+                amount: coin(amount.u128(), denom),
+            })
+        })
+        .collect()
+}
+
+// Minimum balance of a denom distributed by the native distributions, zero without one
+fn min_distribution(deps: Deps<ArchwayQuery>, denom: &str) -> Result<Uint128, ContractError> {
+    match MIN_DISTRIBUTIONS.may_load(deps.storage, denom.to_string())? {
+        Some(ThresholdAmount::Native(amount)) => Ok(amount),
+        Some(ThresholdAmount::Usd(usd_amount)) => usd_to_denom(deps, usd_amount, denom),
+        None => Ok(Uint128::zero()),
+    }
+}
+
+// Converts a USD amount to the denom with the oracle price of one display unit, rounding down
+fn usd_to_denom(
+    deps: Deps<ArchwayQuery>,
+    usd_amount: Decimal,
+    denom: &str,
+) -> Result<Uint128, ContractError> {
+    let oracle = PRICE_ORACLE
+        .may_load(deps.storage)?
+        .ok_or(ContractError::PriceOracleNotSet {})?;
+    let exponent = DENOMS.load(deps.storage, denom.to_string())?.exponent;
+
+    let price = query_price(&deps.querier, &oracle, denom)?;
+    if price.is_zero() {
+        return Err(ContractError::InvalidPrice {
+            denom: denom.to_string(),
+        });
+    }
+
+    Ok(Uint128::new(10)
+        .checked_pow(exponent)?
+        .checked_multiply_ratio(usd_amount.atomics(), price.atomics())?)
+}
+
+// Multiplies an amount by a share percentage, rounding down
+fn percentage_of(amount: Uint128, percentage: Decimal) -> Result<Uint128, ContractError> {
+    Ok(amount.checked_multiply_ratio(percentage.atomics(), Decimal::one().atomics())?)
+}
+
+// Used to validate that an IBC route has a channel, a remote address and a timeout
+fn check_ibc_route(ibc: &IbcRoute) -> Result<(), ContractError> {
+    if ibc.channel_id.is_empty() || ibc.remote_address.is_empty() || ibc.timeout_seconds == 0 {
+        return Err(ContractError::InvalidIbcRoute {});
+    }
 
     Ok(())
 }
@@ -0,0 +1,73 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_binary, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::msg::{CronInterval, ExecuteMsg};
+
+// Subset of the CronCat tasks and manager messages used by the splitter
+// The optional task fields are left out and default to none on the CronCat side
+#[cw_serde]
+enum TasksExecuteMsg {
+    CreateTask { task: Box<TaskRequest> },
+}
+
+#[cw_serde]
+enum ManagerExecuteMsg {
+    RefillTaskBalance { task_hash: String },
+}
+
+#[cw_serde]
+struct TaskRequest {
+    interval: CronInterval,
+    stop_on_fail: bool,
+    actions: Vec<Action>,
+}
+
+#[cw_serde]
+struct Action {
+    msg: CosmosMsg,
+    gas_limit: Option<u64>,
+}
+
+// Creates a task calling Cron on the splitter at every interval
+pub fn create_task_msg(
+    tasks_contract: String,
+    splitter: String,
+    interval: CronInterval,
+    gas_limit: Option<u64>,
+    funds: Vec<Coin>,
+) -> StdResult<WasmMsg> {
+    let action = Action {
+        msg: WasmMsg::Execute {
+            contract_addr: splitter,
+            msg: to_binary(&ExecuteMsg::Cron {})?,
+            funds: vec![],
+        }
+        .into(),
+        gas_limit,
+    };
+
+    Ok(WasmMsg::Execute {
+        contract_addr: tasks_contract,
+        msg: to_binary(&TasksExecuteMsg::CreateTask {
+            task: Box::new(TaskRequest {
+                interval,
+                stop_on_fail: false,
+                actions: vec![action],
+            }),
+        })?,
+        funds,
+    })
+}
+
+// Adds the funds to the gas balance of an existing task
+pub fn refill_task_msg(
+    manager_contract: String,
+    task_hash: String,
+    funds: Vec<Coin>,
+) -> StdResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: manager_contract,
+        msg: to_binary(&ManagerExecuteMsg::RefillTaskBalance { task_hash })?,
+        funds,
+    })
+}
@@ -1,4 +1,11 @@
-use cosmwasm_std::{DivideByZeroError, Instantiate2AddressError, StdError};
+use cosmwasm_std::{
+    CheckedMultiplyRatioError, Decimal, DivideByZeroError, Instantiate2AddressError, OverflowError,
+    StdError,
+};
+use cw2::VersionError;
+use cw_ownable::OwnershipError;
+use cw_utils::PaymentError;
+use pantheon_utils::RbacError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -12,18 +19,198 @@ pub enum ContractError {
     #[error("{0}")]
     DivideByZeroError(#[from] DivideByZeroError),
 
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("{0}")]
+    CheckedMultiplyRatioError(#[from] CheckedMultiplyRatioError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("{0}")]
+    Version(#[from] VersionError),
+
+    #[error("{0}")]
+    Ownership(#[from] OwnershipError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("Contract is paused")]
+    Paused {},
+
     #[error("Contract not mutable")]
     ContractNotMutable {},
 
+    #[error("Lock expiration is already expired")]
+    InvalidLockExpiration {},
+
     #[error("Percentage limit exceeded")]
     PercentageLimitExceeded {},
 
     #[error("Percentage limit not met")]
     PercentageLimitNotMet {},
 
+    #[error("Checksum of code id {code_id} does not match the pinned checksum")]
+    ChecksumMismatch { code_id: u64 },
+
+    #[error("A contract already exists at the predicted address {address}")]
+    AddressOccupied { address: String },
+
     #[error("Could not instantiate custom contract")]
     InstantiateError {},
+
+    #[error("Shares pay a splitter that pays this contract back")]
+    ShareCycle {},
+
+    #[error("Recipient already has a share")]
+    RecipientExists {},
+
+    #[error("No pending share transfer")]
+    NoPendingTransfer {},
+
+    #[error("Invalid IBC route")]
+    InvalidIbcRoute {},
+
+    #[error("Burn shares can not have an IBC route")]
+    InvalidBurnShare {},
+
+    #[error("Nothing to distribute")]
+    NothingToDistribute {},
+
+    #[error("Distribution exceeds the available balance")]
+    DistributionExceedsBalance {},
+
+    #[error("Invalid priority payment")]
+    InvalidPriorityPayment {},
+
+    #[error("Invalid waterfall tier")]
+    InvalidWaterfallTier {},
+
+    #[error("Slippage can not be more than 100%")]
+    InvalidSlippage {},
+
+    #[error("A price oracle is required for USD amounts")]
+    PriceOracleNotSet {},
+
+    #[error("Invalid price for {denom}")]
+    InvalidPrice { denom: String },
+
+    #[error("Invalid merkle root")]
+    InvalidMerkleRoot {},
+
+    #[error("Invalid merkle proof")]
+    InvalidMerkleProof {},
+
+    #[error("Already claimed")]
+    AlreadyClaimed {},
+
+    #[error("Insufficient balance")]
+    InsufficientBalance {},
+
+    #[error("Nothing to claim")]
+    NothingToClaim {},
+
+    #[error("Emergency withdrawals are disabled")]
+    EmergencyWithdrawDisabled {},
+
+    #[error("Nothing to withdraw")]
+    NothingToWithdraw {},
+
+    #[error("Rewards can not be withdrawn before height {height}")]
+    WithdrawalTooSoon { height: u64 },
+
+    #[error("Outstanding rewards are below {min_rewards}")]
+    RewardsBelowMinimum { min_rewards: String },
+
+    #[error("Refundable deposits are disabled")]
+    ClawbackDisabled {},
+
+    #[error("Claim deadline is not set")]
+    ClaimDeadlineNotSet {},
+
+    #[error("Claim deadline not reached, rewards can be claimed until {deadline}")]
+    ClaimDeadlineNotReached { deadline: String },
+
+    #[error("Nothing to forfeit")]
+    NothingToForfeit {},
+
+    #[error("Nothing to sweep")]
+    NothingToSweep {},
+
+    #[error("Registered denoms are distributed and cannot be swept")]
+    SweepRegisteredDenom {},
+
+    #[error("Not a x/tokenfactory denom")]
+    InvalidTokenFactoryDenom {},
+
+    #[error("Receipt denom already created")]
+    ReceiptDenomExists {},
+
+    #[error("Stream duration must be above zero")]
+    InvalidStreamDuration {},
+
+    #[error("Claim bounty above the maximum of {max}")]
+    InvalidClaimBounty { max: Decimal },
+
+    #[error("Clawback window of the deposit is closed")]
+    ClawbackWindowClosed {},
+
+    #[error("Invalid approval threshold")]
+    InvalidApprovalThreshold {},
+
+    #[error("No pending share update")]
+    NoPendingShareUpdate {},
+
+    #[error("No pending update of the approvers")]
+    NoPendingApproversUpdate {},
+
+    #[error("With share approvers the distributed shares can only be changed by UpdateShares")]
+    ShareApprovalRequired {},
+
+    #[error("Share update already confirmed")]
+    AlreadyConfirmed {},
+
+    #[error("No share authority is set")]
+    NoShareAuthority {},
+
+    #[error("Invalid public key")]
+    InvalidPublicKey {},
+
+    #[error("Invalid signature")]
+    InvalidSignature {},
+
+    #[error("Invalid nonce, expected {expected}")]
+    InvalidNonce { expected: u64 },
+
+    #[error("Import mode differs from the mode of the running import")]
+    ImportModeMismatch {},
+
+    #[error("No shares are staged for import")]
+    NoImport {},
+
+    #[error("Denom not registered")]
+    DenomNotRegistered {},
+
+    #[error("Deposits are not available while the distributions do not use the share table")]
+    DepositsUnavailable {},
+
+    #[error("Invalid listing price")]
+    InvalidPrice {},
+
+    #[error("Invalid IBC channel")]
+    InvalidIbcChannel {},
+
+    #[error("Invalid payment, expected {price}")]
+    InvalidPayment { price: String },
+}
+
+impl From<RbacError> for ContractError {
+    fn from(err: RbacError) -> Self {
+        match err {
+            RbacError::Std(err) => ContractError::Std(err),
+            RbacError::Unauthorized {} => ContractError::Unauthorized {},
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use cosmwasm_std::{Binary, Coin, CosmosMsg, Timestamp};
+use prost::Message;
+
+// IbcMsg::Transfer does not support memos, so transfers with a memo are sent as
+// a raw ICS-20 MsgTransfer
+const MSG_TRANSFER_TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
+
+#[derive(Clone, PartialEq, Message)]
+struct ProtoCoin {
+    #[prost(string, tag = "1")]
+    denom: String,
+    #[prost(string, tag = "2")]
+    amount: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct Height {
+    #[prost(uint64, tag = "1")]
+    revision_number: u64,
+    #[prost(uint64, tag = "2")]
+    revision_height: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MsgTransfer {
+    #[prost(string, tag = "1")]
+    source_port: String,
+    #[prost(string, tag = "2")]
+    source_channel: String,
+    #[prost(message, optional, tag = "3")]
+    token: Option<ProtoCoin>,
+    #[prost(string, tag = "4")]
+    sender: String,
+    #[prost(string, tag = "5")]
+    receiver: String,
+    #[prost(message, optional, tag = "6")]
+    timeout_height: Option<Height>,
+    #[prost(uint64, tag = "7")]
+    timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    memo: String,
+}
+
+// Builds an ICS-20 transfer with a memo from the transfer port of the given channel
+pub fn transfer_with_memo<T>(
+    channel_id: String,
+    sender: String,
+    receiver: String,
+    amount: Coin,
+    timeout: Timestamp,
+    memo: String,
+) -> CosmosMsg<T> {
+    let msg = MsgTransfer {
+        source_port: "transfer".to_string(),
+        source_channel: channel_id,
+        token: Some(ProtoCoin {
+            denom: amount.denom,
+            amount: amount.amount.to_string(),
+        }),
+        sender,
+        receiver,
+        timeout_height: None,
+        timeout_timestamp: timeout.nanos(),
+        memo,
+    };
+
+    CosmosMsg::Stargate {
+        type_url: MSG_TRANSFER_TYPE_URL.to_string(),
+        value: Binary::from(msg.encode_to_vec()),
+    }
+}
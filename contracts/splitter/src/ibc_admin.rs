@@ -0,0 +1,153 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, to_binary, Binary, DepsMut, Env, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcOrder, IbcPacket,
+    IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, StdResult,
+};
+
+use crate::contract::{is_mutable, registry_distribution_msgs, replace_shares};
+use crate::error::ContractError;
+use crate::msg::{SplitterAck, SplitterPacketMsg};
+use crate::state::{CONFIG, PAUSED};
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+
+pub const IBC_VERSION: &str = "pantheon-splitter-1";
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    check_channel(msg.channel(), msg.counterparty_version())?;
+    Ok(None)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse<ArchwayMsg>, ContractError> {
+    let channel = msg.channel();
+    check_channel(channel, msg.counterparty_version())?;
+
+    // Packets are only accepted once the admin adds the channel as a controller
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_connect")
+        .add_attribute("channel_id", channel.endpoint.channel_id.clone())
+        .add_attribute("port_id", channel.counterparty_endpoint.port_id.clone()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse<ArchwayMsg>, ContractError> {
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "ibc_channel_close")
+        .add_attribute("channel_id", msg.channel().endpoint.channel_id.clone()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse<ArchwayMsg>, ContractError> {
+    // Failed packets are acknowledged with an error instead of aborting the transaction
+    match receive_packet(deps, env, msg.packet) {
+        Ok(response) => Ok(response),
+        Err(err) => Ok(IbcReceiveResponse::new()
+            .set_ack(to_binary(&SplitterAck::Error(err.to_string()))?)
+            .add_attribute("action", "ibc_packet_receive")
+            .add_attribute("error", err.to_string())),
+    }
+}
+
+// The splitter does not send packets, so acknowledgements and timeouts are ignored
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse<ArchwayMsg>, ContractError> {
+    Ok(IbcBasicResponse::new())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse<ArchwayMsg>, ContractError> {
+    Ok(IbcBasicResponse::new())
+}
+
+fn receive_packet(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    packet: IbcPacket,
+) -> Result<IbcReceiveResponse<ArchwayMsg>, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if PAUSED.may_load(deps.storage)?.unwrap_or_default() {
+        return Err(ContractError::Paused {});
+    }
+
+    // Packets are authenticated by the local channel and the port of the sender
+    let authorized = config.ibc_controllers.iter().any(|controller| {
+        controller.channel_id == packet.dest.channel_id && controller.port_id == packet.src.port_id
+    });
+    if !authorized {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let response = IbcReceiveResponse::new()
+        .set_ack(ack_success()?)
+        .add_attribute("channel_id", packet.dest.channel_id);
+
+    match from_binary(&packet.data)? {
+        SplitterPacketMsg::UpdateShares { shares } => {
+            if !is_mutable(&config, &env.block) {
+                return Err(ContractError::ContractNotMutable {});
+            }
+
+            replace_shares(deps, &env, shares)?;
+
+            Ok(response.add_attribute("action", "ibc_update_shares"))
+        }
+        SplitterPacketMsg::DistributeNativeTokens {} => {
+            let (msgs, events) = registry_distribution_msgs(deps, &env)?;
+
+            Ok(response
+                .add_submessages(msgs)
+                .add_events(events)
+                .add_attribute("action", "ibc_distribute_native_tokens"))
+        }
+    }
+}
+
+fn check_channel(
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    if channel.order != IbcOrder::Unordered || channel.version != IBC_VERSION {
+        return Err(ContractError::InvalidIbcChannel {});
+    }
+
+    if let Some(version) = counterparty_version {
+        if version != IBC_VERSION {
+            return Err(ContractError::InvalidIbcChannel {});
+        }
+    }
+
+    Ok(())
+}
+
+fn ack_success() -> StdResult<Binary> {
+    to_binary(&SplitterAck::Result(Binary::default()))
+}
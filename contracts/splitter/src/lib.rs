@@ -1,6 +1,19 @@
+// The splitter can be embedded in other contracts with the library feature, which
+// disables the entry points:
+// - call contract::instantiate from the instantiate entry point of the host contract
+// - forward splitter messages to contract::execute, or call the execute_* and query_*
+//   handlers directly for a subset of the features
+// - route replies with an id from contract::PAYOUT_REPLY_ID_START to contract::reply
+// The splitter state uses the namespaces of the keys in the state module, the host
+// contract must not use them for its own state
 pub mod contract;
+mod croncat;
 mod error;
+mod ibc;
+pub mod ibc_admin;
 pub mod msg;
+mod simulation;
 pub mod state;
+mod tokenfactory;
 
 pub use crate::error::ContractError;
@@ -1,10 +1,22 @@
 // use archway_bindings::Coins;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, HexBinary, Order, Uint128};
+use cw2::ContractVersion;
+use cw20::{Cw20Coin, Cw20ReceiveMsg};
+use cw4::MemberChangedHookMsg;
+use cw721::Cw721ReceiveMsg;
+use cw_ownable::{cw_ownable_execute, cw_ownable_query};
+use cw_utils::{Duration, Expiration};
 
 use pantheon_utils::Share;
 
-use crate::state::Config;
+use crate::state::{
+    AuditEntry, ClaimDeadline, ClaimDelegation, Config, Cw20Source, Cw20Stream, DenomInfo,
+    DepositRecord, DistributionGasCosts, DistributionRecord, EpochInfo, ExpiredSharePolicy,
+    IbcController, ImportMode, Listing, MerkleDistribution, PayoutRedirect, PendingApproversUpdate,
+    PendingDeposit, PendingShareUpdate, PriorityPayment, Receipt, ReceiptDenom, ShareApprovers,
+    ShareCap, ThresholdAmount, WaterfallTier, WithdrawalPolicy,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -12,54 +24,958 @@ pub struct InstantiateMsg {
     pub shares: Vec<Share>,
     // If true, the contract will not allow any more shares to be added
     pub mutable: bool,
+    // If true, share transfers started by recipients need to be approved by the admin
+    pub transfer_requires_approval: bool,
+    // If true, the owner can never withdraw the balances with EmergencyWithdraw
+    #[serde(default)]
+    pub disable_emergency_withdraw: bool,
+    // State exported by another splitter, see InstantiateMsg::from_export
+    #[serde(default)]
+    pub restore: Option<RestoredState>,
 }
 
+impl InstantiateMsg {
+    // Instantiates a copy of the splitter that produced the export
+    // The export must hold the shares of every page, the accounting counters are only kept with preserve_counters
+    pub fn from_export(admin: String, export: StateExport, preserve_counters: bool) -> Self {
+        InstantiateMsg {
+            admin,
+            shares: export.shares,
+            mutable: export.config.mutable,
+            transfer_requires_approval: export.config.transfer_requires_approval,
+            disable_emergency_withdraw: export.config.emergency_withdraw_disabled,
+            restore: Some(RestoredState {
+                config: export.config,
+                denoms: export.denoms,
+                counters: preserve_counters.then_some(export.counters),
+            }),
+        }
+    }
+}
+
+// The mutability and the flags of the instantiate message take precedence over the restored config
+#[cw_serde]
+pub struct RestoredState {
+    pub config: Config,
+    pub denoms: Vec<DenomInfo>,
+    pub counters: Option<ExportCounters>,
+}
+
+// UpdateOwnership is added by cw_ownable to transfer, accept or renounce the ownership
+#[cw_ownable_execute]
 #[cw_serde]
 pub enum ExecuteMsg {
     // Update shares in the contract
+    // With share approvers, the update is only proposed and applies once enough approvers confirm it
     UpdateShares {
         shares: Vec<Share>,
     },
+    // Set the addresses confirming the share updates and how many confirmations are needed
+    // An empty list applies the share updates immediately again
+    // Once approvers are set, a change of them is only proposed and applies once enough of them confirm it
+    UpdateShareApprovers {
+        approvers: Vec<String>,
+        threshold: u32,
+    },
+    // Confirm the pending share update as an approver
+    ConfirmShareUpdate {},
+    // Confirm the pending change of the approvers as a current approver
+    ConfirmApproversUpdate {},
+    // Drop the pending share update
+    CancelShareUpdate {},
+    // Set the secp256k1 public key whose signatures authorize share updates, None removes it
+    UpdateShareAuthority {
+        pubkey: Option<Binary>,
+    },
+    // Update the shares with a signature of the share authority over the ShareUpdatePayload
+    // Anyone can submit it, e.g. a hot operational key for a cold stored authority
+    UpdateSharesWithSignature {
+        shares: Vec<Share>,
+        nonce: u64,
+        signature: Binary,
+    },
+    // Stage a batch of shares, e.g. to move a large share table from another contract
+    // The batches are only validated when the import is committed
+    ImportShares {
+        shares: Vec<Share>,
+        mode: ImportMode,
+    },
+    // Validate the staged shares and apply them with the mode of the import
+    CommitImport {},
+    // Drop the staged shares
+    CancelImport {},
+    // Replace the share table of a denom, an empty table restores the default shares for the denom
+    UpdateDenomShares {
+        denom: String,
+        shares: Vec<Share>,
+    },
     // Instantiate a new contract as admin
     // The new contract's owner and reward address will be this contract
     AddCustomContract {
         code_id: u64,
         msg: Binary,
     },
+    // Instantiate a new splitter of the same code with the share table of this contract
+    Clone {
+        new_admin: String,
+        overrides: CloneOverrides,
+    },
+    // Link a cw4 group, the distributions then pay its members pro-rata to their weights
+    // Unlinking the group restores the share table
+    UpdateGroup {
+        group: Option<String>,
+    },
+    // Sent by the linked group when its members change, the share table is rebuilt from the weights
+    MemberChangedHook(MemberChangedHookMsg),
+    // Set the time or height at which the share of a recipient expires, None makes it permanent
+    UpdateShareExpiration {
+        recipient: String,
+        expires_at: Option<Expiration>,
+    },
+    // Set what happens to the percentage of expired shares
+    UpdateExpiredSharePolicy {
+        policy: ExpiredSharePolicy,
+    },
+    // Set the address receiving the payouts that fail, the portions of frozen recipients
+    // and, with the fallback policy, the portions of expired shares
+    UpdateFallbackAddress {
+        address: Option<String>,
+    },
+    // Withhold or release the payouts of a recipient
+    // Like the fallback address and the expired share policy, only allowed while mutable
+    UpdateFrozenRecipient {
+        recipient: String,
+        frozen: bool,
+    },
+    // Set the cumulative payout cap of a recipient in a denom, None removes the cap
+    // Portions above the cap go to the overflow address, or stay in the contract for the next distribution
+    UpdateShareCap {
+        recipient: String,
+        denom: String,
+        cap: Option<Uint128>,
+    },
+    // Set the minimum payout of a recipient in a denom, None removes it
+    // Smaller portions are carried over and paid with a later portion of the recipient
+    UpdateMinPayout {
+        recipient: String,
+        denom: String,
+        amount: Option<Uint128>,
+    },
+    // Set the address receiving the portions of capped recipients
+    UpdateOverflowAddress {
+        address: Option<String>,
+    },
+    // Set the gas costs used to estimate the cost of a distribution
+    UpdateDistributionGasCosts {
+        costs: DistributionGasCosts,
+    },
+    // Set the swap adapter swapping the balances to a single denom before the distributions
+    // None distributes every denom as it is
+    UpdateSwapConfig {
+        swap: Option<SwapConfigMsg>,
+    },
+    // Set the price oracle converting the USD amounts, None disables them
+    UpdatePriceOracle {
+        oracle: Option<String>,
+    },
+    // Set the minimum balance of a denom distributed by the native distributions, None removes it
+    UpdateMinDistribution {
+        denom: String,
+        amount: Option<ThresholdAmount>,
+    },
+    // Replace the fixed payments deducted from every distribution before the percentage split
+    // Distributions of a denom fail while the balance does not cover its payments
+    UpdatePriorityPayments {
+        payments: Vec<PriorityPaymentMsg>,
+    },
+    // Replace the waterfall tiers, distributions fill them in order before splitting the overflow
+    // Replacing the tiers resets the filled amounts
+    UpdateWaterfall {
+        tiers: Vec<WaterfallTierMsg>,
+    },
+    // Allow an address to withdraw the rewards and to run the distributions
+    AddOperator {
+        operator: String,
+    },
+    // Revoke the operational permissions of an address
+    RemoveOperator {
+        operator: String,
+    },
+    // Add an address to a role
+    GrantRole {
+        role: String,
+        address: String,
+    },
+    // Remove an address from a role
+    RevokeRole {
+        role: String,
+        address: String,
+    },
+    // Replace the actions the members of a role are permitted to run
+    // Actions are named after the execute messages, e.g. update_share_cap
+    UpdateRoleActions {
+        role: String,
+        actions: Vec<String>,
+    },
+    // Set the automation agent allowed to call Cron
+    UpdateCronAgent {
+        agent: Option<String>,
+    },
+    // Distribute the balance of every registered denom, only callable by the cron agent
+    Cron {},
+    // Create a CronCat task calling Cron, the sent funds pay for the gas of the task
+    CreateCronTask {
+        tasks_contract: String,
+        interval: CronInterval,
+        gas_limit: Option<u64>,
+    },
+    // Refill the gas balance of a CronCat task with the sent funds
+    RefillCronTask {
+        manager_contract: String,
+        task_hash: String,
+    },
+    // Pin the expected checksum of a code id, AddCustomContract rejects any other code
+    PinCodeChecksum {
+        code_id: u64,
+        checksum: HexBinary,
+    },
+    // Remove the pinned checksum of a code id
+    UnpinCodeChecksum {
+        code_id: u64,
+    },
     // Updates the custom contract's owner and reward address
     UpdateCustomContractRewardMetadata {
         address: String,
         owner_address: Option<String>,
         rewards_address: Option<String>,
     },
+    // Set the premium paid by every transaction calling the splitter, a zero amount removes it
+    // The splitter must own its reward metadata
+    UpdatePremium {
+        fee: Coin,
+    },
     // Set the mutable flag on this contract
-    LockContract {},
+    // A lock with an expiration makes the contract mutable again once it expires, e.g. after vesting
+    LockContract {
+        until: Option<Expiration>,
+    },
+    // Move the sender's share to a new recipient address
+    TransferShare {
+        new_recipient: String,
+    },
+    // Approve a pending share transfer of a recipient
+    ApproveShareTransfer {
+        recipient: String,
+    },
+    // Move the sender's share and accounting to a new address of the same recipient
+    // Unlike TransferShare, the balances, caps and settings move along
+    // Waits for ApproveShareTransfer like a transfer when transfers require approval
+    RotateAddress {
+        new_address: String,
+    },
+    // Split the sender's slice between sub-recipients, an empty list pays the sender again
+    // The percentages of the sub-shares are relative to the slice and must add up to 100%
+    UpdateSubShares {
+        shares: Vec<Share>,
+    },
+    // Replace the controllers allowed to send administration packets
+    UpdateIbcControllers {
+        controllers: Vec<IbcController>,
+    },
+    // Add a denom to the distributed denoms or update its metadata
+    RegisterDenom {
+        denom: String,
+        display: String,
+        exponent: u32,
+    },
+    // Add a x/tokenfactory denom to the distributed denoms with the metadata registered in x/bank
+    RegisterTokenFactoryDenom {
+        denom: String,
+    },
+    // Create the x/tokenfactory denom factory/{splitter}/{subdenom} with the splitter as admin
+    // Every deposit of the deposit denom mints the same amount of receipt tokens to its sender
+    CreateReceiptDenom {
+        subdenom: String,
+        deposit_denom: String,
+    },
+    // Stop distributing a denom
+    RemoveDenom {
+        denom: String,
+    },
+    // Send the balance of a denom to an address, only callable by the owner
+    // Rescues funds stuck by a misconfigured share table or a failing recipient, also while paused
+    // but not while the contract is locked
+    // Deposited rewards not claimed yet are reserved for the recipients and are not withdrawn
+    EmergencyWithdraw {
+        denom: String,
+        to: String,
+    },
     // Withdraw rewards for the contract
+    // The withdrawal policy can require a minimum of outstanding rewards or of blocks since the last one
     WithdrawRewards {},
-    // Distribute native tokens to all shares
+    // Set the limits of the reward withdrawals, None removes them
+    UpdateWithdrawalPolicy {
+        policy: Option<WithdrawalPolicy>,
+    },
+    // Distribute the balance of every registered denom to all shares
     DistributeNativeTokens {},
+    // Close the current epoch, the next distributions are reported in a new epoch
+    CloseEpoch {},
+    // Sent by the contract to itself once the balances are swapped to the target denom
+    DistributeSwappedTokens {},
+    // Claim the payouts that could not be sent to the sender during a distribution
+    ClaimFailedPayouts {},
+    // Set the denoms the sender is paid in, the portions in other native denoms are held
+    // An empty list accepts every denom
+    UpdatePreferredDenoms {
+        denoms: Vec<String>,
+    },
+    // Claim the portions held in the denoms the sender does not prefer
+    ClaimHeldBalances {},
+    // Claim the portions carried over because they were below the minimum payout of the sender
+    ClaimCarriedBalances {},
+    // Opt the sender out of its payouts or pay them to another address, None pays the sender again
+    UpdatePayoutRedirect {
+        redirect: Option<PayoutRedirectMsg>,
+    },
+    // Deposit the sent funds as rewards claimed individually by the recipients
+    // Unlike DistributeNativeTokens, no message is sent to the recipients
+    // The sender and the memo are recorded in the deposit history
+    // Rewards accrue by the share table, so it fails with a group, shares of the denom,
+    // share expirations or sub-shares
+    Deposit {
+        memo: Option<String>,
+    },
+    // Claim the deposited rewards of the sender, every claim renews the claim deadline of the sender
+    ClaimRewards {},
+    // Claim the deposited rewards of a recipient on its behalf, callable by anyone
+    // The rewards are paid to the recipient, minus the claim bounty paid to the sender
+    ClaimFor {
+        recipient: String,
+    },
+    // Authorize a delegate to claim the deposited rewards of the sender, None revokes the delegate
+    UpdateClaimDelegation {
+        delegation: Option<ClaimDelegationMsg>,
+    },
+    // Claim the deposited rewards of a recipient as its delegate
+    // The rewards are paid to the payout address of the delegation, or to the recipient without one
+    ClaimAsDelegate {
+        recipient: String,
+    },
+    // Set the part of the claimed rewards paid to the sender of ClaimFor, None disables the bounty
+    UpdateClaimBounty {
+        bounty: Option<Decimal>,
+    },
+    // Set the period recipients have to claim their deposited rewards, None disables the deadline
+    // Changing the period restarts the deadline of every recipient
+    UpdateClaimDeadline {
+        period: Option<Duration>,
+    },
+    // Forfeit the deposited rewards a recipient did not claim before its deadline
+    // The rewards are paid to the fallback address, or distributed again without one
+    ForfeitUnclaimed {
+        recipient: String,
+    },
+    // Set the address receiving the swept balances, None deposits them for the recipients
+    UpdateSweepAddress {
+        address: Option<String>,
+    },
+    // Move the balance of a denom that is not registered and not reserved for anyone to the
+    // sweep address, only while the contract is mutable and emergency withdrawals are enabled
+    // Without a sweep address the balance is deposited and claimable like the deposits
+    Sweep {
+        denom: String,
+    },
+    // Set the window in which refundable deposits can be reclaimed, None disables them
+    UpdateClawbackWindow {
+        window: Option<Duration>,
+    },
+    // Deposit the sent funds for the distributions once the clawback window ends
+    // Until then the sender can reclaim them, e.g. after sending to the wrong contract
+    DepositRefundable {},
+    // Send a refundable deposit back to its sender during the clawback window
+    ReclaimDeposit {
+        id: u64,
+    },
+    // Set the senders whose deposits are distributed, None accepts deposits from anyone
+    // Deposits of other senders are held until their sender reclaims them
+    UpdateDepositAllowlist {
+        senders: Option<Vec<String>>,
+    },
+    // Reserve an amount of the contract balance for the recipients of a merkle tree
+    // The leaves are the sha256 hashes of the recipient address followed by the amount
+    RegisterMerkleDistribution {
+        merkle_root: HexBinary,
+        amount: Coin,
+    },
+    // Claim the amount of the sender in a merkle distribution
+    ClaimMerkleDistribution {
+        id: u64,
+        amount: Uint128,
+        proof: Vec<HexBinary>,
+    },
+    // Receive CW20 tokens sent with Cw20ExecuteMsg::Send
+    Receive(Cw20ReceiveMsg),
+    // Distribute the held balance of a CW20 token to all shares
+    // The revenue of the sources of the token is pulled and distributed with it
+    DistributeCw20 {
+        token: String,
+    },
+    // Set the owners pulled by DistributeCw20, each owner must give the splitter an allowance
+    // The pulled amount is the allowance, or the balance of the owner when it is lower
+    UpdateCw20Sources {
+        sources: Vec<Cw20SourceMsg>,
+    },
+    // Stream the held balance of a CW20 token to all shares over a duration in seconds
+    // The part of a running stream that did not vest yet is streamed again with the balance
+    StreamCw20 {
+        token: String,
+        duration: u64,
+    },
+    // Claim the tokens of a CW20 stream vested for the sender so far
+    ClaimStreamed {
+        token: String,
+    },
+    // Receive an NFT sent with Cw721ExecuteMsg::SendNft and list it for sale
+    ReceiveNft(Cw721ReceiveMsg),
+    // Buy a listed NFT, the paid price is distributed to all shares
+    BuyNft {
+        collection: String,
+        token_id: String,
+    },
+    // Remove a listing and send the NFT to the recipient, defaults to the admin
+    DelistNft {
+        collection: String,
+        token_id: String,
+        recipient: Option<String>,
+    },
+}
+
+#[cw_serde]
+pub struct DenomMetadataResponse {
+    pub denom: String,
+    pub display: String,
+    pub exponent: u32,
+    pub name: String,
+    pub symbol: String,
+    pub description: String,
+}
+
+#[cw_serde]
+pub struct Cw20SourceMsg {
+    pub token: String,
+    pub owner: String,
+}
+
+#[cw_serde]
+pub struct ClaimDelegationMsg {
+    pub delegate: String,
+    pub payout_address: Option<String>,
+}
+
+#[cw_serde]
+pub enum PayoutRedirectMsg {
+    OptOut,
+    Address { address: String },
+}
+
+#[cw_serde]
+pub struct SwapConfigMsg {
+    // Contract implementing the pantheon-swap-adapter interface
+    pub adapter: String,
+    pub target_denom: String,
+    pub max_slippage: Decimal,
+}
+
+// Settings of the clone that differ from this contract
+#[cw_serde]
+#[derive(Default)]
+pub struct CloneOverrides {
+    pub mutable: Option<bool>,
+    pub transfer_requires_approval: Option<bool>,
+    pub label: Option<String>,
+}
+
+// Recipient and fixed amount of a priority payment
+#[cw_serde]
+pub struct PriorityPaymentMsg {
+    pub recipient: String,
+    pub amount: Coin,
+    // Pays the USD value in the denom of the amount instead, converted with the price oracle
+    #[serde(default)]
+    pub usd_amount: Option<Decimal>,
+}
+
+// Recipient and fixed target amount of a waterfall tier
+#[cw_serde]
+pub struct WaterfallTierMsg {
+    pub recipient: String,
+    pub target: Coin,
+}
+
+// Interval of a CronCat task
+#[cw_serde]
+pub enum CronInterval {
+    Once,
+    Immediate,
+    Block(u64),
+    Cron(String),
 }
 
+// Deployments with the legacy layout are converted to the current layout by the migration
+// Only deployments of the splitter with an older or the same version can be migrated
+#[cw_serde]
+pub struct MigrateMsg {}
+
+// Messages sent by the chain, e.g. by a governance proposal when the admin key is lost
+#[cw_serde]
+pub enum SudoMsg {
+    // Distribute the balance of every registered denom, also while paused
+    DistributeNativeTokens {},
+    // Reject every execute message until unpaused
+    Pause {},
+    Unpause {},
+    // Replace the admin of the contract
+    UpdateAdmin { admin: String },
+}
+
+// Message attached to a CW20 send to the splitter
+#[cw_serde]
+pub enum Cw20HookMsg {
+    // Distribute the received tokens right away
+    Distribute {},
+    // Hold the received tokens for a later DistributeCw20 call
+    Hold {},
+    // Stream the received tokens to all shares over a duration in seconds, like StreamCw20
+    // Only accepted from the CW20 sources or from senders allowed to run StreamCw20
+    Stream { duration: u64 },
+}
+
+// Message attached to an NFT send to the splitter
+#[cw_serde]
+pub enum Cw721HookMsg {
+    // List the received NFT for a fixed price
+    List { price: Coin },
+}
+
+// Packets sent by a controller on another chain
+#[cw_serde]
+pub enum SplitterPacketMsg {
+    UpdateShares { shares: Vec<Share> },
+    DistributeNativeTokens {},
+}
+
+// Acknowledgement written for every received packet
+#[cw_serde]
+pub enum SplitterAck {
+    Result(Binary),
+    Error(String),
+}
+
+// Ownership is added by cw_ownable to return the owner and the pending ownership transfer
+#[cw_ownable_query]
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
-    // Returns the contract config
-    #[returns(Config)]
+    // Returns the contract config with a summary of the shares
+    #[returns(ConfigResponse)]
     Config {},
     // Returns all the shares
-    #[returns(Vec<Share>)]
+    // Order defaults to ascending, descending pages start from the end of the table
+    #[returns(SharesResponse)]
     Shares {
         start_after: Option<String>,
         limit: Option<u8>,
+        order: Option<Order>,
+    },
+    // Returns the share table of a denom, empty when the denom uses the default shares
+    #[returns(Vec<Share>)]
+    DenomShares { denom: String },
+    // Returns the denoms with their own share table
+    #[returns(Vec<String>)]
+    SharesDenoms {},
+    // Returns the sub-shares splitting the slice of a recipient
+    #[returns(Vec<Share>)]
+    SubShares { recipient: String },
+    // Returns the shares as they were at the beginning of the block at the given height
+    #[returns(Vec<Share>)]
+    SharesAt {
+        height: u64,
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+    // Returns the shares with a percentage between min and max, both inclusive
+    // Order defaults to descending so the largest shares come first
+    #[returns(Vec<Share>)]
+    SharesByPercentage {
+        min: Option<Decimal>,
+        max: Option<Decimal>,
+        limit: Option<u8>,
+        order: Option<Order>,
+    },
+    // Returns the percentage every final recipient gets once the shares paying
+    // other pantheon splitters are expanded, nested splitters are followed up to 3 levels
+    #[returns(Vec<Share>)]
+    FlattenedShares {},
+    // Returns a past native distribution by id, or the last one at or before the height
+    #[returns(Option<DistributionRecord>)]
+    DistributionAt {
+        id: Option<u64>,
+        height: Option<u64>,
+    },
+    // Returns the payouts of a recipient, oldest first
+    #[returns(Vec<Receipt>)]
+    ReceiptsByRecipient {
+        recipient: String,
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+    // Returns the epoch the next distribution belongs to
+    #[returns(u64)]
+    CurrentEpoch {},
+    // Returns the totals of an epoch and a page of the amounts paid to its recipients
+    #[returns(EpochReportResponse)]
+    EpochReport {
+        epoch: u64,
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+    // Returns the payouts of a distribution of the amount with the current shares and settings
+    // Nothing is sent and the state is not changed
+    #[returns(SimulateDistributionResponse)]
+    SimulateDistribution { amount: Uint128, denom: String },
+    // Returns the payouts of a denom if the rewards were withdrawn and distributed now
+    #[returns(EstimatedPayoutsResponse)]
+    EstimatedPayouts { denom: String },
+    // Returns the messages the next native distribution would emit and an estimate of its gas
+    #[returns(DistributionCostResponse)]
+    DistributionCost {},
+    // Returns the price oracle converting the USD amounts
+    #[returns(Option<Addr>)]
+    PriceOracle {},
+    // Returns the minimum balance of a denom distributed by the native distributions
+    #[returns(Option<ThresholdAmount>)]
+    MinDistribution { denom: String },
+    // Returns the admin changes, oldest first
+    #[returns(Vec<AuditEntry>)]
+    AuditLog {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+    // Returns the recorded deposits with their source and memo, oldest first
+    #[returns(Vec<DepositRecord>)]
+    DepositHistory {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+    // Returns the total deposited by a source
+    #[returns(Vec<Coin>)]
+    DepositTotals { sender: String },
+    // Returns the operators allowed to trigger the operational actions
+    #[returns(Vec<Addr>)]
+    Operators {},
+    // Returns the members of a role
+    #[returns(Vec<Addr>)]
+    RoleMembers { role: String },
+    // Returns the actions permitted to a role
+    #[returns(Vec<String>)]
+    RoleActions { role: String },
+    // Returns the config, the accounting state and a page of the shares for backups
+    #[returns(StateExport)]
+    ExportState {
+        start_after: Option<String>,
+        limit: Option<u8>,
     },
     // Returns a single share for an address
     #[returns(Share)]
     Share { recipient: String },
+    // Returns the limits of the reward withdrawals
+    #[returns(Option<WithdrawalPolicy>)]
+    WithdrawalPolicy {},
+    // Returns the window in which refundable deposits can be reclaimed
+    #[returns(Option<Duration>)]
+    ClawbackWindow {},
+    // Returns the senders whose deposits are distributed
+    #[returns(Option<Vec<Addr>>)]
+    DepositAllowlist {},
+    // Returns the refundable deposits that are not distributable yet
+    #[returns(Vec<PendingDeposit>)]
+    PendingDeposits {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+    // Returns the addresses confirming the share updates
+    #[returns(Option<ShareApprovers>)]
+    ShareApprovers {},
+    // Returns the share update waiting for the confirmations of the approvers
+    #[returns(Option<PendingShareUpdate>)]
+    PendingShareUpdate {},
+    // Returns the change of the approvers waiting for the confirmations of the current approvers
+    #[returns(Option<PendingApproversUpdate>)]
+    PendingApproversUpdate {},
+    // Returns the public key of the share authority and the nonce of its next signature
+    #[returns(ShareAuthorityResponse)]
+    ShareAuthority {},
+    // Returns the mode and the staged shares of the running import
+    #[returns(ImportResponse)]
+    Import {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Returns the pending share transfer of a recipient
+    #[returns(Option<Addr>)]
+    PendingShareTransfer { recipient: String },
+    // Returns the expiration of the share of a recipient
+    #[returns(Option<Expiration>)]
+    ShareExpiration { recipient: String },
+    // Returns whether a recipient opted out of its payouts or redirects them
+    #[returns(Option<PayoutRedirect>)]
+    PayoutRedirect { recipient: String },
+    // Returns the payout cap of a recipient in a denom with the amount paid so far
+    #[returns(Option<ShareCap>)]
+    ShareCap { recipient: String, denom: String },
+    // Returns the minimum payout of a recipient in a denom
+    #[returns(Option<Uint128>)]
+    MinPayout { recipient: String, denom: String },
+    // Returns the portions carried over for a recipient
+    #[returns(Vec<Coin>)]
+    CarriedBalances { recipient: String },
+    // Returns the priority payments
+    #[returns(Vec<PriorityPayment>)]
+    PriorityPayments {},
+    // Returns the waterfall tiers with their filled amounts
+    #[returns(Vec<WaterfallTier>)]
+    Waterfall {},
+    // Returns a merkle distribution
+    #[returns(MerkleDistribution)]
+    MerkleDistribution { id: u64 },
+    // Returns whether a recipient claimed its amount in a merkle distribution
+    #[returns(bool)]
+    MerkleClaimed { id: u64, recipient: String },
+    // Returns the pinned checksum of a code id
+    #[returns(Option<HexBinary>)]
+    CodeChecksum { code_id: u64 },
+    // Returns the registered denoms distributed to the shares
+    #[returns(Vec<DenomInfo>)]
+    SupportedDenoms {},
+    // Returns the metadata registered in x/bank for a denom, e.g. a x/tokenfactory denom
+    #[returns(DenomMetadataResponse)]
+    DenomMetadata { denom: String },
+    // Returns the receipt denom minted to the depositors
+    #[returns(Option<ReceiptDenom>)]
+    ReceiptDenom {},
+    // Returns the failed payouts claimable by a recipient
+    #[returns(Vec<Coin>)]
+    FailedPayouts { recipient: String },
+    // Returns the denoms a recipient is paid in, empty when every denom is accepted
+    #[returns(Vec<String>)]
+    PreferredDenoms { recipient: String },
+    // Returns the portions held for a recipient in the denoms it does not prefer
+    #[returns(Vec<Coin>)]
+    HeldBalances { recipient: String },
+    // Returns the amounts paid to the fallback address instead of a recipient
+    #[returns(Vec<Coin>)]
+    RedirectedPayouts { recipient: String },
+    // Returns the deposited rewards claimable by a recipient
+    #[returns(Vec<Coin>)]
+    PendingRewards { recipient: String },
+    // Returns the address receiving the swept balances
+    #[returns(Option<Addr>)]
+    SweepAddress {},
+    // Returns the period recipients have to claim their deposited rewards
+    #[returns(Option<ClaimDeadline>)]
+    ClaimDeadline {},
+    // Returns the part of the claimed rewards paid to the sender of ClaimFor
+    #[returns(Option<Decimal>)]
+    ClaimBounty {},
+    // Returns the delegate allowed to claim the deposited rewards of a recipient
+    #[returns(Option<ClaimDelegation>)]
+    ClaimDelegation { recipient: String },
+    // Returns the recipients with unclaimed deposited rewards and their claim deadline
+    // Empty without a claim deadline
+    #[returns(Vec<AtRiskBalance>)]
+    AtRiskBalances {
+        start_after: Option<String>,
+        limit: Option<u8>,
+    },
+    // Returns the held balance of every received CW20 token
+    #[returns(Vec<Cw20Coin>)]
+    Cw20Balances {},
+    // Returns the owners pulled by DistributeCw20
+    #[returns(Vec<Cw20Source>)]
+    Cw20Sources {},
+    // Returns the stream of a CW20 token
+    #[returns(Option<Cw20Stream>)]
+    Cw20Stream { token: String },
+    // Returns the tokens of a CW20 stream claimable by a recipient now
+    #[returns(Uint128)]
+    StreamedRewards { recipient: String, token: String },
+    // Returns the listing of an NFT
+    #[returns(Listing)]
+    Listing {
+        collection: String,
+        token_id: String,
+    },
+    // Returns all the NFT listings
+    #[returns(Vec<Listing>)]
+    Listings {
+        start_after: Option<(String, String)>,
+        limit: Option<u8>,
+    },
     // // Returns the outstanding rewards in the contract
     // #[returns(OutstandingRewardsResponse)]
     // OutstandingRewards {},
 }
 
+#[cw_serde]
+pub struct ConfigResponse {
+    pub config: Config,
+    // Locked contracts can not update their shares
+    pub locked: bool,
+    pub paused: bool,
+    pub share_count: u64,
+    pub total_percentage: Decimal,
+    // Native denoms distributed to the shares
+    pub denoms: Vec<String>,
+    // Block height of the last change to the shares
+    pub last_share_update: u64,
+    pub version: ContractVersion,
+    pub premium: Option<Coin>,
+}
+
+#[cw_serde]
+pub struct ImportResponse {
+    pub mode: Option<ImportMode>,
+    pub shares: Vec<Share>,
+}
+
+#[cw_serde]
+pub struct ShareAuthorityResponse {
+    pub pubkey: Option<Binary>,
+    pub nonce: u64,
+}
+
+// Signed by the share authority, the signature is over the sha256 hash of its JSON encoding
+// The chain and the contract are part of it so a signature can not be replayed elsewhere
+#[cw_serde]
+pub struct ShareUpdatePayload {
+    pub chain_id: String,
+    pub contract: String,
+    pub nonce: u64,
+    pub shares: Vec<Share>,
+}
+
+#[cw_serde]
+pub struct SharesResponse {
+    pub shares: Vec<Share>,
+    // Pass as start_after to get the next page, None when the last page is returned
+    pub next_start_after: Option<String>,
+    pub total_count: u64,
+}
+
+#[cw_serde]
+pub enum SimulatedPayout {
+    Send {
+        recipient: Addr,
+        amount: Uint128,
+    },
+    IbcTransfer {
+        recipient: String,
+        remote_address: String,
+        amount: Uint128,
+    },
+    Burn {
+        recipient: String,
+        amount: Uint128,
+    },
+    Held {
+        recipient: String,
+        amount: Uint128,
+    },
+    CarriedOver {
+        recipient: String,
+        amount: Uint128,
+    },
+}
+
+#[cw_serde]
+pub struct SimulateDistributionResponse {
+    pub payouts: Vec<SimulatedPayout>,
+    // Rounding dust and redirected portions staying in the contract
+    pub remainder: Uint128,
+}
+
+#[cw_serde]
+pub struct AtRiskBalance {
+    pub recipient: Addr,
+    pub amounts: Vec<Coin>,
+    pub deadline: Expiration,
+    // The rewards can be forfeited
+    pub expired: bool,
+}
+
+#[cw_serde]
+pub struct EpochRecipientAmounts {
+    pub recipient: String,
+    pub amounts: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct EpochReportResponse {
+    // None for the epochs that did not start yet
+    pub epoch: Option<EpochInfo>,
+    pub totals: Vec<Coin>,
+    pub recipients: Vec<EpochRecipientAmounts>,
+}
+
+#[cw_serde]
+pub struct EstimatedPayoutsResponse {
+    // Balance available for distributions, without the reserved balances
+    pub balance: Uint128,
+    // Rewards of the records waiting to be withdrawn by the splitter
+    pub outstanding_rewards: Uint128,
+    pub payouts: Vec<SimulatedPayout>,
+    pub remainder: Uint128,
+}
+
+// Share caps are not applied, so the estimate is an upper bound when a recipient reached its cap
+#[cw_serde]
+pub struct DistributionCostResponse {
+    // Bank sends and burns of every registered denom with a balance
+    pub sends: u64,
+    pub ibc_transfers: u64,
+    // Distinct local and remote addresses receiving a payout
+    pub recipients: u64,
+    pub gas_estimate: u64,
+}
+
+// Versioned export of the contract state
+// An identical splitter can be instantiated from the config and the shares
+#[cw_serde]
+pub struct StateExport {
+    pub format_version: u32,
+    pub contract_version: ContractVersion,
+    pub config: Config,
+    pub last_share_update: u64,
+    pub cw20_balances: Vec<Cw20Coin>,
+    pub shares: Vec<Share>,
+    pub next_start_after: Option<String>,
+    pub total_count: u64,
+    // Added in format version 2
+    #[serde(default)]
+    pub denoms: Vec<DenomInfo>,
+    #[serde(default)]
+    pub counters: ExportCounters,
+}
+
+// Ids of the last distribution and deposit records
+#[cw_serde]
+#[derive(Default)]
+pub struct ExportCounters {
+    pub last_distribution_id: u64,
+    pub last_deposit_record_id: u64,
+}
+
 // #[cw_serde]
 // pub struct OutstandingRewardsResponse {
 //     pub rewards_balance: Coins,
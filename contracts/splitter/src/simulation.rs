@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Order, Record, Storage};
+
+// Storage keeping the writes in memory on top of read-only storage
+// Used by the queries running the distribution logic without changing the contract state
+pub(crate) struct SimulatedStorage<'a> {
+    storage: &'a dyn Storage,
+    // Removed keys are kept as None so they hide the value of the underlying storage
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> SimulatedStorage<'a> {
+    pub(crate) fn new(storage: &'a dyn Storage) -> Self {
+        SimulatedStorage {
+            storage,
+            writes: BTreeMap::new(),
+        }
+    }
+}
+
+impl<'a> Storage for SimulatedStorage<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.writes.get(key) {
+            Some(value) => value.clone(),
+            None => self.storage.get(key),
+        }
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        let mut records: BTreeMap<Vec<u8>, Vec<u8>> =
+            self.storage.range(start, end, Order::Ascending).collect();
+
+        // Start is inclusive and end is exclusive
+        let in_range = |key: &[u8]| {
+            start.map_or(true, |start| key >= start) && end.map_or(true, |end| key < end)
+        };
+        for (key, value) in self.writes.iter().filter(|(key, _)| in_range(key)) {
+            match value {
+                Some(value) => records.insert(key.clone(), value.clone()),
+                None => records.remove(key),
+            };
+        }
+
+        match order {
+            Order::Ascending => Box::new(records.into_iter()),
+            Order::Descending => Box::new(records.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes.insert(key.to_vec(), None);
+    }
+}
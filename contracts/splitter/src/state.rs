@@ -1,15 +1,598 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Item, Map};
-use pantheon_utils::Share;
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Decimal256, Empty, HexBinary, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedSnapshotMap, Item, Map, MultiIndex, Strategy};
+use cw_utils::{Duration, Expiration};
+use pantheon_utils::{Rbac, Share};
 
-// Contract configuration
+// Contract configuration, the owner is stored by cw_ownable
 #[cw_serde]
 pub struct Config {
-    pub admin: Addr,
     pub mutable: bool,
+    // End of a timed lock, the contract is mutable again once it expires
+    pub locked_until: Option<Expiration>,
+    // Set at instantiation for trustless deployments, it can not be changed afterwards
+    #[serde(default)]
+    pub emergency_withdraw_disabled: bool,
+    pub transfer_requires_approval: bool,
+    // Controllers on other chains allowed to administrate the contract over IBC
+    pub ibc_controllers: Vec<IbcController>,
+    // Automation agent allowed to trigger distributions with Cron, e.g. the CronCat manager
+    pub cron_agent: Option<Addr>,
+    // cw4 group whose member weights replace the share table in the distributions
+    pub group: Option<Addr>,
+    // Receives the portions of recipients that reached their payout cap
+    pub overflow_address: Option<Addr>,
+    // What happens to the percentage of expired shares
+    pub expired_share_policy: ExpiredSharePolicy,
+    // Receives the payouts that could not be delivered to their recipient
+    pub fallback_address: Option<Addr>,
+}
+
+#[cw_serde]
+pub enum ExpiredSharePolicy {
+    // The percentage is split between the active shares pro-rata to their percentages
+    Redistribute,
+    // The percentage stays in the contract
+    Retain,
+    // The portion is paid to the fallback address, or stays in the contract without one
+    Fallback,
+}
+
+// A controller is identified by the local channel and the port of the remote contract
+#[cw_serde]
+pub struct IbcController {
+    pub channel_id: String,
+    pub port_id: String,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
-pub const SHARES: Map<Addr, Share> = Map::new("shares");
+// Layout of the deployments from before the owner moved to cw_ownable
+// Only read by the migration, which converts it to the current layout
+#[cw_serde]
+pub struct LegacyConfig {
+    pub admin: Addr,
+    pub mutable: bool,
+}
+
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+
+// Plain map of the legacy shares, it uses the primary namespace of the snapshot map
+pub const LEGACY_SHARES: Map<Addr, Share> = Map::new("shares");
+
+pub struct ShareIndexes<'a> {
+    pub percentage: MultiIndex<'a, u128, Share, Addr>,
+}
+
+impl<'a> IndexList<Share> for ShareIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Share>> + '_> {
+        let v: Vec<&dyn Index<Share>> = vec![&self.percentage];
+        Box::new(v.into_iter())
+    }
+}
+
+// Shares keyed by the recipient and indexed by the percentage atomics
+// Every change is recorded so the shares can be loaded at a past height
+pub fn shares<'a>() -> IndexedSnapshotMap<'a, Addr, Share, ShareIndexes<'a>> {
+    let indexes = ShareIndexes {
+        percentage: MultiIndex::new(
+            |_pk: &[u8], share: &Share| share.percentage.atomics().u128(),
+            "shares",
+            "shares__percentage",
+        ),
+    };
+    IndexedSnapshotMap::new(
+        "shares",
+        "shares__checkpoints",
+        "shares__changelog",
+        Strategy::EveryBlock,
+        indexes,
+    )
+}
+
+// Share tables replacing the default shares for a denom, keyed by the denom and the recipient
+// CW20 tokens use the token address as denom
+pub const DENOM_SHARES: Map<(String, Addr), Share> = Map::new("denom_shares");
+
+// Split of the slice of a recipient between its sub-recipients, managed by the recipient
+// The percentages are relative to the slice and add up to 100%
+pub const SUB_SHARES: Map<Addr, Vec<Share>> = Map::new("sub_shares");
+
+// Every recipient that ever had a share, used to list the shares at a past height
+pub const SHARE_RECIPIENTS: Map<Addr, Empty> = Map::new("share_recipients");
+
+// Native denom distributed to the shares
+// Display and exponent are the metadata used by front-ends to format amounts
+#[cw_serde]
+pub struct DenomInfo {
+    pub denom: String,
+    pub display: String,
+    pub exponent: u32,
+}
+
+pub const DENOMS: Map<String, DenomInfo> = Map::new("denoms");
+
+// x/tokenfactory denom administered by the splitter, minted 1:1 to the depositors of a denom
+#[cw_serde]
+pub struct ReceiptDenom {
+    pub denom: String,
+    pub deposit_denom: String,
+}
+
+pub const RECEIPT_DENOM: Item<ReceiptDenom> = Item::new("receipt_denom");
+
+// Local payout of a distribution, saved under the reply id of its bank send
+#[cw_serde]
+pub struct Payout {
+    pub recipient: Addr,
+    pub amount: Coin,
+}
+
+pub const PAYOUTS: Map<u64, Payout> = Map::new("payouts");
+
+#[cw_serde]
+pub enum PayoutKind {
+    Send,
+    IbcTransfer { remote_address: String },
+    Burn,
+    // Held in the contract because the recipient prefers other denoms
+    Held,
+    // Kept for the next distribution because it is below the minimum payout of the recipient
+    CarriedOver,
+}
+
+// Payout of a recorded distribution
+#[cw_serde]
+pub struct DistributionPayout {
+    pub recipient: String,
+    pub amount: Uint128,
+    pub kind: PayoutKind,
+    // Reply id of the bank send, local payouts only
+    pub reply_id: Option<u64>,
+    // Set by the reply when the bank send fails
+    pub failed: bool,
+}
+
+// Native distribution of the balance of a denom
+#[cw_serde]
+pub struct DistributionRecord {
+    pub id: u64,
+    pub height: u64,
+    pub time: Timestamp,
+    pub amount: Coin,
+    // Shares used by the distribution, expired shares are already skipped or scaled
+    pub shares: Vec<Share>,
+    pub payouts: Vec<DistributionPayout>,
+    // Rounding dust and redirected portions staying in the contract
+    pub remainder: Uint128,
+    // Deposits distributed for the first time by the distribution
+    #[serde(default)]
+    pub sources: Vec<DepositSource>,
+    // Distributions before the epochs were introduced belong to epoch 0
+    #[serde(default)]
+    pub epoch: u64,
+}
+
+// Part of a distribution coming from a recorded deposit
+#[cw_serde]
+pub struct DepositSource {
+    pub deposit_id: u64,
+    pub sender: Addr,
+    pub amount: Uint128,
+}
+
+// Deposit recorded with its source for a revenue by source breakdown
+#[cw_serde]
+pub struct DepositRecord {
+    pub id: u64,
+    pub sender: Addr,
+    pub funds: Vec<Coin>,
+    pub memo: Option<String>,
+    pub height: u64,
+    pub time: Timestamp,
+}
+
+pub const DEPOSIT_HISTORY: Map<u64, DepositRecord> = Map::new("deposit_history");
+pub const LAST_DEPOSIT_RECORD_ID: Item<u64> = Item::new("last_deposit_record_id");
+
+// Total deposited by every source and denom
+pub const DEPOSIT_TOTALS: Map<(Addr, String), Uint128> = Map::new("deposit_totals");
+
+// Released refundable deposits waiting for the next distribution of their denom
+pub const UNDISTRIBUTED_DEPOSITS: Map<(String, u64), DepositSource> =
+    Map::new("undistributed_deposits");
+
+pub const DISTRIBUTION_HISTORY: Map<u64, DistributionRecord> = Map::new("distribution_history");
+pub const LAST_DISTRIBUTION_ID: Item<u64> = Item::new("last_distribution_id");
+
+// Distribution ids by height, several distributions can happen at the same height
+pub const DISTRIBUTION_HEIGHTS: Map<(u64, u64), Empty> = Map::new("distribution_heights");
+
+// Period the distributions are reported by, e.g. a month of revenue
+// An epoch starts with its first distribution or when the previous one is closed
+#[cw_serde]
+pub struct EpochInfo {
+    pub id: u64,
+    pub start_height: u64,
+    pub start_time: Timestamp,
+    // Set once the admin closes the epoch
+    pub end_height: Option<u64>,
+    pub end_time: Option<Timestamp>,
+    pub distributions: u64,
+}
+
+pub const CURRENT_EPOCH: Item<u64> = Item::new("current_epoch");
+pub const EPOCHS: Map<u64, EpochInfo> = Map::new("epochs");
+
+// Amount distributed in every epoch and denom, including burned and carried over portions
+pub const EPOCH_TOTALS: Map<(u64, String), Uint128> = Map::new("epoch_totals");
+
+// Amounts paid to every recipient in an epoch, held payouts included
+pub const EPOCH_PAYOUTS: Map<(u64, String), Vec<Coin>> = Map::new("epoch_payouts");
+
+// Payout of a distribution to a recipient, counted like the amounts of the epochs
+#[cw_serde]
+pub struct Receipt {
+    pub id: u64,
+    pub distribution_id: u64,
+    pub epoch: u64,
+    pub amount: Coin,
+    pub kind: PayoutKind,
+    pub height: u64,
+}
+
+// Receipts by recipient and id, the ids are shared by all recipients
+pub const RECEIPTS: Map<(String, u64), Receipt> = Map::new("receipts");
+pub const LAST_RECEIPT_ID: Item<u64> = Item::new("last_receipt_id");
+
+// Length of the window in which refundable deposits can be reclaimed by their sender
+// Refundable deposits are not accepted without a window
+pub const CLAWBACK_WINDOW: Item<Duration> = Item::new("clawback_window");
+
+// Senders whose deposits are distributed, deposits of other senders are held
+pub const DEPOSIT_ALLOWLIST: Item<Vec<Addr>> = Item::new("deposit_allowlist");
+
+// Deposit its sender can reclaim until the end of the clawback window
+// Held deposits of senders outside the allowlist never expire
+// The funds are reserved until the window ends, the next distribution includes them afterwards
+#[cw_serde]
+pub struct PendingDeposit {
+    pub id: u64,
+    pub sender: Addr,
+    pub funds: Vec<Coin>,
+    pub reclaimable_until: Expiration,
+}
+
+pub const PENDING_DEPOSITS: Map<u64, PendingDeposit> = Map::new("pending_deposits");
+pub const LAST_DEPOSIT_ID: Item<u64> = Item::new("last_deposit_id");
+
+// Admin change recorded in the audit log
+// Only the changed fields are kept, e.g. the shares of the recipients touched by update_shares
+#[cw_serde]
+pub struct AuditEntry {
+    pub id: u64,
+    pub sender: Addr,
+    pub height: u64,
+    pub time: Timestamp,
+    pub action: String,
+    pub changes: Vec<AuditChange>,
+}
+
+// Old and new JSON value of a changed field, None when the field was added or removed
+#[cw_serde]
+pub struct AuditChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+pub const AUDIT_LOG: Map<u64, AuditEntry> = Map::new("audit_log");
+pub const LAST_AUDIT_ID: Item<u64> = Item::new("last_audit_id");
+
+// Roles allowed to run some of the admin actions, the actions are named after the execute messages
+pub const RBAC: Rbac = Rbac::new("role_members", "role_permissions");
+
+// Addresses allowed to trigger the operational actions next to the admin, e.g. the keys of bots
+// Operators can withdraw the rewards and run the distributions but not change the configuration
+pub const OPERATOR_ROLE: &str = "operator";
+pub const OPERATOR_ACTIONS: [&str; 3] = [
+    "withdraw_rewards",
+    "distribute_native_tokens",
+    "distribute_cw20",
+];
+
+// Recipients whose payouts are withheld, their portions go to the fallback address
+pub const FROZEN_RECIPIENTS: Map<Addr, Empty> = Map::new("frozen_recipients");
+
+// Choice of a recipient to give up its portions or to have them paid to another address
+#[cw_serde]
+pub enum PayoutRedirect {
+    // The portions are handled like the ones of a frozen recipient
+    OptOut,
+    // The portions are paid to the address, e.g. a charity, instead of the recipient
+    Address { address: Addr },
+}
+
+pub const PAYOUT_REDIRECTS: Map<Addr, PayoutRedirect> = Map::new("payout_redirects");
+
+// Amounts paid to the fallback address instead of the recipient, keyed by the recipient and the denom
+pub const REDIRECTED_PAYOUTS: Map<(Addr, String), Uint128> = Map::new("redirected_payouts");
+
+// Amounts of failed payouts claimable by the recipient, keyed by the recipient and the denom
+pub const FAILED_PAYOUTS: Map<(Addr, String), Uint128> = Map::new("failed_payouts");
+
+// Denoms a recipient wants to be paid in, the other denoms are held until claimed
+pub const PREFERRED_DENOMS: Map<Addr, Vec<String>> = Map::new("preferred_denoms");
+
+// Portions held in the denoms a recipient does not prefer, keyed by the recipient and the denom
+pub const HELD_BALANCES: Map<(Addr, String), Uint128> = Map::new("held_balances");
+
+// Total of the failed payouts per denom, excluded from the distributions
+pub const RESERVED_BALANCES: Map<String, Uint128> = Map::new("reserved_balances");
+
+// Deposited rewards per unit of share, accumulated since the first deposit of each denom
+pub const ACC_PER_SHARE: Map<String, Decimal256> = Map::new("acc_per_share");
+
+// Accumulated rewards per share at the last settlement of a recipient, keyed by the recipient and the denom
+pub const REWARD_INDEXES: Map<(Addr, String), Decimal256> = Map::new("reward_indexes");
+
+// Settled rewards waiting to be claimed, keyed by the recipient and the denom
+pub const UNCLAIMED_REWARDS: Map<(Addr, String), Decimal256> = Map::new("unclaimed_rewards");
+
+// Period a recipient has to claim its deposited rewards, the unclaimed rewards are forfeited afterwards
+#[cw_serde]
+pub struct ClaimDeadline {
+    pub period: Duration,
+    // Deadline of the recipients that did not claim since the period was set
+    pub initial_deadline: Expiration,
+}
+
+pub const CLAIM_DEADLINE: Item<ClaimDeadline> = Item::new("claim_deadline");
+
+// Deadlines renewed by the claims and forfeitures of the recipients
+pub const RECIPIENT_CLAIM_DEADLINES: Map<Addr, Expiration> = Map::new("recipient_claim_deadlines");
+
+// Receives the swept balances, without it the balances are deposited for the recipients
+pub const SWEEP_ADDRESS: Item<Addr> = Item::new("sweep_address");
+
+// Part of the claimed rewards paid to the keeper claiming them on behalf of a recipient
+pub const CLAIM_BOUNTY: Item<Decimal> = Item::new("claim_bounty");
+
+// Address allowed to claim the deposited rewards of a recipient, e.g. a custody service
+// The claims of the delegate are paid to the payout address, or to the recipient without one
+#[cw_serde]
+pub struct ClaimDelegation {
+    pub delegate: Addr,
+    pub payout_address: Option<Addr>,
+}
+
+pub const CLAIM_DELEGATIONS: Map<Addr, ClaimDelegation> = Map::new("claim_delegations");
+
+// Expected checksums of the code ids instantiated with AddCustomContract
+pub const CODE_CHECKSUMS: Map<u64, HexBinary> = Map::new("code_checksums");
+
+// Limits of the reward withdrawals, so gas is not spent withdrawing trivial amounts
+#[cw_serde]
+pub struct WithdrawalPolicy {
+    // Outstanding rewards needed to withdraw
+    pub min_rewards: Option<Coin>,
+    // Blocks between two withdrawals
+    pub min_interval: Option<u64>,
+}
+
+pub const WITHDRAWAL_POLICY: Item<WithdrawalPolicy> = Item::new("withdrawal_policy");
+pub const LAST_WITHDRAWAL_HEIGHT: Item<u64> = Item::new("last_withdrawal_height");
+
+// Premium paid on top of the gas fees by the transactions calling the splitter
+pub const PREMIUM: Item<Coin> = Item::new("premium");
+
+// Paused contracts reject every execute message and packet
+pub const PAUSED: Item<bool> = Item::new("paused");
+
+// Cumulative payout cap of a recipient in a denom, e.g. the size of a loan paid back from revenue
+#[cw_serde]
+pub struct ShareCap {
+    pub cap: Uint128,
+    pub paid: Uint128,
+}
+
+// Keyed by the share recipient and the denom
+pub const SHARE_CAPS: Map<(String, String), ShareCap> = Map::new("share_caps");
+
+// Minimum payout of a recipient in a denom, keyed by the share recipient and the denom
+pub const MIN_PAYOUTS: Map<(String, String), Uint128> = Map::new("min_payouts");
+
+// Portions below the minimum payout, added to the next portion of the recipient in the denom
+pub const CARRIED_BALANCES: Map<(String, String), Uint128> = Map::new("carried_balances");
+
+// Fixed amount paid to a recipient by every distribution of the denom, before any other payout
+// With a USD amount the paid amount is converted at every distribution and the coin only sets the denom
+#[cw_serde]
+pub struct PriorityPayment {
+    pub recipient: Addr,
+    pub amount: Coin,
+    #[serde(default)]
+    pub usd_amount: Option<Decimal>,
+}
+
+// Stored apart from the shares, the percentage split applies to what is left after the payments
+pub const PRIORITY_PAYMENTS: Item<Vec<PriorityPayment>> = Item::new("priority_payments");
+
+// Tier of the waterfall, paid in order until the target is filled
+#[cw_serde]
+pub struct WaterfallTier {
+    pub recipient: Addr,
+    pub target: Coin,
+    pub filled: Uint128,
+}
+
+// The fixed tiers are filled before the percentage shares, which act as the final tier
+pub const WATERFALL: Item<Vec<WaterfallTier>> = Item::new("waterfall");
+
+// Claimable distribution of a merkle tree of (address, amount) pairs, funded by the contract balance
+#[cw_serde]
+pub struct MerkleDistribution {
+    pub merkle_root: HexBinary,
+    pub amount: Coin,
+    pub claimed: Uint128,
+}
+
+pub const MERKLE_DISTRIBUTIONS: Map<u64, MerkleDistribution> = Map::new("merkle_distributions");
+pub const LAST_MERKLE_DISTRIBUTION_ID: Item<u64> = Item::new("last_merkle_distribution_id");
+
+// Recipients that claimed their amount, keyed by the distribution id and the recipient
+pub const MERKLE_CLAIMS: Map<(u64, Addr), Empty> = Map::new("merkle_claims");
+
+// Block height of the last change to the shares
+pub const LAST_SHARE_UPDATE: Item<u64> = Item::new("last_share_update");
+
+// Shares granted for a limited time, skipped by the distributions once expired
+pub const SHARE_EXPIRATIONS: Map<Addr, Expiration> = Map::new("share_expirations");
+
+// Addresses confirming the share updates of the admin before they apply
+#[cw_serde]
+pub struct ShareApprovers {
+    pub approvers: Vec<Addr>,
+    pub threshold: u32,
+}
+
+pub const SHARE_APPROVERS: Item<ShareApprovers> = Item::new("share_approvers");
+
+// Share update waiting for the confirmations of the approvers
+// A new proposal replaces it and drops its confirmations
+#[cw_serde]
+pub struct PendingShareUpdate {
+    pub shares: Vec<Share>,
+    pub proposer: Addr,
+    pub confirmations: Vec<Addr>,
+}
+
+pub const PENDING_SHARE_UPDATE: Item<PendingShareUpdate> = Item::new("pending_share_update");
+
+// Change of the approvers waiting for the confirmations of the current approvers
+// None removes the approvers, a new proposal replaces it and drops its confirmations
+#[cw_serde]
+pub struct PendingApproversUpdate {
+    pub approvers: Option<ShareApprovers>,
+    pub proposer: Addr,
+    pub confirmations: Vec<Addr>,
+}
+
+pub const PENDING_APPROVERS_UPDATE: Item<PendingApproversUpdate> =
+    Item::new("pending_approvers_update");
+
+// How the staged shares of an import are applied to the share table
+#[cw_serde]
+pub enum ImportMode {
+    // The staged shares are added to the current shares, replacing the shares of the same recipients
+    Append,
+    // The staged shares replace the share table
+    Replace,
+}
+
+// Mode set by the first batch of an import, the next batches must use the same mode
+pub const IMPORT_MODE: Item<ImportMode> = Item::new("import_mode");
+// Shares staged by the import batches, keyed by the recipient as sent
+pub const IMPORTED_SHARES: Map<String, Share> = Map::new("imported_shares");
+
+// secp256k1 public key whose off-chain signatures authorize share updates
+pub const SHARE_AUTHORITY: Item<Binary> = Item::new("share_authority");
+// Nonce of the next signed share update, it increases with every update so signatures are used once
+pub const SHARE_AUTHORITY_NONCE: Item<u64> = Item::new("share_authority_nonce");
+
+// Share transfers waiting for admin approval, keyed by the current recipient
+pub const PENDING_TRANSFERS: Map<Addr, Addr> = Map::new("pending_transfers");
+
+// Pending transfers requested with RotateAddress, the accounting of the recipient moves along once approved
+pub const PENDING_ROTATIONS: Map<Addr, Empty> = Map::new("pending_rotations");
+
+// Held balances of the CW20 tokens received by the contract
+pub const CW20_BALANCES: Map<Addr, Uint128> = Map::new("cw20_balances");
+
+// Treasury keeping its CW20 revenue until the distribution, the splitter pulls it with TransferFrom
+#[cw_serde]
+pub struct Cw20Source {
+    pub token: Addr,
+    pub owner: Addr,
+}
+
+pub const CW20_SOURCES: Item<Vec<Cw20Source>> = Item::new("cw20_sources");
+
+// CW20 balance vesting linearly to the shares between the start and the end of the stream
+#[cw_serde]
+pub struct Cw20Stream {
+    pub amount: Uint128,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    // Part of the amount vested at the last checkpoint
+    pub vested: Uint128,
+}
+
+pub const CW20_STREAMS: Map<Addr, Cw20Stream> = Map::new("cw20_streams");
+
+// Vested tokens per unit of share, accumulated since the first stream of each token
+pub const STREAM_ACC_PER_SHARE: Map<Addr, Decimal256> = Map::new("stream_acc_per_share");
+
+// Vested tokens per share at the last settlement of a recipient, keyed by the recipient and the token
+pub const STREAM_INDEXES: Map<(Addr, Addr), Decimal256> = Map::new("stream_indexes");
+
+// Settled vested tokens waiting to be claimed, keyed by the recipient and the token
+pub const UNCLAIMED_STREAMS: Map<(Addr, Addr), Decimal256> = Map::new("unclaimed_streams");
+
+// Gas used by a distribution, the estimates of the DistributionCost query are based on it
+#[cw_serde]
+pub struct DistributionGasCosts {
+    // Gas of the distribution without any payout
+    pub base: u64,
+    // Gas of every bank send or burn
+    pub per_send: u64,
+    pub per_ibc_transfer: u64,
+}
+
+impl Default for DistributionGasCosts {
+    fn default() -> Self {
+        DistributionGasCosts {
+            base: 150_000,
+            per_send: 25_000,
+            per_ibc_transfer: 60_000,
+        }
+    }
+}
+
+pub const DISTRIBUTION_GAS_COSTS: Item<DistributionGasCosts> = Item::new("distribution_gas_costs");
+
+// Balances of the registered denoms are swapped to the target denom before the distributions
+#[cw_serde]
+pub struct SwapConfig {
+    pub adapter: Addr,
+    pub target_denom: String,
+    // The swap fails when it returns less than the quote reduced by the slippage
+    pub max_slippage: Decimal,
+}
+
+pub const SWAP_CONFIG: Item<SwapConfig> = Item::new("swap_config");
+
+// Contract implementing the pantheon-price-oracle interface, used to convert the USD amounts
+pub const PRICE_ORACLE: Item<Addr> = Item::new("price_oracle");
+
+// Amount in the distributed denom, or in USD converted with the price oracle when it is used
+#[cw_serde]
+pub enum ThresholdAmount {
+    Native(Uint128),
+    Usd(Decimal),
+}
+
+// Balances of a denom below its minimum are kept until a later distribution
+pub const MIN_DISTRIBUTIONS: Map<String, ThresholdAmount> = Map::new("min_distributions");
+
+// Fixed price listing of an NFT sent to the contract
+#[cw_serde]
+pub struct Listing {
+    pub collection: Addr,
+    pub token_id: String,
+    pub donor: Addr,
+    pub price: Coin,
+}
+
+// Listed NFTs, keyed by the collection and the token id
+pub const LISTINGS: Map<(Addr, String), Listing> = Map::new("listings");
@@ -0,0 +1,136 @@
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::{Binary, Coin, CosmosMsg, QuerierWrapper, QueryRequest, StdResult};
+use prost::Message;
+use serde::Deserialize;
+
+use crate::msg::DenomMetadataResponse;
+
+// x/tokenfactory has no CosmWasm bindings on Archway, so its messages are sent as raw
+// Stargate messages and the denom metadata is queried from x/bank
+const MSG_CREATE_DENOM_TYPE_URL: &str = "/osmosis.tokenfactory.v1beta1.MsgCreateDenom";
+const MSG_MINT_TYPE_URL: &str = "/osmosis.tokenfactory.v1beta1.MsgMint";
+const DENOM_METADATA_PATH: &str = "/cosmos.bank.v1beta1.Query/DenomMetadata";
+
+// Denoms created with x/tokenfactory are named factory/{creator}/{subdenom}
+pub const TOKEN_FACTORY_PREFIX: &str = "factory";
+
+#[derive(Clone, PartialEq, Message)]
+struct ProtoCoin {
+    #[prost(string, tag = "1")]
+    denom: String,
+    #[prost(string, tag = "2")]
+    amount: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    sender: String,
+    #[prost(string, tag = "2")]
+    subdenom: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MsgMint {
+    #[prost(string, tag = "1")]
+    sender: String,
+    #[prost(message, optional, tag = "2")]
+    amount: Option<ProtoCoin>,
+    #[prost(string, tag = "3")]
+    mint_to_address: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct QueryDenomMetadataRequest {
+    #[prost(string, tag = "1")]
+    denom: String,
+}
+
+// Stargate query responses are returned as proto3 JSON
+#[derive(Deserialize)]
+struct QueryDenomMetadataResponse {
+    metadata: Metadata,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    denom_units: Vec<DenomUnit>,
+    base: String,
+    #[serde(default)]
+    display: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    symbol: String,
+}
+
+#[derive(Deserialize)]
+struct DenomUnit {
+    denom: String,
+    #[serde(default)]
+    exponent: u32,
+}
+
+// Returns true for denoms created with x/tokenfactory
+pub fn is_token_factory_denom(denom: &str) -> bool {
+    let parts: Vec<&str> = denom.splitn(3, '/').collect();
+    parts.len() == 3
+        && parts[0] == TOKEN_FACTORY_PREFIX
+        && !parts[1].is_empty()
+        && !parts[2].is_empty()
+}
+
+// Creates the denom factory/{sender}/{subdenom}, the sender becomes its admin
+pub fn create_denom_msg<T>(sender: String, subdenom: String) -> CosmosMsg<T> {
+    CosmosMsg::Stargate {
+        type_url: MSG_CREATE_DENOM_TYPE_URL.to_string(),
+        value: Binary::from(MsgCreateDenom { sender, subdenom }.encode_to_vec()),
+    }
+}
+
+// Mints an amount of a denom administered by the sender to an address
+pub fn mint_msg<T>(sender: String, amount: Coin, mint_to_address: String) -> CosmosMsg<T> {
+    let msg = MsgMint {
+        sender,
+        amount: Some(ProtoCoin {
+            denom: amount.denom,
+            amount: amount.amount.to_string(),
+        }),
+        mint_to_address,
+    };
+
+    CosmosMsg::Stargate {
+        type_url: MSG_MINT_TYPE_URL.to_string(),
+        value: Binary::from(msg.encode_to_vec()),
+    }
+}
+
+// The exponent is the one of the display unit, 0 when the display unit is not listed
+pub fn query_denom_metadata(
+    querier: &QuerierWrapper<ArchwayQuery>,
+    denom: String,
+) -> StdResult<DenomMetadataResponse> {
+    let res: QueryDenomMetadataResponse = querier.query(&QueryRequest::Stargate {
+        path: DENOM_METADATA_PATH.to_string(),
+        data: Binary::from(QueryDenomMetadataRequest { denom }.encode_to_vec()),
+    })?;
+    let metadata = res.metadata;
+
+    let exponent = metadata
+        .denom_units
+        .iter()
+        .find(|unit| unit.denom == metadata.display)
+        .map_or(0, |unit| unit.exponent);
+
+    Ok(DenomMetadataResponse {
+        denom: metadata.base,
+        display: metadata.display,
+        exponent,
+        name: metadata.name,
+        symbol: metadata.symbol,
+        description: metadata.description,
+    })
+}
@@ -0,0 +1,91 @@
+pub mod helpers;
+use helpers::*;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::{coins, Addr, QueryRequest, Uint128};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ContractMetadata;
+use pantheon_splitter::msg::{EstimatedPayoutsResponse, ExecuteMsg, QueryMsg, SimulatedPayout};
+
+#[test]
+fn test_withdraw_and_distribute() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.init_modules(|router, _, storage| {
+        router
+            .custom
+            .add_rewards(storage, factory_addr.as_str(), coins(1_000, DENOM))
+            .unwrap();
+    });
+
+    // The estimate includes the rewards waiting to be withdrawn
+    let res: EstimatedPayoutsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::EstimatedPayouts {
+                denom: DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balance, Uint128::zero());
+    assert_eq!(res.outstanding_rewards, Uint128::new(1_000));
+    assert!(res.payouts.contains(&SimulatedPayout::Send {
+        recipient: Addr::unchecked(USER),
+        amount: Uint128::new(520),
+    }));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::WithdrawRewards {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(&factory_addr, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_000));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(250));
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(230));
+}
+
+#[test]
+fn test_update_custom_contract_metadata() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateCustomContractRewardMetadata {
+            address: "custom".to_string(),
+            owner_address: None,
+            rewards_address: Some(factory_addr.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: ContractMetadata = app
+        .wrap()
+        .query(&QueryRequest::Custom(ArchwayQuery::ContractMetadata {
+            contract_address: "custom".to_string(),
+        }))
+        .unwrap();
+    assert_eq!(res.owner_address, factory_addr.to_string());
+    assert_eq!(res.rewards_address, factory_addr.to_string());
+}
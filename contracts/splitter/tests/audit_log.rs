@@ -0,0 +1,218 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Decimal};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg, SudoMsg},
+    state::{AuditChange, AuditEntry},
+};
+use pantheon_utils::Share;
+
+#[test]
+fn test_admin_changes_recorded() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateFrozenRecipient {
+            recipient: USER.to_string(),
+            frozen: true,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // Failed changes and operational actions are not recorded
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap_err();
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<AuditEntry> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::AuditLog {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].id, 1);
+    assert_eq!(res[0].sender, Addr::unchecked(ADMIN));
+    assert_eq!(res[0].action, "update_frozen_recipient");
+    assert_eq!(
+        res[0].changes,
+        vec![AuditChange {
+            field: format!("frozen_recipients[{}]", USER),
+            old: Some("false".to_string()),
+            new: Some("true".to_string()),
+        }]
+    );
+    assert_eq!(res[1].action, "lock_contract");
+    // Only the lock of the config is recorded, not the whole config
+    assert_eq!(
+        res[1].changes,
+        vec![AuditChange {
+            field: "config.mutable".to_string(),
+            old: Some("true".to_string()),
+            new: Some("false".to_string()),
+        }]
+    );
+
+    let res: Vec<AuditEntry> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::AuditLog {
+                start_after: Some(1),
+                limit: Some(10),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].id, 2);
+}
+
+#[test]
+fn test_sudo_admin_change_recorded() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.wasm_sudo(
+        factory_addr.clone(),
+        &SudoMsg::UpdateAdmin {
+            admin: USER.to_string(),
+        },
+    )
+    .unwrap();
+
+    let res: Vec<AuditEntry> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::AuditLog {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].sender, factory_addr);
+    assert_eq!(res[0].action, "sudo_update_admin");
+    assert_eq!(
+        res[0].changes,
+        vec![AuditChange {
+            field: "ownership.owner".to_string(),
+            old: Some(format!("\"{}\"", ADMIN)),
+            new: Some(format!("\"{}\"", USER)),
+        }]
+    );
+}
+
+#[test]
+fn test_confirmed_share_update_recorded() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareApprovers {
+            approvers: vec![USER2.to_string()],
+            threshold: 1,
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShares {
+            shares: vec![Share {
+                recipient: USER2.to_string(),
+                percentage: Decimal::one(),
+                ibc: None,
+                burn: false,
+            }],
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER2),
+        factory_addr.clone(),
+        &ExecuteMsg::ConfirmShareUpdate {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<AuditEntry> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::AuditLog {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 3);
+    assert_eq!(res[0].action, "update_share_approvers");
+    assert_eq!(res[0].changes[0].field, "share_approvers");
+    // The proposal only changes the pending update, the shares change with the confirmation
+    assert_eq!(res[1].action, "update_shares");
+    assert_eq!(res[1].changes.len(), 1);
+    assert_eq!(res[1].changes[0].field, "pending_share_update");
+
+    assert_eq!(res[2].action, "confirm_share_update");
+    assert_eq!(res[2].sender, Addr::unchecked(USER2));
+    let fields: Vec<String> = res[2]
+        .changes
+        .iter()
+        .map(|change| change.field.clone())
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            "pending_share_update.confirmations".to_string(),
+            format!("shares[{}]", USER),
+            format!("shares[{}]", USER3),
+            format!("shares[{}]", USER2),
+        ]
+    );
+    assert_eq!(res[2].changes[1].new, None);
+    assert!(res[2].changes[3]
+        .new
+        .as_ref()
+        .unwrap()
+        .contains("\"percentage\":\"1\""));
+}
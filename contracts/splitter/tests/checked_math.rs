@@ -0,0 +1,129 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{
+    mock_env, mock_info, MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR,
+};
+use cosmwasm_std::{coins, BankMsg, CosmosMsg, Decimal, OwnedDeps, Uint128};
+use pantheon_splitter::{
+    contract::{execute, instantiate},
+    msg::{ExecuteMsg, InstantiateMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+fn mock_deps(
+    balance: u128,
+    shares: Vec<Share>,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery> {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[(MOCK_CONTRACT_ADDR, &coins(balance, DENOM))]),
+        custom_query_type: PhantomData,
+    };
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            admin: ADMIN.to_string(),
+            shares,
+            mutable: true,
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
+        },
+    )
+    .unwrap();
+
+    deps
+}
+
+#[test]
+fn test_distribute_max_balance() {
+    let shares = vec![
+        Share {
+            recipient: USER.to_string(),
+            percentage: Decimal::from_str("0.333333333333333333").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+        Share {
+            recipient: USER2.to_string(),
+            percentage: Decimal::from_str("0.333333333333333333").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+        Share {
+            recipient: USER3.to_string(),
+            percentage: Decimal::from_str("0.333333333333333334").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+    ];
+    let mut deps = mock_deps(u128::MAX, shares);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::DistributeNativeTokens {},
+    )
+    .unwrap();
+
+    let total = res
+        .messages
+        .iter()
+        .map(|msg| match &msg.msg {
+            CosmosMsg::Bank(BankMsg::Send { amount, .. }) => amount[0].amount,
+            _ => panic!("unexpected message"),
+        })
+        .try_fold(Uint128::zero(), |acc, amount| acc.checked_add(amount));
+
+    // The payouts never add up to more than the balance
+    assert_eq!(res.messages.len(), 3);
+    assert!(total.is_ok());
+}
+
+#[test]
+fn test_percentage_overflow() {
+    let mut deps = mock_deps(
+        1_000,
+        vec![Share {
+            recipient: USER.to_string(),
+            percentage: Decimal::one(),
+            ibc: None,
+            burn: false,
+        }],
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateShares {
+            shares: vec![
+                Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::MAX,
+                    ibc: None,
+                    burn: false,
+                },
+                Share {
+                    recipient: USER2.to_string(),
+                    percentage: Decimal::one(),
+                    ibc: None,
+                    burn: false,
+                },
+            ],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::OverflowError(_)));
+}
@@ -0,0 +1,246 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Event, Uint128};
+use cw_multi_test::Executor;
+use cw_utils::{Duration, Expiration};
+use pantheon_splitter::{
+    msg::{AtRiskBalance, ExecuteMsg, QueryMsg},
+    ContractError,
+};
+
+#[test]
+fn test_forfeit_unclaimed() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let height = app.block_info().height;
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateClaimDeadline {
+            period: Some(Duration::Height(10)),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let res: Vec<AtRiskBalance> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::AtRiskBalances {
+                start_after: Some(USER.to_string()),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![AtRiskBalance {
+            recipient: Addr::unchecked(USER2),
+            amounts: coins(250, DENOM),
+            deadline: Expiration::AtHeight(height + 10),
+            expired: false,
+        }]
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::ForfeitUnclaimed {
+                recipient: USER2.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ClaimDeadlineNotReached {
+            deadline: Expiration::AtHeight(height + 10).to_string()
+        }
+        .to_string()
+    );
+
+    app.update_block(|block| block.height += 10);
+
+    // Claims renew the deadline of the recipient
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimRewards {},
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::ForfeitUnclaimed {
+                recipient: USER2.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::ForfeitUnclaimed {
+                recipient: USER2.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &Event::new("wasm-forfeited_rewards")
+            .add_attribute("recipient", USER2)
+            .add_attribute("amount", coin(250, DENOM).to_string())
+    ));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::ForfeitUnclaimed {
+                recipient: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ClaimDeadlineNotReached {
+            deadline: Expiration::AtHeight(height + 20).to_string()
+        }
+        .to_string()
+    );
+
+    let res: Vec<AtRiskBalance> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::AtRiskBalances {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![AtRiskBalance {
+            recipient: Addr::unchecked(USER3),
+            amounts: coins(230, DENOM),
+            deadline: Expiration::AtHeight(height + 10),
+            expired: true,
+        }]
+    );
+
+    // Without a fallback address the forfeited rewards are distributed again
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520 + 130));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(62));
+}
+
+#[test]
+fn test_rotation_keeps_claim_deadline() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let height = app.block_info().height;
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateClaimDeadline {
+            period: Some(Duration::Height(10)),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    // The claim renews the deadline past the initial one
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimRewards {},
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::RotateAddress {
+            new_address: "newuser".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::ForfeitUnclaimed {
+                recipient: "newuser".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ClaimDeadlineNotReached {
+            deadline: Expiration::AtHeight(height + 20).to_string()
+        }
+        .to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked("newuser"),
+        factory_addr,
+        &ExecuteMsg::ClaimRewards {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance("newuser", DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+}
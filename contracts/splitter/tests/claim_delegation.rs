@@ -0,0 +1,194 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ClaimDelegationMsg, ExecuteMsg, QueryMsg},
+    state::ClaimDelegation,
+    ContractError,
+};
+
+const CUSTODIAN: &str = "custodian";
+const VAULT: &str = "vault";
+
+#[test]
+fn test_claim_as_delegate() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(CUSTODIAN),
+            factory_addr.clone(),
+            &ExecuteMsg::ClaimAsDelegate {
+                recipient: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateClaimDelegation {
+            delegation: Some(ClaimDelegationMsg {
+                delegate: CUSTODIAN.to_string(),
+                payout_address: Some(VAULT.to_string()),
+            }),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<ClaimDelegation> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::ClaimDelegation {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Some(ClaimDelegation {
+            delegate: Addr::unchecked(CUSTODIAN),
+            payout_address: Some(Addr::unchecked(VAULT)),
+        })
+    );
+
+    // The delegate claims to the payout address, never to itself
+    app.execute_contract(
+        Addr::unchecked(CUSTODIAN),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimAsDelegate {
+            recipient: USER.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(VAULT, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+    let res = app.wrap().query_balance(CUSTODIAN, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+
+    // The delegate of a recipient cannot claim for the others
+    let err = app
+        .execute_contract(
+            Addr::unchecked(CUSTODIAN),
+            factory_addr.clone(),
+            &ExecuteMsg::ClaimAsDelegate {
+                recipient: USER2.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateClaimDelegation { delegation: None },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(CUSTODIAN),
+            factory_addr,
+            &ExecuteMsg::ClaimAsDelegate {
+                recipient: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+}
+
+#[test]
+fn test_rotation_keeps_delegation() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateClaimDelegation {
+            delegation: Some(ClaimDelegationMsg {
+                delegate: CUSTODIAN.to_string(),
+                payout_address: None,
+            }),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::RotateAddress {
+            new_address: "newuser".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let res: Option<ClaimDelegation> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::ClaimDelegation {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, None);
+
+    // The delegate claims for the new address of the recipient
+    app.execute_contract(
+        Addr::unchecked(CUSTODIAN),
+        factory_addr,
+        &ExecuteMsg::ClaimAsDelegate {
+            recipient: "newuser".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance("newuser", DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+}
@@ -0,0 +1,112 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Decimal, Event, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+
+const KEEPER: &str = "keeper";
+
+#[test]
+fn test_claim_for() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateClaimBounty {
+                bounty: Some(Decimal::percent(10)),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidClaimBounty {
+            max: Decimal::percent(5)
+        }
+        .to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateClaimBounty {
+            bounty: Some(Decimal::percent(1)),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<Decimal> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::ClaimBounty {})
+        .unwrap();
+    assert_eq!(res, Some(Decimal::percent(1)));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    // The keeper only receives the bounty, the rewards go to the recipient
+    let res = app
+        .execute_contract(
+            Addr::unchecked(KEEPER),
+            factory_addr.clone(),
+            &ExecuteMsg::ClaimFor {
+                recipient: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &Event::new("wasm")
+            .add_attribute("action", "claim_for")
+            .add_attribute("recipient", USER)
+            .add_attribute("keeper", KEEPER)
+            .add_attribute("bounty", coin(5, DENOM).to_string())
+    ));
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(515));
+    let res = app.wrap().query_balance(KEEPER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(5));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(KEEPER),
+            factory_addr.clone(),
+            &ExecuteMsg::ClaimFor {
+                recipient: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToClaim {}.to_string()
+    );
+
+    // Recipients claiming for themselves do not pay the bounty
+    app.execute_contract(
+        Addr::unchecked(USER2),
+        factory_addr,
+        &ExecuteMsg::ClaimFor {
+            recipient: USER2.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(250));
+}
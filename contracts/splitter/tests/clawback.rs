@@ -0,0 +1,156 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use cw_utils::Duration;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::PendingDeposit,
+    ContractError,
+};
+
+fn deposit(app: &mut ArchwayApp, factory_addr: &Addr) {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DepositRefundable {},
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+}
+
+fn distribute(app: &mut ArchwayApp, factory_addr: &Addr) {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_reclaim_deposit() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::DepositRefundable {},
+            &coins(1_000, DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ClawbackDisabled {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateClawbackWindow {
+            window: Some(Duration::Height(10)),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    deposit(&mut app, &factory_addr);
+    distribute(&mut app, &factory_addr);
+
+    // The deposit is not distributed during the window
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+
+    let res: Vec<PendingDeposit> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PendingDeposits {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].sender, Addr::unchecked(ADMIN));
+    assert_eq!(res[0].funds, coins(1_000, DENOM));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::ReclaimDeposit { id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let before = app.wrap().query_balance(ADMIN, DENOM).unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::ReclaimDeposit { id: 1 },
+        &vec![],
+    )
+    .unwrap();
+    let after = app.wrap().query_balance(ADMIN, DENOM).unwrap();
+    assert_eq!(after.amount - before.amount, Uint128::new(1_000));
+
+    let res: Vec<PendingDeposit> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::PendingDeposits {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn test_window_closed() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateClawbackWindow {
+            window: Some(Duration::Height(10)),
+        },
+        &vec![],
+    )
+    .unwrap();
+    deposit(&mut app, &factory_addr);
+
+    app.update_block(|block| block.height += 10);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::ReclaimDeposit { id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ClawbackWindowClosed {}.to_string()
+    );
+
+    // The deposit becomes distributable once the window is over
+    distribute(&mut app, &factory_addr);
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+}
@@ -0,0 +1,63 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{Addr, HexBinary};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+
+#[test]
+fn test_pin_and_unpin() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let checksum = HexBinary::from(vec![1u8; 32]);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::PinCodeChecksum {
+                code_id: 1,
+                checksum: checksum.clone(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::PinCodeChecksum {
+            code_id: 1,
+            checksum: checksum.clone(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<HexBinary> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::CodeChecksum { code_id: 1 })
+        .unwrap();
+    assert_eq!(res, Some(checksum));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UnpinCodeChecksum { code_id: 1 },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<HexBinary> = app
+        .wrap()
+        .query_wasm_smart(factory_addr, &QueryMsg::CodeChecksum { code_id: 1 })
+        .unwrap();
+    assert_eq!(res, None);
+}
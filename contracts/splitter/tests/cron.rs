@@ -0,0 +1,85 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ConfigResponse, ExecuteMsg, QueryMsg},
+    ContractError,
+};
+
+const AGENT: &str = "croncat_manager";
+
+#[test]
+fn test_cron_distribution() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(AGENT),
+            factory_addr.clone(),
+            &ExecuteMsg::Cron {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateCronAgent {
+            agent: Some(AGENT.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.config.cron_agent, Some(Addr::unchecked(AGENT)));
+
+    app.execute_contract(
+        Addr::unchecked(AGENT),
+        factory_addr.clone(),
+        &ExecuteMsg::Cron {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+}
+
+#[test]
+fn test_update_cron_agent_unauthorized() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr,
+            &ExecuteMsg::UpdateCronAgent {
+                agent: Some(USER.to_string()),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+}
@@ -0,0 +1,227 @@
+pub mod helpers;
+use helpers::*;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, Event, MessageInfo, Response, StdError,
+    StdResult, Uint128,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+use cw_multi_test::{ContractWrapper, Executor};
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{Cw20HookMsg, Cw20SourceMsg, ExecuteMsg, QueryMsg},
+    state::Cw20Source,
+    ContractError,
+};
+
+fn cw20_balance(app: &ArchwayApp, token: &Addr, address: &str) -> Uint128 {
+    let res: BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            token,
+            &Cw20QueryMsg::Balance {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    res.balance
+}
+
+// Token answering no queries, sources of it can not be pulled
+fn failing_token_instantiate(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response<ArchwayMsg>> {
+    Ok(Response::new())
+}
+
+fn failing_token_execute(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    msg: Cw20ExecuteMsg,
+) -> StdResult<Response<ArchwayMsg>> {
+    match msg {
+        Cw20ExecuteMsg::Send {
+            contract,
+            amount,
+            msg,
+        } => Ok(Response::new().add_message(
+            Cw20ReceiveMsg {
+                sender: info.sender.to_string(),
+                amount,
+                msg,
+            }
+            .into_cosmos_msg(contract)?,
+        )),
+        Cw20ExecuteMsg::Transfer { .. } => Ok(Response::new()),
+        _ => Err(StdError::generic_err("unsupported")),
+    }
+}
+
+fn failing_token_query(
+    _deps: Deps<ArchwayQuery>,
+    _env: Env,
+    _msg: Cw20QueryMsg,
+) -> StdResult<Binary> {
+    Err(StdError::generic_err("unsupported"))
+}
+
+#[test]
+fn test_pull_cw20_sources() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let token = instantiate_cw20(&mut app, 1_000_000);
+
+    let sources = vec![Cw20SourceMsg {
+        token: token.to_string(),
+        owner: ADMIN.to_string(),
+    }];
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateCw20Sources {
+                sources: sources.clone(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateCw20Sources { sources },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<Cw20Source> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Cw20Sources {})
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![Cw20Source {
+            token: token.clone(),
+            owner: Addr::unchecked(ADMIN),
+        }]
+    );
+
+    // The treasury keeps its revenue until the distribution
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        token.clone(),
+        &Cw20ExecuteMsg::IncreaseAllowance {
+            spender: factory_addr.to_string(),
+            amount: Uint128::new(1_000),
+            expires: None,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeCw20 {
+            token: token.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    assert_eq!(cw20_balance(&app, &token, USER), Uint128::new(520));
+    assert_eq!(cw20_balance(&app, &token, USER2), Uint128::new(250));
+    assert_eq!(cw20_balance(&app, &token, USER3), Uint128::new(230));
+    assert_eq!(cw20_balance(&app, &token, ADMIN), Uint128::new(999_000));
+
+    // The allowance is spent, nothing is left to pull
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::DistributeCw20 {
+                token: token.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToDistribute {}.to_string()
+    );
+}
+
+#[test]
+fn test_failing_cw20_source() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let code_id = app.store_code(Box::new(ContractWrapper::new(
+        failing_token_execute,
+        failing_token_instantiate,
+        failing_token_query,
+    )));
+    let token = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &Empty {},
+            &vec![],
+            "Failing Token",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateCw20Sources {
+            sources: vec![Cw20SourceMsg {
+                token: token.to_string(),
+                owner: ADMIN.to_string(),
+            }],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        token.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: factory_addr.to_string(),
+            amount: Uint128::new(1_000),
+            msg: to_binary(&Cw20HookMsg::Hold {}).unwrap(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // The source is skipped and the held tokens are still distributed
+    let res = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::DistributeCw20 {
+                token: token.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &Event::new("wasm")
+            .add_attribute("action", "distribute_cw20")
+            .add_attribute("pulled", "0")
+            .add_attribute("amount", "1000")
+    ));
+}
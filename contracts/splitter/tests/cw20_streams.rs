@@ -0,0 +1,282 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{to_binary, Addr, Uint128};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{Cw20HookMsg, ExecuteMsg, QueryMsg},
+    state::Cw20Stream,
+    ContractError,
+};
+
+fn cw20_balance(app: &ArchwayApp, token: &Addr, address: &str) -> Uint128 {
+    let res: BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            token,
+            &Cw20QueryMsg::Balance {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    res.balance
+}
+
+fn streamed_rewards(
+    app: &ArchwayApp,
+    factory_addr: &Addr,
+    token: &Addr,
+    recipient: &str,
+) -> Uint128 {
+    app.wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::StreamedRewards {
+                recipient: recipient.to_string(),
+                token: token.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+fn send_cw20(
+    app: &mut ArchwayApp,
+    token: &Addr,
+    factory_addr: &Addr,
+    amount: u128,
+    msg: Cw20HookMsg,
+) {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        token.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: factory_addr.to_string(),
+            amount: Uint128::new(amount),
+            msg: to_binary(&msg).unwrap(),
+        },
+        &vec![],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_claim_streamed() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let token = instantiate_cw20(&mut app, 1_000_000);
+
+    send_cw20(
+        &mut app,
+        &token,
+        &factory_addr,
+        10_000,
+        Cw20HookMsg::Stream { duration: 100 },
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::ClaimStreamed {
+                token: token.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToClaim {}.to_string()
+    );
+
+    // A quarter of the stream vested
+    app.update_block(|block| block.time = block.time.plus_seconds(25));
+    assert_eq!(
+        streamed_rewards(&app, &factory_addr, &token, USER),
+        Uint128::new(1_300)
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimStreamed {
+            token: token.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    assert_eq!(cw20_balance(&app, &token, USER), Uint128::new(1_300));
+    assert_eq!(
+        streamed_rewards(&app, &factory_addr, &token, USER),
+        Uint128::zero()
+    );
+
+    // The held balance is streamed with the part that did not vest yet
+    send_cw20(&mut app, &token, &factory_addr, 1_000, Cw20HookMsg::Hold {});
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::StreamCw20 {
+                token: token.to_string(),
+                duration: 100,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::StreamCw20 {
+            token: token.to_string(),
+            duration: 100,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let time = app.block_info().time;
+    let res: Option<Cw20Stream> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Cw20Stream {
+                token: token.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Some(Cw20Stream {
+            amount: Uint128::new(8_500),
+            start: time,
+            end: time.plus_seconds(100),
+            vested: Uint128::zero(),
+        })
+    );
+
+    app.update_block(|block| block.time = block.time.plus_seconds(50));
+    assert_eq!(
+        streamed_rewards(&app, &factory_addr, &token, USER2),
+        Uint128::new(1_687)
+    );
+
+    // Once the stream ended every recipient can claim its whole part
+    app.update_block(|block| block.time = block.time.plus_seconds(1_000));
+    app.execute_contract(
+        Addr::unchecked(USER2),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimStreamed {
+            token: token.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    assert_eq!(cw20_balance(&app, &token, USER2), Uint128::new(2_750));
+    assert_eq!(
+        streamed_rewards(&app, &factory_addr, &token, USER),
+        Uint128::new(4_420)
+    );
+}
+
+#[test]
+fn test_rotation_keeps_streamed() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let token = instantiate_cw20(&mut app, 1_000_000);
+
+    send_cw20(
+        &mut app,
+        &token,
+        &factory_addr,
+        10_000,
+        Cw20HookMsg::Stream { duration: 100 },
+    );
+    app.update_block(|block| block.time = block.time.plus_seconds(25));
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::RotateAddress {
+            new_address: "newuser".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // The part streamed before the rotation is claimed by the new address
+    assert_eq!(
+        streamed_rewards(&app, &factory_addr, &token, USER),
+        Uint128::zero()
+    );
+    assert_eq!(
+        streamed_rewards(&app, &factory_addr, &token, "newuser"),
+        Uint128::new(1_300)
+    );
+
+    app.update_block(|block| block.time = block.time.plus_seconds(1_000));
+    app.execute_contract(
+        Addr::unchecked("newuser"),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimStreamed {
+            token: token.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    assert_eq!(cw20_balance(&app, &token, "newuser"), Uint128::new(5_200));
+}
+
+#[test]
+fn test_stream_from_unknown_sender() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let token = instantiate_cw20(&mut app, 1_000_000);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        token.clone(),
+        &Cw20ExecuteMsg::Transfer {
+            recipient: USER.to_string(),
+            amount: Uint128::new(1_000),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // Anyone could otherwise add streams of worthless tokens to every claim
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            token.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: factory_addr.to_string(),
+                amount: Uint128::new(1_000),
+                msg: to_binary(&Cw20HookMsg::Stream { duration: 100 }).unwrap(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let res: Option<Cw20Stream> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::Cw20Stream {
+                token: token.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, None);
+}
@@ -0,0 +1,241 @@
+pub mod helpers;
+use helpers::*;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    coins, to_binary, Addr, Binary, Decimal, Deps, DepsMut, Env, Event, MessageInfo, Order,
+    Response, StdError, StdResult, Uint128,
+};
+use cw4::{Cw4QueryMsg, Member, MemberChangedHookMsg, MemberListResponse};
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+use cw_storage_plus::{Bound, Map};
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg, SharesResponse},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+// Minimal group that supports the member list query used by the splitter
+const MEMBERS: Map<String, u64> = Map::new("members");
+
+fn group_instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: Vec<Member>,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    for member in msg {
+        MEMBERS.save(deps.storage, member.addr, &member.weight)?;
+    }
+    Ok(Response::new())
+}
+
+fn group_execute(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Cw4QueryMsg,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    Err(StdError::generic_err("unsupported"))
+}
+
+fn group_query(deps: Deps<ArchwayQuery>, _env: Env, msg: Cw4QueryMsg) -> StdResult<Binary> {
+    match msg {
+        Cw4QueryMsg::ListMembers { start_after, limit } => {
+            let members = MEMBERS
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit.unwrap_or(10) as usize)
+                .map(|item| {
+                    let (addr, weight) = item?;
+                    Ok(Member { addr, weight })
+                })
+                .collect::<StdResult<Vec<Member>>>()?;
+            to_binary(&MemberListResponse { members })
+        }
+        _ => Err(StdError::generic_err("unsupported")),
+    }
+}
+
+fn group_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(group_execute, group_instantiate, group_query);
+    Box::new(contract)
+}
+
+fn instantiate_group(app: &mut ArchwayApp, members: Vec<Member>) -> Addr {
+    let code_id = app.store_code(group_contract());
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(ADMIN),
+        &members,
+        &vec![],
+        "Group",
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_group_distribution() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    // More members than a single page of the member list
+    let mut members: Vec<Member> = (0..40)
+        .map(|i| Member {
+            addr: format!("member{:02}", i),
+            weight: 1,
+        })
+        .collect();
+    members.push(Member {
+        addr: USER.to_string(),
+        weight: 60,
+    });
+    members.push(Member {
+        addr: USER2.to_string(),
+        weight: 0,
+    });
+    let group = instantiate_group(&mut app, members);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateGroup {
+            group: Some(group.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    // The share table is ignored while the group is linked
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(600));
+    let res = app.wrap().query_balance("member39", DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(10));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+}
+
+#[test]
+fn test_member_changed_hook() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let group = instantiate_group(
+        &mut app,
+        vec![
+            Member {
+                addr: USER.to_string(),
+                weight: 2,
+            },
+            Member {
+                addr: USER2.to_string(),
+                weight: 1,
+            },
+        ],
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateGroup {
+            group: Some(group.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let hook = ExecuteMsg::MemberChangedHook(MemberChangedHookMsg { diffs: vec![] });
+
+    let err = app
+        .execute_contract(Addr::unchecked(USER), factory_addr.clone(), &hook, &vec![])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let res = app
+        .execute_contract(group, factory_addr.clone(), &hook, &vec![])
+        .unwrap();
+    assert!(res.has_event(
+        &Event::new("wasm-share_changed")
+            .add_attribute("recipient", USER3)
+            .add_attribute("old_percentage", "0.23")
+            .add_attribute("new_percentage", "0")
+    ));
+
+    // The rounding dust goes to the last member
+    let res: SharesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::Shares {
+                start_after: None,
+                limit: None,
+                order: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.shares,
+        vec![
+            Share {
+                recipient: USER.to_string(),
+                percentage: Decimal::from_str("0.666666666666666666").unwrap(),
+                ibc: None,
+                burn: false,
+            },
+            Share {
+                recipient: USER2.to_string(),
+                percentage: Decimal::from_str("0.333333333333333334").unwrap(),
+                ibc: None,
+                burn: false,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_update_group_unauthorized() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr,
+            &ExecuteMsg::UpdateGroup {
+                group: Some(USER.to_string()),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+}
@@ -0,0 +1,138 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{coins, to_binary, Addr, Decimal, Uint128};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{Cw20HookMsg, ExecuteMsg, QueryMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+fn share(recipient: &str, percentage: &str) -> Share {
+    Share {
+        recipient: recipient.to_string(),
+        percentage: Decimal::from_str(percentage).unwrap(),
+        ibc: None,
+        burn: false,
+    }
+}
+
+fn cw20_balance(app: &ArchwayApp, token: &Addr, address: &str) -> Uint128 {
+    let res: BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            token,
+            &Cw20QueryMsg::Balance {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    res.balance
+}
+
+#[test]
+fn test_denom_shares() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let token = instantiate_cw20(&mut app, 1_000_000);
+
+    let denom_shares = vec![share(USER, "0.8"), share(USER2, "0.2")];
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateDenomShares {
+                denom: token.to_string(),
+                shares: denom_shares.clone(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateDenomShares {
+            denom: token.to_string(),
+            shares: denom_shares.clone(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<Share> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::DenomShares {
+                denom: token.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, denom_shares);
+    let res: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::SharesDenoms {})
+        .unwrap();
+    assert_eq!(res, vec![token.to_string()]);
+
+    // The token is split by its own table
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        token.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: factory_addr.to_string(),
+            amount: Uint128::new(1_000),
+            msg: to_binary(&Cw20HookMsg::Distribute {}).unwrap(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    assert_eq!(cw20_balance(&app, &token, USER), Uint128::new(800));
+    assert_eq!(cw20_balance(&app, &token, USER2), Uint128::new(200));
+    assert_eq!(cw20_balance(&app, &token, USER3), Uint128::zero());
+
+    // The native denom keeps the default shares
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(230));
+
+    // An empty table restores the default shares
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateDenomShares {
+            denom: token.to_string(),
+            shares: vec![],
+        },
+        &vec![],
+    )
+    .unwrap();
+    let res: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(factory_addr, &QueryMsg::SharesDenoms {})
+        .unwrap();
+    assert!(res.is_empty());
+}
@@ -0,0 +1,101 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Coin, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::PendingDeposit,
+};
+
+#[test]
+fn test_deposit_allowlist() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateDepositAllowlist {
+            senders: Some(vec![ADMIN.to_string()]),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<Vec<Addr>> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::DepositAllowlist {})
+        .unwrap();
+    assert_eq!(res, Some(vec![Addr::unchecked(ADMIN)]));
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        Addr::unchecked(USER3),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    // The deposit of a sender outside the allowlist is held
+    app.execute_contract(
+        Addr::unchecked(USER3),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PendingRewards {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+
+    let res: Vec<PendingDeposit> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PendingDeposits {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].sender, Addr::unchecked(USER3));
+
+    app.execute_contract(
+        Addr::unchecked(USER3),
+        factory_addr.clone(),
+        &ExecuteMsg::ReclaimDeposit { id: res[0].id },
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_000));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::PendingRewards {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec![coin(520, DENOM)]);
+}
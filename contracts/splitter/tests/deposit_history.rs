@@ -0,0 +1,112 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Coin, Uint128};
+use cw_multi_test::Executor;
+use cw_utils::Duration;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::{DepositRecord, DepositSource, DistributionRecord},
+};
+
+#[test]
+fn test_deposit_memo() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit {
+            memo: Some("invoice 42".to_string()),
+        },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(500, DENOM),
+    )
+    .unwrap();
+
+    let res: Vec<DepositRecord> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::DepositHistory {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].sender, Addr::unchecked(ADMIN));
+    assert_eq!(res[0].funds, coins(1_000, DENOM));
+    assert_eq!(res[0].memo, Some("invoice 42".to_string()));
+    assert_eq!(res[1].memo, None);
+
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::DepositTotals {
+                sender: ADMIN.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec![coin(1_500, DENOM)]);
+}
+
+#[test]
+fn test_distribution_sources() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateClawbackWindow {
+            window: Some(Duration::Height(10)),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DepositRefundable {},
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    // The released deposit is attributed to the distribution
+    let res: Option<DistributionRecord> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::DistributionAt {
+                id: Some(1),
+                height: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res.unwrap().sources,
+        vec![DepositSource {
+            deposit_id: 1,
+            sender: Addr::unchecked(ADMIN),
+            amount: Uint128::new(1_000),
+        }]
+    );
+}
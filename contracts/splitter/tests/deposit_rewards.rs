@@ -0,0 +1,218 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{coin, coins, Addr, Coin, Decimal, Uint128};
+use cw_multi_test::Executor;
+use cw_utils::Expiration;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+#[test]
+fn test_deposit_and_claim() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    // Nothing is sent until the recipients claim
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PendingRewards {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec![coin(520, DENOM)]);
+
+    // Rewards deposited before a share change are kept at the previous share
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShares {
+            shares: vec![
+                Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::from_str("0.5").unwrap(),
+                    ibc: None,
+                    burn: false,
+                },
+                Share {
+                    recipient: USER2.to_string(),
+                    percentage: Decimal::from_str("0.5").unwrap(),
+                    ibc: None,
+                    burn: false,
+                },
+            ],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimRewards {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_020));
+
+    // Removed recipients can still claim their settled rewards
+    app.execute_contract(
+        Addr::unchecked(USER3),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimRewards {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(230));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr,
+            &ExecuteMsg::ClaimRewards {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToClaim {}.to_string()
+    );
+}
+
+#[test]
+fn test_empty_deposit() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::Deposit { memo: None },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToDistribute {}.to_string()
+    );
+}
+
+#[test]
+fn test_deposit_with_other_distribution_shares() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    // Shares of the denom are paid by the distributions, not by the deposits
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateDenomShares {
+            denom: DENOM.to_string(),
+            shares: vec![Share {
+                recipient: USER2.to_string(),
+                percentage: Decimal::one(),
+                ibc: None,
+                burn: false,
+            }],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::Deposit { memo: None },
+            &coins(1_000, DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::DepositsUnavailable {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateDenomShares {
+            denom: DENOM.to_string(),
+            shares: vec![],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // Expiring shares are skipped or redirected by the distributions
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareExpiration {
+            recipient: USER.to_string(),
+            expires_at: Some(Expiration::AtHeight(app.block_info().height + 10)),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::Deposit { memo: None },
+            &coins(1_000, DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::DepositsUnavailable {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareExpiration {
+            recipient: USER.to_string(),
+            expires_at: None,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr,
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+}
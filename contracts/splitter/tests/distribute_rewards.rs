@@ -1,9 +1,12 @@
 pub mod helpers;
 use helpers::*;
 
-use cosmwasm_std::{coins, Addr, Uint128};
+use std::str::FromStr;
+
+use cosmwasm_std::{coins, Addr, Decimal, Event, Uint128};
 use cw_multi_test::Executor;
 use pantheon_splitter::msg::ExecuteMsg;
+use pantheon_utils::Share;
 
 #[test]
 fn test_happy_path() {
@@ -36,3 +39,94 @@ fn test_happy_path() {
     let res = app.wrap().query_balance(USER3, "aconst").unwrap();
     assert_eq!(res.amount, Uint128::new(2_888_800_000_000_000_000_000))
 }
+
+#[test]
+fn test_remainder() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_001, DENOM),
+    )
+    .unwrap();
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::DistributeNativeTokens {},
+            &vec![],
+        )
+        .unwrap();
+
+    // The rounding dust stays in the splitter
+    assert!(res.has_event(
+        &Event::new("wasm-distribution")
+            .add_attribute("denom", DENOM)
+            .add_attribute("distributed", "1000")
+            .add_attribute("remainder", "1")
+    ));
+
+    let res = app.wrap().query_balance(factory_addr, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1));
+}
+
+#[test]
+fn test_burn_share() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShares {
+            shares: vec![
+                Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::from_str("0.8").unwrap(),
+                    ibc: None,
+                    burn: false,
+                },
+                Share {
+                    recipient: USER3.to_string(),
+                    percentage: Decimal::from_str("0.2").unwrap(),
+                    ibc: None,
+                    burn: true,
+                },
+            ],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    let res = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::DistributeNativeTokens {},
+            &vec![],
+        )
+        .unwrap();
+
+    // The portion of the burn share is burned instead of sent to its recipient
+    assert!(res.has_event(
+        &Event::new("wasm-burn")
+            .add_attribute("recipient", USER3)
+            .add_attribute("amount", "200")
+    ));
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(800));
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app.wrap().query_balance(factory_addr, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+}
@@ -0,0 +1,85 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{DistributionCostResponse, ExecuteMsg, QueryMsg},
+    state::DistributionGasCosts,
+    ContractError,
+};
+
+#[test]
+fn test_distribution_cost() {
+    let mut app = mock_app();
+    let contract_addr = proper_instantiate_with_shares(&mut app);
+
+    // Nothing is sent without a balance
+    let res: DistributionCostResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::DistributionCost {})
+        .unwrap();
+    assert_eq!(
+        res,
+        DistributionCostResponse {
+            sends: 0,
+            ibc_transfers: 0,
+            recipients: 0,
+            gas_estimate: DistributionGasCosts::default().base,
+        }
+    );
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            contract_addr.clone(),
+            &ExecuteMsg::UpdateDistributionGasCosts {
+                costs: DistributionGasCosts {
+                    base: 100_000,
+                    per_send: 10_000,
+                    per_ibc_transfer: 50_000,
+                },
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        contract_addr.clone(),
+        &ExecuteMsg::UpdateDistributionGasCosts {
+            costs: DistributionGasCosts {
+                base: 100_000,
+                per_send: 10_000,
+                per_ibc_transfer: 50_000,
+            },
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: DistributionCostResponse = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::DistributionCost {})
+        .unwrap();
+    assert_eq!(
+        res,
+        DistributionCostResponse {
+            sends: 3,
+            ibc_transfers: 0,
+            recipients: 3,
+            gas_estimate: 130_000,
+        }
+    );
+}
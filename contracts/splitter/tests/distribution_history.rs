@@ -0,0 +1,158 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{
+    mock_env, mock_info, MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR,
+};
+use cosmwasm_std::{
+    coin, coins, from_binary, Decimal, Env, OwnedDeps, Reply, SubMsgResult, Uint128,
+};
+use pantheon_splitter::{
+    contract::{execute, instantiate, query, reply},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::{DistributionPayout, DistributionRecord, PayoutKind},
+};
+use pantheon_utils::Share;
+
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery> {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[(MOCK_CONTRACT_ADDR, &coins(1_000, DENOM))]),
+        custom_query_type: PhantomData,
+    };
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            admin: ADMIN.to_string(),
+            shares: shares(),
+            mutable: true,
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
+        },
+    )
+    .unwrap();
+
+    deps
+}
+
+fn shares() -> Vec<Share> {
+    vec![
+        Share {
+            recipient: USER.to_string(),
+            percentage: Decimal::from_str("0.75").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+        Share {
+            recipient: USER2.to_string(),
+            percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+    ]
+}
+
+fn distribution_at(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery>,
+    id: Option<u64>,
+    height: Option<u64>,
+) -> Option<DistributionRecord> {
+    from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::DistributionAt { id, height },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn env_at(height: u64) -> Env {
+    let mut env = mock_env();
+    env.block.height = height;
+    env
+}
+
+#[test]
+fn test_distribution_history() {
+    let mut deps = mock_deps();
+    let height = mock_env().block.height;
+
+    let res = execute(
+        deps.as_mut(),
+        env_at(height),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::DistributeNativeTokens {},
+    )
+    .unwrap();
+
+    // The payout of the second recipient fails
+    reply(
+        deps.as_mut(),
+        env_at(height),
+        Reply {
+            id: res.messages[1].id,
+            result: SubMsgResult::Err("blocked address".to_string()),
+        },
+    )
+    .unwrap();
+
+    let record = DistributionRecord {
+        id: 1,
+        height,
+        time: mock_env().block.time,
+        amount: coin(1_000, DENOM),
+        shares: shares(),
+        payouts: vec![
+            DistributionPayout {
+                recipient: USER.to_string(),
+                amount: Uint128::new(750),
+                kind: PayoutKind::Send,
+                reply_id: Some(res.messages[0].id),
+                failed: false,
+            },
+            DistributionPayout {
+                recipient: USER2.to_string(),
+                amount: Uint128::new(250),
+                kind: PayoutKind::Send,
+                reply_id: Some(res.messages[1].id),
+                failed: true,
+            },
+        ],
+        remainder: Uint128::zero(),
+        sources: vec![],
+        epoch: 0,
+    };
+    assert_eq!(distribution_at(&deps, Some(1), None), Some(record.clone()));
+    assert_eq!(distribution_at(&deps, None, Some(height)), Some(record));
+    assert_eq!(distribution_at(&deps, None, Some(height - 1)), None);
+
+    execute(
+        deps.as_mut(),
+        env_at(height + 10),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::DistributeNativeTokens {},
+    )
+    .unwrap();
+
+    // The last distribution at or before the height is returned
+    assert_eq!(
+        distribution_at(&deps, None, Some(height + 5)).map(|record| record.id),
+        Some(1)
+    );
+    assert_eq!(
+        distribution_at(&deps, None, Some(height + 10)).map(|record| record.id),
+        Some(2)
+    );
+    assert_eq!(distribution_at(&deps, Some(3), None), None);
+}
@@ -0,0 +1,228 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Decimal, Event, Uint128};
+use cw_multi_test::Executor;
+use cw_ownable::OwnershipError;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, InstantiateMsg, SudoMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+const RESCUE: &str = "rescue";
+
+#[test]
+fn test_emergency_withdraw() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::EmergencyWithdraw {
+                denom: DENOM.to_string(),
+                to: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        OwnershipError::NotOwner.to_string()
+    );
+
+    // Withdrawals also work while the contract is paused
+    app.wasm_sudo(factory_addr.clone(), &SudoMsg::Pause {})
+        .unwrap();
+
+    let res = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::EmergencyWithdraw {
+                denom: DENOM.to_string(),
+                to: RESCUE.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &Event::new("wasm-emergency_withdraw")
+            .add_attribute("sender", ADMIN)
+            .add_attribute("to", RESCUE)
+            .add_attribute("amount", format!("1000{}", DENOM))
+    ));
+
+    let res = app.wrap().query_balance(RESCUE, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_000));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::EmergencyWithdraw {
+                denom: DENOM.to_string(),
+                to: RESCUE.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToWithdraw {}.to_string()
+    );
+}
+
+#[test]
+fn test_disabled_at_instantiation() {
+    let mut app = mock_app();
+    let code_id = app.store_code(factory_contract());
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                shares: vec![Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::one(),
+                    ibc: None,
+                    burn: false,
+                }],
+                mutable: false,
+                admin: ADMIN.to_string(),
+                transfer_requires_approval: false,
+                disable_emergency_withdraw: true,
+                restore: None,
+            },
+            &vec![],
+            "Pantheon Splitter",
+            None,
+        )
+        .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::EmergencyWithdraw {
+                denom: DENOM.to_string(),
+                to: RESCUE.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::EmergencyWithdrawDisabled {}.to_string()
+    );
+}
+
+#[test]
+fn test_emergency_withdraw_keeps_reserved_rewards() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    // Only deposited rewards are in the contract
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::EmergencyWithdraw {
+                denom: DENOM.to_string(),
+                to: RESCUE.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToWithdraw {}.to_string()
+    );
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(500, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::EmergencyWithdraw {
+            denom: DENOM.to_string(),
+            to: RESCUE.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(RESCUE, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(500));
+
+    // The recipients can still claim their rewards
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimRewards {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+}
+
+#[test]
+fn test_locked_emergency_withdraw() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::EmergencyWithdraw {
+                denom: DENOM.to_string(),
+                to: RESCUE.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ContractNotMutable {}.to_string()
+    );
+}
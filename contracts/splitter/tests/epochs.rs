@@ -0,0 +1,112 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{EpochRecipientAmounts, EpochReportResponse, ExecuteMsg, QueryMsg},
+    ContractError,
+};
+
+fn distribute(app: &mut ArchwayApp, factory_addr: &Addr, amount: u128) {
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(amount, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+}
+
+fn epoch_report(app: &ArchwayApp, factory_addr: &Addr, epoch: u64) -> EpochReportResponse {
+    app.wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::EpochReport {
+                epoch,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_epoch_reports() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    distribute(&mut app, &factory_addr, 1_000);
+    distribute(&mut app, &factory_addr, 1_000);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::CloseEpoch {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::CloseEpoch {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res: u64 = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::CurrentEpoch {})
+        .unwrap();
+    assert_eq!(res, 1);
+
+    distribute(&mut app, &factory_addr, 2_000);
+
+    let res = epoch_report(&app, &factory_addr, 0);
+    let epoch = res.epoch.unwrap();
+    assert_eq!(epoch.distributions, 2);
+    assert_eq!(epoch.end_height, Some(app.block_info().height));
+    assert_eq!(res.totals, coins(2_000, DENOM));
+    assert_eq!(
+        res.recipients,
+        vec![
+            EpochRecipientAmounts {
+                recipient: USER.to_string(),
+                amounts: coins(1_040, DENOM),
+            },
+            EpochRecipientAmounts {
+                recipient: USER2.to_string(),
+                amounts: coins(500, DENOM),
+            },
+            EpochRecipientAmounts {
+                recipient: USER3.to_string(),
+                amounts: coins(460, DENOM),
+            },
+        ]
+    );
+
+    let res = epoch_report(&app, &factory_addr, 1);
+    assert_eq!(res.epoch.unwrap().distributions, 1);
+    assert_eq!(res.totals, vec![coin(2_000, DENOM)]);
+    assert_eq!(res.recipients[0].amounts, coins(1_040, DENOM));
+
+    // Epochs that did not start yet have no report
+    let res = epoch_report(&app, &factory_addr, 2);
+    assert_eq!(res.epoch, None);
+    assert!(res.totals.is_empty());
+    assert!(res.recipients.is_empty());
+}
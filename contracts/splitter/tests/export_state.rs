@@ -0,0 +1,114 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr};
+use cw_multi_test::Executor;
+use pantheon_splitter::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, StateExport};
+
+#[test]
+fn test_export_pages() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let res: StateExport = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::ExportState {
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.format_version, 2);
+    assert_eq!(res.contract_version.contract, "crates.io:pantheon-splitter");
+    assert_eq!(res.config.admin, Addr::unchecked(ADMIN));
+    assert_eq!(res.shares.len(), 2);
+    assert_eq!(res.total_count, 3);
+    assert!(res.cw20_balances.is_empty());
+
+    let res: StateExport = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::ExportState {
+                start_after: res.next_start_after,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.shares.len(), 1);
+    assert_eq!(res.shares[0].recipient, USER3.to_string());
+    assert_eq!(res.next_start_after, None);
+}
+
+#[test]
+fn test_instantiate_from_export() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::RegisterDenom {
+            denom: "ibc/usdc".to_string(),
+            display: "usdc".to_string(),
+            exponent: 6,
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Deposit { memo: None },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let export: StateExport = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::ExportState {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(export.denoms.len(), 2);
+    assert_eq!(export.counters.last_deposit_record_id, 1);
+
+    let code_id = app.store_code(factory_contract());
+    for preserve_counters in [true, false] {
+        let copy_addr = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked(ADMIN),
+                &InstantiateMsg::from_export(ADMIN.to_string(), export.clone(), preserve_counters),
+                &vec![],
+                "Pantheon Splitter",
+                None,
+            )
+            .unwrap();
+
+        let res: StateExport = app
+            .wrap()
+            .query_wasm_smart(
+                copy_addr,
+                &QueryMsg::ExportState {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(res.config, export.config);
+        assert_eq!(res.shares, export.shares);
+        assert_eq!(res.denoms, export.denoms);
+        if preserve_counters {
+            assert_eq!(res.counters, export.counters);
+        } else {
+            assert_eq!(res.counters.last_deposit_record_id, 0);
+        }
+    }
+}
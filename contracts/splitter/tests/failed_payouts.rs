@@ -0,0 +1,252 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{
+    mock_env, mock_info, MockApi, MockQuerier, MockStorage, MOCK_CONTRACT_ADDR,
+};
+use cosmwasm_std::{
+    coin, coins, from_binary, BankMsg, Coin, CosmosMsg, Decimal, OwnedDeps, Reply, ReplyOn,
+    SubMsgResult,
+};
+use pantheon_splitter::{
+    contract::{execute, instantiate, query, reply},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+const FALLBACK: &str = "fallback";
+
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery> {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[(MOCK_CONTRACT_ADDR, &coins(1_000, DENOM))]),
+        custom_query_type: PhantomData,
+    };
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            admin: ADMIN.to_string(),
+            shares: vec![
+                Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::from_str("0.52").unwrap(),
+                    ibc: None,
+                    burn: false,
+                },
+                Share {
+                    recipient: USER2.to_string(),
+                    percentage: Decimal::from_str("0.25").unwrap(),
+                    ibc: None,
+                    burn: false,
+                },
+                Share {
+                    recipient: USER3.to_string(),
+                    percentage: Decimal::from_str("0.23").unwrap(),
+                    ibc: None,
+                    burn: false,
+                },
+            ],
+            mutable: true,
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
+        },
+    )
+    .unwrap();
+
+    deps
+}
+
+fn failed_payouts(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery>,
+    recipient: &str,
+) -> Vec<Coin> {
+    from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::FailedPayouts {
+                recipient: recipient.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_failed_payout_is_claimable() {
+    let mut deps = mock_deps();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::DistributeNativeTokens {},
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 3);
+    assert!(res
+        .messages
+        .iter()
+        .all(|msg| msg.reply_on == ReplyOn::Error));
+
+    // The payout of the second recipient fails
+    let failed = res
+        .messages
+        .iter()
+        .find(|msg| {
+            msg.msg
+                == CosmosMsg::Bank(BankMsg::Send {
+                    to_address: USER2.to_string(),
+                    amount: coins(250, DENOM),
+                })
+        })
+        .unwrap();
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: failed.id,
+            result: SubMsgResult::Err("blocked address".to_string()),
+        },
+    )
+    .unwrap();
+
+    assert_eq!(failed_payouts(&deps, USER2), vec![coin(250, DENOM)]);
+    assert!(failed_payouts(&deps, USER).is_empty());
+
+    // The failed amount is not distributed again
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::DistributeNativeTokens {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: USER.to_string(),
+            amount: coins(390, DENOM),
+        })
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(USER2, &[]),
+        ExecuteMsg::ClaimFailedPayouts {},
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: USER2.to_string(),
+            amount: coins(250, DENOM),
+        })
+    );
+    assert!(failed_payouts(&deps, USER2).is_empty());
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(USER2, &[]),
+        ExecuteMsg::ClaimFailedPayouts {},
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::NothingToClaim {}.to_string()
+    );
+}
+
+fn redirected_payouts(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery>,
+    recipient: &str,
+) -> Vec<Coin> {
+    from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::RedirectedPayouts {
+                recipient: recipient.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_undeliverable_payouts_sent_to_fallback() {
+    let mut deps = mock_deps();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateFallbackAddress {
+            address: Some(FALLBACK.to_string()),
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateFrozenRecipient {
+            recipient: USER3.to_string(),
+            frozen: true,
+        },
+    )
+    .unwrap();
+
+    // The portion of the frozen recipient is paid to the fallback address
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::DistributeNativeTokens {},
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 3);
+    assert_eq!(
+        res.messages[2].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: FALLBACK.to_string(),
+            amount: coins(230, DENOM),
+        })
+    );
+    assert_eq!(redirected_payouts(&deps, USER3), vec![coin(230, DENOM)]);
+
+    // A failed payout is sent to the fallback address instead of being held for a claim
+    let failed = &res.messages[1];
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: failed.id,
+            result: SubMsgResult::Err("blocked address".to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: FALLBACK.to_string(),
+            amount: coins(250, DENOM),
+        })
+    );
+    assert_eq!(redirected_payouts(&deps, USER2), vec![coin(250, DENOM)]);
+    assert!(failed_payouts(&deps, USER2).is_empty());
+}
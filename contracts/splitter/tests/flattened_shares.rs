@@ -0,0 +1,56 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::Decimal;
+use pantheon_splitter::msg::QueryMsg;
+use pantheon_utils::Share;
+
+fn share(recipient: &str, percentage: &str) -> Share {
+    Share {
+        recipient: recipient.to_string(),
+        percentage: Decimal::from_str(percentage).unwrap(),
+        ibc: None,
+        burn: false,
+    }
+}
+
+#[test]
+fn test_flattened_shares() {
+    let mut app = mock_app();
+    let inner = proper_instantiate(
+        &mut app,
+        vec![share(USER, "0.5"), share(USER2, "0.5")],
+        true,
+    );
+    let outer = proper_instantiate(
+        &mut app,
+        vec![
+            share(inner.as_str(), "0.5"),
+            share(USER, "0.3"),
+            share(USER3, "0.2"),
+        ],
+        true,
+    );
+
+    let res: Vec<Share> = app
+        .wrap()
+        .query_wasm_smart(outer, &QueryMsg::FlattenedShares {})
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![
+            share(USER, "0.55"),
+            share(USER2, "0.25"),
+            share(USER3, "0.2"),
+        ]
+    );
+
+    // Splitters without nested splitters are returned as they are
+    let res: Vec<Share> = app
+        .wrap()
+        .query_wasm_smart(inner, &QueryMsg::FlattenedShares {})
+        .unwrap();
+    assert_eq!(res, vec![share(USER, "0.5"), share(USER2, "0.5")]);
+}
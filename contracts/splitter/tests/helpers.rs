@@ -1,8 +1,8 @@
 use archway_bindings::{ArchwayMsg, ArchwayQuery};
-use cosmwasm_std::{testing::MockApi, Addr, Coin, Decimal, MemoryStorage, Uint128};
-use cw_multi_test::{
-    custom_app, App, BankKeeper, Contract, ContractWrapper, Executor, FailingModule, WasmKeeper,
-};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use cw20::Cw20Coin;
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+use pantheon_archway_mock::{archway_app, ArchwayApp};
 use pantheon_splitter::msg::InstantiateMsg;
 use pantheon_utils::Share;
 use std::str::FromStr;
@@ -12,6 +12,16 @@ pub fn factory_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
         pantheon_splitter::contract::execute,
         pantheon_splitter::contract::instantiate,
         pantheon_splitter::contract::query,
+    )
+    .with_sudo(pantheon_splitter::contract::sudo);
+    Box::new(contract)
+}
+
+pub fn cw20_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(
+        pantheon_share_token::contract::execute,
+        pantheon_share_token::contract::instantiate,
+        pantheon_share_token::contract::query,
     );
     Box::new(contract)
 }
@@ -23,39 +33,21 @@ pub const USER3: &str = "user3";
 
 pub const DENOM: &str = "aconst";
 
-pub fn mock_app() -> App<
-    BankKeeper,
-    MockApi,
-    MemoryStorage,
-    FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
-    WasmKeeper<ArchwayMsg, ArchwayQuery>,
-> {
-    custom_app::<ArchwayMsg, ArchwayQuery, _>(|router, _, storage| {
-        router
-            .bank
-            .init_balance(
-                storage,
-                &Addr::unchecked(ADMIN),
-                vec![Coin {
-                    denom: DENOM.to_string(),
-                    amount: Uint128::new(1_000_000_000_000_000_000_000_000_000_000),
-                }],
-            )
-            .unwrap();
+pub fn mock_app() -> ArchwayApp {
+    archway_app(|bank, _, storage| {
+        bank.init_balance(
+            storage,
+            &Addr::unchecked(ADMIN),
+            vec![Coin {
+                denom: DENOM.to_string(),
+                amount: Uint128::new(1_000_000_000_000_000_000_000_000_000_000),
+            }],
+        )
+        .unwrap();
     })
 }
 
-pub fn proper_instantiate(
-    app: &mut App<
-        BankKeeper,
-        MockApi,
-        MemoryStorage,
-        FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
-        WasmKeeper<ArchwayMsg, ArchwayQuery>,
-    >,
-    shares: Vec<Share>,
-    mutable: bool,
-) -> Addr {
+pub fn proper_instantiate(app: &mut ArchwayApp, shares: Vec<Share>, mutable: bool) -> Addr {
     let code_id = app.store_code(factory_contract());
 
     app.instantiate_contract(
@@ -65,6 +57,9 @@ pub fn proper_instantiate(
             shares,
             mutable,
             admin: ADMIN.to_string(),
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
         },
         &vec![],
         "Pantheon Splitter",
@@ -73,29 +68,50 @@ pub fn proper_instantiate(
     .unwrap()
 }
 
-pub fn proper_instantiate_with_shares(
-    app: &mut App<
-        BankKeeper,
-        MockApi,
-        MemoryStorage,
-        FailingModule<ArchwayMsg, ArchwayQuery, cosmwasm_std::Empty>,
-        WasmKeeper<ArchwayMsg, ArchwayQuery>,
-    >,
-) -> Addr {
+pub fn instantiate_cw20(app: &mut ArchwayApp, amount: u128) -> Addr {
+    let code_id = app.store_code(cw20_contract());
+
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(ADMIN),
+        &pantheon_share_token::msg::InstantiateMsg {
+            name: "Pantheon Token".to_string(),
+            symbol: "PNTH".to_string(),
+            decimals: 6,
+            initial_balances: vec![Cw20Coin {
+                address: ADMIN.to_string(),
+                amount: Uint128::new(amount),
+            }],
+            denom: DENOM.to_string(),
+        },
+        &vec![],
+        "Pantheon Token",
+        None,
+    )
+    .unwrap()
+}
+
+pub fn proper_instantiate_with_shares(app: &mut ArchwayApp) -> Addr {
     let code_id = app.store_code(factory_contract());
 
     let shares = vec![
         Share {
             recipient: USER.to_string(),
             percentage: Decimal::from_str("0.52").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.23").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -106,6 +122,9 @@ pub fn proper_instantiate_with_shares(
             shares,
             mutable: true,
             admin: ADMIN.to_string(),
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
         },
         &vec![],
         "Pantheon Splitter",
@@ -0,0 +1,164 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{
+    mock_env, mock_ibc_channel_open_init, mock_ibc_packet_recv, mock_info, MockApi, MockQuerier,
+    MockStorage,
+};
+use cosmwasm_std::{from_binary, Decimal, IbcOrder, OwnedDeps};
+use pantheon_splitter::{
+    contract::{execute, instantiate, query},
+    ibc_admin::{ibc_channel_open, ibc_packet_receive, IBC_VERSION},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SharesResponse, SplitterAck, SplitterPacketMsg},
+    state::IbcController,
+    ContractError,
+};
+use pantheon_utils::Share;
+
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery> {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData,
+    };
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            admin: ADMIN.to_string(),
+            shares: vec![Share {
+                recipient: USER.to_string(),
+                percentage: Decimal::one(),
+                ibc: None,
+                burn: false,
+            }],
+            mutable: true,
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
+        },
+    )
+    .unwrap();
+
+    deps
+}
+
+fn new_shares() -> Vec<Share> {
+    vec![
+        Share {
+            recipient: USER.to_string(),
+            percentage: Decimal::from_str("0.5").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+        Share {
+            recipient: USER2.to_string(),
+            percentage: Decimal::from_str("0.5").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+    ]
+}
+
+#[test]
+fn test_channel_open() {
+    let mut deps = mock_deps();
+
+    let msg = mock_ibc_channel_open_init("channel-1", IbcOrder::Unordered, IBC_VERSION);
+    ibc_channel_open(deps.as_mut(), mock_env(), msg).unwrap();
+
+    let msg = mock_ibc_channel_open_init("channel-1", IbcOrder::Ordered, IBC_VERSION);
+    let err = ibc_channel_open(deps.as_mut(), mock_env(), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidIbcChannel {}.to_string()
+    );
+
+    let msg = mock_ibc_channel_open_init("channel-1", IbcOrder::Unordered, "ics20-1");
+    let err = ibc_channel_open(deps.as_mut(), mock_env(), msg).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidIbcChannel {}.to_string()
+    );
+}
+
+#[test]
+fn test_update_shares_packet() {
+    let mut deps = mock_deps();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdateIbcControllers {
+            controllers: vec![IbcController {
+                channel_id: "channel-1".to_string(),
+                port_id: "their-port".to_string(),
+            }],
+        },
+    )
+    .unwrap();
+
+    let msg = mock_ibc_packet_recv(
+        "channel-1",
+        &SplitterPacketMsg::UpdateShares {
+            shares: new_shares(),
+        },
+    )
+    .unwrap();
+    let res = ibc_packet_receive(deps.as_mut(), mock_env(), msg).unwrap();
+    let ack: SplitterAck = from_binary(&res.acknowledgement).unwrap();
+    assert!(matches!(ack, SplitterAck::Result(_)));
+
+    let res: SharesResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Shares {
+                start_after: None,
+                limit: None,
+                order: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.shares, new_shares());
+}
+
+#[test]
+fn test_unauthorized_channel() {
+    let mut deps = mock_deps();
+
+    let msg = mock_ibc_packet_recv(
+        "channel-2",
+        &SplitterPacketMsg::UpdateShares {
+            shares: new_shares(),
+        },
+    )
+    .unwrap();
+    let res = ibc_packet_receive(deps.as_mut(), mock_env(), msg).unwrap();
+    let ack: SplitterAck = from_binary(&res.acknowledgement).unwrap();
+    assert_eq!(
+        ack,
+        SplitterAck::Error(ContractError::Unauthorized {}.to_string())
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(USER, &[]),
+        ExecuteMsg::UpdateIbcControllers {
+            controllers: vec![],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), ContractError::Unauthorized {}.to_string());
+}
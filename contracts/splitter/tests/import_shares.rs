@@ -0,0 +1,155 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Decimal};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, ImportResponse, QueryMsg, SharesResponse},
+    state::ImportMode,
+    ContractError,
+};
+use pantheon_utils::Share;
+
+const USER4: &str = "user4";
+
+fn share(recipient: &str, percentage: &str) -> Share {
+    Share {
+        recipient: recipient.to_string(),
+        percentage: Decimal::from_str(percentage).unwrap(),
+        ibc: None,
+        burn: false,
+    }
+}
+
+fn import(
+    app: &mut ArchwayApp,
+    factory_addr: &Addr,
+    shares: Vec<Share>,
+    mode: ImportMode,
+) -> Result<(), String> {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::ImportShares { shares, mode },
+        &vec![],
+    )
+    .map(|_| ())
+    .map_err(|err| err.source().unwrap().to_string())
+}
+
+fn commit(app: &mut ArchwayApp, factory_addr: &Addr) -> Result<(), String> {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::CommitImport {},
+        &vec![],
+    )
+    .map(|_| ())
+    .map_err(|err| err.source().unwrap().to_string())
+}
+
+fn query_shares(app: &ArchwayApp, factory_addr: &Addr) -> Vec<Share> {
+    let res: SharesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::Shares {
+                start_after: None,
+                limit: None,
+                order: None,
+            },
+        )
+        .unwrap();
+    res.shares
+}
+
+#[test]
+fn test_replace_import() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    import(
+        &mut app,
+        &factory_addr,
+        vec![share(USER, "0.5")],
+        ImportMode::Replace,
+    )
+    .unwrap();
+
+    assert_eq!(
+        import(
+            &mut app,
+            &factory_addr,
+            vec![share(USER2, "0.5")],
+            ImportMode::Append
+        ),
+        Err(ContractError::ImportModeMismatch {}.to_string())
+    );
+
+    // The incomplete table is only rejected by the commit
+    assert_eq!(
+        commit(&mut app, &factory_addr),
+        Err(ContractError::PercentageLimitNotMet {}.to_string())
+    );
+
+    import(
+        &mut app,
+        &factory_addr,
+        vec![share(USER2, "0.5")],
+        ImportMode::Replace,
+    )
+    .unwrap();
+
+    let res: ImportResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Import {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.mode, Some(ImportMode::Replace));
+    assert_eq!(res.shares.len(), 2);
+
+    commit(&mut app, &factory_addr).unwrap();
+    assert_eq!(
+        query_shares(&app, &factory_addr),
+        vec![share(USER, "0.5"), share(USER2, "0.5")]
+    );
+
+    assert_eq!(
+        commit(&mut app, &factory_addr),
+        Err(ContractError::NoImport {}.to_string())
+    );
+}
+
+#[test]
+fn test_append_import() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    // The share of an existing recipient is replaced by the imported one
+    import(
+        &mut app,
+        &factory_addr,
+        vec![share(USER3, "0.2"), share(USER4, "0.03")],
+        ImportMode::Append,
+    )
+    .unwrap();
+    commit(&mut app, &factory_addr).unwrap();
+
+    assert_eq!(
+        query_shares(&app, &factory_addr),
+        vec![
+            share(USER, "0.52"),
+            share(USER2, "0.25"),
+            share(USER3, "0.2"),
+            share(USER4, "0.03"),
+        ]
+    );
+}
@@ -7,11 +7,11 @@ use cosmwasm_std::{Addr, Decimal};
 use cw_multi_test::Executor;
 
 use pantheon_splitter::{
-    msg::{InstantiateMsg, QueryMsg},
+    msg::{ConfigResponse, InstantiateMsg, QueryMsg, SharesResponse},
     ContractError,
 };
 
-use pantheon_utils::Share;
+use pantheon_utils::{IbcRoute, Share};
 
 #[test]
 fn test_happy_path() {
@@ -23,14 +23,20 @@ fn test_happy_path() {
         Share {
             recipient: USER.to_string(),
             percentage: Decimal::from_str("0.52").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.23").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -41,6 +47,9 @@ fn test_happy_path() {
             shares,
             mutable: false,
             admin: ADMIN.to_string(),
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
         },
         &vec![],
         "Pantheon Splitter",
@@ -54,23 +63,24 @@ fn test_happy_path() {
     assert_eq!(res.admin, None);
     assert_eq!(res.label, "Pantheon Splitter");
 
-    let res: Vec<Share> = app
+    let res: SharesResponse = app
         .wrap()
         .query_wasm_smart(
             Addr::unchecked("contract0"),
             &QueryMsg::Shares {
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
-    assert_eq!(res.len(), 3);
-    assert_eq!(res[0].recipient, USER.to_string());
-    assert_eq!(res[0].percentage, Decimal::from_str("0.52").unwrap());
-    assert_eq!(res[1].recipient, USER2.to_string());
-    assert_eq!(res[1].percentage, Decimal::from_str("0.25").unwrap());
-    assert_eq!(res[2].recipient, USER3.to_string());
-    assert_eq!(res[2].percentage, Decimal::from_str("0.23").unwrap());
+    assert_eq!(res.shares.len(), 3);
+    assert_eq!(res.shares[0].recipient, USER.to_string());
+    assert_eq!(res.shares[0].percentage, Decimal::from_str("0.52").unwrap());
+    assert_eq!(res.shares[1].recipient, USER2.to_string());
+    assert_eq!(res.shares[1].percentage, Decimal::from_str("0.25").unwrap());
+    assert_eq!(res.shares[2].recipient, USER3.to_string());
+    assert_eq!(res.shares[2].percentage, Decimal::from_str("0.23").unwrap());
 }
 
 #[test]
@@ -83,14 +93,20 @@ fn test_percentage_limit_exceeded() {
         Share {
             recipient: USER.to_string(),
             percentage: Decimal::from_str("0.52").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.30").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -102,6 +118,9 @@ fn test_percentage_limit_exceeded() {
                 shares,
                 mutable: false,
                 admin: ADMIN.to_string(),
+                transfer_requires_approval: false,
+                disable_emergency_withdraw: false,
+                restore: None,
             },
             &vec![],
             "Pantheon Splitter",
@@ -124,10 +143,14 @@ fn test_percentage_limit_not_met() {
         Share {
             recipient: USER.to_string(),
             percentage: Decimal::from_str("0.52").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -139,6 +162,9 @@ fn test_percentage_limit_not_met() {
                 shares,
                 mutable: false,
                 admin: ADMIN.to_string(),
+                transfer_requires_approval: false,
+                disable_emergency_withdraw: false,
+                restore: None,
             },
             &vec![],
             "Pantheon Splitter",
@@ -150,3 +176,123 @@ fn test_percentage_limit_not_met() {
         ContractError::PercentageLimitNotMet {}.to_string()
     )
 }
+
+#[test]
+fn test_ibc_route() {
+    let mut app = mock_app();
+
+    let code_id = app.store_code(factory_contract());
+
+    let shares = vec![
+        Share {
+            recipient: USER.to_string(),
+            percentage: Decimal::from_str("0.6").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+        Share {
+            recipient: USER2.to_string(),
+            percentage: Decimal::from_str("0.4").unwrap(),
+            ibc: Some(IbcRoute {
+                channel_id: "channel-0".to_string(),
+                remote_address: "osmo1recipient".to_string(),
+                timeout_seconds: 600,
+                memo: Some("{\"forward\":{\"receiver\":\"cosmos1recipient\"}}".to_string()),
+            }),
+            burn: false,
+        },
+    ];
+
+    let splitter_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                shares,
+                mutable: false,
+                admin: ADMIN.to_string(),
+                transfer_requires_approval: false,
+                disable_emergency_withdraw: false,
+                restore: None,
+            },
+            &vec![],
+            "Pantheon Splitter",
+            None,
+        )
+        .unwrap();
+
+    let res: Share = app
+        .wrap()
+        .query_wasm_smart(
+            splitter_addr,
+            &QueryMsg::Share {
+                recipient: USER2.to_string(),
+            },
+        )
+        .unwrap();
+    let ibc = res.ibc.unwrap();
+    assert_eq!(ibc.channel_id, "channel-0".to_string());
+    assert_eq!(
+        ibc.memo,
+        Some("{\"forward\":{\"receiver\":\"cosmos1recipient\"}}".to_string())
+    );
+}
+
+#[test]
+fn test_invalid_ibc_route() {
+    let mut app = mock_app();
+
+    let code_id = app.store_code(factory_contract());
+
+    let shares = vec![Share {
+        recipient: USER.to_string(),
+        percentage: Decimal::from_str("1").unwrap(),
+        ibc: Some(IbcRoute {
+            channel_id: "".to_string(),
+            remote_address: "osmo1recipient".to_string(),
+            timeout_seconds: 600,
+            memo: None,
+        }),
+        burn: false,
+    }];
+
+    let err = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                shares,
+                mutable: false,
+                admin: ADMIN.to_string(),
+                transfer_requires_approval: false,
+                disable_emergency_withdraw: false,
+                restore: None,
+            },
+            &vec![],
+            "Pantheon Splitter",
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidIbcRoute {}.to_string()
+    )
+}
+
+#[test]
+fn test_config() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(factory_addr, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.config.admin, Addr::unchecked(ADMIN));
+    assert_eq!(res.locked, false);
+    assert_eq!(res.share_count, 3);
+    assert_eq!(res.total_percentage, Decimal::one());
+    assert_eq!(res.denoms, vec![DENOM.to_string()]);
+    assert_eq!(res.last_share_update, app.block_info().height);
+    assert_eq!(res.version.contract, "crates.io:pantheon-splitter");
+}
@@ -0,0 +1,101 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, Addr, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError,
+    Uint128,
+};
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+use pantheon_splitter::{
+    contract::{execute_distribute_native_tokens, instantiate},
+    msg::InstantiateMsg,
+    ContractError,
+};
+use pantheon_utils::Share;
+
+// Host contract embedding the native token distribution of the splitter
+#[cw_serde]
+enum HostExecuteMsg {
+    Distribute {},
+}
+
+fn host_execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: HostExecuteMsg,
+) -> Result<Response<ArchwayMsg>, ContractError> {
+    match msg {
+        HostExecuteMsg::Distribute {} => execute_distribute_native_tokens(deps, env, info),
+    }
+}
+
+fn host_query(_deps: Deps<ArchwayQuery>, _env: Env, _msg: Empty) -> Result<Binary, StdError> {
+    Err(StdError::generic_err("no queries"))
+}
+
+fn host_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(host_execute, instantiate, host_query);
+    Box::new(contract)
+}
+
+#[test]
+fn test_embedded_distribution() {
+    let mut app = mock_app();
+    let code_id = app.store_code(host_contract());
+
+    let host_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                admin: ADMIN.to_string(),
+                shares: vec![
+                    Share {
+                        recipient: USER.to_string(),
+                        percentage: Decimal::from_str("0.6").unwrap(),
+                        ibc: None,
+                        burn: false,
+                    },
+                    Share {
+                        recipient: USER2.to_string(),
+                        percentage: Decimal::from_str("0.4").unwrap(),
+                        ibc: None,
+                        burn: false,
+                    },
+                ],
+                mutable: false,
+                transfer_requires_approval: false,
+                disable_emergency_withdraw: false,
+                restore: None,
+            },
+            &vec![],
+            "Host",
+            None,
+        )
+        .unwrap();
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        host_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        host_addr,
+        &HostExecuteMsg::Distribute {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(600));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(400));
+}
@@ -1,13 +1,16 @@
 pub mod helpers;
 use helpers::*;
 
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Decimal};
 use cw_multi_test::Executor;
+use cw_utils::Expiration;
+use pantheon_archway_mock::ArchwayApp;
 use pantheon_splitter::{
-    msg::{ExecuteMsg, QueryMsg},
-    state::Config,
+    msg::{ConfigResponse, ExecuteMsg, QueryMsg, SwapConfigMsg},
+    state::ExpiredSharePolicy,
     ContractError,
 };
+use pantheon_utils::Share;
 
 #[test]
 fn test_happy_path() {
@@ -17,16 +20,17 @@ fn test_happy_path() {
     app.execute_contract(
         Addr::unchecked(ADMIN),
         factory_addr.clone(),
-        &ExecuteMsg::LockContract {},
+        &ExecuteMsg::LockContract { until: None },
         &vec![],
     )
     .unwrap();
 
-    let res: Config = app
+    let res: ConfigResponse = app
         .wrap()
         .query_wasm_smart(factory_addr.clone(), &QueryMsg::Config {})
         .unwrap();
-    assert_eq!(res.mutable, false);
+    assert_eq!(res.config.mutable, false);
+    assert_eq!(res.locked, true);
 
     let err = app
         .execute_contract(
@@ -51,7 +55,7 @@ fn test_invalid_admin() {
         .execute_contract(
             Addr::unchecked(USER),
             factory_addr.clone(),
-            &ExecuteMsg::LockContract {},
+            &ExecuteMsg::LockContract { until: None },
             &vec![],
         )
         .unwrap_err();
@@ -60,3 +64,172 @@ fn test_invalid_admin() {
         ContractError::Unauthorized {}.to_string()
     )
 }
+
+#[test]
+fn test_timed_lock() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let until = Expiration::AtHeight(app.block_info().height + 100);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::LockContract { until: Some(until) },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.config.locked_until, Some(until));
+    assert_eq!(res.locked, true);
+
+    // A timed lock can not replace the running lock
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::LockContract {
+                until: Some(Expiration::AtHeight(app.block_info().height + 1)),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ContractNotMutable {}.to_string()
+    );
+
+    app.update_block(|block| block.height += 100);
+
+    let res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.locked, false);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr,
+        &ExecuteMsg::UpdateShares {
+            shares: vec![Share {
+                recipient: USER.to_string(),
+                percentage: Decimal::one(),
+                ibc: None,
+                burn: false,
+            }],
+        },
+        &vec![],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_expired_lock() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::LockContract {
+                until: Some(Expiration::AtHeight(app.block_info().height)),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidLockExpiration {}.to_string()
+    );
+}
+
+fn assert_not_mutable(app: &mut ArchwayApp, contract: &Addr, msg: &ExecuteMsg) {
+    let err = app
+        .execute_contract(Addr::unchecked(ADMIN), contract.clone(), msg, &vec![])
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ContractNotMutable {}.to_string()
+    );
+}
+
+#[test]
+fn test_locked_undeliverable_portions() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+
+    // The admin cannot divert the portions of the recipients to the fallback address
+    assert_not_mutable(
+        &mut app,
+        &factory_addr,
+        &ExecuteMsg::UpdateFallbackAddress {
+            address: Some(ADMIN.to_string()),
+        },
+    );
+    assert_not_mutable(
+        &mut app,
+        &factory_addr,
+        &ExecuteMsg::UpdateFrozenRecipient {
+            recipient: USER.to_string(),
+            frozen: true,
+        },
+    );
+    assert_not_mutable(
+        &mut app,
+        &factory_addr,
+        &ExecuteMsg::UpdateOverflowAddress {
+            address: Some(ADMIN.to_string()),
+        },
+    );
+    assert_not_mutable(
+        &mut app,
+        &factory_addr,
+        &ExecuteMsg::UpdateExpiredSharePolicy {
+            policy: ExpiredSharePolicy::Fallback,
+        },
+    );
+}
+
+#[test]
+fn test_locked_swap_config() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+
+    // The admin cannot send the balance to an adapter of its own
+    assert_not_mutable(
+        &mut app,
+        &factory_addr,
+        &ExecuteMsg::UpdateSwapConfig {
+            swap: Some(SwapConfigMsg {
+                adapter: ADMIN.to_string(),
+                target_denom: DENOM.to_string(),
+                max_slippage: Decimal::percent(1),
+            }),
+        },
+    );
+    assert_not_mutable(
+        &mut app,
+        &factory_addr,
+        &ExecuteMsg::UpdateSwapConfig { swap: None },
+    );
+}
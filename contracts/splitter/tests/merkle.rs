@@ -0,0 +1,160 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, HexBinary, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::MerkleDistribution,
+    ContractError,
+};
+use sha2::{Digest, Sha256};
+
+fn leaf(address: &str, amount: u128) -> [u8; 32] {
+    Sha256::digest(format!("{}{}", address, amount)).into()
+}
+
+fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let mut pair = [a, b];
+    pair.sort_unstable();
+    Sha256::digest(pair.concat()).into()
+}
+
+#[test]
+fn test_merkle_distribution() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let user_leaf = leaf(USER, 600);
+    let user2_leaf = leaf(USER2, 400);
+    let root = parent(user_leaf, user2_leaf);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::RegisterMerkleDistribution {
+                merkle_root: HexBinary::from(root.to_vec()),
+                amount: coin(1_001, DENOM),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InsufficientBalance {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::RegisterMerkleDistribution {
+            merkle_root: HexBinary::from(root.to_vec()),
+            amount: coin(1_000, DENOM),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // A wrong amount does not match the root
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::ClaimMerkleDistribution {
+                id: 1,
+                amount: Uint128::new(1_000),
+                proof: vec![HexBinary::from(user2_leaf.to_vec())],
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidMerkleProof {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimMerkleDistribution {
+            id: 1,
+            amount: Uint128::new(600),
+            proof: vec![HexBinary::from(user2_leaf.to_vec())],
+        },
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(600));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::ClaimMerkleDistribution {
+                id: 1,
+                amount: Uint128::new(600),
+                proof: vec![HexBinary::from(user2_leaf.to_vec())],
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::AlreadyClaimed {}.to_string()
+    );
+
+    // The reserved amount is not distributed to the shares
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(600));
+
+    app.execute_contract(
+        Addr::unchecked(USER2),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimMerkleDistribution {
+            id: 1,
+            amount: Uint128::new(400),
+            proof: vec![HexBinary::from(user_leaf.to_vec())],
+        },
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(400));
+
+    let res: MerkleDistribution = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::MerkleDistribution { id: 1 },
+        )
+        .unwrap();
+    assert_eq!(res.claimed, Uint128::new(1_000));
+
+    let res: bool = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::MerkleClaimed {
+                id: 1,
+                recipient: USER2.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res);
+}
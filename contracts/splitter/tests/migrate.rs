@@ -0,0 +1,130 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{from_binary, Addr, Decimal, OwnedDeps, Storage};
+use cw2::{set_contract_version, VersionError};
+use cw_ownable::Ownership;
+use pantheon_splitter::{
+    contract::{migrate, query},
+    msg::{ConfigResponse, MigrateMsg, QueryMsg, SharesResponse},
+    state::{LegacyConfig, LEGACY_CONFIG, LEGACY_SHARES},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+const CONTRACT_NAME: &str = "crates.io:pantheon-splitter";
+
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery> {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData::<ArchwayQuery>,
+    }
+}
+
+fn legacy_share(recipient: &str, percentage: &str) -> Share {
+    Share {
+        recipient: recipient.to_string(),
+        percentage: Decimal::from_str(percentage).unwrap(),
+        ibc: None,
+        burn: false,
+    }
+}
+
+#[test]
+fn test_migrate_legacy_layout() {
+    let mut deps = mock_deps();
+    set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.1").unwrap();
+
+    LEGACY_CONFIG
+        .save(
+            deps.as_mut().storage,
+            &LegacyConfig {
+                admin: Addr::unchecked(ADMIN),
+                mutable: true,
+            },
+        )
+        .unwrap();
+    for share in [legacy_share(USER, "0.6"), legacy_share(USER2, "0.4")] {
+        LEGACY_SHARES
+            .save(
+                deps.as_mut().storage,
+                Addr::unchecked(&share.recipient),
+                &share,
+            )
+            .unwrap();
+    }
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let res: Ownership<Addr> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Ownership {}).unwrap()).unwrap();
+    assert_eq!(res.owner, Some(Addr::unchecked(ADMIN)));
+
+    let res: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert!(res.config.mutable);
+    assert_eq!(res.share_count, 2);
+
+    let res: SharesResponse = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Shares {
+                start_after: None,
+                limit: None,
+                order: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.shares,
+        vec![legacy_share(USER, "0.6"), legacy_share(USER2, "0.4")]
+    );
+
+    // Migrating the current layout again changes nothing
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+}
+
+#[test]
+fn test_migrate_unrelated_contract() {
+    let mut deps = mock_deps();
+    set_contract_version(deps.as_mut().storage, "crates.io:cw20-base", "0.0.1").unwrap();
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Version(VersionError::WrongContract { .. })
+    ));
+}
+
+#[test]
+fn test_migrate_newer_version() {
+    let mut deps = mock_deps();
+    set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0").unwrap();
+
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::Version(VersionError::WrongVersion { .. })
+    ));
+}
+
+#[test]
+fn test_migrate_unreadable_config() {
+    let mut deps = mock_deps();
+    set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "0.0.1").unwrap();
+
+    // A config in neither layout is not skipped as if there was nothing to convert
+    deps.storage.set(b"config", b"{\"admin\":1}");
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert!(matches!(err, ContractError::Std(_)));
+}
@@ -0,0 +1,174 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Coin, Uint128};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+
+fn distribute(app: &mut ArchwayApp, factory_addr: &Addr, amount: u128) {
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(amount, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+}
+
+fn carried_balances(app: &ArchwayApp, factory_addr: &Addr, recipient: &str) -> Vec<Coin> {
+    app.wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::CarriedBalances {
+                recipient: recipient.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+fn set_min_payout(app: &mut ArchwayApp, factory_addr: &Addr, amount: Option<Uint128>) {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateMinPayout {
+            recipient: USER3.to_string(),
+            denom: DENOM.to_string(),
+            amount,
+        },
+        &vec![],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_carry_over() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateMinPayout {
+                recipient: USER3.to_string(),
+                denom: DENOM.to_string(),
+                amount: Some(Uint128::new(300)),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    set_min_payout(&mut app, &factory_addr, Some(Uint128::new(300)));
+
+    // The portion of 230 is below the minimum and carried over
+    distribute(&mut app, &factory_addr, 1_000);
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    assert_eq!(
+        carried_balances(&app, &factory_addr, USER3),
+        vec![coin(230, DENOM)]
+    );
+
+    // The carried amount is not split again and is paid with the next portion
+    distribute(&mut app, &factory_addr, 1_000);
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_040));
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(460));
+    assert!(carried_balances(&app, &factory_addr, USER3).is_empty());
+
+    // The recipient is validated like in the other queries
+    let res: Result<Vec<Coin>, _> = app.wrap().query_wasm_smart(
+        factory_addr,
+        &QueryMsg::CarriedBalances {
+            recipient: "INVALID".to_string(),
+        },
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_claim_carried_balances() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER3),
+            factory_addr.clone(),
+            &ExecuteMsg::ClaimCarriedBalances {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToClaim {}.to_string()
+    );
+
+    set_min_payout(&mut app, &factory_addr, Some(Uint128::new(1_000)));
+    distribute(&mut app, &factory_addr, 1_000);
+
+    app.execute_contract(
+        Addr::unchecked(USER3),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimCarriedBalances {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(230));
+    assert!(carried_balances(&app, &factory_addr, USER3).is_empty());
+
+    // Nothing is left reserved for the recipient
+    let res = app
+        .wrap()
+        .query_balance(factory_addr.clone(), DENOM)
+        .unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+}
+
+#[test]
+fn test_locked_min_payout() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::UpdateMinPayout {
+                recipient: USER3.to_string(),
+                denom: DENOM.to_string(),
+                amount: Some(Uint128::MAX),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ContractNotMutable {}.to_string()
+    );
+}
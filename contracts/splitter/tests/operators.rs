@@ -0,0 +1,134 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Decimal, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+const OPERATOR: &str = "operator";
+
+#[test]
+fn test_operator_distributes() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::AddOperator {
+                operator: OPERATOR.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::AddOperator {
+            operator: OPERATOR.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<Addr> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Operators {})
+        .unwrap();
+    assert_eq!(res, vec![Addr::unchecked(OPERATOR)]);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OPERATOR),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+
+    // Operators cannot change the configuration unless their role is permitted to
+    let err = app
+        .execute_contract(
+            Addr::unchecked(OPERATOR),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateShares {
+                shares: vec![Share {
+                    recipient: OPERATOR.to_string(),
+                    percentage: Decimal::one(),
+                    ibc: None,
+                    burn: false,
+                }],
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateRoleActions {
+            role: "operator".to_string(),
+            actions: vec!["distribute_native_tokens".to_string()],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(OPERATOR),
+            factory_addr.clone(),
+            &ExecuteMsg::WithdrawRewards {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::RemoveOperator {
+            operator: OPERATOR.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(OPERATOR),
+            factory_addr,
+            &ExecuteMsg::DistributeNativeTokens {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+}
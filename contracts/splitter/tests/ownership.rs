@@ -0,0 +1,103 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::Addr;
+use cw_multi_test::Executor;
+use cw_ownable::{Action, Ownership};
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+
+#[test]
+fn test_two_step_transfer() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateOwnership(Action::TransferOwnership {
+            new_owner: USER.to_string(),
+            expiry: None,
+        }),
+        &vec![],
+    )
+    .unwrap();
+
+    // The owner keeps the permissions until the transfer is accepted
+    let res: Ownership<Addr> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Ownership {})
+        .unwrap();
+    assert_eq!(res.owner, Some(Addr::unchecked(ADMIN)));
+    assert_eq!(res.pending_owner, Some(Addr::unchecked(USER)));
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateOwnership(Action::AcceptOwnership),
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Ownership<Addr> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Ownership {})
+        .unwrap();
+    assert_eq!(res.owner, Some(Addr::unchecked(USER)));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::LockContract { until: None },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr,
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_renounce() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateOwnership(Action::RenounceOwnership),
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Ownership<Addr> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Ownership {})
+        .unwrap();
+    assert_eq!(res.owner, None);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::LockContract { until: None },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+}
@@ -0,0 +1,129 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, PayoutRedirectMsg, QueryMsg},
+    state::PayoutRedirect,
+    ContractError,
+};
+
+const CHARITY: &str = "charity";
+
+fn distribute(app: &mut ArchwayApp, factory_addr: &Addr) {
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+}
+
+fn update_redirect(
+    app: &mut ArchwayApp,
+    factory_addr: &Addr,
+    recipient: &str,
+    redirect: Option<PayoutRedirectMsg>,
+) {
+    app.execute_contract(
+        Addr::unchecked(recipient),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdatePayoutRedirect { redirect },
+        &vec![],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_redirect_and_opt_out() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    update_redirect(
+        &mut app,
+        &factory_addr,
+        USER,
+        Some(PayoutRedirectMsg::Address {
+            address: CHARITY.to_string(),
+        }),
+    );
+    update_redirect(
+        &mut app,
+        &factory_addr,
+        USER3,
+        Some(PayoutRedirectMsg::OptOut),
+    );
+
+    let res: Option<PayoutRedirect> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PayoutRedirect {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Some(PayoutRedirect::Address {
+            address: Addr::unchecked(CHARITY)
+        })
+    );
+
+    distribute(&mut app, &factory_addr);
+
+    let res = app.wrap().query_balance(CHARITY, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(250));
+
+    // Without a fallback address the portion of the opted out recipient stays in the contract
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app
+        .wrap()
+        .query_balance(factory_addr.clone(), DENOM)
+        .unwrap();
+    assert_eq!(res.amount, Uint128::new(230));
+
+    // Removing the redirect pays the recipient again
+    update_redirect(&mut app, &factory_addr, USER, None);
+    distribute(&mut app, &factory_addr);
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(639));
+    let res = app.wrap().query_balance(CHARITY, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+}
+
+#[test]
+fn test_unauthorized() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::UpdatePayoutRedirect {
+                redirect: Some(PayoutRedirectMsg::OptOut),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+}
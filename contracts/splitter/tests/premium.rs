@@ -0,0 +1,96 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{coin, from_binary, Coin, CosmosMsg, Decimal, OwnedDeps};
+use pantheon_splitter::{
+    contract::{execute, instantiate, query},
+    msg::{ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery> {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData,
+    };
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            admin: ADMIN.to_string(),
+            shares: vec![Share {
+                recipient: USER.to_string(),
+                percentage: Decimal::one(),
+                ibc: None,
+                burn: false,
+            }],
+            mutable: true,
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
+        },
+    )
+    .unwrap();
+
+    deps
+}
+
+fn premium(
+    deps: &OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery>,
+) -> Option<Coin> {
+    let res: ConfigResponse =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    res.premium
+}
+
+#[test]
+fn test_update_premium() {
+    let mut deps = mock_deps();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(USER, &[]),
+        ExecuteMsg::UpdatePremium {
+            fee: coin(1_000, DENOM),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), ContractError::Unauthorized {}.to_string());
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdatePremium {
+            fee: coin(1_000, DENOM),
+        },
+    )
+    .unwrap();
+    assert!(matches!(
+        &res.messages[0].msg,
+        CosmosMsg::Stargate { type_url, .. } if type_url == "/archway.rewards.v1.MsgSetFlatFee"
+    ));
+    assert_eq!(premium(&deps), Some(coin(1_000, DENOM)));
+
+    // A zero premium removes it
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::UpdatePremium {
+            fee: coin(0, DENOM),
+        },
+    )
+    .unwrap();
+    assert_eq!(premium(&deps), None);
+}
@@ -0,0 +1,94 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, PriorityPaymentMsg},
+    ContractError,
+};
+
+const INFRA: &str = "infra";
+
+#[test]
+fn test_priority_payments() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdatePriorityPayments {
+                payments: vec![PriorityPaymentMsg {
+                    recipient: INFRA.to_string(),
+                    amount: coin(100, "unregistered"),
+                    usd_amount: None,
+                }],
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidPriorityPayment {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdatePriorityPayments {
+            payments: vec![PriorityPaymentMsg {
+                recipient: INFRA.to_string(),
+                amount: coin(100, DENOM),
+                usd_amount: None,
+            }],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // The balance must cover the priority payments
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(50, DENOM),
+    )
+    .unwrap();
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::DistributeNativeTokens {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InsufficientBalance {}.to_string()
+    );
+
+    // The percentage split applies to what is left after the payment
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_050, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(INFRA, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(100));
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(250));
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(230));
+}
@@ -0,0 +1,123 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{Decimal, Order};
+use pantheon_splitter::msg::{QueryMsg, SharesResponse};
+use pantheon_utils::Share;
+
+#[test]
+fn test_ascending_pages() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let res: SharesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Shares {
+                start_after: None,
+                limit: Some(2),
+                order: Some(Order::Ascending),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.shares.len(), 2);
+    assert_eq!(res.shares[0].recipient, USER.to_string());
+    assert_eq!(res.shares[1].recipient, USER2.to_string());
+    assert_eq!(res.next_start_after, Some(USER2.to_string()));
+    assert_eq!(res.total_count, 3);
+
+    let res: SharesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::Shares {
+                start_after: Some(USER2.to_string()),
+                limit: Some(2),
+                order: Some(Order::Ascending),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.shares.len(), 1);
+    assert_eq!(res.shares[0].recipient, USER3.to_string());
+    assert_eq!(res.next_start_after, None);
+}
+
+#[test]
+fn test_descending_pages() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let res: SharesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Shares {
+                start_after: None,
+                limit: Some(2),
+                order: Some(Order::Descending),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.shares.len(), 2);
+    assert_eq!(res.shares[0].recipient, USER3.to_string());
+    assert_eq!(res.shares[1].recipient, USER2.to_string());
+    assert_eq!(res.next_start_after, Some(USER2.to_string()));
+    assert_eq!(res.total_count, 3);
+
+    let res: SharesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::Shares {
+                start_after: Some(USER2.to_string()),
+                limit: Some(2),
+                order: Some(Order::Descending),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.shares.len(), 1);
+    assert_eq!(res.shares[0].recipient, USER.to_string());
+    assert_eq!(res.next_start_after, None);
+}
+
+#[test]
+fn test_shares_by_percentage() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let res: Vec<Share> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::SharesByPercentage {
+                min: None,
+                max: None,
+                limit: None,
+                order: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 3);
+    assert_eq!(res[0].recipient, USER.to_string());
+    assert_eq!(res[1].recipient, USER2.to_string());
+    assert_eq!(res[2].recipient, USER3.to_string());
+
+    let res: Vec<Share> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::SharesByPercentage {
+                min: Some(Decimal::from_str("0.23").unwrap()),
+                max: Some(Decimal::from_str("0.25").unwrap()),
+                limit: None,
+                order: Some(Order::Ascending),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].recipient, USER3.to_string());
+    assert_eq!(res[1].recipient, USER2.to_string());
+}
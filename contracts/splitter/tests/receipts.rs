@@ -0,0 +1,96 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::{PayoutKind, Receipt},
+};
+
+fn distribute(app: &mut ArchwayApp, factory_addr: &Addr, amount: u128) {
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(amount, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+}
+
+fn receipts(
+    app: &ArchwayApp,
+    factory_addr: &Addr,
+    recipient: &str,
+    start_after: Option<u64>,
+) -> Vec<Receipt> {
+    app.wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::ReceiptsByRecipient {
+                recipient: recipient.to_string(),
+                start_after,
+                limit: Some(1),
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_receipts_by_recipient() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    distribute(&mut app, &factory_addr, 1_000);
+    let height = app.block_info().height;
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::CloseEpoch {},
+        &vec![],
+    )
+    .unwrap();
+    app.update_block(|block| block.height += 1);
+    distribute(&mut app, &factory_addr, 2_000);
+
+    let res = receipts(&app, &factory_addr, USER2, None);
+    assert_eq!(
+        res,
+        vec![Receipt {
+            id: 2,
+            distribution_id: 1,
+            epoch: 0,
+            amount: coin(250, DENOM),
+            kind: PayoutKind::Send,
+            height,
+        }]
+    );
+
+    let res = receipts(&app, &factory_addr, USER2, Some(2));
+    assert_eq!(
+        res,
+        vec![Receipt {
+            id: 5,
+            distribution_id: 2,
+            epoch: 1,
+            amount: coin(500, DENOM),
+            kind: PayoutKind::Send,
+            height: height + 1,
+        }]
+    );
+
+    let res = receipts(&app, &factory_addr, USER2, Some(5));
+    assert!(res.is_empty());
+
+    let res = receipts(&app, &factory_addr, ADMIN, None);
+    assert!(res.is_empty());
+}
@@ -0,0 +1,125 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{to_binary, Addr, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{Cw20HookMsg, ExecuteMsg, QueryMsg},
+    ContractError,
+};
+
+fn cw20_balance(app: &ArchwayApp, token: &Addr, address: &str) -> Uint128 {
+    let res: BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            token,
+            &Cw20QueryMsg::Balance {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    res.balance
+}
+
+#[test]
+fn test_distribute_on_receive() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let token = instantiate_cw20(&mut app, 1_000_000);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        token.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: factory_addr.to_string(),
+            amount: Uint128::new(1_001),
+            msg: to_binary(&Cw20HookMsg::Distribute {}).unwrap(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    assert_eq!(cw20_balance(&app, &token, USER), Uint128::new(520));
+    assert_eq!(cw20_balance(&app, &token, USER2), Uint128::new(250));
+    assert_eq!(cw20_balance(&app, &token, USER3), Uint128::new(230));
+
+    // The rounding dust stays in the splitter
+    let res: Vec<Cw20Coin> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Cw20Balances {})
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![Cw20Coin {
+            address: token.to_string(),
+            amount: Uint128::new(1),
+        }]
+    );
+}
+
+#[test]
+fn test_hold_and_distribute() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let token = instantiate_cw20(&mut app, 1_000_000);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        token.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: factory_addr.to_string(),
+            amount: Uint128::new(10_000),
+            msg: to_binary(&Cw20HookMsg::Hold {}).unwrap(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    assert_eq!(cw20_balance(&app, &token, USER), Uint128::zero());
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::DistributeCw20 {
+                token: token.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeCw20 {
+            token: token.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    assert_eq!(cw20_balance(&app, &token, USER), Uint128::new(5_200));
+    assert_eq!(cw20_balance(&app, &token, USER2), Uint128::new(2_500));
+    assert_eq!(cw20_balance(&app, &token, USER3), Uint128::new(2_300));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::DistributeCw20 {
+                token: token.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToDistribute {}.to_string()
+    );
+}
@@ -0,0 +1,239 @@
+pub mod helpers;
+use helpers::*;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{
+    coin, coins, to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult, Uint128,
+};
+use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, OwnerOfResponse};
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+use cw_storage_plus::Map;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{Cw721HookMsg, ExecuteMsg, QueryMsg},
+    state::Listing,
+    ContractError,
+};
+
+// Minimal collection that supports the messages used by the splitter
+const OWNERS: Map<String, String> = Map::new("owners");
+
+fn collection_instantiate(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    Ok(Response::new())
+}
+
+fn collection_execute(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    msg: Cw721ExecuteMsg,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    match msg {
+        Cw721ExecuteMsg::TransferNft {
+            recipient,
+            token_id,
+        } => {
+            OWNERS.save(deps.storage, token_id, &recipient)?;
+            Ok(Response::new())
+        }
+        Cw721ExecuteMsg::SendNft {
+            contract,
+            token_id,
+            msg,
+        } => {
+            OWNERS.save(deps.storage, token_id.clone(), &contract)?;
+            let msg: CosmosMsg<ArchwayMsg> = Cw721ReceiveMsg {
+                sender: info.sender.to_string(),
+                token_id,
+                msg,
+            }
+            .into_cosmos_msg(contract)?;
+            Ok(Response::new().add_message(msg))
+        }
+        _ => Err(StdError::generic_err("unsupported")),
+    }
+}
+
+fn collection_query(deps: Deps<ArchwayQuery>, _env: Env, msg: Cw721QueryMsg) -> StdResult<Binary> {
+    match msg {
+        Cw721QueryMsg::OwnerOf { token_id, .. } => to_binary(&OwnerOfResponse {
+            owner: OWNERS.load(deps.storage, token_id)?,
+            approvals: vec![],
+        }),
+        _ => Err(StdError::generic_err("unsupported")),
+    }
+}
+
+fn collection_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract =
+        ContractWrapper::new(collection_execute, collection_instantiate, collection_query);
+    Box::new(contract)
+}
+
+fn send_nft(app: &mut ArchwayApp, factory_addr: &Addr) -> Addr {
+    let code_id = app.store_code(collection_contract());
+    let collection = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &Empty {},
+            &vec![],
+            "Collection",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        collection.clone(),
+        &Cw721ExecuteMsg::SendNft {
+            contract: factory_addr.to_string(),
+            token_id: "1".to_string(),
+            msg: to_binary(&Cw721HookMsg::List {
+                price: coin(1_000, DENOM),
+            })
+            .unwrap(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    collection
+}
+
+fn owner_of(app: &ArchwayApp, collection: &Addr) -> String {
+    let res: OwnerOfResponse = app
+        .wrap()
+        .query_wasm_smart(
+            collection,
+            &Cw721QueryMsg::OwnerOf {
+                token_id: "1".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    res.owner
+}
+
+#[test]
+fn test_happy_path() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let collection = send_nft(&mut app, &factory_addr);
+
+    let res: Listing = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Listing {
+                collection: collection.to_string(),
+                token_id: "1".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.donor, Addr::unchecked(USER));
+    assert_eq!(res.price, coin(1_000, DENOM));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::BuyNft {
+            collection: collection.to_string(),
+            token_id: "1".to_string(),
+        },
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    assert_eq!(owner_of(&app, &collection), ADMIN.to_string());
+
+    // The sale proceeds are distributed to the shares
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(250));
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(230));
+
+    let res: Vec<Listing> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::Listings {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn test_invalid_payment() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let collection = send_nft(&mut app, &factory_addr);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::BuyNft {
+                collection: collection.to_string(),
+                token_id: "1".to_string(),
+            },
+            &coins(999, DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidPayment {
+            price: coin(1_000, DENOM).to_string()
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_delist() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let collection = send_nft(&mut app, &factory_addr);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::DelistNft {
+                collection: collection.to_string(),
+                token_id: "1".to_string(),
+                recipient: None,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DelistNft {
+            collection: collection.to_string(),
+            token_id: "1".to_string(),
+            recipient: Some(USER.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    assert_eq!(owner_of(&app, &collection), USER.to_string());
+}
@@ -0,0 +1,281 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{coin, coins, Addr, Coin, Decimal, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::ShareCap,
+    ContractError,
+};
+use pantheon_utils::Share;
+
+const NEW_ADDRESS: &str = "user4";
+
+#[test]
+fn test_rotate_address() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    // The portion of USER is held and its payouts are capped before the rotation
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdatePreferredDenoms {
+            denoms: vec!["uother".to_string()],
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareCap {
+            recipient: USER.to_string(),
+            denom: DENOM.to_string(),
+            cap: Some(Uint128::new(10_000)),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::RotateAddress {
+            new_address: NEW_ADDRESS.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Share = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Share {
+                recipient: NEW_ADDRESS.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.percentage, Decimal::from_str("0.52").unwrap());
+
+    let res: Option<ShareCap> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::ShareCap {
+                recipient: NEW_ADDRESS.to_string(),
+                denom: DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Some(ShareCap {
+            cap: Uint128::new(10_000),
+            paid: Uint128::new(520),
+        })
+    );
+
+    let res: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PreferredDenoms {
+                recipient: NEW_ADDRESS.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec!["uother".to_string()]);
+
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::HeldBalances {
+                recipient: NEW_ADDRESS.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec![coin(520, DENOM)]);
+
+    // The held portion is claimed from the new address
+    app.execute_contract(
+        Addr::unchecked(NEW_ADDRESS),
+        factory_addr.clone(),
+        &ExecuteMsg::ClaimHeldBalances {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(NEW_ADDRESS, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::ClaimHeldBalances {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::NothingToClaim {}.to_string()
+    );
+}
+
+#[test]
+fn test_invalid_rotation() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::RotateAddress {
+                new_address: NEW_ADDRESS.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::RotateAddress {
+                new_address: USER2.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::RecipientExists {}.to_string()
+    );
+}
+
+#[test]
+fn test_rotation_requires_approval() {
+    let mut app = mock_app();
+    let code_id = app.store_code(factory_contract());
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                shares: vec![Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::one(),
+                    ibc: None,
+                    burn: false,
+                }],
+                mutable: true,
+                admin: ADMIN.to_string(),
+                transfer_requires_approval: true,
+                disable_emergency_withdraw: false,
+                restore: None,
+            },
+            &vec![],
+            "Pantheon Splitter",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdatePreferredDenoms {
+            denoms: vec!["uother".to_string()],
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::RotateAddress {
+            new_address: NEW_ADDRESS.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // The share stays with the old address until the admin approves
+    let res: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PendingShareTransfer {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Some(Addr::unchecked(NEW_ADDRESS)));
+
+    let res: Share = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Share {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.percentage, Decimal::one());
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::ApproveShareTransfer {
+            recipient: USER.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // The settings move along like in a direct rotation
+    let res: Share = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Share {
+                recipient: NEW_ADDRESS.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.percentage, Decimal::one());
+
+    let res: Vec<String> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::PreferredDenoms {
+                recipient: NEW_ADDRESS.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec!["uother".to_string()]);
+}
@@ -0,0 +1,288 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{Addr, Decimal};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg, SharesResponse},
+    state::{PendingApproversUpdate, PendingShareUpdate, ShareApprovers},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+fn new_shares() -> Vec<Share> {
+    vec![Share {
+        recipient: USER2.to_string(),
+        percentage: Decimal::one(),
+        ibc: None,
+        burn: false,
+    }]
+}
+
+fn query_shares(app: &ArchwayApp, factory_addr: &Addr) -> Vec<Share> {
+    let res: SharesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::Shares {
+                start_after: None,
+                limit: None,
+                order: None,
+            },
+        )
+        .unwrap();
+    res.shares
+}
+
+fn confirm(app: &mut ArchwayApp, factory_addr: &Addr, sender: &str) -> Option<String> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        factory_addr.clone(),
+        &ExecuteMsg::ConfirmShareUpdate {},
+        &vec![],
+    )
+    .err()
+    .map(|err| err.source().unwrap().to_string())
+}
+
+#[test]
+fn test_confirmed_share_update() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateShareApprovers {
+                approvers: vec![USER.to_string(), USER2.to_string()],
+                threshold: 3,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidApprovalThreshold {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareApprovers {
+            approvers: vec![USER.to_string(), USER2.to_string()],
+            threshold: 2,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let shares = query_shares(&app, &factory_addr);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShares {
+            shares: new_shares(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // The update is only proposed
+    assert_eq!(query_shares(&app, &factory_addr), shares);
+
+    assert_eq!(
+        confirm(&mut app, &factory_addr, USER3),
+        Some(ContractError::Unauthorized {}.to_string())
+    );
+    assert_eq!(confirm(&mut app, &factory_addr, USER), None);
+    assert_eq!(
+        confirm(&mut app, &factory_addr, USER),
+        Some(ContractError::AlreadyConfirmed {}.to_string())
+    );
+
+    let res: Option<PendingShareUpdate> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::PendingShareUpdate {})
+        .unwrap();
+    assert_eq!(res.unwrap().confirmations, vec![Addr::unchecked(USER)]);
+
+    assert_eq!(confirm(&mut app, &factory_addr, USER2), None);
+    assert_eq!(query_shares(&app, &factory_addr), new_shares());
+
+    let res: Option<PendingShareUpdate> = app
+        .wrap()
+        .query_wasm_smart(factory_addr, &QueryMsg::PendingShareUpdate {})
+        .unwrap();
+    assert_eq!(res, None);
+}
+
+#[test]
+fn test_cancel_share_update() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareApprovers {
+            approvers: vec![USER.to_string()],
+            threshold: 1,
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShares {
+            shares: new_shares(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::CancelShareUpdate {},
+        &vec![],
+    )
+    .unwrap();
+
+    assert_eq!(
+        confirm(&mut app, &factory_addr, USER),
+        Some(ContractError::NoPendingShareUpdate {}.to_string())
+    );
+}
+
+#[test]
+fn test_approvers_update_needs_confirmations() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareApprovers {
+            approvers: vec![USER.to_string(), USER2.to_string()],
+            threshold: 2,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // Removing the approvers is only proposed, the admin can not apply shares alone
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareApprovers {
+            approvers: vec![],
+            threshold: 0,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<ShareApprovers> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::ShareApprovers {})
+        .unwrap();
+    assert_eq!(res.unwrap().threshold, 2);
+
+    let shares = query_shares(&app, &factory_addr);
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShares {
+            shares: new_shares(),
+        },
+        &vec![],
+    )
+    .unwrap();
+    assert_eq!(query_shares(&app, &factory_addr), shares);
+
+    let confirm_update = |app: &mut ArchwayApp, sender: &str| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            factory_addr.clone(),
+            &ExecuteMsg::ConfirmApproversUpdate {},
+            &vec![],
+        )
+        .err()
+        .map(|err| err.source().unwrap().to_string())
+    };
+
+    assert_eq!(
+        confirm_update(&mut app, ADMIN),
+        Some(ContractError::Unauthorized {}.to_string())
+    );
+    assert_eq!(confirm_update(&mut app, USER), None);
+
+    let res: Option<PendingApproversUpdate> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::PendingApproversUpdate {})
+        .unwrap();
+    let res = res.unwrap();
+    assert_eq!(res.approvers, None);
+    assert_eq!(res.confirmations, vec![Addr::unchecked(USER)]);
+
+    assert_eq!(confirm_update(&mut app, USER2), None);
+
+    // The approvers are removed and the pending share update is dropped
+    let res: Option<ShareApprovers> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::ShareApprovers {})
+        .unwrap();
+    assert_eq!(res, None);
+    assert_eq!(
+        confirm(&mut app, &factory_addr, USER),
+        Some(ContractError::NoPendingShareUpdate {}.to_string())
+    );
+    assert_eq!(
+        confirm_update(&mut app, USER),
+        Some(ContractError::NoPendingApproversUpdate {}.to_string())
+    );
+}
+
+#[test]
+fn test_other_share_changes_need_approval() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareApprovers {
+            approvers: vec![USER.to_string(), USER2.to_string()],
+            threshold: 2,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // The admin can not replace the distributed shares without the approvers
+    let msgs = vec![
+        ExecuteMsg::UpdateDenomShares {
+            denom: DENOM.to_string(),
+            shares: new_shares(),
+        },
+        ExecuteMsg::UpdateGroup {
+            group: Some(USER2.to_string()),
+        },
+        ExecuteMsg::UpdateShareExpiration {
+            recipient: USER.to_string(),
+            expires_at: None,
+        },
+    ];
+    for msg in msgs {
+        let err = app
+            .execute_contract(Addr::unchecked(ADMIN), factory_addr.clone(), &msg, &vec![])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            ContractError::ShareApprovalRequired {}.to_string()
+        );
+    }
+}
@@ -0,0 +1,132 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::ShareCap,
+    ContractError,
+};
+
+const OVERFLOW: &str = "overflow";
+
+fn distribute(app: &mut ArchwayApp, factory_addr: &Addr, amount: u128) {
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(amount, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_share_cap() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateShareCap {
+                recipient: USER.to_string(),
+                denom: DENOM.to_string(),
+                cap: Some(Uint128::new(700)),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareCap {
+            recipient: USER.to_string(),
+            denom: DENOM.to_string(),
+            cap: Some(Uint128::new(700)),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateOverflowAddress {
+            address: Some(OVERFLOW.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    distribute(&mut app, &factory_addr, 1_000);
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+
+    // Only the rest of the cap is paid, the portion above it goes to the overflow address
+    distribute(&mut app, &factory_addr, 1_000);
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(700));
+    let res = app.wrap().query_balance(OVERFLOW, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(340));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(500));
+
+    let res: Option<ShareCap> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::ShareCap {
+                recipient: USER.to_string(),
+                denom: DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Some(ShareCap {
+            cap: Uint128::new(700),
+            paid: Uint128::new(700),
+        })
+    );
+}
+
+#[test]
+fn test_share_cap_without_overflow_address() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareCap {
+            recipient: USER.to_string(),
+            denom: DENOM.to_string(),
+            cap: Some(Uint128::zero()),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // The capped portion stays in the contract for the next distribution
+    distribute(&mut app, &factory_addr, 1_000);
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app
+        .wrap()
+        .query_balance(factory_addr.to_string(), DENOM)
+        .unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+}
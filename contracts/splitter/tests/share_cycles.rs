@@ -0,0 +1,72 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Decimal};
+use cw_multi_test::Executor;
+use pantheon_splitter::{msg::ExecuteMsg, ContractError};
+use pantheon_utils::Share;
+
+fn share(recipient: &str, percentage: &str) -> Share {
+    Share {
+        recipient: recipient.to_string(),
+        percentage: Decimal::from_str(percentage).unwrap(),
+        ibc: None,
+        burn: false,
+    }
+}
+
+#[test]
+fn test_reject_cycles() {
+    let mut app = mock_app();
+    let first = proper_instantiate(&mut app, vec![share(USER, "1")], true);
+    let second = proper_instantiate(&mut app, vec![share(first.as_str(), "1")], true);
+    let third = proper_instantiate(
+        &mut app,
+        vec![share(second.as_str(), "0.5"), share(USER2, "0.5")],
+        true,
+    );
+
+    // first -> third -> second -> first
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            first.clone(),
+            &ExecuteMsg::UpdateShares {
+                shares: vec![share(USER, "0.5"), share(third.as_str(), "0.5")],
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ShareCycle {}.to_string()
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            first.clone(),
+            &ExecuteMsg::UpdateShares {
+                shares: vec![share(first.as_str(), "1")],
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ShareCycle {}.to_string()
+    );
+
+    // Nested splitters that do not pay back are accepted
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        third,
+        &ExecuteMsg::UpdateShares {
+            shares: vec![share(second.as_str(), "0.5"), share(first.as_str(), "0.5")],
+        },
+        &vec![],
+    )
+    .unwrap();
+}
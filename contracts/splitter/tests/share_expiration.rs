@@ -0,0 +1,120 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use cw_utils::Expiration;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::ExpiredSharePolicy,
+    ContractError,
+};
+
+fn distribute(app: &mut ArchwayApp, factory_addr: &Addr, amount: u128) {
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(amount, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+}
+
+fn expire_user_share(app: &mut ArchwayApp, factory_addr: &Addr) {
+    let expires_at = Expiration::AtHeight(app.block_info().height + 10);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateShareExpiration {
+                recipient: USER.to_string(),
+                expires_at: Some(expires_at),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareExpiration {
+            recipient: USER.to_string(),
+            expires_at: Some(expires_at),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<Expiration> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::ShareExpiration {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Some(expires_at));
+
+    // The share is paid until it expires
+    distribute(app, factory_addr, 1_000);
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+
+    app.update_block(|block| block.height += 10);
+}
+
+#[test]
+fn test_expired_share_redistributed() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    expire_user_share(&mut app, &factory_addr);
+
+    distribute(&mut app, &factory_addr, 1_000);
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(250 + 520));
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(230 + 479));
+}
+
+#[test]
+fn test_expired_share_retained() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateExpiredSharePolicy {
+            policy: ExpiredSharePolicy::Retain,
+        },
+        &vec![],
+    )
+    .unwrap();
+    expire_user_share(&mut app, &factory_addr);
+
+    distribute(&mut app, &factory_addr, 1_000);
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(500));
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(460));
+    let res = app
+        .wrap()
+        .query_balance(factory_addr.to_string(), DENOM)
+        .unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+}
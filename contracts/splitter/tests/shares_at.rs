@@ -0,0 +1,66 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Decimal};
+use cw_multi_test::Executor;
+use pantheon_splitter::msg::{ExecuteMsg, QueryMsg};
+use pantheon_utils::Share;
+
+#[test]
+fn test_shares_at_height() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let instantiate_height = app.block_info().height;
+
+    app.update_block(|block| block.height += 1);
+
+    let new_shares = vec![Share {
+        recipient: USER2.to_string(),
+        percentage: Decimal::one(),
+        ibc: None,
+        burn: false,
+    }];
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShares { shares: new_shares },
+        &vec![],
+    )
+    .unwrap();
+    let update_height = app.block_info().height;
+
+    app.update_block(|block| block.height += 1);
+
+    // The shares saved in a block are visible from the next block
+    let res: Vec<Share> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::SharesAt {
+                height: instantiate_height + 1,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 3);
+    assert_eq!(res[0].recipient, USER.to_string());
+    assert_eq!(res[0].percentage, Decimal::from_str("0.52").unwrap());
+
+    let res: Vec<Share> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::SharesAt {
+                height: update_height + 1,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].recipient, USER2.to_string());
+    assert_eq!(res[0].percentage, Decimal::one());
+}
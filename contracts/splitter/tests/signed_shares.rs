@@ -0,0 +1,118 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{to_vec, Addr, Binary, Decimal};
+use cw_multi_test::Executor;
+use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg, ShareAuthorityResponse, ShareUpdatePayload, SharesResponse},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+fn new_shares() -> Vec<Share> {
+    vec![Share {
+        recipient: USER2.to_string(),
+        percentage: Decimal::one(),
+        ibc: None,
+        burn: false,
+    }]
+}
+
+fn sign(app: &ArchwayApp, key: &SigningKey, factory_addr: &Addr, nonce: u64) -> Binary {
+    let payload = ShareUpdatePayload {
+        chain_id: app.block_info().chain_id,
+        contract: factory_addr.to_string(),
+        nonce,
+        shares: new_shares(),
+    };
+    // The signer hashes the payload with sha256
+    let signature: Signature = key.sign(&to_vec(&payload).unwrap());
+    Binary::from(signature.as_ref())
+}
+
+fn update_shares(
+    app: &mut ArchwayApp,
+    factory_addr: &Addr,
+    nonce: u64,
+    signature: Binary,
+) -> Result<(), String> {
+    app.execute_contract(
+        Addr::unchecked(USER3),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateSharesWithSignature {
+            shares: new_shares(),
+            nonce,
+            signature,
+        },
+        &vec![],
+    )
+    .map(|_| ())
+    .map_err(|err| err.source().unwrap().to_string())
+}
+
+#[test]
+fn test_signed_share_update() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+    let pubkey = key.verifying_key().to_encoded_point(true);
+
+    let signature = sign(&app, &key, &factory_addr, 0);
+    assert_eq!(
+        update_shares(&mut app, &factory_addr, 0, signature.clone()),
+        Err(ContractError::NoShareAuthority {}.to_string())
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareAuthority {
+            pubkey: Some(Binary::from(pubkey.as_bytes())),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // A signature of another key is rejected
+    let other = SigningKey::from_bytes(&[8u8; 32]).unwrap();
+    assert_eq!(
+        update_shares(
+            &mut app,
+            &factory_addr,
+            0,
+            sign(&app, &other, &factory_addr, 0)
+        ),
+        Err(ContractError::InvalidSignature {}.to_string())
+    );
+
+    update_shares(&mut app, &factory_addr, 0, signature.clone()).unwrap();
+
+    let res: SharesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Shares {
+                start_after: None,
+                limit: None,
+                order: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.shares, new_shares());
+
+    // Signatures can not be replayed
+    assert_eq!(
+        update_shares(&mut app, &factory_addr, 0, signature),
+        Err(ContractError::InvalidNonce { expected: 1 }.to_string())
+    );
+
+    let res: ShareAuthorityResponse = app
+        .wrap()
+        .query_wasm_smart(factory_addr, &QueryMsg::ShareAuthority {})
+        .unwrap();
+    assert_eq!(res.nonce, 1);
+}
@@ -0,0 +1,69 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::msg::{ExecuteMsg, QueryMsg, SimulateDistributionResponse, SimulatedPayout};
+
+#[test]
+fn test_simulate_distribution() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let simulate = QueryMsg::SimulateDistribution {
+        amount: Uint128::new(1_001),
+        denom: DENOM.to_string(),
+    };
+    let res: SimulateDistributionResponse = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &simulate)
+        .unwrap();
+    assert_eq!(
+        res,
+        SimulateDistributionResponse {
+            payouts: vec![
+                SimulatedPayout::Send {
+                    recipient: Addr::unchecked(USER),
+                    amount: Uint128::new(520),
+                },
+                SimulatedPayout::Send {
+                    recipient: Addr::unchecked(USER2),
+                    amount: Uint128::new(250),
+                },
+                SimulatedPayout::Send {
+                    recipient: Addr::unchecked(USER3),
+                    amount: Uint128::new(230),
+                },
+            ],
+            remainder: Uint128::new(1),
+        }
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShareCap {
+            recipient: USER.to_string(),
+            denom: DENOM.to_string(),
+            cap: Some(Uint128::new(100)),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // The simulation does not consume the cap, so repeated simulations return the same payouts
+    for _ in 0..2 {
+        let res: SimulateDistributionResponse = app
+            .wrap()
+            .query_wasm_smart(factory_addr.clone(), &simulate)
+            .unwrap();
+        assert_eq!(
+            res.payouts[0],
+            SimulatedPayout::Send {
+                recipient: Addr::unchecked(USER),
+                amount: Uint128::new(100),
+            }
+        );
+        assert_eq!(res.remainder, Uint128::new(421));
+    }
+}
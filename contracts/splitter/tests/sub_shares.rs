@@ -0,0 +1,190 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{coins, Addr, Decimal, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+const SUB1: &str = "sub1";
+const SUB2: &str = "sub2";
+
+fn sub_shares(first: &str, second: &str) -> Vec<Share> {
+    vec![
+        Share {
+            recipient: SUB1.to_string(),
+            percentage: Decimal::from_str(first).unwrap(),
+            ibc: None,
+            burn: false,
+        },
+        Share {
+            recipient: SUB2.to_string(),
+            percentage: Decimal::from_str(second).unwrap(),
+            ibc: None,
+            burn: false,
+        },
+    ]
+}
+
+#[test]
+fn test_split_slice() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateSubShares {
+            shares: sub_shares("0.5", "0.5"),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<Share> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::SubShares {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, sub_shares("0.5", "0.5"));
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    // The slice of the recipient is split between the sub-recipients
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app.wrap().query_balance(SUB1, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(260));
+    let res = app.wrap().query_balance(SUB2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(260));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(250));
+
+    // An empty list removes the split
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateSubShares { shares: vec![] },
+        &vec![],
+    )
+    .unwrap();
+    let res: Vec<Share> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::SubShares {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn test_invalid_sub_shares() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(SUB1),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateSubShares {
+                shares: sub_shares("0.5", "0.5"),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr,
+            &ExecuteMsg::UpdateSubShares {
+                shares: sub_shares("0.5", "0.4"),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::PercentageLimitNotMet {}.to_string()
+    );
+}
+
+#[test]
+fn test_frozen_recipient_with_sub_shares() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateSubShares {
+            shares: sub_shares("0.5", "0.5"),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateFrozenRecipient {
+            recipient: USER.to_string(),
+            frozen: true,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    // The slice of a frozen recipient is not split, it stays in the contract
+    let res = app.wrap().query_balance(SUB1, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app.wrap().query_balance(SUB2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(250));
+    let res = app.wrap().query_balance(&factory_addr, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+}
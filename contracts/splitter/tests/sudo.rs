@@ -0,0 +1,103 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use cw_ownable::Ownership;
+use pantheon_splitter::{
+    msg::{ConfigResponse, ExecuteMsg, QueryMsg, SudoMsg},
+    ContractError,
+};
+
+#[test]
+fn test_pause_and_distribute() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.wasm_sudo(factory_addr.clone(), &SudoMsg::Pause {})
+        .unwrap();
+
+    let res: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert!(res.paused);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::DistributeNativeTokens {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Paused {}.to_string()
+    );
+
+    // Governance can still distribute while the contract is paused
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+    app.wasm_sudo(factory_addr.clone(), &SudoMsg::DistributeNativeTokens {})
+        .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+
+    app.wasm_sudo(factory_addr.clone(), &SudoMsg::Unpause {})
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr,
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_update_admin() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.wasm_sudo(
+        factory_addr.clone(),
+        &SudoMsg::UpdateAdmin {
+            admin: USER.to_string(),
+        },
+    )
+    .unwrap();
+
+    let res: Ownership<Addr> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::Ownership {})
+        .unwrap();
+    assert_eq!(res.owner, Some(Addr::unchecked(USER)));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::LockContract { until: None },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr,
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+}
@@ -0,0 +1,142 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::DenomInfo,
+    ContractError,
+};
+
+const STAKE: &str = "ustake";
+
+#[test]
+fn test_register_denom() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::RegisterDenom {
+                denom: STAKE.to_string(),
+                display: "STAKE".to_string(),
+                exponent: 6,
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::RegisterDenom {
+            denom: STAKE.to_string(),
+            display: "STAKE".to_string(),
+            exponent: 6,
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Vec<DenomInfo> = app
+        .wrap()
+        .query_wasm_smart(factory_addr.clone(), &QueryMsg::SupportedDenoms {})
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![
+            DenomInfo {
+                denom: DENOM.to_string(),
+                display: "CONST".to_string(),
+                exponent: 18,
+            },
+            DenomInfo {
+                denom: STAKE.to_string(),
+                display: "STAKE".to_string(),
+                exponent: 6,
+            },
+        ]
+    );
+
+    // Funding the splitter with both denoms
+    app.init_modules(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &factory_addr,
+                vec![coin(1_000, DENOM), coin(100, STAKE)],
+            )
+            .unwrap();
+    });
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+    let res = app.wrap().query_balance(USER, STAKE).unwrap();
+    assert_eq!(res.amount, Uint128::new(52));
+}
+
+#[test]
+fn test_remove_denom() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::RemoveDenom {
+            denom: DENOM.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::RemoveDenom {
+                denom: DENOM.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::DenomNotRegistered {}.to_string()
+    );
+
+    // Unregistered denoms stay in the contract
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &[coin(1_000, DENOM)],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+}
@@ -0,0 +1,172 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{
+    coin, coins, to_binary, Addr, BankMsg, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult, Uint128,
+};
+use cw_multi_test::{BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
+use pantheon_splitter::{
+    msg::{ExecuteMsg, SwapConfigMsg},
+    ContractError,
+};
+use pantheon_swap_adapter::{QuoteResponse, SwapAdapterExecuteMsg, SwapAdapterQueryMsg};
+
+const OTHER_DENOM: &str = "uother";
+
+// Adapter swapping every denom 1:1
+fn adapter_instantiate(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    Ok(Response::new())
+}
+
+fn adapter_execute(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    msg: SwapAdapterExecuteMsg,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    match msg {
+        SwapAdapterExecuteMsg::Swap {
+            ask_denom,
+            min_receive,
+        } => {
+            let amount: Uint128 = info.funds.iter().map(|fund| fund.amount).sum();
+            if amount < min_receive {
+                return Err(StdError::generic_err("slippage exceeded"));
+            }
+            Ok(Response::new().add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(amount.u128(), ask_denom),
+            }))
+        }
+    }
+}
+
+fn adapter_query(
+    _deps: Deps<ArchwayQuery>,
+    _env: Env,
+    msg: SwapAdapterQueryMsg,
+) -> StdResult<Binary> {
+    match msg {
+        SwapAdapterQueryMsg::Quote { offer, .. } => to_binary(&QuoteResponse {
+            amount: offer.amount,
+        }),
+    }
+}
+
+fn adapter_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(adapter_execute, adapter_instantiate, adapter_query);
+    Box::new(contract)
+}
+
+#[test]
+fn test_swap_before_distribution() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let code_id = app.store_code(adapter_contract());
+    let adapter = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &Empty {},
+            &vec![],
+            "Adapter",
+            None,
+        )
+        .unwrap();
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        adapter.clone(),
+        &coins(10_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::RegisterDenom {
+            denom: OTHER_DENOM.to_string(),
+            display: "OTHER".to_string(),
+            exponent: 6,
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: factory_addr.to_string(),
+        amount: coins(1_000, OTHER_DENOM),
+    }))
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateSwapConfig {
+                swap: Some(SwapConfigMsg {
+                    adapter: adapter.to_string(),
+                    target_denom: DENOM.to_string(),
+                    max_slippage: Decimal::from_str("1.5").unwrap(),
+                }),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidSlippage {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateSwapConfig {
+            swap: Some(SwapConfigMsg {
+                adapter: adapter.to_string(),
+                target_denom: DENOM.to_string(),
+                max_slippage: Decimal::from_str("0.01").unwrap(),
+            }),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    // Only the contract can trigger the distribution of the swapped balance
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::DistributeSwappedTokens {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    // The recipients are paid in the target denom only
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(520));
+    let res = app.wrap().query_balance(USER, OTHER_DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+    let res = app.wrap().query_balance(adapter, OTHER_DENOM).unwrap();
+    assert_eq!(res, coin(1_000, OTHER_DENOM));
+}
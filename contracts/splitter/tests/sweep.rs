@@ -0,0 +1,192 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Coin, Decimal, Event, Uint128};
+use cw_multi_test::{AppResponse, Executor};
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    ContractError,
+};
+use pantheon_utils::Share;
+
+const TREASURY: &str = "treasury";
+
+// Returns the message of the contract error on failure
+fn sweep(app: &mut ArchwayApp, factory_addr: &Addr) -> Result<AppResponse, String> {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::Sweep {
+            denom: DENOM.to_string(),
+        },
+        &vec![],
+    )
+    .map_err(|err| err.source().unwrap().to_string())
+}
+
+fn remove_denom(app: &mut ArchwayApp, factory_addr: &Addr) {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::RemoveDenom {
+            denom: DENOM.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+}
+
+fn update_sweep_address(app: &mut ArchwayApp, factory_addr: &Addr) -> Result<AppResponse, String> {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateSweepAddress {
+            address: Some(TREASURY.to_string()),
+        },
+        &vec![],
+    )
+    .map_err(|err| err.source().unwrap().to_string())
+}
+
+#[test]
+fn test_sweep() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    // The balance of a registered denom is revenue of the recipients
+    let err = sweep(&mut app, &factory_addr).unwrap_err();
+    assert_eq!(err, ContractError::SweepRegisteredDenom {}.to_string());
+
+    remove_denom(&mut app, &factory_addr);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::Sweep {
+                denom: DENOM.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    // Without a sweep address the balance is deposited for the recipients
+    sweep(&mut app, &factory_addr).unwrap();
+
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PendingRewards {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec![coin(520, DENOM)]);
+
+    let err = sweep(&mut app, &factory_addr).unwrap_err();
+    assert_eq!(err, ContractError::NothingToSweep {}.to_string());
+
+    update_sweep_address(&mut app, &factory_addr).unwrap();
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1, DENOM),
+    )
+    .unwrap();
+
+    let res = sweep(&mut app, &factory_addr).unwrap();
+    assert!(res.has_event(
+        &Event::new("wasm-sweep")
+            .add_attribute("denom", DENOM)
+            .add_attribute("amount", "1")
+            .add_attribute("address", TREASURY)
+    ));
+
+    let res = app.wrap().query_balance(TREASURY, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1));
+
+    // The deposited balance is still claimable
+    let res = app.wrap().query_balance(factory_addr, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_000));
+}
+
+#[test]
+fn test_sweep_locked() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    update_sweep_address(&mut app, &factory_addr).unwrap();
+    remove_denom(&mut app, &factory_addr);
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let err = update_sweep_address(&mut app, &factory_addr).unwrap_err();
+    assert_eq!(err, ContractError::ContractNotMutable {}.to_string());
+
+    let err = sweep(&mut app, &factory_addr).unwrap_err();
+    assert_eq!(err, ContractError::ContractNotMutable {}.to_string());
+}
+
+#[test]
+fn test_sweep_emergency_withdraw_disabled() {
+    let mut app = mock_app();
+    let code_id = app.store_code(factory_contract());
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                shares: vec![Share {
+                    recipient: USER.to_string(),
+                    percentage: Decimal::one(),
+                    ibc: None,
+                    burn: false,
+                }],
+                mutable: true,
+                admin: ADMIN.to_string(),
+                transfer_requires_approval: false,
+                disable_emergency_withdraw: true,
+                restore: None,
+            },
+            &vec![],
+            "Pantheon Splitter",
+            None,
+        )
+        .unwrap();
+
+    update_sweep_address(&mut app, &factory_addr).unwrap();
+    remove_denom(&mut app, &factory_addr);
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    let err = sweep(&mut app, &factory_addr).unwrap_err();
+    assert_eq!(err, ContractError::EmergencyWithdrawDisabled {}.to_string());
+}
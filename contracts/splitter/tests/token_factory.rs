@@ -0,0 +1,155 @@
+pub mod helpers;
+use helpers::*;
+
+use std::marker::PhantomData;
+
+use archway_bindings::ArchwayQuery;
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{coin, coins, from_binary, CosmosMsg, Decimal, OwnedDeps};
+use pantheon_splitter::{
+    contract::{execute, instantiate, query},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::ReceiptDenom,
+    ContractError,
+};
+use pantheon_utils::Share;
+
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier<ArchwayQuery>, ArchwayQuery> {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<ArchwayQuery>::new(&[]),
+        custom_query_type: PhantomData,
+    };
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        InstantiateMsg {
+            admin: ADMIN.to_string(),
+            shares: vec![Share {
+                recipient: USER.to_string(),
+                percentage: Decimal::one(),
+                ibc: None,
+                burn: false,
+            }],
+            mutable: true,
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
+        },
+    )
+    .unwrap();
+
+    deps
+}
+
+#[test]
+fn test_register_token_factory_denom() {
+    let mut deps = mock_deps();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::RegisterTokenFactoryDenom {
+            denom: "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+                .to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::InvalidTokenFactoryDenom {}.to_string()
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(USER, &[]),
+        ExecuteMsg::RegisterTokenFactoryDenom {
+            denom: "factory/archway1creator/token".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err.to_string(), ContractError::Unauthorized {}.to_string());
+}
+
+#[test]
+fn test_mint_deposit_receipts() {
+    let mut deps = mock_deps();
+    let receipt_denom = format!("factory/{}/receipt", mock_env().contract.address);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::CreateReceiptDenom {
+            subdenom: "receipt".to_string(),
+            deposit_denom: "uusdc".to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::DenomNotRegistered {}.to_string()
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::CreateReceiptDenom {
+            subdenom: "receipt".to_string(),
+            deposit_denom: DENOM.to_string(),
+        },
+    )
+    .unwrap();
+    assert!(matches!(
+        &res.messages[0].msg,
+        CosmosMsg::Stargate { type_url, .. }
+            if type_url == "/osmosis.tokenfactory.v1beta1.MsgCreateDenom"
+    ));
+
+    let res: Option<ReceiptDenom> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::ReceiptDenom {}).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        Some(ReceiptDenom {
+            denom: receipt_denom.clone(),
+            deposit_denom: DENOM.to_string(),
+        })
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &[]),
+        ExecuteMsg::CreateReceiptDenom {
+            subdenom: "receipt".to_string(),
+            deposit_denom: DENOM.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        ContractError::ReceiptDenomExists {}.to_string()
+    );
+
+    // The depositor receives as many receipt tokens as it deposited
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ADMIN, &coins(1_000, DENOM)),
+        ExecuteMsg::Deposit { memo: None },
+    )
+    .unwrap();
+    assert!(matches!(
+        &res.messages[0].msg,
+        CosmosMsg::Stargate { type_url, .. }
+            if type_url == "/osmosis.tokenfactory.v1beta1.MsgMint"
+    ));
+    assert!(res.attributes.iter().any(|attr| attr.key == "receipt"
+        && attr.value == coin(1_000, receipt_denom.as_str()).to_string()));
+}
@@ -0,0 +1,191 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Decimal};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SharesResponse},
+    ContractError,
+};
+
+use pantheon_utils::Share;
+
+#[test]
+fn test_happy_path() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::TransferShare {
+            new_recipient: "user4".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Share = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::Share {
+                recipient: "user4".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.recipient, "user4".to_string());
+    assert_eq!(res.percentage, Decimal::from_str("0.52").unwrap());
+
+    let res: SharesResponse = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::Shares {
+                start_after: None,
+                limit: None,
+                order: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.shares.len(), 3);
+    assert!(res.iter().all(|share| share.recipient != USER.to_string()));
+}
+
+#[test]
+fn test_admin_approval() {
+    let mut app = mock_app();
+    let code_id = app.store_code(factory_contract());
+
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                shares: vec![
+                    Share {
+                        recipient: USER.to_string(),
+                        percentage: Decimal::from_str("0.5").unwrap(),
+                        ibc: None,
+                        burn: false,
+                    },
+                    Share {
+                        recipient: USER2.to_string(),
+                        percentage: Decimal::from_str("0.5").unwrap(),
+                        ibc: None,
+                        burn: false,
+                    },
+                ],
+                mutable: true,
+                admin: ADMIN.to_string(),
+                transfer_requires_approval: true,
+                disable_emergency_withdraw: false,
+                restore: None,
+            },
+            &vec![],
+            "Pantheon Splitter",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        factory_addr.clone(),
+        &ExecuteMsg::TransferShare {
+            new_recipient: USER3.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PendingShareTransfer {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Some(Addr::unchecked(USER3)));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::ApproveShareTransfer {
+                recipient: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::ApproveShareTransfer {
+            recipient: USER.to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Share = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::Share {
+                recipient: USER3.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.percentage, Decimal::from_str("0.5").unwrap());
+}
+
+#[test]
+fn test_recipient_exists() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::TransferShare {
+                new_recipient: USER2.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::RecipientExists {}.to_string()
+    )
+}
+
+#[test]
+fn test_invalid_recipient() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::TransferShare {
+                new_recipient: "user4".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    )
+}
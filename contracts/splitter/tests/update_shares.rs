@@ -3,10 +3,11 @@ use helpers::*;
 
 use std::str::FromStr;
 
-use cosmwasm_std::{Addr, Decimal};
+use cosmwasm_std::{coins, Addr, Decimal};
 use cw_multi_test::Executor;
+use cw_utils::PaymentError;
 use pantheon_splitter::{
-    msg::{ExecuteMsg, QueryMsg},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, SharesResponse},
     ContractError,
 };
 
@@ -20,14 +21,20 @@ fn test_happy_path() {
         Share {
             recipient: USER.to_string(),
             percentage: Decimal::from_str("0.52").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.23").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -37,10 +44,14 @@ fn test_happy_path() {
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.38").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.62").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -52,21 +63,22 @@ fn test_happy_path() {
     )
     .unwrap();
 
-    let res: Vec<Share> = app
+    let res: SharesResponse = app
         .wrap()
         .query_wasm_smart(
             factory_addr,
             &QueryMsg::Shares {
                 start_after: None,
                 limit: None,
+                order: None,
             },
         )
         .unwrap();
-    assert_eq!(res.len(), 2);
-    assert_eq!(res[0].recipient, USER2.to_string());
-    assert_eq!(res[0].percentage, Decimal::from_str("0.38").unwrap());
-    assert_eq!(res[1].recipient, USER3.to_string());
-    assert_eq!(res[1].percentage, Decimal::from_str("0.62").unwrap());
+    assert_eq!(res.shares.len(), 2);
+    assert_eq!(res.shares[0].recipient, USER2.to_string());
+    assert_eq!(res.shares[0].percentage, Decimal::from_str("0.38").unwrap());
+    assert_eq!(res.shares[1].recipient, USER3.to_string());
+    assert_eq!(res.shares[1].percentage, Decimal::from_str("0.62").unwrap());
 }
 
 #[test]
@@ -77,14 +89,20 @@ fn test_locked_contract() {
         Share {
             recipient: USER.to_string(),
             percentage: Decimal::from_str("0.52").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.23").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -94,10 +112,14 @@ fn test_locked_contract() {
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.38").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.62").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -123,14 +145,20 @@ fn test_invalid_admin() {
         Share {
             recipient: USER.to_string(),
             percentage: Decimal::from_str("0.52").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.23").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -140,10 +168,14 @@ fn test_invalid_admin() {
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.38").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.62").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -169,14 +201,20 @@ fn test_percentage_limit_exceeded() {
         Share {
             recipient: USER.to_string(),
             percentage: Decimal::from_str("0.52").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.23").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -186,10 +224,14 @@ fn test_percentage_limit_exceeded() {
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("2.38").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.62").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -215,14 +257,20 @@ fn test_percentage_limit_not_met() {
         Share {
             recipient: USER.to_string(),
             percentage: Decimal::from_str("0.52").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.25").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.23").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -232,10 +280,14 @@ fn test_percentage_limit_not_met() {
         Share {
             recipient: USER2.to_string(),
             percentage: Decimal::from_str("0.10").unwrap(),
+            ibc: None,
+            burn: false,
         },
         Share {
             recipient: USER3.to_string(),
             percentage: Decimal::from_str("0.62").unwrap(),
+            ibc: None,
+            burn: false,
         },
     ];
 
@@ -252,3 +304,152 @@ fn test_percentage_limit_not_met() {
         ContractError::PercentageLimitNotMet {}.to_string()
     )
 }
+
+#[test]
+fn test_unchanged_recipients_keep_state() {
+    let mut app = mock_app();
+
+    let code_id = app.store_code(factory_contract());
+
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &InstantiateMsg {
+                shares: vec![
+                    Share {
+                        recipient: USER.to_string(),
+                        percentage: Decimal::from_str("0.5").unwrap(),
+                        ibc: None,
+                        burn: false,
+                    },
+                    Share {
+                        recipient: USER2.to_string(),
+                        percentage: Decimal::from_str("0.5").unwrap(),
+                        ibc: None,
+                        burn: false,
+                    },
+                ],
+                mutable: true,
+                admin: ADMIN.to_string(),
+                transfer_requires_approval: true,
+                disable_emergency_withdraw: false,
+                restore: None,
+            },
+            &vec![],
+            "Pantheon Splitter",
+            None,
+        )
+        .unwrap();
+
+    for sender in [USER, USER2] {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            factory_addr.clone(),
+            &ExecuteMsg::TransferShare {
+                new_recipient: "user4".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap();
+    }
+
+    // USER is removed, USER2 keeps the same share
+    let new_shares = vec![
+        Share {
+            recipient: USER2.to_string(),
+            percentage: Decimal::from_str("0.5").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+        Share {
+            recipient: USER3.to_string(),
+            percentage: Decimal::from_str("0.5").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+    ];
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateShares { shares: new_shares },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PendingShareTransfer {
+                recipient: USER.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, None);
+
+    let res: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::PendingShareTransfer {
+                recipient: USER2.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Some(Addr::unchecked("user4")));
+}
+
+#[test]
+fn test_duplicate_recipient() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let new_shares = vec![
+        Share {
+            recipient: USER.to_string(),
+            percentage: Decimal::from_str("0.5").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+        Share {
+            recipient: USER.to_string(),
+            percentage: Decimal::from_str("0.5").unwrap(),
+            ibc: None,
+            burn: false,
+        },
+    ];
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::UpdateShares { shares: new_shares },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::RecipientExists {}.to_string()
+    )
+}
+
+#[test]
+fn test_funds_rejected() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr,
+            &ExecuteMsg::UpdateShares { shares: vec![] },
+            &coins(1_000, DENOM),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::PaymentError(PaymentError::NonPayable {}).to_string()
+    )
+}
@@ -0,0 +1,260 @@
+pub mod helpers;
+use helpers::*;
+
+use std::str::FromStr;
+
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{
+    coin, coins, to_binary, Addr, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult, Uint128,
+};
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+use pantheon_archway_mock::ArchwayApp;
+use pantheon_price_oracle::{PriceOracleQueryMsg, PriceResponse};
+use pantheon_splitter::{
+    msg::{ExecuteMsg, PriorityPaymentMsg, QueryMsg},
+    state::ThresholdAmount,
+    ContractError,
+};
+
+const INFRA: &str = "infra";
+
+// 1 CONST is worth 2 USD
+const ONE_CONST: u128 = 1_000_000_000_000_000_000;
+
+fn oracle_instantiate(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    Ok(Response::new())
+}
+
+fn oracle_execute(
+    _deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> Result<Response<ArchwayMsg>, StdError> {
+    Err(StdError::generic_err("unsupported"))
+}
+
+fn oracle_query(
+    _deps: Deps<ArchwayQuery>,
+    _env: Env,
+    msg: PriceOracleQueryMsg,
+) -> StdResult<Binary> {
+    match msg {
+        PriceOracleQueryMsg::Price { .. } => to_binary(&PriceResponse {
+            price: Decimal::from_str("2").unwrap(),
+        }),
+    }
+}
+
+fn oracle_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(oracle_execute, oracle_instantiate, oracle_query);
+    Box::new(contract)
+}
+
+fn instantiate_oracle(app: &mut ArchwayApp) -> Addr {
+    let code_id = app.store_code(oracle_contract());
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(ADMIN),
+        &Empty {},
+        &vec![],
+        "Oracle",
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_usd_min_distribution() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let min_distribution = ExecuteMsg::UpdateMinDistribution {
+        denom: DENOM.to_string(),
+        amount: Some(ThresholdAmount::Usd(Decimal::from_str("10").unwrap())),
+    };
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &min_distribution,
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::PriceOracleNotSet {}.to_string()
+    );
+
+    let oracle = instantiate_oracle(&mut app);
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdatePriceOracle {
+            oracle: Some(oracle.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &min_distribution,
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<ThresholdAmount> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::MinDistribution {
+                denom: DENOM.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        Some(ThresholdAmount::Usd(Decimal::from_str("10").unwrap()))
+    );
+
+    // 4 CONST are worth 8 USD, below the minimum of 10 USD
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(4 * ONE_CONST, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(ONE_CONST, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(26 * ONE_CONST / 10));
+}
+
+#[test]
+fn test_usd_priority_payment() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let payments = ExecuteMsg::UpdatePriorityPayments {
+        payments: vec![PriorityPaymentMsg {
+            recipient: INFRA.to_string(),
+            amount: coin(0, DENOM),
+            usd_amount: Some(Decimal::from_str("1").unwrap()),
+        }],
+    };
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &payments,
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::PriceOracleNotSet {}.to_string()
+    );
+
+    let oracle = instantiate_oracle(&mut app);
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdatePriceOracle {
+            oracle: Some(oracle.to_string()),
+        },
+        &vec![],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &payments,
+        &vec![],
+    )
+    .unwrap();
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(ONE_CONST, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    // 1 USD is paid as 0.5 CONST before the percentage split
+    let res = app.wrap().query_balance(INFRA, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(ONE_CONST / 2));
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(26 * ONE_CONST / 100));
+}
+
+#[test]
+fn test_locked_thresholds() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+    let oracle = instantiate_oracle(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::LockContract { until: None },
+        &vec![],
+    )
+    .unwrap();
+
+    // The oracle prices the priority payments, so it is locked with them
+    let msgs = vec![
+        ExecuteMsg::UpdatePriceOracle {
+            oracle: Some(oracle.to_string()),
+        },
+        ExecuteMsg::UpdateMinDistribution {
+            denom: DENOM.to_string(),
+            amount: Some(ThresholdAmount::Native(Uint128::new(1_000))),
+        },
+    ];
+    for msg in msgs {
+        let err = app
+            .execute_contract(Addr::unchecked(ADMIN), factory_addr.clone(), &msg, &vec![])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            ContractError::ContractNotMutable {}.to_string()
+        );
+    }
+}
@@ -0,0 +1,103 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{
+    msg::{ExecuteMsg, QueryMsg, WaterfallTierMsg},
+    state::WaterfallTier,
+    ContractError,
+};
+
+#[test]
+fn test_waterfall() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    let tiers = vec![
+        WaterfallTierMsg {
+            recipient: USER3.to_string(),
+            target: coin(300, DENOM),
+        },
+        WaterfallTierMsg {
+            recipient: USER.to_string(),
+            target: coin(200, DENOM),
+        },
+    ];
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            factory_addr.clone(),
+            &ExecuteMsg::UpdateWaterfall {
+                tiers: tiers.clone(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateWaterfall { tiers },
+        &vec![],
+    )
+    .unwrap();
+
+    // The first tier is filled, the second tier gets the rest
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(400, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(300));
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(100));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::zero());
+
+    // Once the tiers are filled, the overflow is split by the shares
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &coins(1_100, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::DistributeNativeTokens {},
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(720));
+    let res = app.wrap().query_balance(USER2, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(250));
+    let res = app.wrap().query_balance(USER3, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(530));
+
+    let res: Vec<WaterfallTier> = app
+        .wrap()
+        .query_wasm_smart(factory_addr, &QueryMsg::Waterfall {})
+        .unwrap();
+    assert_eq!(
+        res.iter().map(|tier| tier.filled).collect::<Vec<_>>(),
+        vec![Uint128::new(300), Uint128::new(200)]
+    );
+}
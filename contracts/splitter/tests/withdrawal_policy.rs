@@ -0,0 +1,98 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_splitter::{msg::ExecuteMsg, state::WithdrawalPolicy, ContractError};
+
+#[test]
+fn test_withdrawal_policy() {
+    let mut app = mock_app();
+    let factory_addr = proper_instantiate_with_shares(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::UpdateWithdrawalPolicy {
+            policy: Some(WithdrawalPolicy {
+                min_rewards: Some(coin(1_000, DENOM)),
+                min_interval: Some(10),
+            }),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.init_modules(|router, _, storage| {
+        router
+            .custom
+            .add_rewards(storage, factory_addr.as_str(), coins(500, DENOM))
+            .unwrap();
+    });
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::WithdrawRewards {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::RewardsBelowMinimum {
+            min_rewards: coin(1_000, DENOM).to_string()
+        }
+        .to_string()
+    );
+
+    app.init_modules(|router, _, storage| {
+        router
+            .custom
+            .add_rewards(storage, factory_addr.as_str(), coins(500, DENOM))
+            .unwrap();
+    });
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr.clone(),
+        &ExecuteMsg::WithdrawRewards {},
+        &vec![],
+    )
+    .unwrap();
+    let res = app.wrap().query_balance(&factory_addr, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(1_000));
+
+    app.init_modules(|router, _, storage| {
+        router
+            .custom
+            .add_rewards(storage, factory_addr.as_str(), coins(1_000, DENOM))
+            .unwrap();
+    });
+
+    let height = app.block_info().height;
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            factory_addr.clone(),
+            &ExecuteMsg::WithdrawRewards {},
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::WithdrawalTooSoon {
+            height: height + 10
+        }
+        .to_string()
+    );
+
+    app.update_block(|block| block.height += 10);
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        factory_addr,
+        &ExecuteMsg::WithdrawRewards {},
+        &vec![],
+    )
+    .unwrap();
+}
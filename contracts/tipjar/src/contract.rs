@@ -0,0 +1,181 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Uint128,
+    WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{donors, Config, Donor, Tip, CONFIG, LAST_TIP_ID, TIPS};
+use pantheon_splitter::msg::ExecuteMsg as SplitterExecuteMsg;
+
+use archway_bindings::{ArchwayQuery, ArchwayResult};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:pantheon-tipjar";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ArchwayResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        admin: deps.api.addr_validate(&msg.admin)?,
+        splitter: deps.api.addr_validate(&msg.splitter)?,
+        denom: msg.denom,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("admin", config.admin))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ArchwayResult<ContractError> {
+    match msg {
+        ExecuteMsg::Tip { memo } => execute_tip(deps, env, info, memo),
+        ExecuteMsg::UpdateSplitter { splitter } => {
+            execute_update_splitter(deps, env, info, splitter)
+        }
+    }
+}
+
+fn execute_tip(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    memo: Option<String>,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only a single coin of the configured denom is accepted
+    if info.funds.len() != 1
+        || info.funds[0].denom != config.denom
+        || info.funds[0].amount.is_zero()
+    {
+        return Err(ContractError::InvalidTip {
+            denom: config.denom,
+        });
+    }
+    let amount = info.funds[0].amount;
+
+    let mut donor = donors()
+        .may_load(deps.storage, info.sender.clone())?
+        .unwrap_or(Donor {
+            address: info.sender.clone(),
+            total: Uint128::zero(),
+            tips: 0,
+        });
+    donor.total = donor.total.checked_add(amount)?;
+    donor.tips += 1;
+    donors().save(deps.storage, info.sender.clone(), &donor)?;
+
+    let id = LAST_TIP_ID.may_load(deps.storage)?.unwrap_or_default() + 1;
+    LAST_TIP_ID.save(deps.storage, &id)?;
+    TIPS.save(
+        deps.storage,
+        id,
+        &Tip {
+            id,
+            donor: info.sender.clone(),
+            amount,
+            memo: memo.clone(),
+            time: env.block.time,
+        },
+    )?;
+
+    // Deposited rewards are claimed by the recipients of the splitter
+    let deposit_msg = WasmMsg::Execute {
+        contract_addr: config.splitter.to_string(),
+        msg: to_binary(&SplitterExecuteMsg::Deposit { memo: memo.clone() })?,
+        funds: info.funds,
+    };
+
+    Ok(Response::new()
+        .add_message(deposit_msg)
+        .add_attribute("action", "tip")
+        .add_attribute("id", id.to_string())
+        .add_attribute("donor", info.sender)
+        .add_attribute("amount", amount)
+        .add_attribute("memo", memo.unwrap_or_default()))
+}
+
+fn execute_update_splitter(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    splitter: String,
+) -> ArchwayResult<ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    config.splitter = deps.api.addr_validate(&splitter)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_splitter"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Donor { address } => to_binary(&query_donor(deps, address)?),
+        QueryMsg::Leaderboard { limit } => to_binary(&query_leaderboard(deps, limit)?),
+        QueryMsg::Tips { start_after, limit } => to_binary(&query_tips(deps, start_after, limit)?),
+    }
+}
+
+fn query_donor(deps: Deps<ArchwayQuery>, address: String) -> StdResult<Donor> {
+    let address = deps.api.addr_validate(&address)?;
+    let donor = donors().load(deps.storage, address)?;
+    Ok(donor)
+}
+
+fn query_leaderboard(deps: Deps<ArchwayQuery>, limit: Option<u8>) -> StdResult<Vec<Donor>> {
+    let limit = limit.unwrap_or(10) as usize;
+
+    let leaderboard = donors()
+        .idx
+        .total
+        .range(deps.storage, None, None, Order::Descending)
+        .take(limit)
+        .map(|item| {
+            let (_, donor) = item?;
+            Ok(donor)
+        })
+        .collect::<StdResult<Vec<Donor>>>()?;
+
+    Ok(leaderboard)
+}
+
+fn query_tips(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Tip>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    TIPS.range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, tip) = item?;
+            Ok(tip)
+        })
+        .collect::<StdResult<Vec<Tip>>>()
+}
@@ -0,0 +1,39 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+
+use crate::state::{Config, Donor, Tip};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin: String,
+    pub splitter: String,
+    pub denom: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Deposit the sent funds to the splitter for its recipients and record the donor
+    // The memo is kept in the tip history and passed to the deposit history of the splitter
+    Tip { memo: Option<String> },
+    // Update the splitter tips are deposited to
+    UpdateSplitter { splitter: String },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Returns the contract config
+    #[returns(Config)]
+    Config {},
+    // Returns a single donor
+    #[returns(Donor)]
+    Donor { address: String },
+    // Returns the donors with the highest totals
+    #[returns(Vec<Donor>)]
+    Leaderboard { limit: Option<u8> },
+    // Returns the tips with their memos, oldest first
+    #[returns(Vec<Tip>)]
+    Tips {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+}
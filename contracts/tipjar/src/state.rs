@@ -0,0 +1,59 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
+
+// Contract configuration
+// Splitter is the contract every tip is deposited to
+// Denom is the only token accepted as a tip
+#[cw_serde]
+pub struct Config {
+    pub admin: Addr,
+    pub splitter: Addr,
+    pub denom: String,
+}
+
+// Total amount tipped by a donor
+#[cw_serde]
+pub struct Donor {
+    pub address: Addr,
+    pub total: Uint128,
+    pub tips: u64,
+}
+
+// Single tip with the memo of its donor
+#[cw_serde]
+pub struct Tip {
+    pub id: u64,
+    pub donor: Addr,
+    pub amount: Uint128,
+    pub memo: Option<String>,
+    pub time: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+pub const TIPS: Map<u64, Tip> = Map::new("tips");
+pub const LAST_TIP_ID: Item<u64> = Item::new("last_tip_id");
+
+pub struct DonorIndexes<'a> {
+    pub total: MultiIndex<'a, u128, Donor, Addr>,
+}
+
+impl<'a> IndexList<Donor> for DonorIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Donor>> + '_> {
+        let v: Vec<&dyn Index<Donor>> = vec![&self.total];
+        Box::new(v.into_iter())
+    }
+}
+
+// Donors indexed by their total for the leaderboard
+pub fn donors<'a>() -> IndexedMap<'a, Addr, Donor, DonorIndexes<'a>> {
+    let indexes = DonorIndexes {
+        total: MultiIndex::new(
+            |_pk: &[u8], donor: &Donor| donor.total.u128(),
+            "donors",
+            "donors__total",
+        ),
+    };
+    IndexedMap::new("donors", indexes)
+}
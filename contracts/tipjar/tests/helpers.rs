@@ -0,0 +1,109 @@
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use cw_multi_test::{Contract, ContractWrapper, Executor};
+use pantheon_archway_mock::{archway_app, ArchwayApp};
+use pantheon_tipjar::msg::InstantiateMsg;
+use pantheon_utils::Share;
+
+pub fn tipjar_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(
+        pantheon_tipjar::contract::execute,
+        pantheon_tipjar::contract::instantiate,
+        pantheon_tipjar::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub fn splitter_contract() -> Box<dyn Contract<ArchwayMsg, ArchwayQuery>> {
+    let contract = ContractWrapper::new(
+        pantheon_splitter::contract::execute,
+        pantheon_splitter::contract::instantiate,
+        pantheon_splitter::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub const ADMIN: &str = "admin";
+pub const USER: &str = "user";
+pub const USER2: &str = "user2";
+pub const CREATOR: &str = "creator";
+
+pub const DENOM: &str = "aconst";
+
+pub fn mock_app() -> ArchwayApp {
+    archway_app(|bank, _, storage| {
+        bank.init_balance(
+            storage,
+            &Addr::unchecked(ADMIN),
+            vec![Coin {
+                denom: DENOM.to_string(),
+                amount: Uint128::new(1_000_000_000_000_000_000),
+            }],
+        )
+        .unwrap();
+        bank.init_balance(
+            storage,
+            &Addr::unchecked(USER),
+            vec![Coin {
+                denom: DENOM.to_string(),
+                amount: Uint128::new(1_000_000),
+            }],
+        )
+        .unwrap();
+        bank.init_balance(
+            storage,
+            &Addr::unchecked(USER2),
+            vec![Coin {
+                denom: DENOM.to_string(),
+                amount: Uint128::new(1_000_000),
+            }],
+        )
+        .unwrap();
+    })
+}
+
+// Splitter paying the whole tips to the creator
+pub fn instantiate_splitter(app: &mut ArchwayApp) -> Addr {
+    let code_id = app.store_code(splitter_contract());
+
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(ADMIN),
+        &pantheon_splitter::msg::InstantiateMsg {
+            shares: vec![Share {
+                recipient: CREATOR.to_string(),
+                percentage: Decimal::one(),
+                ibc: None,
+                burn: false,
+            }],
+            mutable: false,
+            admin: ADMIN.to_string(),
+            transfer_requires_approval: false,
+            disable_emergency_withdraw: false,
+            restore: None,
+        },
+        &vec![],
+        "Pantheon Splitter",
+        None,
+    )
+    .unwrap()
+}
+
+pub fn proper_instantiate(app: &mut ArchwayApp) -> Addr {
+    let splitter_addr = instantiate_splitter(app);
+    let code_id = app.store_code(tipjar_contract());
+
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(ADMIN),
+        &InstantiateMsg {
+            admin: ADMIN.to_string(),
+            splitter: splitter_addr.to_string(),
+            denom: DENOM.to_string(),
+        },
+        &vec![],
+        "Pantheon Tip Jar",
+        None,
+    )
+    .unwrap()
+}
@@ -0,0 +1,143 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coin, coins, Addr, Coin, Uint128};
+use cw_multi_test::Executor;
+use pantheon_tipjar::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::{Config, Donor, Tip},
+    ContractError,
+};
+
+#[test]
+fn test_happy_path() {
+    let mut app = mock_app();
+    let tipjar_addr = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(USER),
+        tipjar_addr.clone(),
+        &ExecuteMsg::Tip {
+            memo: Some("Great stream".to_string()),
+        },
+        &coins(100, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER2),
+        tipjar_addr.clone(),
+        &ExecuteMsg::Tip { memo: None },
+        &coins(250, DENOM),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(USER),
+        tipjar_addr.clone(),
+        &ExecuteMsg::Tip { memo: None },
+        &coins(200, DENOM),
+    )
+    .unwrap();
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(tipjar_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    let res = app.wrap().query_balance(&config.splitter, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(550));
+
+    // The tips are deposited for the recipients of the splitter
+    let res: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            config.splitter.clone(),
+            &pantheon_splitter::msg::QueryMsg::PendingRewards {
+                recipient: CREATOR.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, vec![coin(550, DENOM)]);
+
+    let res: Vec<Tip> = app
+        .wrap()
+        .query_wasm_smart(
+            tipjar_addr.clone(),
+            &QueryMsg::Tips {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 3);
+    assert_eq!(res[0].donor, Addr::unchecked(USER));
+    assert_eq!(res[0].amount, Uint128::new(100));
+    assert_eq!(res[0].memo, Some("Great stream".to_string()));
+    assert_eq!(res[1].memo, None);
+
+    let res: Vec<Tip> = app
+        .wrap()
+        .query_wasm_smart(
+            tipjar_addr.clone(),
+            &QueryMsg::Tips {
+                start_after: Some(1),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].id, 2);
+    assert_eq!(res[0].donor, Addr::unchecked(USER2));
+
+    let res: Vec<Donor> = app
+        .wrap()
+        .query_wasm_smart(tipjar_addr, &QueryMsg::Leaderboard { limit: None })
+        .unwrap();
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].address, Addr::unchecked(USER));
+    assert_eq!(res[0].total, Uint128::new(300));
+    assert_eq!(res[0].tips, 2);
+    assert_eq!(res[1].address, Addr::unchecked(USER2));
+    assert_eq!(res[1].total, Uint128::new(250));
+}
+
+#[test]
+fn test_invalid_tip() {
+    let mut app = mock_app();
+    let tipjar_addr = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            tipjar_addr.clone(),
+            &ExecuteMsg::Tip { memo: None },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::InvalidTip {
+            denom: DENOM.to_string()
+        }
+        .to_string()
+    )
+}
+
+#[test]
+fn test_invalid_admin() {
+    let mut app = mock_app();
+    let tipjar_addr = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            tipjar_addr.clone(),
+            &ExecuteMsg::UpdateSplitter {
+                splitter: USER.to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    )
+}
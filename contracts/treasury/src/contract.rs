@@ -0,0 +1,251 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    Storage,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{
+    Config, PendingTimelock, Proposal, ProposalStatus, CONFIG, PENDING_TIMELOCK, PROPOSALS,
+    PROPOSAL_COUNT,
+};
+
+use archway_bindings::{ArchwayQuery, ArchwayResult};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "crates.io:pantheon-treasury";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ArchwayResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let admin = deps.api.addr_validate(&msg.admin)?;
+    let config = Config {
+        admin,
+        timelock: msg.timelock,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    PROPOSAL_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::new().add_attribute("admin", config.admin))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ArchwayResult<ContractError> {
+    match msg {
+        ExecuteMsg::ProposeSpend {
+            recipient,
+            amount,
+            description,
+        } => execute_propose_spend(deps, env, info, recipient, amount, description),
+        ExecuteMsg::ExecuteSpend { id } => execute_execute_spend(deps, env, info, id),
+        ExecuteMsg::CancelSpend { id } => execute_cancel_spend(deps, env, info, id),
+        ExecuteMsg::UpdateConfig { admin, timelock } => {
+            execute_update_config(deps, env, info, admin, timelock)
+        }
+    }
+}
+
+fn execute_propose_spend(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Vec<Coin>,
+    description: String,
+) -> ArchwayResult<ContractError> {
+    let config = load_config(deps.storage, &env)?;
+
+    // Only the admin can queue a spend
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if amount.is_empty() || amount.iter().any(|coin| coin.amount.is_zero()) {
+        return Err(ContractError::EmptySpendAmount {});
+    }
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    let id = PROPOSAL_COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+    let proposal = Proposal {
+        id,
+        recipient,
+        amount,
+        description,
+        executable_at: env.block.time.plus_seconds(config.timelock),
+        status: ProposalStatus::Pending,
+    };
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_spend")
+        .add_attribute("proposal_id", id.to_string()))
+}
+
+fn execute_execute_spend(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut proposal = PROPOSALS.load(deps.storage, id)?;
+
+    if proposal.status != ProposalStatus::Pending {
+        return Err(ContractError::ProposalNotPending {});
+    }
+
+    if env.block.time < proposal.executable_at {
+        return Err(ContractError::TimelockNotExpired {});
+    }
+
+    proposal.status = ProposalStatus::Executed;
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: proposal.recipient.to_string(),
+            amount: proposal.amount,
+        })
+        .add_attribute("action", "execute_spend")
+        .add_attribute("proposal_id", id.to_string()))
+}
+
+fn execute_cancel_spend(
+    deps: DepsMut<ArchwayQuery>,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> ArchwayResult<ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut proposal = PROPOSALS.load(deps.storage, id)?;
+
+    if proposal.status != ProposalStatus::Pending {
+        return Err(ContractError::ProposalNotPending {});
+    }
+
+    proposal.status = ProposalStatus::Cancelled;
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_spend")
+        .add_attribute("proposal_id", id.to_string()))
+}
+
+fn execute_update_config(
+    deps: DepsMut<ArchwayQuery>,
+    env: Env,
+    info: MessageInfo,
+    admin: Option<String>,
+    timelock: Option<u64>,
+) -> ArchwayResult<ContractError> {
+    let mut config = load_config(deps.storage, &env)?;
+
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(admin) = admin {
+        config.admin = deps.api.addr_validate(&admin)?;
+    }
+    if let Some(timelock) = timelock {
+        if timelock >= config.timelock {
+            config.timelock = timelock;
+            PENDING_TIMELOCK.remove(deps.storage);
+        } else {
+            // Proposals queued before the change still wait for the current timelock
+            PENDING_TIMELOCK.save(
+                deps.storage,
+                &PendingTimelock {
+                    timelock,
+                    effective_at: env.block.time.plus_seconds(config.timelock),
+                },
+            )?;
+        }
+    }
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+// Loads the config with the pending timelock applied once it is effective
+fn load_config(storage: &mut dyn Storage, env: &Env) -> StdResult<Config> {
+    let mut config = CONFIG.load(storage)?;
+    if let Some(pending) = PENDING_TIMELOCK.may_load(storage)? {
+        if env.block.time >= pending.effective_at {
+            config.timelock = pending.timelock;
+            CONFIG.save(storage, &config)?;
+            PENDING_TIMELOCK.remove(storage);
+        }
+    }
+    Ok(config)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<ArchwayQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&query_config(deps, env)?),
+        QueryMsg::PendingTimelock {} => to_binary(&PENDING_TIMELOCK.may_load(deps.storage)?),
+        QueryMsg::Proposal { id } => to_binary(&PROPOSALS.load(deps.storage, id)?),
+        QueryMsg::Proposals { start_after, limit } => {
+            to_binary(&query_proposals(deps, start_after, limit)?)
+        }
+    }
+}
+
+fn query_config(deps: Deps<ArchwayQuery>, env: Env) -> StdResult<Config> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if let Some(pending) = PENDING_TIMELOCK.may_load(deps.storage)? {
+        if env.block.time >= pending.effective_at {
+            config.timelock = pending.timelock;
+        }
+    }
+    Ok(config)
+}
+
+fn query_proposals(
+    deps: Deps<ArchwayQuery>,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Vec<Proposal>> {
+    let limit = limit.unwrap_or(10) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let proposals = PROPOSALS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (_, proposal) = item?;
+            Ok(proposal)
+        })
+        .collect::<StdResult<Vec<Proposal>>>()?;
+
+    Ok(proposals)
+}
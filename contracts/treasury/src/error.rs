@@ -0,0 +1,20 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Spend amount cannot be empty")]
+    EmptySpendAmount {},
+
+    #[error("Proposal is not pending")]
+    ProposalNotPending {},
+
+    #[error("Timelock has not expired yet")]
+    TimelockNotExpired {},
+}
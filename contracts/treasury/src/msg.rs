@@ -0,0 +1,55 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Coin;
+
+use crate::state::{Config, PendingTimelock, Proposal};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin: String,
+    // Number of seconds between queueing and executing a spend proposal
+    pub timelock: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Queue a new spend proposal
+    ProposeSpend {
+        recipient: String,
+        amount: Vec<Coin>,
+        description: String,
+    },
+    // Send the funds of a proposal after its timelock has expired
+    ExecuteSpend {
+        id: u64,
+    },
+    // Cancel a pending proposal
+    CancelSpend {
+        id: u64,
+    },
+    // Update the admin and the timelock of the treasury
+    // A longer timelock applies immediately, a shorter one only once the current timelock has passed
+    UpdateConfig {
+        admin: Option<String>,
+        timelock: Option<u64>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Returns the contract config
+    #[returns(Config)]
+    Config {},
+    // Returns the lower timelock waiting to apply
+    #[returns(Option<PendingTimelock>)]
+    PendingTimelock {},
+    // Returns a single proposal
+    #[returns(Proposal)]
+    Proposal { id: u64 },
+    // Returns all the proposals
+    #[returns(Vec<Proposal>)]
+    Proposals {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
+}
@@ -0,0 +1,46 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+// Contract configuration
+// Admin can be a single wallet or a cw3 multisig contract
+// Timelock is the number of seconds a proposal has to wait before it can be executed
+#[cw_serde]
+pub struct Config {
+    pub admin: Addr,
+    pub timelock: u64,
+}
+
+#[cw_serde]
+pub enum ProposalStatus {
+    Pending,
+    Executed,
+    Cancelled,
+}
+
+// Spend proposal queued by the admin
+#[cw_serde]
+pub struct Proposal {
+    pub id: u64,
+    pub recipient: Addr,
+    pub amount: Vec<Coin>,
+    pub description: String,
+    pub executable_at: Timestamp,
+    pub status: ProposalStatus,
+}
+
+// Lower timelock waiting for the current timelock to pass before it applies
+// Otherwise the admin could drop the timelock and spend in the same block
+#[cw_serde]
+pub struct PendingTimelock {
+    pub timelock: u64,
+    pub effective_at: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+pub const PENDING_TIMELOCK: Item<PendingTimelock> = Item::new("pending_timelock");
+
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
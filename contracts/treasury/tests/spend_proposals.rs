@@ -0,0 +1,225 @@
+pub mod helpers;
+use helpers::*;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use cw_multi_test::Executor;
+use pantheon_treasury::{
+    msg::{ExecuteMsg, QueryMsg},
+    state::{Config, PendingTimelock, Proposal, ProposalStatus},
+    ContractError,
+};
+
+#[test]
+fn test_happy_path() {
+    let mut app = mock_app();
+    let treasury_addr = proper_instantiate(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        treasury_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        treasury_addr.clone(),
+        &ExecuteMsg::ProposeSpend {
+            recipient: USER.to_string(),
+            amount: coins(400, DENOM),
+            description: "Audit payment".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            treasury_addr.clone(),
+            &ExecuteMsg::ExecuteSpend { id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::TimelockNotExpired {}.to_string()
+    );
+
+    app.update_block(|block| block.time = block.time.plus_seconds(TIMELOCK));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        treasury_addr.clone(),
+        &ExecuteMsg::ExecuteSpend { id: 1 },
+        &vec![],
+    )
+    .unwrap();
+
+    let res = app.wrap().query_balance(USER, DENOM).unwrap();
+    assert_eq!(res.amount, Uint128::new(400));
+
+    let res: Proposal = app
+        .wrap()
+        .query_wasm_smart(treasury_addr, &QueryMsg::Proposal { id: 1 })
+        .unwrap();
+    assert_eq!(res.status, ProposalStatus::Executed);
+}
+
+#[test]
+fn test_cancelled_proposal() {
+    let mut app = mock_app();
+    let treasury_addr = proper_instantiate(&mut app);
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        treasury_addr.clone(),
+        &ExecuteMsg::ProposeSpend {
+            recipient: USER.to_string(),
+            amount: coins(400, DENOM),
+            description: "Audit payment".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        treasury_addr.clone(),
+        &ExecuteMsg::CancelSpend { id: 1 },
+        &vec![],
+    )
+    .unwrap();
+
+    app.update_block(|block| block.time = block.time.plus_seconds(TIMELOCK));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            treasury_addr.clone(),
+            &ExecuteMsg::ExecuteSpend { id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::ProposalNotPending {}.to_string()
+    )
+}
+
+#[test]
+fn test_invalid_admin() {
+    let mut app = mock_app();
+    let treasury_addr = proper_instantiate(&mut app);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER),
+            treasury_addr.clone(),
+            &ExecuteMsg::ProposeSpend {
+                recipient: USER.to_string(),
+                amount: coins(400, DENOM),
+                description: "Audit payment".to_string(),
+            },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::Unauthorized {}.to_string()
+    )
+}
+
+#[test]
+fn test_lower_timelock_is_queued() {
+    let mut app = mock_app();
+    let treasury_addr = proper_instantiate(&mut app);
+
+    app.send_tokens(
+        Addr::unchecked(ADMIN),
+        treasury_addr.clone(),
+        &coins(1_000, DENOM),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        treasury_addr.clone(),
+        &ExecuteMsg::UpdateConfig {
+            admin: None,
+            timelock: Some(0),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Option<PendingTimelock> = app
+        .wrap()
+        .query_wasm_smart(treasury_addr.clone(), &QueryMsg::PendingTimelock {})
+        .unwrap();
+    assert_eq!(
+        res,
+        Some(PendingTimelock {
+            timelock: 0,
+            effective_at: app.block_info().time.plus_seconds(TIMELOCK),
+        })
+    );
+
+    // The current timelock still applies to the proposals of the same block
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        treasury_addr.clone(),
+        &ExecuteMsg::ProposeSpend {
+            recipient: USER.to_string(),
+            amount: coins(400, DENOM),
+            description: "Audit payment".to_string(),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(ADMIN),
+            treasury_addr.clone(),
+            &ExecuteMsg::ExecuteSpend { id: 1 },
+            &vec![],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.source().unwrap().to_string(),
+        ContractError::TimelockNotExpired {}.to_string()
+    );
+
+    app.update_block(|block| block.time = block.time.plus_seconds(TIMELOCK));
+
+    let res: Config = app
+        .wrap()
+        .query_wasm_smart(treasury_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.timelock, 0);
+
+    // A longer timelock applies immediately
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        treasury_addr.clone(),
+        &ExecuteMsg::UpdateConfig {
+            admin: None,
+            timelock: Some(TIMELOCK * 2),
+        },
+        &vec![],
+    )
+    .unwrap();
+
+    let res: Config = app
+        .wrap()
+        .query_wasm_smart(treasury_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.timelock, TIMELOCK * 2);
+
+    let res: Option<PendingTimelock> = app
+        .wrap()
+        .query_wasm_smart(treasury_addr, &QueryMsg::PendingTimelock {})
+        .unwrap();
+    assert_eq!(res, None);
+}
@@ -0,0 +1,211 @@
+use std::fmt::Debug;
+
+use anyhow::{bail, Result as AnyResult};
+use archway_bindings::{ArchwayMsg, ArchwayQuery};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    testing::MockApi, to_binary, Addr, Api, Binary, BlockInfo, Coin, CustomQuery, Empty,
+    MemoryStorage, Querier, Storage,
+};
+use cw_multi_test::{
+    App, AppResponse, BankKeeper, BankSudo, BasicAppBuilder, CosmosRouter, Module, WasmKeeper,
+};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+
+// App handling the Archway messages and queries with ArchwayModule
+pub type ArchwayApp =
+    App<BankKeeper, MockApi, MemoryStorage, ArchwayModule, WasmKeeper<ArchwayMsg, ArchwayQuery>>;
+
+// Metadata set with UpdateContractMetadata, returned by the ContractMetadata query
+#[cw_serde]
+pub struct ContractMetadata {
+    pub owner_address: String,
+    pub rewards_address: String,
+}
+
+// Rewards record returned by the RewardsRecords query
+#[cw_serde]
+pub struct RewardsRecord {
+    pub id: u64,
+    pub rewards_address: String,
+    pub rewards: Vec<Coin>,
+    pub calculated_height: i64,
+    pub calculated_time: String,
+}
+
+#[cw_serde]
+pub struct RewardsRecordsResponse {
+    pub records: Vec<RewardsRecord>,
+    pub pagination: Option<Empty>,
+}
+
+// Contract metadata, keyed by the contract address
+const METADATA: Map<String, ContractMetadata> = Map::new("archway_metadata");
+
+// Rewards waiting to be withdrawn, keyed by the rewards address
+const REWARDS: Map<String, Vec<Coin>> = Map::new("archway_rewards");
+
+// Stub of the Archway rewards module
+// Rewards are recorded with add_rewards and minted to the rewards address on withdrawal
+#[derive(Default)]
+pub struct ArchwayModule {}
+
+impl ArchwayModule {
+    pub fn add_rewards(
+        &self,
+        storage: &mut dyn Storage,
+        rewards_address: &str,
+        amount: Vec<Coin>,
+    ) -> AnyResult<()> {
+        let mut rewards = REWARDS
+            .may_load(storage, rewards_address.to_string())?
+            .unwrap_or_default();
+
+        for coin in amount {
+            match rewards.iter_mut().find(|reward| reward.denom == coin.denom) {
+                Some(reward) => reward.amount += coin.amount,
+                None => rewards.push(coin),
+            }
+        }
+        REWARDS.save(storage, rewards_address.to_string(), &rewards)?;
+
+        Ok(())
+    }
+}
+
+impl Module for ArchwayModule {
+    type ExecT = ArchwayMsg;
+    type QueryT = ArchwayQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: ArchwayMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            ArchwayMsg::UpdateContractMetadata {
+                contract_address,
+                owner_address,
+                rewards_address,
+            } => {
+                let contract_address = contract_address.unwrap_or_else(|| sender.to_string());
+                let mut metadata = METADATA
+                    .may_load(storage, contract_address.clone())?
+                    .unwrap_or(ContractMetadata {
+                        owner_address: sender.to_string(),
+                        rewards_address: String::new(),
+                    });
+
+                // Only the owner can update the metadata once it is set
+                if metadata.owner_address != sender.as_str() {
+                    bail!("unauthorized metadata update by {}", sender);
+                }
+
+                if let Some(owner_address) = owner_address {
+                    metadata.owner_address = owner_address;
+                }
+                if let Some(rewards_address) = rewards_address {
+                    metadata.rewards_address = rewards_address;
+                }
+                METADATA.save(storage, contract_address, &metadata)?;
+
+                Ok(AppResponse::default())
+            }
+            ArchwayMsg::WithdrawRewards { .. } => {
+                let rewards = REWARDS
+                    .may_load(storage, sender.to_string())?
+                    .unwrap_or_default();
+                REWARDS.remove(storage, sender.to_string());
+
+                if rewards.is_empty() {
+                    return Ok(AppResponse::default());
+                }
+
+                router.sudo(
+                    api,
+                    storage,
+                    block,
+                    BankSudo::Mint {
+                        to_address: sender.to_string(),
+                        amount: rewards,
+                    }
+                    .into(),
+                )
+            }
+            _ => bail!("unsupported archway message"),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _msg: Empty,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        bail!("sudo is not supported by the archway module")
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        block: &BlockInfo,
+        request: ArchwayQuery,
+    ) -> AnyResult<Binary> {
+        match request {
+            ArchwayQuery::ContractMetadata { contract_address } => {
+                Ok(to_binary(&METADATA.load(storage, contract_address)?)?)
+            }
+            // The rewards waiting to be withdrawn are returned as a single record
+            ArchwayQuery::RewardsRecords {
+                rewards_address, ..
+            } => {
+                let records = REWARDS
+                    .may_load(storage, rewards_address.clone())?
+                    .map(|rewards| RewardsRecord {
+                        id: 1,
+                        rewards_address,
+                        rewards,
+                        calculated_height: block.height as i64,
+                        calculated_time: block.time.to_string(),
+                    })
+                    .into_iter()
+                    .collect();
+
+                Ok(to_binary(&RewardsRecordsResponse {
+                    records,
+                    pagination: None,
+                })?)
+            }
+            _ => bail!("unsupported archway query"),
+        }
+    }
+}
+
+// Creates an app with the Archway module, the init function funds the accounts
+pub fn archway_app<F>(init_fn: F) -> ArchwayApp
+where
+    F: FnOnce(&BankKeeper, &ArchwayModule, &mut dyn Storage),
+{
+    BasicAppBuilder::<ArchwayMsg, ArchwayQuery>::new_custom()
+        .with_custom(ArchwayModule::default())
+        .build(|router, _, storage| init_fn(&router.bank, &router.custom, storage))
+}
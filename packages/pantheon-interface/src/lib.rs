@@ -0,0 +1,234 @@
+use cosmwasm_std::Empty;
+use cw_orch::prelude::*;
+use cw_orch::{anyhow, interface};
+
+// The contracts use the Archway bindings, so they can not be wrapped for the Empty mock
+// environment of cw-orch and only the wasm artifacts are uploadable
+// Build the artifacts with ./scripts/optimize.sh before uploading
+
+#[interface(
+    pantheon_bounty::msg::InstantiateMsg,
+    pantheon_bounty::msg::ExecuteMsg,
+    pantheon_bounty::msg::QueryMsg,
+    Empty
+)]
+pub struct Bounty;
+
+impl<Chain: CwEnv> Uploadable for Bounty<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_bounty")
+            .unwrap()
+    }
+}
+
+#[interface(
+    pantheon_crowdfund::msg::InstantiateMsg,
+    pantheon_crowdfund::msg::ExecuteMsg,
+    pantheon_crowdfund::msg::QueryMsg,
+    Empty
+)]
+pub struct Crowdfund;
+
+impl<Chain: CwEnv> Uploadable for Crowdfund<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_crowdfund")
+            .unwrap()
+    }
+}
+
+#[interface(
+    custom_contract::msg::InstantiateMsg,
+    custom_contract::msg::ExecuteMsg,
+    custom_contract::msg::QueryMsg,
+    Empty
+)]
+pub struct CustomContract;
+
+impl<Chain: CwEnv> Uploadable for CustomContract<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("custom_contract")
+            .unwrap()
+    }
+}
+
+#[interface(
+    pantheon_factory::msg::InstantiateMsg,
+    pantheon_factory::msg::ExecuteMsg,
+    pantheon_factory::msg::QueryMsg,
+    Empty
+)]
+pub struct Factory;
+
+impl<Chain: CwEnv> Uploadable for Factory<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_factory")
+            .unwrap()
+    }
+}
+
+#[interface(
+    pantheon_multisig::msg::InstantiateMsg,
+    pantheon_multisig::msg::ExecuteMsg,
+    pantheon_multisig::msg::QueryMsg,
+    Empty
+)]
+pub struct Multisig;
+
+impl<Chain: CwEnv> Uploadable for Multisig<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_multisig")
+            .unwrap()
+    }
+}
+
+#[interface(
+    pantheon_pair_adapter::msg::InstantiateMsg,
+    pantheon_pair_adapter::msg::ExecuteMsg,
+    pantheon_pair_adapter::msg::QueryMsg,
+    Empty
+)]
+pub struct PairAdapter;
+
+impl<Chain: CwEnv> Uploadable for PairAdapter<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_pair_adapter")
+            .unwrap()
+    }
+}
+
+#[interface(
+    pantheon_payroll::msg::InstantiateMsg,
+    pantheon_payroll::msg::ExecuteMsg,
+    pantheon_payroll::msg::QueryMsg,
+    Empty
+)]
+pub struct Payroll;
+
+impl<Chain: CwEnv> Uploadable for Payroll<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_payroll")
+            .unwrap()
+    }
+}
+
+#[interface(
+    pantheon_share_token::msg::InstantiateMsg,
+    pantheon_share_token::msg::ExecuteMsg,
+    pantheon_share_token::msg::QueryMsg,
+    Empty
+)]
+pub struct ShareToken;
+
+impl<Chain: CwEnv> Uploadable for ShareToken<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_share_token")
+            .unwrap()
+    }
+}
+
+#[interface(
+    pantheon_splitter::msg::InstantiateMsg,
+    pantheon_splitter::msg::ExecuteMsg,
+    pantheon_splitter::msg::QueryMsg,
+    Empty
+)]
+pub struct Splitter;
+
+impl<Chain: CwEnv> Uploadable for Splitter<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_splitter")
+            .unwrap()
+    }
+}
+
+#[interface(
+    pantheon_tipjar::msg::InstantiateMsg,
+    pantheon_tipjar::msg::ExecuteMsg,
+    pantheon_tipjar::msg::QueryMsg,
+    Empty
+)]
+pub struct Tipjar;
+
+impl<Chain: CwEnv> Uploadable for Tipjar<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_tipjar")
+            .unwrap()
+    }
+}
+
+#[interface(
+    pantheon_treasury::msg::InstantiateMsg,
+    pantheon_treasury::msg::ExecuteMsg,
+    pantheon_treasury::msg::QueryMsg,
+    Empty
+)]
+pub struct Treasury;
+
+impl<Chain: CwEnv> Uploadable for Treasury<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("pantheon_treasury")
+            .unwrap()
+    }
+}
+
+// Every Pantheon contract on one chain, e.g. Constantine, Triomphe or a local node
+pub struct Pantheon<Chain: CwEnv> {
+    pub bounty: Bounty<Chain>,
+    pub crowdfund: Crowdfund<Chain>,
+    pub custom: CustomContract<Chain>,
+    pub factory: Factory<Chain>,
+    pub multisig: Multisig<Chain>,
+    pub pair_adapter: PairAdapter<Chain>,
+    pub payroll: Payroll<Chain>,
+    pub share_token: ShareToken<Chain>,
+    pub splitter: Splitter<Chain>,
+    pub tipjar: Tipjar<Chain>,
+    pub treasury: Treasury<Chain>,
+}
+
+impl<Chain: CwEnv> Pantheon<Chain> {
+    // Contract ids are the crate names, used as keys in the cw-orch state file
+    pub fn new(chain: Chain) -> Self {
+        Self {
+            bounty: Bounty::new("pantheon_bounty", chain.clone()),
+            crowdfund: Crowdfund::new("pantheon_crowdfund", chain.clone()),
+            custom: CustomContract::new("custom_contract", chain.clone()),
+            factory: Factory::new("pantheon_factory", chain.clone()),
+            multisig: Multisig::new("pantheon_multisig", chain.clone()),
+            pair_adapter: PairAdapter::new("pantheon_pair_adapter", chain.clone()),
+            payroll: Payroll::new("pantheon_payroll", chain.clone()),
+            share_token: ShareToken::new("pantheon_share_token", chain.clone()),
+            splitter: Splitter::new("pantheon_splitter", chain.clone()),
+            tipjar: Tipjar::new("pantheon_tipjar", chain.clone()),
+            treasury: Treasury::new("pantheon_treasury", chain.clone()),
+        }
+    }
+
+    // Uploads the code of every contract
+    pub fn upload(&self) -> anyhow::Result<()> {
+        self.bounty.upload()?;
+        self.crowdfund.upload()?;
+        self.custom.upload()?;
+        self.factory.upload()?;
+        self.multisig.upload()?;
+        self.pair_adapter.upload()?;
+        self.payroll.upload()?;
+        self.share_token.upload()?;
+        self.splitter.upload()?;
+        self.tipjar.upload()?;
+        self.treasury.upload()?;
+
+        Ok(())
+    }
+}
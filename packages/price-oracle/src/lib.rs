@@ -0,0 +1,33 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, CustomQuery, Decimal, QuerierWrapper, StdResult};
+
+// Interface of the price oracle adapters used by the splitter to compare USD amounts
+// An adapter wraps a single price feed, so the splitter is not tied to the messages of one oracle
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PriceOracleQueryMsg {
+    // Returns the USD price of one display unit of the denom, e.g. 1 ARCH instead of 1 aarch
+    #[returns(PriceResponse)]
+    Price { denom: String },
+}
+
+#[cw_serde]
+pub struct PriceResponse {
+    pub price: Decimal,
+}
+
+// Queries the USD price of a denom from an adapter
+pub fn query_price<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    oracle: &Addr,
+    denom: impl Into<String>,
+) -> StdResult<Decimal> {
+    let res: PriceResponse = querier.query_wasm_smart(
+        oracle,
+        &PriceOracleQueryMsg::Price {
+            denom: denom.into(),
+        },
+    )?;
+    Ok(res.price)
+}
@@ -0,0 +1,65 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_binary, Addr, Coin, CustomQuery, QuerierWrapper, StdResult, Uint128, WasmMsg,
+};
+
+// Interface of the swap adapters used by the splitter to swap its balances before a distribution
+// An adapter wraps a single DEX, so the splitter is not tied to the messages of one AMM
+// Adapters can extend their own messages as long as these variants are kept
+
+#[cw_serde]
+pub enum SwapAdapterExecuteMsg {
+    // Swap the sent funds to the ask denom and send the result back to the sender
+    // Fails when less than min_receive is returned
+    Swap {
+        ask_denom: String,
+        min_receive: Uint128,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum SwapAdapterQueryMsg {
+    // Returns the amount of the ask denom the offer currently swaps to
+    #[returns(QuoteResponse)]
+    Quote { offer: Coin, ask_denom: String },
+}
+
+#[cw_serde]
+pub struct QuoteResponse {
+    pub amount: Uint128,
+}
+
+// Queries the quote of an adapter
+pub fn query_quote<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    adapter: &Addr,
+    offer: Coin,
+    ask_denom: impl Into<String>,
+) -> StdResult<Uint128> {
+    let res: QuoteResponse = querier.query_wasm_smart(
+        adapter,
+        &SwapAdapterQueryMsg::Quote {
+            offer,
+            ask_denom: ask_denom.into(),
+        },
+    )?;
+    Ok(res.amount)
+}
+
+// Creates the message swapping the offer through an adapter
+pub fn swap_msg(
+    adapter: &Addr,
+    offer: Coin,
+    ask_denom: impl Into<String>,
+    min_receive: Uint128,
+) -> StdResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: adapter.to_string(),
+        msg: to_binary(&SwapAdapterExecuteMsg::Swap {
+            ask_denom: ask_denom.into(),
+            min_receive,
+        })?,
+        funds: vec![offer],
+    })
+}
@@ -1,11 +1,34 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Decimal;
 
+pub mod premium;
+pub mod rbac;
+
+pub use rbac::{Rbac, RbacError};
+
 // Share consists of an address and a percentage
 // Address is the address of the recipient
 // Percentage is the percentage of the total amount to be sent to the recipient
+// Ibc is the optional route used to pay the recipient on another chain
+// Burn shares destroy their portion instead of paying the recipient, who only labels the share
 #[cw_serde]
 pub struct Share {
     pub recipient: String,
     pub percentage: Decimal,
+    pub ibc: Option<IbcRoute>,
+    #[serde(default)]
+    pub burn: bool,
+}
+
+// IbcRoute consists of a channel, a remote address and a timeout
+// Channel is the ICS-20 transfer channel on this chain
+// Remote address is the address of the recipient on the other chain
+// Timeout is the number of seconds the transfer packet stays valid
+// Memo is attached to the ICS-20 packet, e.g. for packet forwarding or swaps on the destination
+#[cw_serde]
+pub struct IbcRoute {
+    pub channel_id: String,
+    pub remote_address: String,
+    pub timeout_seconds: u64,
+    pub memo: Option<String>,
 }
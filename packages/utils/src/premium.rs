@@ -0,0 +1,41 @@
+use cosmwasm_std::{Binary, Coin, CosmosMsg};
+use prost::Message;
+
+// Premiums are the flat fees of the Archway rewards module
+const MSG_SET_FLAT_FEE_TYPE_URL: &str = "/archway.rewards.v1.MsgSetFlatFee";
+
+#[derive(Clone, PartialEq, Message)]
+struct ProtoCoin {
+    #[prost(string, tag = "1")]
+    denom: String,
+    #[prost(string, tag = "2")]
+    amount: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct MsgSetFlatFee {
+    #[prost(string, tag = "1")]
+    sender_address: String,
+    #[prost(string, tag = "2")]
+    contract_address: String,
+    #[prost(message, optional, tag = "3")]
+    flat_fee_amount: Option<ProtoCoin>,
+}
+
+// Sets the premium paid on top of the gas fees by every transaction calling the contract
+// The sender must own the reward metadata of the contract, a zero amount removes the premium
+pub fn set_premium<T>(sender_address: String, contract_address: String, fee: Coin) -> CosmosMsg<T> {
+    let msg = MsgSetFlatFee {
+        sender_address,
+        contract_address,
+        flat_fee_amount: Some(ProtoCoin {
+            denom: fee.denom,
+            amount: fee.amount.to_string(),
+        }),
+    };
+
+    CosmosMsg::Stargate {
+        type_url: MSG_SET_FLAT_FEE_TYPE_URL.to_string(),
+        value: Binary::from(msg.encode_to_vec()),
+    }
+}
@@ -0,0 +1,127 @@
+use cosmwasm_std::{Addr, Empty, Order, StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RbacError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+}
+
+// Role based access control, the members of a role can run the actions permitted to the role
+// Actions are named by the contract, e.g. after the execute message they guard
+// The owner of the contract is not stored and is allowed every action
+pub struct Rbac<'a> {
+    // Members of each role keyed by role and address
+    members: Map<'a, (String, Addr), Empty>,
+    // Roles permitted to run each action keyed by action and role
+    permissions: Map<'a, (String, String), Empty>,
+}
+
+impl<'a> Rbac<'a> {
+    pub const fn new(members_namespace: &'a str, permissions_namespace: &'a str) -> Self {
+        Rbac {
+            members: Map::new(members_namespace),
+            permissions: Map::new(permissions_namespace),
+        }
+    }
+
+    pub fn grant_role(
+        &self,
+        storage: &mut dyn Storage,
+        role: &str,
+        address: &Addr,
+    ) -> StdResult<()> {
+        self.members
+            .save(storage, (role.to_string(), address.clone()), &Empty {})
+    }
+
+    pub fn revoke_role(&self, storage: &mut dyn Storage, role: &str, address: &Addr) {
+        self.members
+            .remove(storage, (role.to_string(), address.clone()))
+    }
+
+    pub fn has_role(&self, storage: &dyn Storage, role: &str, address: &Addr) -> bool {
+        self.members
+            .has(storage, (role.to_string(), address.clone()))
+    }
+
+    pub fn role_members(&self, storage: &dyn Storage, role: &str) -> StdResult<Vec<Addr>> {
+        self.members
+            .prefix(role.to_string())
+            .keys(storage, None, None, Order::Ascending)
+            .collect()
+    }
+
+    // Replaces the actions permitted to a role, an empty list leaves the role without permissions
+    pub fn set_role_actions(
+        &self,
+        storage: &mut dyn Storage,
+        role: &str,
+        actions: &[String],
+    ) -> StdResult<()> {
+        for action in self.role_actions(storage, role)? {
+            self.permissions.remove(storage, (action, role.to_string()));
+        }
+        for action in actions {
+            self.permissions
+                .save(storage, (action.clone(), role.to_string()), &Empty {})?;
+        }
+
+        Ok(())
+    }
+
+    pub fn role_actions(&self, storage: &dyn Storage, role: &str) -> StdResult<Vec<String>> {
+        let mut actions = vec![];
+        for key in self.permissions.keys(storage, None, None, Order::Ascending) {
+            let (action, key_role) = key?;
+            if key_role == role {
+                actions.push(action);
+            }
+        }
+
+        Ok(actions)
+    }
+
+    pub fn is_allowed(
+        &self,
+        storage: &dyn Storage,
+        owner: Option<&Addr>,
+        sender: &Addr,
+        action: &str,
+    ) -> StdResult<bool> {
+        if owner == Some(sender) {
+            return Ok(true);
+        }
+
+        for role in
+            self.permissions
+                .prefix(action.to_string())
+                .keys(storage, None, None, Order::Ascending)
+        {
+            if self.has_role(storage, &role?, sender) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    // Fails with Unauthorized unless the sender is the owner or a member of a permitted role
+    pub fn assert_allowed(
+        &self,
+        storage: &dyn Storage,
+        owner: Option<&Addr>,
+        sender: &Addr,
+        action: &str,
+    ) -> Result<(), RbacError> {
+        if !self.is_allowed(storage, owner, sender, action)? {
+            return Err(RbacError::Unauthorized {});
+        }
+
+        Ok(())
+    }
+}
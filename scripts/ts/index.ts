@@ -60,6 +60,7 @@ const CUSTOM_CONTRACT_CODE_ID = process.env.CUSTOM_CONTRACT_CODE_ID || ""
             { recipient: user2Account.address, percentage: "0.75" },
           ],
           mutable: true,
+          transfer_requires_approval: false,
           label: "My Splitter Contract",
         },
       },